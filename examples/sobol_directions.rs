@@ -0,0 +1,225 @@
+//! Generate `src/engine/math/sobol_directions.rs`, the direction numbers of the Sobol sequence:
+//!
+//!     cargo run --release --example sobol_directions > src/engine/math/sobol_directions.rs
+//!
+//! The dimensions use the primitive polynomials by increasing degree and inner coefficients. The first ones take
+//! the direction numbers of Joe and Kuo, and each following one the best of a few random candidates, scored like
+//! Joe and Kuo do by the t-values of its two-dimensional projections with all the previous dimensions
+
+/// Number of dimensions of the table, including the first one which has no polynomial
+const NUM_SOBOL_DIMENSIONS : usize = 1024;
+/// The t-values are measured over the first 2^2 to 2^M points
+const M : usize = 12;
+/// Random candidates tried for the direction numbers of each dimension
+const CANDIDATES : usize = 48;
+
+/// Direction numbers of the first dimensions following the first one, from Joe and Kuo's new-joe-kuo-6.21201
+const JOE_KUO_DIRECTIONS : [&[u64]; 15] = [
+    &[1], &[1, 3], &[1, 3, 1], &[1, 1, 1], &[1, 1, 3, 3], &[1, 3, 5, 13], &[1, 1, 5, 5, 17], &[1, 1, 5, 5, 5],
+    &[1, 1, 7, 11, 19], &[1, 1, 5, 1, 1], &[1, 1, 1, 3, 11], &[1, 3, 5, 5, 31], &[1, 3, 3, 9, 7, 49],
+    &[1, 1, 1, 15, 21, 21], &[1, 3, 1, 13, 27, 49],
+];
+
+/// Whether the polynomial over GF(2) whose coefficients are the bits of `p` is primitive
+fn is_primitive(p : u64, degree : usize) -> bool {
+    let mul_mod = |mut a : u64, mut b : u64| {
+        let mut result = 0;
+        while b != 0 {
+            if b & 1 != 0 {
+                result ^= a;
+            }
+            b >>= 1;
+            a <<= 1;
+            if a & (1 << degree) != 0 {
+                a ^= p;
+            }
+        }
+        result
+    };
+    let pow_mod = |mut e : u64| {
+        let mut base = if degree == 1 { 2 ^ p } else { 2 };
+        let mut result = 1;
+        while e != 0 {
+            if e & 1 != 0 {
+                result = mul_mod(result, base);
+            }
+            base = mul_mod(base, base);
+            e >>= 1;
+        }
+        result
+    };
+
+    // x has to be of order 2^degree - 1, and not of any of its divisors
+    let order = (1u64 << degree) - 1;
+    if pow_mod(order) != 1 {
+        return false;
+    }
+    let mut n = order;
+    let mut q = 2;
+    while q * q <= n {
+        if n.is_multiple_of(q) {
+            if pow_mod(order / q) == 1 {
+                return false;
+            }
+            while n.is_multiple_of(q) {
+                n /= q;
+            }
+        }
+        q += 1;
+    }
+    n == 1 || pow_mod(order / n) != 1
+}
+
+/// The first `M` columns of the generator matrix, with the digit `r` of a column at the bit `M - 1 - r`
+fn columns(s : usize, a : u64, m : &[u64]) -> [u32; M] {
+    let mut v = [0u32; M];
+    for k in 0..M {
+        v[k] = if k < s {
+            (m[k] as u32) << (M - 1 - k)
+        } else {
+            let mut column = v[k - s] ^ (v[k - s] >> s);
+            for j in 1..s {
+                if (a >> (s - 1 - j)) & 1 != 0 {
+                    column ^= v[k - j];
+                }
+            }
+            column
+        };
+    }
+    v
+}
+
+/// The rows of the generator matrix, each one a mask of the columns
+fn rows(v : &[u32; M]) -> [u16; M] {
+    let mut r = [0u16; M];
+    for (row, mask) in r.iter_mut().enumerate() {
+        for (c, column) in v.iter().enumerate() {
+            if (column >> (M - 1 - row)) & 1 != 0 {
+                *mask |= 1 << c;
+            }
+        }
+    }
+    r
+}
+
+/// Whether the rows are linearly independent over GF(2)
+fn independent(rows : &[u16]) -> bool {
+    let mut basis = [0u16; 16];
+    for &row in rows {
+        let mut v = row;
+        while v != 0 {
+            let h = 15 - v.leading_zeros() as usize;
+            if basis[h] == 0 {
+                basis[h] = v;
+                break;
+            }
+            v ^= basis[h];
+        }
+        if v == 0 {
+            return false;
+        }
+    }
+    true
+}
+
+/// The t-value of the first 2^m points of the two dimensions: the smallest t such that any `m - t` first rows
+/// taken from both generator matrices are independent
+fn t_value(ri : &[u16; M], rj : &[u16; M], m : usize) -> usize {
+    let mask = ((1u32 << m) - 1) as u16;
+    let mut selected = [0u16; 2 * M];
+    'search: for t in 0..=m {
+        let k = m - t;
+        for d1 in 0..=k {
+            let d2 = k - d1;
+            for r in 0..d1 {
+                selected[r] = ri[r] & mask;
+            }
+            for r in 0..d2 {
+                selected[d1 + r] = rj[r] & mask;
+            }
+            if !independent(&selected[..k]) {
+                continue 'search;
+            }
+        }
+        return t;
+    }
+    m
+}
+
+/// Sum of the squared t-values of the projections of a dimension with all the previous ones
+fn cost(previous : &[[u16; M]], rj : &[u16; M]) -> u64 {
+    let mut total = 0;
+    for ri in previous {
+        for m in 2..=M {
+            let t = t_value(ri, rj, m) as u64;
+            total += t * t;
+        }
+    }
+    total
+}
+
+/// Xorshift generator, so that the table doesn't depend on the random generators of the renderer
+struct Xorshift(u64);
+
+impl Xorshift{
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+fn main() {
+    let mut polynomials = Vec::new();
+    let (mut degree, mut a) = (1, 0u64);
+    while polynomials.len() < NUM_SOBOL_DIMENSIONS - 1 {
+        if is_primitive((1 << degree) | (a << 1) | 1, degree) {
+            polynomials.push((degree, a));
+        }
+        a += 1;
+        if a == 1 << (degree - 1) {
+            degree += 1;
+            a = 0;
+        }
+    }
+
+    // The first dimension is the van der Corput sequence, with the identity as its generator matrix
+    let mut identity = [0u32; M];
+    for (c, column) in identity.iter_mut().enumerate() {
+        *column = 1 << (M - 1 - c);
+    }
+    let mut previous = vec![rows(&identity)];
+    let mut rng = Xorshift(0x9e37_79b9_7f4a_7c15);
+    let mut directions = Vec::with_capacity(polynomials.len());
+    for (d, &(s, a)) in polynomials.iter().enumerate() {
+        let m = match JOE_KUO_DIRECTIONS.get(d) {
+            Some(m) => m.to_vec(),
+            None => {
+                let mut best : Option<(u64, Vec<u64>)> = None;
+                for _ in 0..CANDIDATES {
+                    // The direction number k is odd and less than 2^k
+                    let m : Vec<u64> = (1..=s).map(|k| (rng.next() % (1 << k)) | 1).collect();
+                    let c = cost(&previous, &rows(&columns(s, a, &m)));
+                    if best.as_ref().is_none_or(|(best_cost, _)| c < *best_cost) {
+                        best = Some((c, m));
+                    }
+                }
+                best.unwrap().1
+            }
+        };
+        previous.push(rows(&columns(s, a, &m)));
+        directions.push((s, a, m));
+    }
+
+    println!("use crate::engine::math::sobol::NUM_SOBOL_DIMENSIONS;");
+    println!();
+    println!("/// Degree `s`, inner coefficients `a` and initial direction numbers `m` of the dimensions following the first one,");
+    println!("/// with the primitive polynomials taken by increasing degree and `a`. Generated by `examples/sobol_directions.rs`");
+    println!("pub(crate) static SOBOL_DIRECTIONS : [(usize, u64, &[u64]); NUM_SOBOL_DIMENSIONS - 1] = [");
+    for (s, a, m) in directions {
+        let m : Vec<String> = m.iter().map(|m| m.to_string()).collect();
+        println!("    ({}, {}, &[{}]),", s, a, m.join(", "));
+    }
+    println!("];");
+}
@@ -1,17 +1,73 @@
-mod surface_interaction;
+pub(crate) mod surface_interaction;
 
+use std::sync::Arc;
+use crate::engine::math::{next_float_down, next_float_up};
 use crate::engine::math::Normal::Normal3f;
 use crate::engine::math::Point::Point3f;
+use crate::engine::math::rays::BaseRay;
+use crate::engine::math::rays::Ray::Ray;
 use crate::engine::math::Vector::{Vector3, Vector3f};
 
-trait MediumInterface{
+// Fraction of the distance to the target that shadow rays stop short of,
+// so they don't report the target surface itself as an occluder
+pub const SHADOW_EPSILON : f32 = 0.0001;
+
+pub(crate) trait MediumInterface : Send + Sync{
 
 }
 
-pub trait Interactions{
-    fn new(point: Point3f, normal: Normal3f, point_error: Vector3f, wo: Vector3f, medium_interface : Box<dyn MediumInterface>) -> Self;
+pub(crate) trait Interactions{
+    fn new(point: Point3f, normal: Normal3f, point_error: Vector3f, wo: Vector3f, time : f32, medium_interface : Option<Arc<dyn MediumInterface>>) -> Self;
+
+    fn get_point(&self) -> Point3f;
+
+    fn get_point_error(&self) -> Vector3f;
+
+    fn get_normal(&self) -> Normal3f;
+
+    fn get_time(&self) -> f32;
 
     fn is_surface_interaction(&self, n: Normal3f) -> bool {
         n != Normal3f::default()
     }
+
+    /// Spawn a ray leaving the interaction in the direction `d`
+    fn spawn_ray(&self, d : Vector3f) -> Ray {
+        let origin = offset_ray_origin(&self.get_point(), &self.get_point_error(), &self.get_normal(), &d);
+        Ray::new(origin, d, f32::INFINITY, self.get_time(), None)
+    }
+
+    /// Spawn a ray towards the point `p`, stopping just before reaching it
+    fn spawn_ray_to(&self, p : Point3f) -> Ray {
+        let origin = offset_ray_origin(&self.get_point(), &self.get_point_error(), &self.get_normal(), &(p - self.get_point()));
+        let d = p - origin;
+        Ray::new(origin, d, 1.0 - SHADOW_EPSILON, self.get_time(), None)
+    }
+
+    /// Spawn a ray towards another interaction, offsetting both ends of the ray
+    /// so it doesn't intersect either of the surfaces
+    fn spawn_ray_to_interaction(&self, other : &impl Interactions) -> Ray {
+        let origin = offset_ray_origin(&self.get_point(), &self.get_point_error(), &self.get_normal(), &(other.get_point() - self.get_point()));
+        let target = offset_ray_origin(&other.get_point(), &other.get_point_error(), &other.get_normal(), &(origin - other.get_point()));
+        let d = target - origin;
+        Ray::new(origin, d, 1.0 - SHADOW_EPSILON, self.get_time(), None)
+    }
+}
+
+/// Offset a ray origin along the normal so that it is outside of the error bounds
+/// of the intersection point, on the same side of the surface as the direction `w`
+pub(crate) fn offset_ray_origin(p : &Point3f, p_error : &Vector3f, n : &Normal3f, w : &Vector3f) -> Point3f {
+    let d = n.abs().dot(p_error);
+    let mut offset = Vector3f::from_normal(*n) * d;
+    if n.dot(w) < 0.0 {
+        offset = offset.neg();
+    }
+
+    let mut po = *p + offset;
+    // Round away from p to make sure the offset point is outside of the error bounds
+    if offset.x > 0.0 { po.x = next_float_up(po.x) } else if offset.x < 0.0 { po.x = next_float_down(po.x) }
+    if offset.y > 0.0 { po.y = next_float_up(po.y) } else if offset.y < 0.0 { po.y = next_float_down(po.y) }
+    if offset.z > 0.0 { po.z = next_float_up(po.z) } else if offset.z < 0.0 { po.z = next_float_down(po.z) }
+
+    po
 }
\ No newline at end of file
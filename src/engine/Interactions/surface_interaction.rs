@@ -1,108 +1,172 @@
+use std::sync::Arc;
 use crate::engine::Interactions::{Interactions, MediumInterface};
+use crate::engine::lights::AreaLight;
+use crate::engine::materials::{Material, TransportMode};
 use crate::engine::math::Normal::{Normal3, Normal3f};
 use crate::engine::math::Point::{Point2f, Point3f};
 use crate::engine::math::Vector::Vector3f;
 use crate::engine::primitives::Shape;
 
-struct Shading{
-    normal: Normal3f,
-    dp_du : Vector3f,
-    dp_dv : Vector3f,
-    dn_du : Normal3f,
-    dn_dv : Normal3f,
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Shading{
+    pub normal: Normal3f,
+    pub dp_du : Vector3f,
+    pub dp_dv : Vector3f,
+    pub dn_du : Normal3f,
+    pub dn_dv : Normal3f,
 }
 
-struct SurfaceInteraction{
-    point: Point3f,
-    normal: Normal3f,
-    point_error: Vector3f,
-    wo: Vector3f,
-    medium_interface : Box<dyn MediumInterface>, //TODO:FIX
-
-    uv : Point2f,
-    dp_du : Vector3f,
-    dp_dv : Vector3f,
-    dn_du : Normal3f,
-    dn_dv : Normal3f,
-    //shape : Option<Shape> //TODO:FIX
+/// The parametrization of the surface around a point: its `(u, v)` coordinates and the partial derivatives
+/// of the position and of the normal along them
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct DifferentialGeometry{
+    pub uv : Point2f,
+    pub dp_du : Vector3f,
+    pub dp_dv : Vector3f,
+    pub dn_du : Normal3f,
+    pub dn_dv : Normal3f,
+}
+
+#[derive(Clone)]
+pub(crate) struct SurfaceInteraction{
+    pub point: Point3f,
+    pub normal: Normal3f,
+    pub point_error: Vector3f,
+    pub wo: Vector3f,
+    pub time: f32,
+    pub medium_interface : Option<Arc<dyn MediumInterface>>,
+
+    pub uv : Point2f,
+    pub dp_du : Vector3f,
+    pub dp_dv : Vector3f,
+    pub dn_du : Normal3f,
+    pub dn_dv : Normal3f,
+    pub shading : Shading,
+
+    // Set by the primitive that was hit
+    pub material : Option<Arc<dyn Material>>,
+    pub area_light : Option<Arc<dyn AreaLight>>,
+    // Identifiers given to the primitive and its material by the scene, zero when unset
+    pub primitive_id : u32,
+    pub material_id : u32,
+    // Whether the shape that was hit has its orientation reversed, either explicitly or by its transform
+    flip_normals : bool,
 }
 
 impl Interactions for SurfaceInteraction {
-    fn new(point: Point3f, normal: Normal3f, point_error: Vector3f, wo: Vector3f, medium_interface: Box<dyn MediumInterface>) -> Self {
+    fn new(point: Point3f, normal: Normal3f, point_error: Vector3f, wo: Vector3f, time: f32, medium_interface: Option<Arc<dyn MediumInterface>>) -> Self {
         Self{
             point,
             normal,
             point_error,
             wo,
+            time,
             medium_interface,
             uv: Default::default(),
             dp_du: Default::default(),
             dp_dv: Default::default(),
             dn_du: Default::default(),
             dn_dv: Default::default(),
+            shading: Shading{
+                normal,
+                ..Default::default()
+            },
+            material: None,
+            area_light: None,
+            primitive_id: 0,
+            material_id: 0,
+            flip_normals: false,
         }
     }
+
+    fn get_point(&self) -> Point3f {
+        self.point
+    }
+
+    fn get_point_error(&self) -> Vector3f {
+        self.point_error
+    }
+
+    fn get_normal(&self) -> Normal3f {
+        self.normal
+    }
+
+    fn get_time(&self) -> f32 {
+        self.time
+    }
 }
 
 impl SurfaceInteraction {
-    fn new_surface(
-        point: Point3f, normal: Normal3f, point_error: Vector3f, wo: Vector3f, medium_interface: Box<dyn MediumInterface>,
-        uv : Point2f, dp_du : Vector3f, dp_dv : Vector3f, dn_du : Normal3f, dn_dv : Normal3f, shape : Option<impl Shape>
+    /// Interaction on the surface of a shape, outside of any medium until the primitive sets its medium interface
+    pub fn new_surface(
+        point: Point3f, normal: Normal3f, point_error: Vector3f, wo: Vector3f, time: f32,
+        geometry : DifferentialGeometry, shape : Option<&dyn Shape>
     ) -> Self{
-        let mut surface = Self{
-            point,
+        let DifferentialGeometry{ uv, dp_du, dp_dv, dn_du, dn_dv } = geometry;
+        let flip_normals = shape.is_some_and(|shape| shape.reverse_orientation() ^ shape.transform_swaps_handedness());
+        let normal = if flip_normals { normal.neg() } else { normal };
+        let shading = Shading{
             normal,
-            point_error,
-            wo,
-            medium_interface,
-            uv,
             dp_du,
             dp_dv,
             dn_du,
             dn_dv,
-            //shape,
         };
 
-        let mut shader = Shading{
+        Self{
+            point,
             normal,
+            point_error,
+            wo,
+            time,
+            medium_interface: None,
+            uv,
             dp_du,
             dp_dv,
             dn_du,
             dn_dv,
-        };
-
-        //TODO
-        // if (shape.is_some() && shape.unwrap().reverseOrientation() ^ shape.unwrap().transformSwapsHandedness()){
-        //     surface.normal = -1;
-        //     shader.normal = -1;
-        // }
-
-        surface
+            shading,
+            material: None,
+            area_light: None,
+            primitive_id: 0,
+            material_id: 0,
+            flip_normals,
+        }
     }
 
-    fn set_shading_geometry(
-        &mut self, shader: &mut Shading, dp_du : Vector3f, dp_dv : Vector3f, dn_du : Normal3f, dn_dv : Normal3f, orientation_is_authorative : bool){
+    /// Update the shading geometry, usually perturbed by per-vertex normals or bump mapping.
+    /// When `orientation_is_authorative` the geometric normal is flipped to lie in the shading normal hemisphere,
+    /// otherwise the shading normal is flipped to lie in the geometric normal hemisphere
+    pub fn set_shading_geometry(
+        &mut self, dp_du : Vector3f, dp_dv : Vector3f, dn_du : Normal3f, dn_dv : Normal3f, orientation_is_authorative : bool){
         let data = dp_du.cross(&dp_dv)
             .normalize();
-        shader.normal = Normal3f{
+        self.shading.normal = Normal3f{
             x: data.x,
             y: data.y,
             z: data.z,
         };
 
-        //TODO
-        // if (shape.is_some() && shape.unwrap().reverseOrientation() ^ shape.unwrap().transformSwapsHandedness()){
-        //     surface.normal = -1;
-        //     shader.normal = -1;
-        // }
+        if self.flip_normals {
+            self.shading.normal = self.shading.normal.neg();
+        }
 
         if (orientation_is_authorative){
-            self.normal = shader.normal.face_forward(&self.normal);
+            self.normal = self.normal.face_forward(&self.shading.normal);
         }else {
-            shader.normal = shader.normal.face_forward(&self.normal);
+            self.shading.normal = self.shading.normal.face_forward(&self.normal);
         }
+
+        self.shading.dp_du = dp_du;
+        self.shading.dp_dv = dp_dv;
+        self.shading.dn_du = dn_du;
+        self.shading.dn_dv = dn_dv;
     }
 
-    //TODO
-    //Transform()
-}
\ No newline at end of file
+    /// Let the material of the primitive that was hit compute the scattering functions at the interaction
+    pub fn compute_scattering_functions(&mut self, mode : TransportMode, allow_multiple_lobes : bool){
+        if let Some(material) = self.material.clone() {
+            material.compute_scattering_functions(self, mode, allow_multiple_lobes);
+        }
+    }
+}
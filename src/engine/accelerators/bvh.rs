@@ -0,0 +1,476 @@
+use std::sync::Arc;
+use crate::engine::{Bound3f, SurfaceInteraction};
+use crate::engine::accelerators::hlbvh::hlbvh_build;
+use crate::engine::math::Point::Point3f;
+use crate::engine::math::rays::BaseRay;
+use crate::engine::math::rays::Ray::Ray;
+use crate::engine::math::Vector::Vector3f;
+use crate::engine::primitives::Primitive;
+
+/// Number of buckets the centroids are binned into when evaluating the surface area heuristic
+const SAH_BUCKETS : usize = 12;
+/// Cost of traversing an interior node, relative to the cost of intersecting a primitive
+pub(crate) const SAH_TRAVERSAL_COST : f32 = 1.0;
+/// Size of the traversal stack, which bounds the number of interior nodes on any path of the hierarchy
+pub(crate) const MAX_TODO : usize = 64;
+
+/// How the primitives of a node are split between its two children
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum SplitMethod{
+    // Binned surface area heuristic, minimizing the expected cost of tracing a ray
+    Sah,
+    // Split at the midpoint of the centroid bounds along the largest axis
+    Middle,
+    // Split the primitives in two halves of equal size along the largest axis
+    EqualCounts,
+    // Sort the primitives along a Morton curve, much faster to build but slower to trace
+    Hlbvh,
+}
+
+/// Bounds and centroid of a primitive, used while building the hierarchy
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BVHPrimitiveInfo{
+    pub primitive_number : usize,
+    pub bounds : Bound3f,
+    pub centroid : Point3f,
+}
+
+impl BVHPrimitiveInfo{
+    pub fn new(primitive_number : usize, bounds : Bound3f) -> BVHPrimitiveInfo{
+        Self{
+            primitive_number,
+            bounds,
+            centroid: bounds.p_min * 0.5 + bounds.p_max * 0.5,
+        }
+    }
+}
+
+/// Tree representation of the hierarchy, only used during the build before being flattened
+pub(crate) enum BVHBuildNode{
+    Leaf{
+        bounds : Bound3f,
+        first_prim_offset : usize,
+        n_primitives : usize,
+    },
+    Interior{
+        bounds : Bound3f,
+        split_axis : usize,
+        children : [Box<BVHBuildNode>; 2],
+    },
+}
+
+impl BVHBuildNode{
+    pub fn bounds(&self) -> Bound3f {
+        match self {
+            BVHBuildNode::Leaf { bounds, .. } => *bounds,
+            BVHBuildNode::Interior { bounds, .. } => *bounds,
+        }
+    }
+
+    pub fn interior(split_axis : usize, child0 : BVHBuildNode, child1 : BVHBuildNode) -> BVHBuildNode {
+        BVHBuildNode::Interior {
+            bounds: child0.bounds().union_with_box(child1.bounds()),
+            split_axis,
+            children: [Box::new(child0), Box::new(child1)],
+        }
+    }
+}
+
+/// Node of the flattened hierarchy, stored in depth first order so the first child
+/// of an interior node directly follows it
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct LinearBVHNode{
+    bounds : Bound3f,
+    // Offset of the first primitive for leaves, of the second child for interior nodes
+    offset : usize,
+    n_primitives : u32,
+    axis : u8,
+}
+
+/// Bounding volume hierarchy over a set of primitives
+pub(crate) struct BVHAggregate{
+    max_prims_in_node : usize,
+    split_method : SplitMethod,
+    primitives : Vec<Arc<dyn Primitive>>,
+    nodes : Vec<LinearBVHNode>,
+}
+
+impl BVHAggregate{
+    pub fn new(primitives : Vec<Arc<dyn Primitive>>, max_prims_in_node : usize, split_method : SplitMethod) -> BVHAggregate{
+        let max_prims_in_node = max_prims_in_node.clamp(1, 255);
+        if primitives.is_empty() {
+            return Self{
+                max_prims_in_node,
+                split_method,
+                primitives,
+                nodes: Vec::new(),
+            };
+        }
+
+        let mut primitive_info : Vec<BVHPrimitiveInfo> = primitives.iter()
+            .enumerate()
+            .map(|(i, primitive)| BVHPrimitiveInfo::new(i, primitive.world_bound()))
+            .collect();
+
+        let mut total_nodes = 0;
+        let (root, ordered_prims) = if split_method == SplitMethod::Hlbvh {
+            let (root, ordering) = hlbvh_build(&primitive_info, max_prims_in_node, &mut total_nodes);
+            (root, ordering.iter().map(|&i| primitives[i].clone()).collect())
+        } else {
+            let mut ordered_prims = Vec::with_capacity(primitives.len());
+            let root = recursive_build(
+                &primitives, &mut primitive_info, max_prims_in_node, split_method,
+                0, &mut total_nodes, &mut ordered_prims
+            );
+            (root, ordered_prims)
+        };
+
+        Self::from_build_tree(ordered_prims, &root, total_nodes, max_prims_in_node, split_method)
+    }
+
+    /// Flatten a build tree whose leaves index into the already ordered primitives
+    pub(crate) fn from_build_tree(
+        primitives : Vec<Arc<dyn Primitive>>, root : &BVHBuildNode, total_nodes : usize,
+        max_prims_in_node : usize, split_method : SplitMethod
+    ) -> BVHAggregate{
+        let mut nodes = Vec::with_capacity(total_nodes);
+        flatten_bvh_tree(root, &mut nodes);
+
+        Self{
+            max_prims_in_node,
+            split_method,
+            primitives,
+            nodes,
+        }
+    }
+
+    pub fn split_method(&self) -> SplitMethod {
+        self.split_method
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Walk the hierarchy front to back, calling `visit` on the primitives of every leaf the ray reaches.
+    /// The traversal stops as soon as `visit` returns true
+    fn traverse(&self, ray : &Ray, mut visit : impl FnMut(&Arc<dyn Primitive>) -> bool) {
+        if self.nodes.is_empty() {
+            return;
+        }
+
+        let d = ray.get_direction();
+        let inv_dir = Vector3f::new(1.0 / d.x, 1.0 / d.y, 1.0 / d.z);
+        let dir_is_neg = [
+            (inv_dir.x < 0.0) as usize,
+            (inv_dir.y < 0.0) as usize,
+            (inv_dir.z < 0.0) as usize,
+        ];
+
+        let mut nodes_to_visit = [0usize; MAX_TODO];
+        let mut to_visit_offset = 0;
+        let mut current_node_index = 0;
+        loop {
+            let node = &self.nodes[current_node_index];
+            if node.bounds.intersect_p_with_inv_dir(ray, &inv_dir, &dir_is_neg) {
+                if node.n_primitives > 0 {
+                    for primitive in &self.primitives[node.offset..node.offset + node.n_primitives as usize] {
+                        if visit(primitive) {
+                            return;
+                        }
+                    }
+                    if to_visit_offset == 0 {
+                        break;
+                    }
+                    to_visit_offset -= 1;
+                    current_node_index = nodes_to_visit[to_visit_offset];
+                } else if dir_is_neg[node.axis as usize] == 1 {
+                    // Visit the second child first when the ray points down the split axis
+                    nodes_to_visit[to_visit_offset] = current_node_index + 1;
+                    to_visit_offset += 1;
+                    current_node_index = node.offset;
+                } else {
+                    nodes_to_visit[to_visit_offset] = node.offset;
+                    to_visit_offset += 1;
+                    current_node_index += 1;
+                }
+            } else {
+                if to_visit_offset == 0 {
+                    break;
+                }
+                to_visit_offset -= 1;
+                current_node_index = nodes_to_visit[to_visit_offset];
+            }
+        }
+    }
+}
+
+impl Primitive for BVHAggregate{
+    fn world_bound(&self) -> Bound3f {
+        match self.nodes.first() {
+            Some(root) => root.bounds,
+            None => Bound3f::new(),
+        }
+    }
+
+    fn intersect(&self, ray : &Ray) -> Option<SurfaceInteraction> {
+        // The primitives shorten the ray on every hit, so the last interaction found is the closest one
+        let mut interaction = None;
+        self.traverse(ray, |primitive| {
+            if let Some(si) = primitive.intersect(ray) {
+                interaction = Some(si);
+            }
+            false
+        });
+        interaction
+    }
+
+    fn intersect_p(&self, ray : &Ray) -> bool {
+        let mut hit = false;
+        self.traverse(ray, |primitive| {
+            hit = primitive.intersect_p(ray);
+            hit
+        });
+        hit
+    }
+}
+
+/// Recursively build the hierarchy over `primitive_info`, appending the primitives of
+/// every leaf created to `ordered_prims` in the order the leaves reference them
+fn recursive_build(
+    primitives : &[Arc<dyn Primitive>], primitive_info : &mut [BVHPrimitiveInfo],
+    max_prims_in_node : usize, split_method : SplitMethod, depth : usize,
+    total_nodes : &mut usize, ordered_prims : &mut Vec<Arc<dyn Primitive>>
+) -> BVHBuildNode {
+    *total_nodes += 1;
+
+    let bounds = primitive_info.iter().fold(Bound3f::new(), |b, info| b.union_with_box(info.bounds));
+    let n_primitives = primitive_info.len();
+    let mut create_leaf = |primitive_info : &[BVHPrimitiveInfo]| {
+        let first_prim_offset = ordered_prims.len();
+        for info in primitive_info {
+            ordered_prims.push(primitives[info.primitive_number].clone());
+        }
+        BVHBuildNode::Leaf { bounds, first_prim_offset, n_primitives }
+    };
+
+    if n_primitives == 1 {
+        return create_leaf(primitive_info);
+    }
+
+    // Split along the axis where the centroids are the most spread out
+    let centroid_bounds = primitive_info.iter().fold(Bound3f::new(), |b, info| b.union(&info.centroid));
+    let dim = centroid_bounds.max_extent() as usize;
+    if centroid_bounds.p_max[dim] == centroid_bounds.p_min[dim] {
+        // All the centroids are at the same position, there is no meaningful way to split them
+        return create_leaf(primitive_info);
+    }
+
+    let mid = match split_method {
+        // Deep nodes are split in halves so that the traversal stack can't overflow
+        _ if must_split_equal_counts(depth, n_primitives, MAX_TODO) => split_equal_counts(primitive_info, dim),
+        SplitMethod::Middle => {
+            let p_mid = (centroid_bounds.p_min[dim] + centroid_bounds.p_max[dim]) / 2.0;
+            let mid = partition(primitive_info, |info| info.centroid[dim] < p_mid);
+            // Overlapping bounds can put every primitive on the same side
+            if mid == 0 || mid == n_primitives {
+                split_equal_counts(primitive_info, dim)
+            } else {
+                mid
+            }
+        }
+        SplitMethod::EqualCounts => split_equal_counts(primitive_info, dim),
+        SplitMethod::Hlbvh => unreachable!("HLBVH hierarchies are built by hlbvh_build"),
+        SplitMethod::Sah => {
+            if n_primitives <= 2 {
+                split_equal_counts(primitive_info, dim)
+            } else {
+                match split_sah(primitive_info, &bounds, &centroid_bounds, dim, max_prims_in_node) {
+                    Some(mid) => mid,
+                    None => return create_leaf(primitive_info),
+                }
+            }
+        }
+    };
+
+    let (left, right) = primitive_info.split_at_mut(mid);
+    let child0 = recursive_build(primitives, left, max_prims_in_node, split_method, depth + 1, total_nodes, ordered_prims);
+    let child1 = recursive_build(primitives, right, max_prims_in_node, split_method, depth + 1, total_nodes, ordered_prims);
+    BVHBuildNode::interior(dim, child0, child1)
+}
+
+/// Whether a node at `depth` has to be split in halves so that the hierarchy below it, over
+/// `n_primitives`, still has at most `max_depth` interior nodes on any path
+pub(crate) fn must_split_equal_counts(depth : usize, n_primitives : usize, max_depth : usize) -> bool {
+    depth + n_primitives.next_power_of_two().trailing_zeros() as usize >= max_depth
+}
+
+/// Partially sort the primitives so that the lower half along `dim` comes first
+fn split_equal_counts(primitive_info : &mut [BVHPrimitiveInfo], dim : usize) -> usize {
+    let mid = primitive_info.len() / 2;
+    primitive_info.select_nth_unstable_by(mid, |a, b| a.centroid[dim].total_cmp(&b.centroid[dim]));
+    mid
+}
+
+/// Find the cheapest split according to the surface area heuristic.
+/// Returns `None` when turning the primitives into a leaf is cheaper than any split
+fn split_sah(
+    primitive_info : &mut [BVHPrimitiveInfo], bounds : &Bound3f, centroid_bounds : &Bound3f,
+    dim : usize, max_prims_in_node : usize
+) -> Option<usize> {
+    let bucket_of = |info : &BVHPrimitiveInfo| {
+        let b = (SAH_BUCKETS as f32 * centroid_bounds.offset(info.centroid)[dim]) as usize;
+        b.min(SAH_BUCKETS - 1)
+    };
+
+    let mut counts = [0usize; SAH_BUCKETS];
+    let mut bucket_bounds = [Bound3f::new(); SAH_BUCKETS];
+    for info in primitive_info.iter() {
+        let b = bucket_of(info);
+        counts[b] += 1;
+        bucket_bounds[b] = bucket_bounds[b].union_with_box(info.bounds);
+    }
+
+    // Cost of splitting after each bucket, relative to the cost of intersecting a primitive
+    let mut min_cost = f32::INFINITY;
+    let mut min_cost_split_bucket = 0;
+    for i in 0..SAH_BUCKETS - 1 {
+        let (mut b0, mut b1) = (Bound3f::new(), Bound3f::new());
+        let (mut count0, mut count1) = (0, 0);
+        for j in 0..=i {
+            b0 = b0.union_with_box(bucket_bounds[j]);
+            count0 += counts[j];
+        }
+        for j in i + 1..SAH_BUCKETS {
+            b1 = b1.union_with_box(bucket_bounds[j]);
+            count1 += counts[j];
+        }
+        if count0 == 0 || count1 == 0 {
+            continue;
+        }
+
+        let cost = SAH_TRAVERSAL_COST + (count0 as f32 * b0.surface_area() + count1 as f32 * b1.surface_area()) / bounds.surface_area();
+        if cost < min_cost {
+            min_cost = cost;
+            min_cost_split_bucket = i;
+        }
+    }
+
+    let n_primitives = primitive_info.len();
+    let leaf_cost = n_primitives as f32;
+    if n_primitives > max_prims_in_node || min_cost < leaf_cost {
+        let mid = partition(primitive_info, |info| bucket_of(info) <= min_cost_split_bucket);
+        if mid == 0 || mid == n_primitives {
+            return Some(split_equal_counts(primitive_info, dim));
+        }
+        Some(mid)
+    } else {
+        None
+    }
+}
+
+/// Reorder the slice so that the elements matching the predicate come first,
+/// returning the number of matching elements
+pub(crate) fn partition<T>(items : &mut [T], predicate : impl Fn(&T) -> bool) -> usize {
+    let mut first = 0;
+    for i in 0..items.len() {
+        if predicate(&items[i]) {
+            items.swap(first, i);
+            first += 1;
+        }
+    }
+    first
+}
+
+/// Append the nodes of the tree to `nodes` in depth first order, returning the offset of `node`
+fn flatten_bvh_tree(node : &BVHBuildNode, nodes : &mut Vec<LinearBVHNode>) -> usize {
+    let offset = nodes.len();
+    match node {
+        BVHBuildNode::Leaf { bounds, first_prim_offset, n_primitives } => {
+            nodes.push(LinearBVHNode{
+                bounds: *bounds,
+                offset: *first_prim_offset,
+                n_primitives: u32::try_from(*n_primitives).expect("too many primitives in a BVH leaf"),
+                axis: 0,
+            });
+        }
+        BVHBuildNode::Interior { bounds, split_axis, children } => {
+            nodes.push(LinearBVHNode{
+                bounds: *bounds,
+                offset: 0,
+                n_primitives: 0,
+                axis: *split_axis as u8,
+            });
+            flatten_bvh_tree(&children[0], nodes);
+            nodes[offset].offset = flatten_bvh_tree(&children[1], nodes);
+        }
+    }
+    offset
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::accelerators::test_scenes::{assert_matches_brute_force, brute_force, sphere_grid};
+    use crate::engine::math::transformations::Transform;
+    use crate::engine::primitives::GeometricPrimitive;
+    use crate::engine::primitives::sphere::Sphere;
+
+    #[test]
+    fn test_bvh_matches_brute_force() {
+        let primitives = sphere_grid(4);
+        for split_method in [SplitMethod::Sah, SplitMethod::Middle, SplitMethod::EqualCounts, SplitMethod::Hlbvh] {
+            let bvh = BVHAggregate::new(primitives.clone(), 4, split_method);
+            assert_matches_brute_force(&bvh, &primitives);
+        }
+    }
+
+    /// Number of interior nodes on the deepest path from `node`
+    fn depth(bvh : &BVHAggregate, node : usize) -> usize {
+        if bvh.nodes[node].n_primitives > 0 {
+            0
+        } else {
+            1 + depth(bvh, node + 1).max(depth(bvh, bvh.nodes[node].offset))
+        }
+    }
+
+    #[test]
+    fn test_bvh_depth_is_bounded_by_the_traversal_stack() {
+        // Middle splits peel off one sphere at a time when they shrink geometrically towards the origin
+        let primitives : Vec<Arc<dyn Primitive>> = (0..90).map(|i| {
+            let scale = 0.4f32.powi(i);
+            let object_to_world = Transform::translate(Vector3f::new(scale, 0.0, 0.0));
+            let sphere = Sphere::new(Arc::new(object_to_world), Arc::new(object_to_world.inverse()), false, 0.1 * scale, -scale, scale, 360.0);
+            Arc::new(GeometricPrimitive::new(Arc::new(sphere), None, None, None)) as Arc<dyn Primitive>
+        }).collect();
+        let bvh = BVHAggregate::new(primitives.clone(), 1, SplitMethod::Middle);
+        assert!(depth(&bvh, 0) <= MAX_TODO);
+        // Along the line the ray goes down to the smallest sphere, in the deepest leaf
+        let r = || Ray::new(Point3f::new(-1.0, 0.0, 0.0), Vector3f::new(1.0, 0.0, 0.0), f32::INFINITY, 0.0, None);
+        let expected = brute_force(&primitives, &r()).unwrap();
+        assert!(expected.point.distance(bvh.intersect(&r()).unwrap().point) < 1e-4);
+        assert!(bvh.intersect_p(&r()));
+    }
+
+    #[test]
+    fn test_bvh_leaves_respect_max_prims() {
+        let bvh = BVHAggregate::new(sphere_grid(5), 3, SplitMethod::Sah);
+        assert!(bvh.nodes.iter().all(|node| node.n_primitives <= 3));
+        assert_eq!(bvh.nodes.iter().map(|node| node.n_primitives as usize).sum::<usize>(), 125);
+    }
+
+    #[test]
+    fn test_bvh_world_bound() {
+        let bound = BVHAggregate::new(sphere_grid(2), 1, SplitMethod::Sah).world_bound();
+        assert_eq!(bound.p_min, Point3f::new(-1.0, -1.0, -1.0));
+        assert_eq!(bound.p_max, Point3f::new(4.0, 4.0, 4.0));
+    }
+
+    #[test]
+    fn test_empty_bvh() {
+        let bvh = BVHAggregate::new(Vec::new(), 4, SplitMethod::Sah);
+        let r = Ray::new(Point3f::default(), Vector3f::new(1.0, 0.0, 0.0), f32::INFINITY, 0.0, None);
+        assert!(bvh.intersect(&r).is_none());
+        assert!(!bvh.intersect_p(&r));
+    }
+}
@@ -0,0 +1,272 @@
+use crate::engine::accelerators::bvh::{must_split_equal_counts, partition, BVHBuildNode, BVHPrimitiveInfo, MAX_TODO, SAH_TRAVERSAL_COST};
+use crate::engine::Bound3f;
+use crate::util::parallel_for;
+
+/// Number of bits of each coordinate quantized in the Morton codes
+const MORTON_BITS : u32 = 10;
+/// The treelets group the primitives sharing the 12 high bits of their Morton code
+const TREELET_MASK : u32 = 0b0011_1111_1111_1100_0000_0000_0000_0000;
+/// First bit of the Morton codes that is not covered by the treelet grid
+const FIRST_TREELET_BIT : i32 = 29 - 12;
+/// Depth left to the upper levels once the treelets, split at most once per remaining bit, are below them
+const MAX_UPPER_DEPTH : usize = MAX_TODO - (FIRST_TREELET_BIT as usize + 1);
+
+const UPPER_SAH_BUCKETS : usize = 12;
+
+#[derive(Debug, Clone, Copy, Default)]
+struct MortonPrimitive{
+    // Index of the primitive in the build information
+    index : usize,
+    code : u32,
+}
+
+/// Subtree built over a contiguous range of the sorted Morton primitives
+struct Treelet{
+    start : usize,
+    n_primitives : usize,
+    total_nodes : usize,
+    root : Option<BVHBuildNode>,
+}
+
+/// Spread the lowest 10 bits of `x` so that there are two zero bits between each of them
+fn left_shift_3(mut x : u32) -> u32 {
+    if x == (1 << 10) {
+        x -= 1;
+    }
+    x = (x | (x << 16)) & 0b0000_0011_0000_0000_0000_0000_1111_1111;
+    x = (x | (x << 8)) & 0b0000_0011_0000_0000_1111_0000_0000_1111;
+    x = (x | (x << 4)) & 0b0000_0011_0000_1100_0011_0000_1100_0011;
+    x = (x | (x << 2)) & 0b0000_1001_0010_0100_1001_0010_0100_1001;
+    x
+}
+
+/// Interleave the bits of the three coordinates into a 30 bits Morton code
+fn encode_morton_3(x : u32, y : u32, z : u32) -> u32 {
+    (left_shift_3(z) << 2) | (left_shift_3(y) << 1) | left_shift_3(x)
+}
+
+/// Sort the primitives by their Morton code, 6 bits at a time
+fn radix_sort(primitives : &mut Vec<MortonPrimitive>) {
+    const BITS_PER_PASS : u32 = 6;
+    const N_BUCKETS : usize = 1 << BITS_PER_PASS;
+    let n_passes = 30 / BITS_PER_PASS;
+
+    let mut temp = vec![MortonPrimitive::default(); primitives.len()];
+    for pass in 0..n_passes {
+        let low_bit = pass * BITS_PER_PASS;
+        let bucket_of = |p : &MortonPrimitive| ((p.code >> low_bit) as usize) & (N_BUCKETS - 1);
+
+        let mut bucket_count = [0usize; N_BUCKETS];
+        for p in primitives.iter() {
+            bucket_count[bucket_of(p)] += 1;
+        }
+
+        // Starting index in the output of each bucket
+        let mut out_index = [0usize; N_BUCKETS];
+        for i in 1..N_BUCKETS {
+            out_index[i] = out_index[i - 1] + bucket_count[i - 1];
+        }
+
+        for p in primitives.iter() {
+            let bucket = bucket_of(p);
+            temp[out_index[bucket]] = *p;
+            out_index[bucket] += 1;
+        }
+        std::mem::swap(primitives, &mut temp);
+    }
+}
+
+/// Build a hierarchy by sorting the primitives along a Morton curve, building the
+/// lower levels of treelets in parallel and joining them with the surface area heuristic.
+/// Returns the root node along with the primitive numbers in the order the leaves reference them
+pub(crate) fn hlbvh_build(
+    primitive_info : &[BVHPrimitiveInfo], max_prims_in_node : usize, total_nodes : &mut usize
+) -> (BVHBuildNode, Vec<usize>) {
+    let bounds = primitive_info.iter().fold(Bound3f::new(), |b, info| b.union(&info.centroid));
+
+    // Compute the Morton code of every centroid inside the centroid bounds
+    let mut morton_prims : Vec<MortonPrimitive> = (0..primitive_info.len())
+        .map(|index| MortonPrimitive{ index, code: 0 })
+        .collect();
+    let morton_scale = (1 << MORTON_BITS) as f32;
+    parallel_for(&mut morton_prims, |p| {
+        let offset = bounds.offset(primitive_info[p.index].centroid) * morton_scale;
+        p.code = encode_morton_3(offset.x as u32, offset.y as u32, offset.z as u32);
+    });
+    radix_sort(&mut morton_prims);
+
+    // Find the ranges of primitives falling in the same treelet
+    let mut treelets = Vec::new();
+    let mut start = 0;
+    for end in 1..=morton_prims.len() {
+        if end == morton_prims.len() || (morton_prims[start].code & TREELET_MASK) != (morton_prims[end].code & TREELET_MASK) {
+            treelets.push(Treelet{ start, n_primitives: end - start, total_nodes: 0, root: None });
+            start = end;
+        }
+    }
+
+    parallel_for(&mut treelets, |treelet| {
+        let range = treelet.start..treelet.start + treelet.n_primitives;
+        treelet.root = Some(emit_lbvh(
+            primitive_info, &morton_prims[range], treelet.start, &mut treelet.total_nodes,
+            FIRST_TREELET_BIT, max_prims_in_node
+        ));
+    });
+
+    let mut roots = Vec::with_capacity(treelets.len());
+    for treelet in treelets {
+        *total_nodes += treelet.total_nodes;
+        roots.extend(treelet.root);
+    }
+
+    let ordering = morton_prims.iter().map(|p| primitive_info[p.index].primitive_number).collect();
+    (build_upper_sah(roots, 0, total_nodes), ordering)
+}
+
+/// Build the hierarchy of a treelet by splitting the primitives where the bits of their Morton codes change.
+/// `first_offset` is the position of the first primitive of `morton_prims` in the final primitive ordering
+fn emit_lbvh(
+    primitive_info : &[BVHPrimitiveInfo], morton_prims : &[MortonPrimitive], first_offset : usize,
+    total_nodes : &mut usize, bit_index : i32, max_prims_in_node : usize
+) -> BVHBuildNode {
+    let n_primitives = morton_prims.len();
+    if bit_index == -1 || n_primitives < max_prims_in_node {
+        *total_nodes += 1;
+        let bounds = morton_prims.iter().fold(Bound3f::new(), |b, p| b.union_with_box(primitive_info[p.index].bounds));
+        return BVHBuildNode::Leaf { bounds, first_prim_offset: first_offset, n_primitives };
+    }
+
+    let mask = 1 << bit_index;
+    if (morton_prims[0].code & mask) == (morton_prims[n_primitives - 1].code & mask) {
+        // All the primitives are on the same side of this plane, try the next one
+        return emit_lbvh(primitive_info, morton_prims, first_offset, total_nodes, bit_index - 1, max_prims_in_node);
+    }
+
+    // The codes are sorted so the primitives with the bit set are at the end
+    let split = morton_prims.partition_point(|p| (p.code & mask) == 0);
+    *total_nodes += 1;
+    let child0 = emit_lbvh(primitive_info, &morton_prims[..split], first_offset, total_nodes, bit_index - 1, max_prims_in_node);
+    let child1 = emit_lbvh(primitive_info, &morton_prims[split..], first_offset + split, total_nodes, bit_index - 1, max_prims_in_node);
+    BVHBuildNode::interior((bit_index % 3) as usize, child0, child1)
+}
+
+/// Join the treelets with a binned surface area heuristic over their bounds
+fn build_upper_sah(mut roots : Vec<BVHBuildNode>, depth : usize, total_nodes : &mut usize) -> BVHBuildNode {
+    if roots.len() == 1 {
+        return roots.pop().unwrap();
+    }
+    *total_nodes += 1;
+
+    let centroid = |node : &BVHBuildNode| {
+        let b = node.bounds();
+        b.p_min * 0.5 + b.p_max * 0.5
+    };
+    let bounds = roots.iter().fold(Bound3f::new(), |b, node| b.union_with_box(node.bounds()));
+    let centroid_bounds = roots.iter().fold(Bound3f::new(), |b, node| b.union(&centroid(node)));
+    let dim = centroid_bounds.max_extent() as usize;
+
+    let mid = if centroid_bounds.p_max[dim] == centroid_bounds.p_min[dim] {
+        roots.len() / 2
+    } else if must_split_equal_counts(depth, roots.len(), MAX_UPPER_DEPTH) {
+        // Deep nodes are split in halves so that the traversal stack can't overflow
+        let mid = roots.len() / 2;
+        roots.select_nth_unstable_by(mid, |a, b| centroid(a)[dim].total_cmp(&centroid(b)[dim]));
+        mid
+    } else {
+        let bucket_of = |node : &BVHBuildNode| {
+            let b = (UPPER_SAH_BUCKETS as f32 * centroid_bounds.offset(centroid(node))[dim]) as usize;
+            b.min(UPPER_SAH_BUCKETS - 1)
+        };
+
+        let mut counts = [0usize; UPPER_SAH_BUCKETS];
+        let mut bucket_bounds = [Bound3f::new(); UPPER_SAH_BUCKETS];
+        for node in &roots {
+            let b = bucket_of(node);
+            counts[b] += 1;
+            bucket_bounds[b] = bucket_bounds[b].union_with_box(node.bounds());
+        }
+
+        let mut min_cost = f32::INFINITY;
+        let mut min_cost_split_bucket = 0;
+        for i in 0..UPPER_SAH_BUCKETS - 1 {
+            let (mut b0, mut b1) = (Bound3f::new(), Bound3f::new());
+            let (mut count0, mut count1) = (0, 0);
+            for j in 0..=i {
+                b0 = b0.union_with_box(bucket_bounds[j]);
+                count0 += counts[j];
+            }
+            for j in i + 1..UPPER_SAH_BUCKETS {
+                b1 = b1.union_with_box(bucket_bounds[j]);
+                count1 += counts[j];
+            }
+            if count0 == 0 || count1 == 0 {
+                continue;
+            }
+
+            let cost = SAH_TRAVERSAL_COST + (count0 as f32 * b0.surface_area() + count1 as f32 * b1.surface_area()) / bounds.surface_area();
+            if cost < min_cost {
+                min_cost = cost;
+                min_cost_split_bucket = i;
+            }
+        }
+
+        partition(&mut roots, |node| bucket_of(node) <= min_cost_split_bucket)
+    };
+
+    let right = roots.split_off(mid);
+    let child0 = build_upper_sah(roots, depth + 1, total_nodes);
+    let child1 = build_upper_sah(right, depth + 1, total_nodes);
+    BVHBuildNode::interior(dim, child0, child1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::math::Point::Point3f;
+
+    #[test]
+    fn test_morton_code_interleaves_bits() {
+        assert_eq!(encode_morton_3(1, 0, 0), 0b001);
+        assert_eq!(encode_morton_3(0, 1, 0), 0b010);
+        assert_eq!(encode_morton_3(0, 0, 1), 0b100);
+        assert_eq!(encode_morton_3(3, 0, 0), 0b001_001);
+        assert_eq!(encode_morton_3(1023, 1023, 1023), (1 << 30) - 1);
+        // Coordinates at the upper edge of the bounds are clamped into the grid
+        assert_eq!(encode_morton_3(1024, 0, 0), encode_morton_3(1023, 0, 0));
+    }
+
+    #[test]
+    fn test_radix_sort() {
+        let mut primitives : Vec<MortonPrimitive> = (0..1000)
+            .map(|i| MortonPrimitive{ index: i, code: ((i as u32).wrapping_mul(2_654_435_761)) >> 2 })
+            .collect();
+        radix_sort(&mut primitives);
+        assert!(primitives.windows(2).all(|w| w[0].code <= w[1].code));
+        assert_eq!(primitives.len(), 1000);
+    }
+
+    #[test]
+    fn test_upper_sah_splits_by_surface_area() {
+        // A wall, a strip and a small box along x: splitting the wall off is the cheapest split by surface area,
+        // while the sum of the squared extents would wrongly group the wall with the strip
+        let leaf = |i : usize, p_min : Point3f, p_max : Point3f| BVHBuildNode::Leaf{
+            bounds: Bound3f::from_points(&p_min, &p_max), first_prim_offset: i, n_primitives: 1
+        };
+        let wall = leaf(0, Point3f::new(0.0, 0.0, 0.0), Point3f::new(0.0, 8.0, 8.0));
+        let wall_bounds = wall.bounds();
+        let roots = vec![
+            leaf(2, Point3f::new(8.5, 0.0, 0.0), Point3f::new(9.0, 0.5, 0.5)),
+            wall,
+            leaf(1, Point3f::new(4.0, 0.0, 0.0), Point3f::new(4.0, 8.0, 2.0)),
+        ];
+        let mut total_nodes = roots.len();
+        match build_upper_sah(roots, 0, &mut total_nodes) {
+            BVHBuildNode::Interior { split_axis, children, .. } => {
+                assert_eq!(split_axis, 0);
+                assert_eq!(children[0].bounds(), wall_bounds);
+            }
+            BVHBuildNode::Leaf { .. } => panic!("the treelets must be joined by an interior node"),
+        }
+        assert_eq!(total_nodes, 5);
+    }
+}
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use crate::engine::accelerators::bvh::{BVHAggregate, SplitMethod};
+use crate::engine::math::animated_transform::AnimatedTransform;
+use crate::engine::primitives::{Primitive, TransformedPrimitive};
+
+/// Named prototypes of geometry that can be placed many times in a scene.
+/// Every prototype is built into its own bottom-level BVH once, and its instances only
+/// hold a reference to it along with their transform, so that a top-level aggregate over
+/// the instances transforms the rays into the space of the prototype during the traversal
+pub(crate) struct InstanceLibrary{
+    prototypes : HashMap<String, Arc<dyn Primitive>>,
+    split_method : SplitMethod,
+}
+
+impl InstanceLibrary{
+    pub fn new(split_method : SplitMethod) -> InstanceLibrary{
+        Self{
+            prototypes: HashMap::new(),
+            split_method,
+        }
+    }
+
+    /// Define the prototype `name` from its primitives, replacing any previous definition.
+    /// Instances created before a redefinition keep referencing the previous geometry
+    pub fn define_object(&mut self, name : &str, mut primitives : Vec<Arc<dyn Primitive>>) {
+        let prototype : Arc<dyn Primitive> = if primitives.len() == 1 {
+            primitives.pop().unwrap()
+        } else {
+            Arc::new(BVHAggregate::new(primitives, 4, self.split_method))
+        };
+        self.prototypes.insert(name.to_string(), prototype);
+    }
+
+    pub fn contains(&self, name : &str) -> bool {
+        self.prototypes.contains_key(name)
+    }
+
+    /// Create an instance of the prototype `name` placed by `instance_to_world`.
+    /// Returns `None` if no object with that name has been defined
+    pub fn instance(&self, name : &str, instance_to_world : AnimatedTransform) -> Option<Arc<dyn Primitive>> {
+        let prototype = self.prototypes.get(name)?;
+        Some(Arc::new(TransformedPrimitive::new(prototype.clone(), instance_to_world)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::accelerators::test_scenes::{assert_matches_brute_force, sphere_grid};
+    use crate::engine::math::transformations::Transform;
+    use crate::engine::math::Vector::Vector3f;
+
+    #[test]
+    fn test_instances_share_prototype() {
+        let mut library = InstanceLibrary::new(SplitMethod::Sah);
+        library.define_object("cluster", sphere_grid(2));
+        assert!(library.contains("cluster"));
+        assert!(library.instance("missing", AnimatedTransform::from_transform(Transform::identity())).is_none());
+
+        let mut instances = Vec::new();
+        for i in 0..4 {
+            for j in 0..4 {
+                let instance_to_world = Transform::translate(Vector3f::new(i as f32 * 7.0, j as f32 * 3.0, j as f32 * 2.0))
+                    * Transform::rotate_z(i as f32 * 30.0);
+                instances.push(library.instance("cluster", AnimatedTransform::from_transform(instance_to_world)).unwrap());
+            }
+        }
+
+        // Only one copy of the prototype BVH exists, referenced by every instance
+        assert_eq!(Arc::strong_count(&library.prototypes["cluster"]), 17);
+
+        let top_level = BVHAggregate::new(instances.clone(), 4, SplitMethod::Sah);
+        assert_matches_brute_force(&top_level, &instances);
+    }
+}
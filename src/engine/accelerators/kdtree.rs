@@ -0,0 +1,376 @@
+use std::sync::Arc;
+use crate::engine::{Bound3f, SurfaceInteraction};
+use crate::engine::math::rays::BaseRay;
+use crate::engine::math::rays::Ray::Ray;
+use crate::engine::primitives::Primitive;
+
+/// Maximum depth of the nodes still to visit during a traversal
+const MAX_TODO : usize = 64;
+
+#[derive(Debug, Clone, Copy)]
+enum KdNode{
+    Leaf{
+        // Range of the primitive indices overlapping the leaf
+        first_index : usize,
+        n_primitives : usize,
+    },
+    // The child below the split plane directly follows its parent
+    Interior{
+        split_axis : usize,
+        split : f32,
+        above_child : usize,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum EdgeType{
+    Start,
+    End,
+}
+
+/// Start or end of the extent of a primitive along an axis
+#[derive(Debug, Clone, Copy)]
+struct BoundEdge{
+    t : f32,
+    prim_num : usize,
+    edge_type : EdgeType,
+}
+
+/// Node still to visit during a traversal, with the parametric range of the ray inside of it
+#[derive(Debug, Clone, Copy, Default)]
+struct KdToDo{
+    node : usize,
+    t_min : f32,
+    t_max : f32,
+}
+
+/// Kd-tree over a set of primitives, splitting space with axis aligned planes chosen by the surface area heuristic
+pub(crate) struct KdTreeAggregate{
+    isect_cost : f32,
+    traversal_cost : f32,
+    max_prims : usize,
+    empty_bonus : f32,
+    primitives : Vec<Arc<dyn Primitive>>,
+    // Primitives overlapping each leaf, a primitive can be referenced by several leaves
+    primitive_indices : Vec<usize>,
+    nodes : Vec<KdNode>,
+    bounds : Bound3f,
+}
+
+impl KdTreeAggregate{
+    /// Build a kd-tree with the given relative costs of intersecting a primitive and traversing a node.
+    /// `empty_bonus` in [0, 1] favors splits that leave one side empty, and `max_depth` defaults to 8 + 1.3 log2(N).
+    /// The depth is clamped to `MAX_TODO` so that the traversal stack can't overflow
+    pub fn new(
+        primitives : Vec<Arc<dyn Primitive>>, isect_cost : f32, traversal_cost : f32, empty_bonus : f32,
+        max_prims : usize, max_depth : Option<usize>
+    ) -> KdTreeAggregate{
+        let max_depth = max_depth.unwrap_or_else(|| {
+            (8.0 + 1.3 * (primitives.len().max(1) as f32).log2()).round() as usize
+        }).min(MAX_TODO);
+
+        let prim_bounds : Vec<Bound3f> = primitives.iter().map(|p| p.world_bound()).collect();
+        let bounds = prim_bounds.iter().fold(Bound3f::new(), |b, pb| b.union_with_box(*pb));
+
+        let mut tree = Self{
+            isect_cost,
+            traversal_cost,
+            max_prims: max_prims.max(1),
+            empty_bonus,
+            primitives,
+            primitive_indices: Vec::new(),
+            nodes: Vec::new(),
+            bounds,
+        };
+        if !tree.primitives.is_empty() {
+            let prim_nums : Vec<usize> = (0..tree.primitives.len()).collect();
+            tree.build_tree(&bounds, &prim_bounds, &prim_nums, max_depth, 0);
+        }
+        tree
+    }
+
+    /// Build a kd-tree with the default costs
+    pub fn with_defaults(primitives : Vec<Arc<dyn Primitive>>) -> KdTreeAggregate{
+        Self::new(primitives, 80.0, 1.0, 0.5, 1, None)
+    }
+
+    fn push_leaf(&mut self, prim_nums : &[usize]) {
+        self.nodes.push(KdNode::Leaf {
+            first_index: self.primitive_indices.len(),
+            n_primitives: prim_nums.len(),
+        });
+        self.primitive_indices.extend_from_slice(prim_nums);
+    }
+
+    fn build_tree(
+        &mut self, node_bounds : &Bound3f, all_prim_bounds : &[Bound3f], prim_nums : &[usize],
+        depth : usize, mut bad_refines : usize
+    ) {
+        let n_primitives = prim_nums.len();
+        if n_primitives <= self.max_prims || depth == 0 {
+            self.push_leaf(prim_nums);
+            return;
+        }
+
+        // Choose the split with the lowest cost, starting with the largest axis of the node
+        let mut best_axis = None;
+        let mut best_offset = 0;
+        let mut best_cost = f32::INFINITY;
+        let old_cost = self.isect_cost * n_primitives as f32;
+        let inv_total_sa = 1.0 / node_bounds.surface_area();
+        let d = node_bounds.diagonal();
+
+        let mut edges : [Vec<BoundEdge>; 3] = [Vec::new(), Vec::new(), Vec::new()];
+        let mut axis = node_bounds.max_extent() as usize;
+        for _ in 0..3 {
+            edges[axis] = prim_nums.iter().flat_map(|&prim_num| {
+                let bounds = &all_prim_bounds[prim_num];
+                [
+                    BoundEdge{ t: bounds.p_min[axis], prim_num, edge_type: EdgeType::Start },
+                    BoundEdge{ t: bounds.p_max[axis], prim_num, edge_type: EdgeType::End },
+                ]
+            }).collect();
+            edges[axis].sort_by(|e0, e1| e0.t.total_cmp(&e1.t).then(e0.edge_type.cmp(&e1.edge_type)));
+
+            // Evaluate the cost of splitting at every edge inside the node
+            let mut n_below = 0;
+            let mut n_above = n_primitives;
+            for (i, edge) in edges[axis].iter().enumerate() {
+                if edge.edge_type == EdgeType::End {
+                    n_above -= 1;
+                }
+
+                let edge_t = edge.t;
+                if edge_t > node_bounds.p_min[axis] && edge_t < node_bounds.p_max[axis] {
+                    let other_axis0 = (axis + 1) % 3;
+                    let other_axis1 = (axis + 2) % 3;
+                    let below_sa = 2.0 * (d[other_axis0] * d[other_axis1]
+                        + (edge_t - node_bounds.p_min[axis]) * (d[other_axis0] + d[other_axis1]));
+                    let above_sa = 2.0 * (d[other_axis0] * d[other_axis1]
+                        + (node_bounds.p_max[axis] - edge_t) * (d[other_axis0] + d[other_axis1]));
+                    let p_below = below_sa * inv_total_sa;
+                    let p_above = above_sa * inv_total_sa;
+                    let eb = if n_above == 0 || n_below == 0 { self.empty_bonus } else { 0.0 };
+                    let cost = self.traversal_cost
+                        + self.isect_cost * (1.0 - eb) * (p_below * n_below as f32 + p_above * n_above as f32);
+
+                    if cost < best_cost {
+                        best_cost = cost;
+                        best_axis = Some(axis);
+                        best_offset = i;
+                    }
+                }
+
+                if edge.edge_type == EdgeType::Start {
+                    n_below += 1;
+                }
+            }
+
+            if best_axis.is_some() {
+                break;
+            }
+            // No split inside the node along this axis, try the next one
+            axis = (axis + 1) % 3;
+        }
+
+        if best_cost > old_cost {
+            bad_refines += 1;
+        }
+        let best_axis = match best_axis {
+            Some(axis) if !((best_cost > 4.0 * old_cost && n_primitives < 16) || bad_refines == 3) => axis,
+            _ => {
+                self.push_leaf(prim_nums);
+                return;
+            }
+        };
+
+        // Classify the primitives on both sides of the split, the ones straddling it go on both
+        let split_edges = &edges[best_axis];
+        let prims0 : Vec<usize> = split_edges[..best_offset].iter()
+            .filter(|e| e.edge_type == EdgeType::Start)
+            .map(|e| e.prim_num)
+            .collect();
+        let prims1 : Vec<usize> = split_edges[best_offset + 1..].iter()
+            .filter(|e| e.edge_type == EdgeType::End)
+            .map(|e| e.prim_num)
+            .collect();
+
+        let t_split = split_edges[best_offset].t;
+        let mut bounds0 = *node_bounds;
+        let mut bounds1 = *node_bounds;
+        bounds0.p_max[best_axis] = t_split;
+        bounds1.p_min[best_axis] = t_split;
+
+        let node_num = self.nodes.len();
+        self.nodes.push(KdNode::Interior { split_axis: best_axis, split: t_split, above_child: 0 });
+        self.build_tree(&bounds0, all_prim_bounds, &prims0, depth - 1, bad_refines);
+        let above = self.nodes.len();
+        self.nodes[node_num] = KdNode::Interior { split_axis: best_axis, split: t_split, above_child: above };
+        self.build_tree(&bounds1, all_prim_bounds, &prims1, depth - 1, bad_refines);
+    }
+
+    /// Walk the leaves the ray goes through front to back, calling `visit` on their primitives.
+    /// The traversal stops when `visit` returns true or when the ray has been shortened before the next node
+    fn traverse(&self, ray : &Ray, mut visit : impl FnMut(&Arc<dyn Primitive>) -> bool) {
+        if self.nodes.is_empty() {
+            return;
+        }
+        let Some((mut t_min, mut t_max)) = self.bounds.intersect_p(ray) else {
+            return;
+        };
+
+        let o = ray.get_origin();
+        let d = ray.get_direction();
+        let inv_dir = [1.0 / d.x, 1.0 / d.y, 1.0 / d.z];
+
+        let mut todo = [KdToDo::default(); MAX_TODO];
+        let mut todo_pos = 0;
+        let mut node = 0;
+        loop {
+            // Stop once a hit has been found closer than the next node
+            if ray.get_t_max() < t_min {
+                break;
+            }
+
+            match self.nodes[node] {
+                KdNode::Interior { split_axis, split, above_child } => {
+                    let t_plane = (split - o[split_axis]) * inv_dir[split_axis];
+
+                    // Visit first the child on the side of the ray origin
+                    let below_first = o[split_axis] < split || (o[split_axis] == split && d[split_axis] <= 0.0);
+                    let (first_child, second_child) = if below_first {
+                        (node + 1, above_child)
+                    } else {
+                        (above_child, node + 1)
+                    };
+
+                    if t_plane > t_max || t_plane <= 0.0 {
+                        node = first_child;
+                    } else if t_plane < t_min {
+                        node = second_child;
+                    } else {
+                        todo[todo_pos] = KdToDo{ node: second_child, t_min: t_plane, t_max };
+                        todo_pos += 1;
+                        node = first_child;
+                        t_max = t_plane;
+                    }
+                }
+                KdNode::Leaf { first_index, n_primitives } => {
+                    for &index in &self.primitive_indices[first_index..first_index + n_primitives] {
+                        if visit(&self.primitives[index]) {
+                            return;
+                        }
+                    }
+
+                    if todo_pos == 0 {
+                        break;
+                    }
+                    todo_pos -= 1;
+                    node = todo[todo_pos].node;
+                    t_min = todo[todo_pos].t_min;
+                    t_max = todo[todo_pos].t_max;
+                }
+            }
+        }
+    }
+}
+
+impl Primitive for KdTreeAggregate{
+    fn world_bound(&self) -> Bound3f {
+        self.bounds
+    }
+
+    fn intersect(&self, ray : &Ray) -> Option<SurfaceInteraction> {
+        let mut interaction = None;
+        self.traverse(ray, |primitive| {
+            if let Some(si) = primitive.intersect(ray) {
+                interaction = Some(si);
+            }
+            false
+        });
+        interaction
+    }
+
+    fn intersect_p(&self, ray : &Ray) -> bool {
+        let mut hit = false;
+        self.traverse(ray, |primitive| {
+            hit = primitive.intersect_p(ray);
+            hit
+        });
+        hit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::accelerators::test_scenes::{assert_matches_brute_force, sphere_grid};
+    use crate::engine::math::Point::Point3f;
+    use crate::engine::math::transformations::Transform;
+    use crate::engine::math::Vector::Vector3f;
+    use crate::engine::primitives::GeometricPrimitive;
+    use crate::engine::primitives::sphere::Sphere;
+
+    #[test]
+    fn test_kd_tree_matches_brute_force() {
+        let primitives = sphere_grid(4);
+        assert_matches_brute_force(&KdTreeAggregate::with_defaults(primitives.clone()), &primitives);
+        // Cheap intersections make the tree stop splitting early
+        assert_matches_brute_force(&KdTreeAggregate::new(primitives.clone(), 1.0, 1.0, 0.0, 8, None), &primitives);
+        assert_matches_brute_force(&KdTreeAggregate::new(primitives.clone(), 80.0, 1.0, 0.5, 1, Some(2)), &primitives);
+    }
+
+    /// Number of interior nodes on the deepest path from `node`
+    fn depth(tree : &KdTreeAggregate, node : usize) -> usize {
+        match tree.nodes[node] {
+            KdNode::Leaf { .. } => 0,
+            KdNode::Interior { above_child, .. } => 1 + depth(tree, node + 1).max(depth(tree, above_child)),
+        }
+    }
+
+    #[test]
+    fn test_kd_tree_depth_is_bounded_by_the_traversal_stack() {
+        // Spheres growing in size along a line are split off one at a time, which needs more levels than the stack holds
+        let primitives : Vec<Arc<dyn Primitive>> = (0..100).map(|i| {
+            let scale = 1.5f32.powi(i);
+            let object_to_world = Transform::translate(Vector3f::new(scale, 0.0, 0.0));
+            let sphere = Sphere::new(Arc::new(object_to_world), Arc::new(object_to_world.inverse()), false, 0.3 * scale, -scale, scale, 360.0);
+            Arc::new(GeometricPrimitive::new(Arc::new(sphere), None, None, None)) as Arc<dyn Primitive>
+        }).collect();
+        let tree = KdTreeAggregate::new(primitives.clone(), 80.0, 0.0, 0.5, 1, Some(usize::MAX));
+        assert!(depth(&tree, 0) <= MAX_TODO);
+        assert_matches_brute_force(&tree, &primitives);
+    }
+
+    #[test]
+    fn test_kd_tree_references_every_primitive() {
+        let tree = KdTreeAggregate::with_defaults(sphere_grid(3));
+        let mut referenced = tree.primitive_indices.clone();
+        referenced.sort();
+        referenced.dedup();
+        assert_eq!(referenced, (0..27).collect::<Vec<usize>>());
+        assert!(tree.nodes.len() > 1);
+    }
+
+    #[test]
+    fn test_kd_tree_respects_ray_extent() {
+        let tree = KdTreeAggregate::with_defaults(sphere_grid(2));
+        // The first sphere is 9 units away along the ray
+        let short = Ray::new(Point3f::new(-10.0, 0.0, 0.0), Vector3f::new(1.0, 0.0, 0.0), 5.0, 0.0, None);
+        assert!(tree.intersect(&short).is_none());
+        assert!(!tree.intersect_p(&short));
+
+        let long = Ray::new(Point3f::new(-10.0, 0.0, 0.0), Vector3f::new(1.0, 0.0, 0.0), f32::INFINITY, 0.0, None);
+        let si = tree.intersect(&long).unwrap();
+        assert!((si.point.x + 1.0).abs() < 1e-4);
+        assert!((long.get_t_max() - 9.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_empty_kd_tree() {
+        let tree = KdTreeAggregate::with_defaults(Vec::new());
+        let r = Ray::new(Point3f::default(), Vector3f::new(1.0, 0.0, 0.0), f32::INFINITY, 0.0, None);
+        assert!(tree.intersect(&r).is_none());
+        assert!(!tree.intersect_p(&r));
+    }
+}
@@ -0,0 +1,8 @@
+// Aggregates accelerating the ray intersection tests against large amounts of primitives
+pub(crate) mod bvh;
+pub(crate) mod hlbvh;
+pub(crate) mod kdtree;
+pub(crate) mod instancing;
+
+#[cfg(test)]
+pub(crate) mod test_scenes;
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+use crate::engine::SurfaceInteraction;
+use crate::engine::math::Point::Point3f;
+use crate::engine::math::rays::BaseRay;
+use crate::engine::math::rays::Ray::Ray;
+use crate::engine::math::transformations::Transform;
+use crate::engine::math::Vector::Vector3f;
+use crate::engine::primitives::{GeometricPrimitive, Primitive};
+use crate::engine::primitives::sphere::Sphere;
+
+pub(crate) fn sphere_grid(n : usize) -> Vec<Arc<dyn Primitive>> {
+    let mut primitives : Vec<Arc<dyn Primitive>> = Vec::new();
+    for i in 0..n {
+        for j in 0..n {
+            for k in 0..n {
+                let object_to_world = Transform::translate(Vector3f::new(i as f32 * 3.0, j as f32 * 3.0, k as f32 * 3.0));
+                let sphere = Sphere::new(
+                    Arc::new(object_to_world),
+                    Arc::new(object_to_world.inverse()),
+                    false,
+                    1.0, -1.0, 1.0, 360.0
+                );
+                primitives.push(Arc::new(GeometricPrimitive::new(Arc::new(sphere), None, None, None)));
+            }
+        }
+    }
+    primitives
+}
+
+pub(crate) fn rays() -> Vec<Ray> {
+    (0..200).map(|i| {
+        let a = i as f32 * 0.61;
+        let origin = Point3f::new(-10.0 + (a * 1.3).sin(), 5.0 + 4.0 * a.cos(), 5.0 + 4.0 * (a * 0.7).sin());
+        let direction = Vector3f::new(1.0, 0.2 * (a * 2.1).sin(), 0.2 * (a * 1.1).cos()).normalize();
+        Ray::new(origin, direction, f32::INFINITY, 0.0, None)
+    }).collect()
+}
+
+/// Closest hit found by testing every primitive
+pub(crate) fn brute_force(primitives : &[Arc<dyn Primitive>], ray : &Ray) -> Option<SurfaceInteraction> {
+    let mut closest = None;
+    for primitive in primitives {
+        if let Some(si) = primitive.intersect(ray) {
+            closest = Some(si);
+        }
+    }
+    closest
+}
+
+/// Check that the aggregate finds the same hits as testing every primitive, for both kinds of queries
+pub(crate) fn assert_matches_brute_force(aggregate : &impl Primitive, primitives : &[Arc<dyn Primitive>]) {
+    let mut hits = 0;
+    for r in rays() {
+        let expected = brute_force(primitives, &r.clone());
+        hits += expected.is_some() as usize;
+        let found = aggregate.intersect(&r);
+        assert_eq!(expected.is_some(), found.is_some());
+        assert_eq!(expected.is_some(), aggregate.intersect_p(&Ray::new(r.get_origin(), r.get_direction(), f32::INFINITY, 0.0, None)));
+        if let (Some(expected), Some(found)) = (expected, found) {
+            assert!(expected.point.distance(found.point) < 1e-4);
+        }
+    }
+    // Make sure the rays actually exercise the aggregate
+    assert!(hits > 0);
+}
@@ -0,0 +1,174 @@
+use std::f32::consts::PI;
+use crate::engine::cameras::{CameraSample, Shutter};
+use crate::engine::film::Film;
+use crate::engine::math::Camera;
+use crate::engine::math::Point::{Point2f, Point3f};
+use crate::engine::math::rays::BaseRay;
+use crate::engine::math::rays::Ray::Ray;
+use crate::engine::math::transformations::{Apply, Transform};
+use crate::engine::math::Vector::Vector3f;
+
+/// How the directions around the camera are laid out on the film
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum EnvironmentMapping{
+    // Longitude along x and latitude along y, the y axis pointing to the top row
+    Equirectangular,
+    // Every direction covers the same area of a square film, the z axis at its center and -z at its corners
+    EqualAreaOctahedral,
+    // Six square faces on a 3x2 grid, ordered +x, -x, +y on the first row and -y, +z, -z on the second
+    Cubemap,
+}
+
+/// A camera seeing the whole sphere of directions around its position, for panoramas and environment maps
+pub(crate) struct EnvironmentCamera{
+    camera_to_world : Transform,
+    shutter : Shutter,
+    mapping : EnvironmentMapping,
+    film : Film,
+}
+
+impl EnvironmentCamera{
+    pub fn new(camera_to_world : Transform, shutter : Shutter, mapping : EnvironmentMapping, film : Film) -> EnvironmentCamera{
+        Self{
+            camera_to_world,
+            shutter,
+            mapping,
+            film,
+        }
+    }
+
+    /// Direction in camera space seen by the point of the film in raster space
+    fn direction(&self, p_film : &Point2f) -> Vector3f {
+        let resolution = self.film.full_resolution;
+        let u = p_film.x / resolution.x as f32;
+        let v = p_film.y / resolution.y as f32;
+
+        match self.mapping {
+            EnvironmentMapping::Equirectangular => {
+                let theta = PI * v;
+                let phi = 2.0 * PI * u;
+                Vector3f::new(theta.sin() * phi.cos(), theta.cos(), theta.sin() * phi.sin())
+            }
+            EnvironmentMapping::EqualAreaOctahedral => equal_area_square_to_sphere(u, v),
+            EnvironmentMapping::Cubemap => cubemap_direction(u, v),
+        }
+    }
+}
+
+/// Map the unit square onto the unit sphere keeping the relative areas,
+/// the diamond inscribed in the square is the hemisphere around +z
+fn equal_area_square_to_sphere(u : f32, v : f32) -> Vector3f {
+    let (u, v) = (2.0 * u - 1.0, 2.0 * v - 1.0);
+    let (up, vp) = (u.abs(), v.abs());
+
+    // Distance to the edges of the diamond, inside of it for positive values
+    let signed_distance = 1.0 - (up + vp);
+    let r = 1.0 - signed_distance.abs();
+    let phi = if r == 0.0 { 1.0 } else { (vp - up) / r + 1.0 } * PI / 4.0;
+
+    let z = (1.0 - r * r).copysign(signed_distance);
+    let cos_phi = phi.cos().copysign(u);
+    let sin_phi = phi.sin().copysign(v);
+    let scale = r * (2.0 - r * r).max(0.0).sqrt();
+    Vector3f::new(cos_phi * scale, sin_phi * scale, z).normalize()
+}
+
+fn cubemap_direction(u : f32, v : f32) -> Vector3f {
+    let column = ((u * 3.0) as usize).min(2);
+    let row = ((v * 2.0) as usize).min(1);
+    // Position on the face in [-1, 1], with t going down the image
+    let s = 2.0 * (u * 3.0 - column as f32) - 1.0;
+    let t = 2.0 * (v * 2.0 - row as f32) - 1.0;
+
+    let d = match (row, column) {
+        (0, 0) => Vector3f::new(1.0, -t, -s),
+        (0, 1) => Vector3f::new(-1.0, -t, s),
+        (0, _) => Vector3f::new(s, 1.0, t),
+        (_, 0) => Vector3f::new(s, -1.0, -t),
+        (_, 1) => Vector3f::new(s, -t, 1.0),
+        _ => Vector3f::new(-s, -t, -1.0),
+    };
+    d.normalize()
+}
+
+impl Camera for EnvironmentCamera{
+    fn generate_ray(&self, sample : &CameraSample) -> Option<(f32, Ray)> {
+        let ray = Ray::new(
+            Point3f::new(0.0, 0.0, 0.0),
+            self.direction(&sample.p_film),
+            f32::INFINITY,
+            self.shutter.sample_time(sample.time, sample.p_film.y, self.film.full_resolution.y),
+            None
+        );
+        Some((1.0, self.camera_to_world.apply(&ray)))
+    }
+
+    fn get_film(&self) -> &Film {
+        &self.film
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::cameras::test_cameras::{assert_vector_eq, box_film, film_sample};
+    use crate::engine::math::Point::Point2i;
+
+    fn camera(mapping : EnvironmentMapping, resolution : Point2i) -> EnvironmentCamera {
+        let camera_to_world = Transform::translate(Vector3f::new(1.0, 2.0, 3.0));
+        EnvironmentCamera::new(camera_to_world, Shutter::default(), mapping, box_film(resolution))
+    }
+
+    fn direction(camera : &EnvironmentCamera, x : f32, y : f32) -> Vector3f {
+        camera.generate_ray(&film_sample(x, y)).unwrap().1.get_direction()
+    }
+
+    #[test]
+    fn test_equirectangular_latitude_longitude() {
+        let camera = camera(EnvironmentMapping::Equirectangular, Point2i{ x: 200, y: 100 });
+        assert!(camera.generate_ray(&film_sample(10.0, 10.0)).unwrap().1.get_origin().distance(Point3f::new(1.0, 2.0, 3.0)) < 1e-4);
+
+        assert_vector_eq(direction(&camera, 0.0, 0.0), Vector3f::new(0.0, 1.0, 0.0));
+        assert_vector_eq(direction(&camera, 0.0, 50.0), Vector3f::new(1.0, 0.0, 0.0));
+        assert_vector_eq(direction(&camera, 50.0, 50.0), Vector3f::new(0.0, 0.0, 1.0));
+        assert_vector_eq(direction(&camera, 100.0, 50.0), Vector3f::new(-1.0, 0.0, 0.0));
+        assert_vector_eq(direction(&camera, 120.0, 100.0), Vector3f::new(0.0, -1.0, 0.0));
+    }
+
+    #[test]
+    fn test_equal_area_octahedral() {
+        let camera = camera(EnvironmentMapping::EqualAreaOctahedral, Point2i{ x: 100, y: 100 });
+        assert_vector_eq(direction(&camera, 50.0, 50.0), Vector3f::new(0.0, 0.0, 1.0));
+        assert_vector_eq(direction(&camera, 0.0, 0.0), Vector3f::new(0.0, 0.0, -1.0));
+        assert_vector_eq(direction(&camera, 100.0, 50.0), Vector3f::new(1.0, 0.0, 0.0));
+        assert_vector_eq(direction(&camera, 50.0, 0.0), Vector3f::new(0.0, -1.0, 0.0));
+
+        // The inner diamond covers the front hemisphere
+        for i in 0..=10 {
+            for j in 0..=10 {
+                let (x, y) = (i as f32 * 10.0, j as f32 * 10.0);
+                let d = direction(&camera, x, y);
+                assert!((d.length() - 1.0).abs() < 1e-4);
+                let distance = (x - 50.0).abs() + (y - 50.0).abs();
+                if distance != 50.0 {
+                    assert_eq!(d.z > 0.0, distance < 50.0, "{} {} {:?}", x, y, d);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cubemap_faces() {
+        let camera = camera(EnvironmentMapping::Cubemap, Point2i{ x: 300, y: 200 });
+        assert_vector_eq(direction(&camera, 50.0, 50.0), Vector3f::new(1.0, 0.0, 0.0));
+        assert_vector_eq(direction(&camera, 150.0, 50.0), Vector3f::new(-1.0, 0.0, 0.0));
+        assert_vector_eq(direction(&camera, 250.0, 50.0), Vector3f::new(0.0, 1.0, 0.0));
+        assert_vector_eq(direction(&camera, 50.0, 150.0), Vector3f::new(0.0, -1.0, 0.0));
+        assert_vector_eq(direction(&camera, 150.0, 150.0), Vector3f::new(0.0, 0.0, 1.0));
+        assert_vector_eq(direction(&camera, 250.0, 150.0), Vector3f::new(0.0, 0.0, -1.0));
+
+        // The top of the +z face looks up
+        let d = direction(&camera, 150.0, 100.0);
+        assert!(d.y > 0.7 && d.z > 0.7);
+    }
+}
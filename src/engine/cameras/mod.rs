@@ -0,0 +1,199 @@
+pub(crate) mod perspective;
+pub(crate) mod orthographic;
+pub(crate) mod environment;
+pub(crate) mod realistic;
+
+#[cfg(test)]
+pub(crate) mod test_cameras;
+
+use crate::engine::film::Film;
+use crate::engine::math::bounding_box::Bound2f;
+use crate::engine::math::lerp;
+use crate::engine::math::Point::{Point2f, Point3f};
+use crate::engine::math::sampling::concentric_sample_disk;
+use crate::engine::math::transformations::Transform;
+use crate::engine::math::Vector::Vector3f;
+
+/// Everything a camera needs to generate a ray: the point on the film in raster space,
+/// the point on the lens and the time at which the ray is traced
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct CameraSample{
+    pub p_film : Point2f,
+    pub p_lens : Point2f,
+    pub time : f32,
+}
+
+/// How the rows of the film are exposed while the shutter is open
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ShutterMode{
+    // The whole film is exposed at once
+    Global,
+    // The exposure of each row starts after the previous one, as in CMOS sensors reading their rows one by one.
+    // The last row starts `readout_time` after the first one
+    Rolling{ readout_time : f32 },
+}
+
+/// The time range during which the camera records the light, the rays are spread over it for motion blur
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Shutter{
+    pub open : f32,
+    pub close : f32,
+    pub mode : ShutterMode,
+}
+
+impl Default for Shutter{
+    fn default() -> Self {
+        Self::new(0.0, 1.0)
+    }
+}
+
+impl Shutter{
+    pub fn new(open : f32, close : f32) -> Shutter{
+        Self{
+            open,
+            close,
+            mode: ShutterMode::Global,
+        }
+    }
+
+    /// Every row is exposed for `close - open`, the first one starting at `open`
+    pub fn rolling(open : f32, close : f32, readout_time : f32) -> Shutter{
+        Self{
+            open,
+            close,
+            mode: ShutterMode::Rolling{ readout_time },
+        }
+    }
+
+    /// Time during which each point of the film is exposed
+    pub fn exposure(&self) -> f32 {
+        self.close - self.open
+    }
+
+    /// Time of the ray for the sample `u` in [0, 1) of the exposure of the point of the film
+    /// on the raster row `film_y`, out of a film of `resolution_y` rows
+    pub fn sample_time(&self, u : f32, film_y : f32, resolution_y : i32) -> f32 {
+        let time = lerp(u, self.open, self.close);
+        match self.mode {
+            ShutterMode::Global => time,
+            ShutterMode::Rolling{ readout_time } => time + readout_time * (film_y / resolution_y as f32).clamp(0.0, 1.0),
+        }
+    }
+}
+
+/// The screen window matching the aspect ratio of the film,
+/// spanning [-1, 1] along its shortest side
+pub(crate) fn default_screen_window(film : &Film) -> Bound2f {
+    let frame = film.aspect_ratio();
+    if frame > 1.0 {
+        Bound2f::from_points(&Point2f::new(-frame, -1.0), &Point2f::new(frame, 1.0))
+    } else {
+        Bound2f::from_points(&Point2f::new(-1.0, -1.0 / frame), &Point2f::new(1.0, 1.0 / frame))
+    }
+}
+
+/// A thin lens of radius `radius` focused at `focal_distance`, a radius of zero being a pinhole
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) struct ThinLens{
+    pub radius : f32,
+    pub focal_distance : f32,
+}
+
+impl ThinLens{
+    pub fn new(radius : f32, focal_distance : f32) -> ThinLens{
+        Self{
+            radius,
+            focal_distance,
+        }
+    }
+
+    pub fn pinhole() -> ThinLens{
+        Self::default()
+    }
+}
+
+/// The transformations shared by the cameras projecting the scene onto a plane.
+/// The screen space is the image plane of the projection, the raster space is the same plane
+/// measured in pixels from the top left corner of the image.
+/// The cameras map the film position of a sample to camera space with `raster_to_camera`, and keep the moves in
+/// camera space of a one pixel step of the raster position to offset the rays of their ray differentials
+pub(crate) struct ProjectiveCamera{
+    pub camera_to_world : Transform,
+    pub shutter : Shutter,
+    pub camera_to_screen : Transform,
+    pub raster_to_camera : Transform,
+    pub screen_to_raster : Transform,
+    pub raster_to_screen : Transform,
+    pub film : Film,
+    pub lens : ThinLens,
+}
+
+impl ProjectiveCamera{
+    pub fn new(
+        camera_to_world : Transform, shutter : Shutter, camera_to_screen : Transform, screen_window : Bound2f,
+        lens : ThinLens, film : Film
+    ) -> ProjectiveCamera{
+        let resolution = film.full_resolution;
+        // The y axis is flipped as the raster rows go down the image
+        let screen_to_raster = Transform::scale(resolution.x as f32, resolution.y as f32, 1.0)
+            * Transform::scale(
+                1.0 / (screen_window.p_max.x - screen_window.p_min.x),
+                1.0 / (screen_window.p_min.y - screen_window.p_max.y),
+                1.0
+            )
+            * Transform::translate(Vector3f::new(-screen_window.p_min.x, -screen_window.p_max.y, 0.0));
+        let raster_to_screen = screen_to_raster.inverse();
+
+        Self{
+            camera_to_world,
+            shutter,
+            camera_to_screen,
+            raster_to_camera: camera_to_screen.inverse() * raster_to_screen,
+            screen_to_raster,
+            raster_to_screen,
+            film,
+            lens,
+        }
+    }
+
+    /// Time of the ray traced for the sample
+    pub fn ray_time(&self, sample : &CameraSample) -> f32 {
+        self.shutter.sample_time(sample.time, sample.p_film.y, self.film.full_resolution.y)
+    }
+
+    /// Move the origin of a camera space ray to the point of the lens sampled by `sample`
+    /// and bend it so it still goes through the point it reaches on the plane of focus
+    pub fn focus_ray(&self, origin : Point3f, direction : Vector3f, sample : &CameraSample) -> (Point3f, Vector3f) {
+        if self.lens.radius <= 0.0 {
+            return (origin, direction);
+        }
+
+        let p_lens = concentric_sample_disk(&sample.p_lens) * self.lens.radius;
+        let ft = (self.lens.focal_distance - origin.z) / direction.z;
+        let p_focus = origin + direction * ft;
+
+        let lens_origin = origin + Vector3f::new(p_lens.x, p_lens.y, 0.0);
+        (lens_origin, (p_focus - lens_origin).normalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shutter_times() {
+        let global = Shutter::new(0.5, 1.5);
+        assert_eq!(global.sample_time(0.0, 80.0, 100), 0.5);
+        assert_eq!(global.sample_time(0.5, 0.0, 100), 1.0);
+        assert_eq!(global.sample_time(0.5, 99.0, 100), 1.0);
+
+        // The bottom rows are exposed later than the top ones, for the same duration
+        let rolling = Shutter::rolling(0.0, 0.25, 1.0);
+        assert_eq!(rolling.exposure(), 0.25);
+        assert_eq!(rolling.sample_time(0.0, 0.0, 100), 0.0);
+        assert_eq!(rolling.sample_time(0.0, 50.0, 100), 0.5);
+        assert_eq!(rolling.sample_time(1.0, 50.0, 100), 0.75);
+        assert_eq!(rolling.sample_time(1.0, 100.0, 100), 1.25);
+    }
+}
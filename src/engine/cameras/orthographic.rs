@@ -0,0 +1,129 @@
+use crate::engine::cameras::{CameraSample, ProjectiveCamera, Shutter, ThinLens};
+use crate::engine::film::Film;
+use crate::engine::math::bounding_box::Bound2f;
+use crate::engine::math::Camera;
+use crate::engine::math::Point::Point3f;
+use crate::engine::math::rays::BaseRay;
+use crate::engine::math::rays::Ray::Ray;
+use crate::engine::math::rays::ray_differential::RayDifferential;
+use crate::engine::math::transformations::{Apply, Transform};
+use crate::engine::math::Vector::Vector3f;
+
+/// A camera projecting the scene in parallel along its viewing direction, without any foreshortening.
+/// The rays start on the near plane and stop at the far plane
+pub(crate) struct OrthographicCamera{
+    projective : ProjectiveCamera,
+    // Shifts of the ray origin toward the next pixels, the directions staying the same
+    dx_camera : Vector3f,
+    dy_camera : Vector3f,
+    // Distance between the near and the far planes
+    depth : f32,
+}
+
+impl OrthographicCamera{
+    pub fn new(
+        camera_to_world : Transform, shutter : Shutter, screen_window : Bound2f,
+        lens : ThinLens, near : f32, far : f32, film : Film
+    ) -> OrthographicCamera{
+        let projective = ProjectiveCamera::new(
+            camera_to_world,
+            shutter,
+            Transform::orthographic(near, far),
+            screen_window,
+            lens,
+            film
+        );
+
+        let dx_camera = projective.raster_to_camera.apply(&Vector3f::new(1.0, 0.0, 0.0));
+        let dy_camera = projective.raster_to_camera.apply(&Vector3f::new(0.0, 1.0, 0.0));
+
+        Self{
+            projective,
+            dx_camera,
+            dy_camera,
+            depth: far - near,
+        }
+    }
+
+    /// Origin of the ray of the sample without a lens, the raster position mapped on the near plane
+    fn camera_point(&self, sample : &CameraSample) -> Point3f {
+        self.projective.raster_to_camera.apply(&Point3f::new(sample.p_film.x, sample.p_film.y, 0.0))
+    }
+}
+
+impl Camera for OrthographicCamera{
+    fn generate_ray(&self, sample : &CameraSample) -> Option<(f32, Ray)> {
+        let (origin, direction) = self.projective.focus_ray(self.camera_point(sample), Vector3f::new(0.0, 0.0, 1.0), sample);
+        let ray = Ray::new(origin, direction, self.depth, self.projective.ray_time(sample), None);
+        Some((1.0, self.projective.camera_to_world.apply(&ray)))
+    }
+
+    fn generate_ray_differential(&self, sample : &CameraSample) -> Option<(f32, RayDifferential)> {
+        let p_camera = self.camera_point(sample);
+        let direction = Vector3f::new(0.0, 0.0, 1.0);
+
+        // Without a lens the rays are parallel and the neighbour pixels only shift their origins by a constant offset
+        let (o, d) = self.projective.focus_ray(p_camera, direction, sample);
+        let (rx_orig, rx_direction) = self.projective.focus_ray(p_camera + self.dx_camera, direction, sample);
+        let (ry_orig, ry_direction) = self.projective.focus_ray(p_camera + self.dy_camera, direction, sample);
+
+        let mut ray = RayDifferential::new(o, d, self.depth, self.projective.ray_time(sample), None);
+        ray.rx_orig = Some(rx_orig);
+        ray.ry_orig = Some(ry_orig);
+        ray.rx_direction = Some(rx_direction);
+        ray.ry_direction = Some(ry_direction);
+        ray.is_differential = true;
+
+        Some((1.0, self.projective.camera_to_world.apply(&ray)))
+    }
+
+    fn get_film(&self) -> &Film {
+        &self.projective.film
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::cameras::test_cameras::{box_film, film_sample, lens_sample};
+    use crate::engine::math::Point::{Point2f, Point2i};
+
+    #[test]
+    fn test_parallel_rays_between_clipping_planes() {
+        let film = box_film(Point2i{ x: 200, y: 100 });
+        let screen_window = Bound2f::from_points(&Point2f::new(-4.0, -2.0), &Point2f::new(4.0, 2.0));
+        let camera = OrthographicCamera::new(Transform::identity(), Shutter::default(), screen_window, ThinLens::pinhole(), 1.0, 11.0, film);
+
+        let (_, ray) = camera.generate_ray(&film_sample(0.0, 0.0)).unwrap();
+        assert!(ray.get_origin().distance(Point3f::new(-4.0, 2.0, 1.0)) < 1e-4);
+        assert_eq!(ray.get_direction(), Vector3f::new(0.0, 0.0, 1.0));
+        assert!((ray.get_t_max() - 10.0).abs() < 1e-4);
+
+        // Every pixel covers 0.04 units of the screen window
+        let (_, ray) = camera.generate_ray_differential(&film_sample(150.0, 50.0)).unwrap();
+        assert!(ray.get_origin().distance(Point3f::new(2.0, 0.0, 1.0)) < 1e-4);
+        assert!(ray.rx_orig.unwrap().distance(Point3f::new(2.04, 0.0, 1.0)) < 1e-4);
+        assert!(ray.ry_orig.unwrap().distance(Point3f::new(2.0, -0.04, 1.0)) < 1e-4);
+        assert_eq!(ray.rx_direction, Some(ray.get_direction()));
+
+        // Scaling the differentials only narrows the footprint, the rays stay parallel
+        let mut scaled = ray.clone();
+        scaled.scale_differential(0.5);
+        assert!(scaled.rx_orig.unwrap().distance(Point3f::new(2.02, 0.0, 1.0)) < 1e-4);
+        assert_eq!(scaled.rx_direction, Some(ray.get_direction()));
+    }
+
+    #[test]
+    fn test_thin_lens_focuses_parallel_rays() {
+        let film = box_film(Point2i{ x: 100, y: 100 });
+        let screen_window = Bound2f::from_points(&Point2f::new(-1.0, -1.0), &Point2f::new(1.0, 1.0));
+        let camera = OrthographicCamera::new(Transform::identity(), Shutter::default(), screen_window, ThinLens::new(0.2, 4.0), 0.0, 10.0, film);
+
+        for (u, v) in [(0.1, 0.2), (0.9, 0.5), (0.4, 0.95)] {
+            let s = lens_sample(75.0, 25.0, u, v);
+            let (_, ray) = camera.generate_ray(&s).unwrap();
+            let t = (4.0 - ray.get_origin().z) / ray.get_direction().z;
+            assert!((ray.get_origin() + ray.get_direction() * t).distance(Point3f::new(0.5, 0.5, 4.0)) < 1e-4);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use crate::engine::cameras::{CameraSample, ProjectiveCamera, Shutter, ThinLens};
+use crate::engine::film::Film;
+use crate::engine::math::bounding_box::Bound2f;
+use crate::engine::math::Camera;
+use crate::engine::math::Point::Point3f;
+use crate::engine::math::rays::BaseRay;
+use crate::engine::math::rays::Ray::Ray;
+use crate::engine::math::rays::ray_differential::RayDifferential;
+use crate::engine::math::transformations::{Apply, Transform};
+use crate::engine::math::Vector::Vector3f;
+
+/// A camera projecting the scene with a perspective. Without a lens all the rays start at the origin of the camera
+pub(crate) struct PerspectiveCamera{
+    projective : ProjectiveCamera,
+    // Changes of the unnormalized ray direction toward the next pixels, the origin staying the same
+    dx_camera : Vector3f,
+    dy_camera : Vector3f,
+}
+
+impl PerspectiveCamera{
+    /// `fov` is the angle in degrees covered by the shortest side of the screen window
+    pub fn new(
+        camera_to_world : Transform, shutter : Shutter, screen_window : Bound2f,
+        lens : ThinLens, fov : f32, film : Film
+    ) -> PerspectiveCamera{
+        let projective = ProjectiveCamera::new(
+            camera_to_world,
+            shutter,
+            Transform::perspective(fov, 1e-2, 1000.0),
+            screen_window,
+            lens,
+            film
+        );
+
+        let origin = projective.raster_to_camera.apply(&Point3f::new(0.0, 0.0, 0.0));
+        let dx_camera = projective.raster_to_camera.apply(&Point3f::new(1.0, 0.0, 0.0)) - origin;
+        let dy_camera = projective.raster_to_camera.apply(&Point3f::new(0.0, 1.0, 0.0)) - origin;
+
+        Self{
+            projective,
+            dx_camera,
+            dy_camera,
+        }
+    }
+
+    /// Unnormalized direction in camera space from the camera origin to the point on the near plane matching
+    /// the film position of the sample
+    fn camera_direction(&self, sample : &CameraSample) -> Vector3f {
+        let p_camera = self.projective.raster_to_camera.apply(&Point3f::new(sample.p_film.x, sample.p_film.y, 0.0));
+        Vector3f::new(p_camera.x, p_camera.y, p_camera.z)
+    }
+}
+
+impl Camera for PerspectiveCamera{
+    fn generate_ray(&self, sample : &CameraSample) -> Option<(f32, Ray)> {
+        let (origin, direction) = self.projective.focus_ray(
+            Point3f::new(0.0, 0.0, 0.0),
+            self.camera_direction(sample).normalize(),
+            sample
+        );
+        let ray = Ray::new(origin, direction, f32::INFINITY, self.projective.ray_time(sample), None);
+        Some((1.0, self.projective.camera_to_world.apply(&ray)))
+    }
+
+    fn generate_ray_differential(&self, sample : &CameraSample) -> Option<(f32, RayDifferential)> {
+        let d_camera = self.camera_direction(sample);
+        let origin = Point3f::new(0.0, 0.0, 0.0);
+
+        // The offset rays go through the same point of the lens as the main one
+        let (o, d) = self.projective.focus_ray(origin, d_camera.normalize(), sample);
+        let (rx_orig, rx_direction) = self.projective.focus_ray(origin, (d_camera + self.dx_camera).normalize(), sample);
+        let (ry_orig, ry_direction) = self.projective.focus_ray(origin, (d_camera + self.dy_camera).normalize(), sample);
+
+        let mut ray = RayDifferential::new(o, d, f32::INFINITY, self.projective.ray_time(sample), None);
+        ray.rx_orig = Some(rx_orig);
+        ray.ry_orig = Some(ry_orig);
+        ray.rx_direction = Some(rx_direction);
+        ray.ry_direction = Some(ry_direction);
+        ray.is_differential = true;
+
+        Some((1.0, self.projective.camera_to_world.apply(&ray)))
+    }
+
+    fn get_film(&self) -> &Film {
+        &self.projective.film
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::cameras::default_screen_window;
+    use crate::engine::cameras::test_cameras::{assert_vector_eq, box_film, film_sample, lens_sample};
+    use crate::engine::math::Point::{Point2f, Point2i};
+
+    fn camera(resolution : Point2i, fov : f32, lens_radius : f32) -> PerspectiveCamera {
+        let film = box_film(resolution);
+        let camera_to_world = Transform::look_at(
+            Point3f::new(0.0, 0.0, -5.0),
+            Point3f::new(0.0, 0.0, 0.0),
+            &Vector3f::new(0.0, 1.0, 0.0)
+        );
+        PerspectiveCamera::new(camera_to_world, Shutter::default(), default_screen_window(&film), ThinLens::new(lens_radius, 5.0), fov, film)
+    }
+
+    #[test]
+    fn test_rays_cover_field_of_view() {
+        let camera = camera(Point2i{ x: 100, y: 100 }, 90.0, 0.0);
+
+        let (weight, center) = camera.generate_ray(&film_sample(50.0, 50.0)).unwrap();
+        assert_eq!(weight, 1.0);
+        assert!(center.get_origin().distance(Point3f::new(0.0, 0.0, -5.0)) < 1e-4);
+        assert_vector_eq(center.get_direction(), Vector3f::new(0.0, 0.0, 1.0));
+
+        // The top left corner of the image is 45 degrees away from the optical axis on both sides
+        let (_, corner) = camera.generate_ray(&film_sample(0.0, 0.0)).unwrap();
+        let d = corner.get_direction();
+        assert!((d.x / d.z + 1.0).abs() < 1e-4 && (d.y / d.z - 1.0).abs() < 1e-4, "{:?}", d);
+    }
+
+    #[test]
+    fn test_differentials_match_neighbour_pixels() {
+        let camera = camera(Point2i{ x: 160, y: 90 }, 60.0, 0.0);
+        let s = film_sample(31.5, 70.25);
+        let (_, ray) = camera.generate_ray_differential(&s).unwrap();
+        assert!(ray.is_differential);
+
+        let (_, rx) = camera.generate_ray(&film_sample(32.5, 70.25)).unwrap();
+        let (_, ry) = camera.generate_ray(&film_sample(31.5, 71.25)).unwrap();
+        assert_vector_eq(ray.rx_direction.unwrap(), rx.get_direction());
+        assert_vector_eq(ray.ry_direction.unwrap(), ry.get_direction());
+        assert_eq!(ray.rx_orig, Some(ray.get_origin()));
+    }
+
+    #[test]
+    fn test_thin_lens_rays_converge_on_focal_plane() {
+        let camera = camera(Point2i{ x: 100, y: 100 }, 60.0, 0.5);
+        let pinhole = camera.generate_ray(&film_sample(20.0, 70.0)).unwrap().1;
+        // The pinhole ray reaches the plane of focus at z = 0 in world space
+        let t = 5.0 / pinhole.get_direction().z;
+        let p_focus = pinhole.get_origin() + pinhole.get_direction() * t;
+
+        let mut origins = Vec::new();
+        for (u, v) in [(0.1, 0.2), (0.9, 0.5), (0.4, 0.95)] {
+            let s = lens_sample(20.0, 70.0, u, v);
+            let (_, ray) = camera.generate_ray_differential(&s).unwrap();
+            let o = ray.get_origin();
+            assert!((o.z + 5.0).abs() < 1e-4 && (o.x * o.x + o.y * o.y).sqrt() <= 0.5 + 1e-4);
+
+            let t = -o.z / ray.get_direction().z;
+            assert!((o + ray.get_direction() * t).distance(p_focus) < 1e-3);
+            // The differentials leave from the same point of the lens
+            assert!(ray.rx_orig.unwrap().distance(o) < 1e-4);
+            origins.push(o);
+        }
+        assert!(origins[0].distance(origins[1]) > 0.1);
+    }
+
+    #[test]
+    fn test_rolling_shutter_ray_times() {
+        let film = box_film(Point2i{ x: 100, y: 100 });
+        let camera = PerspectiveCamera::new(
+            Transform::identity(), Shutter::rolling(1.0, 1.5, 2.0), default_screen_window(&film), ThinLens::pinhole(), 60.0, film
+        );
+
+        let ray_time = |y : f32, u : f32| {
+            let s = CameraSample{ p_film: Point2f::new(40.0, y), p_lens: Point2f::new(0.5, 0.5), time: u };
+            let (_, ray) = camera.generate_ray_differential(&s).unwrap();
+            ray.get_time()
+        };
+        assert!((ray_time(0.0, 0.0) - 1.0).abs() < 1e-6);
+        assert!((ray_time(0.0, 0.5) - 1.25).abs() < 1e-6);
+        assert!((ray_time(50.0, 0.5) - 2.25).abs() < 1e-6);
+        assert!((ray_time(100.0, 1.0) - 3.5).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,452 @@
+use std::path::Path;
+use crate::engine::cameras::{CameraSample, Shutter};
+use crate::engine::film::Film;
+use crate::engine::math::bounding_box::Bound2f;
+use crate::engine::math::{lerp, quadratic, Camera};
+use crate::engine::math::Point::{Point2f, Point3f};
+use crate::engine::math::rays::BaseRay;
+use crate::engine::math::rays::Ray::Ray;
+use crate::engine::math::sampling::radical_inverse;
+use crate::engine::math::transformations::{Apply, Transform};
+use crate::engine::math::Vector::Vector3f;
+use crate::util::parallel_for;
+
+/// Number of rings of the film with their own bounds of the exit pupil
+const EXIT_PUPIL_INTERVALS : usize = 64;
+/// Number of rays traced through the lenses to bound the exit pupil of each ring
+const EXIT_PUPIL_SAMPLES : usize = 128 * 128;
+
+/// One of the spherical surfaces of the lens system, or the aperture stop when its curvature radius is 0.
+/// All the lengths are in meters
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct LensElementInterface{
+    pub curvature_radius : f32,
+    // Distance along the optical axis to the next interface toward the film
+    pub thickness : f32,
+    // Index of refraction of the medium after the interface, 0 for the aperture stop
+    pub eta : f32,
+    pub aperture_radius : f32,
+}
+
+/// Parse a lens prescription, one interface per line ordered from the scene to the film with
+/// its curvature radius, thickness, index of refraction and aperture diameter in millimeters.
+/// Empty lines and lines starting with `#` are ignored
+pub(crate) fn parse_lens_prescription(text : &str) -> Result<Vec<LensElementInterface>, String> {
+    let mut interfaces = Vec::new();
+    for (line_number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let values = line.split_whitespace()
+            .map(|v| v.parse::<f32>())
+            .collect::<Result<Vec<f32>, _>>()
+            .map_err(|e| format!("line {}: {}", line_number + 1, e))?;
+        if values.len() != 4 {
+            return Err(format!("line {}: expected 4 values, found {}", line_number + 1, values.len()));
+        }
+
+        interfaces.push(LensElementInterface{
+            curvature_radius: values[0] * 0.001,
+            thickness: values[1] * 0.001,
+            eta: values[2],
+            aperture_radius: values[3] * 0.001 / 2.0,
+        });
+    }
+    Ok(interfaces)
+}
+
+/// Load a lens prescription from a file in the format of `parse_lens_prescription`
+pub(crate) fn load_lens_file(path : &Path) -> Result<Vec<LensElementInterface>, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    parse_lens_prescription(&text).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// A camera tracing the rays through a system of spherical lens elements, reproducing
+/// the defocus, the vignetting and the distortion of the real lens.
+/// The lenses lie along the z axis of the camera space with the film at z = 0 and the scene toward +z
+pub(crate) struct RealisticCamera{
+    camera_to_world : Transform,
+    shutter : Shutter,
+    film : Film,
+    // Weight the rays only by the vignetting so the images keep the brightness of the other cameras
+    simple_weighting : bool,
+    // Ordered from the scene to the film, the thickness of the last one is the distance to the film
+    element_interfaces : Vec<LensElementInterface>,
+    // Bounds on the rear element of the directions leaving the lenses, for rings of the film around its center
+    exit_pupil_bounds : Vec<Bound2f>,
+}
+
+impl RealisticCamera{
+    /// Build the camera from the interfaces of a lens prescription, with the aperture stop resized
+    /// to `aperture_diameter` millimeters if it fits in the lens, and the film moved so the lens
+    /// focuses at `focus_distance` meters from it
+    pub fn new(
+        camera_to_world : Transform, shutter : Shutter, mut element_interfaces : Vec<LensElementInterface>,
+        aperture_diameter : f32, focus_distance : f32, simple_weighting : bool, film : Film
+    ) -> Result<RealisticCamera, String> {
+        if element_interfaces.is_empty() {
+            return Err("the lens prescription has no interface".to_string());
+        }
+        for interface in element_interfaces.iter_mut() {
+            if interface.curvature_radius == 0.0 && aperture_diameter * 0.001 / 2.0 <= interface.aperture_radius {
+                interface.aperture_radius = aperture_diameter * 0.001 / 2.0;
+            }
+        }
+
+        let mut camera = Self{
+            camera_to_world,
+            shutter,
+            film,
+            simple_weighting,
+            element_interfaces,
+            exit_pupil_bounds: Vec::new(),
+        };
+
+        let film_distance = camera.focus_thick_lens(focus_distance)?;
+        camera.element_interfaces.last_mut().unwrap().thickness = film_distance;
+
+        let mut bounds = vec![Bound2f::new(); EXIT_PUPIL_INTERVALS];
+        let mut rings : Vec<(usize, &mut Bound2f)> = bounds.iter_mut().enumerate().collect();
+        let film_radius = camera.film.diagonal / 2.0;
+        parallel_for(&mut rings, |(i, b)| {
+            let r0 = *i as f32 / EXIT_PUPIL_INTERVALS as f32 * film_radius;
+            let r1 = (*i + 1) as f32 / EXIT_PUPIL_INTERVALS as f32 * film_radius;
+            **b = camera.bound_exit_pupil(r0, r1);
+        });
+        camera.exit_pupil_bounds = bounds;
+
+        Ok(camera)
+    }
+
+    fn lens_rear_z(&self) -> f32 {
+        self.element_interfaces.last().unwrap().thickness
+    }
+
+    fn lens_front_z(&self) -> f32 {
+        self.element_interfaces.iter().map(|e| e.thickness).sum()
+    }
+
+    fn rear_element_radius(&self) -> f32 {
+        self.element_interfaces.last().unwrap().aperture_radius
+    }
+
+    /// Follow a camera space ray leaving the film through every interface up to the scene.
+    /// Returns None if it is blocked by an aperture or totally reflected
+    fn trace_lenses_from_film(&self, origin : Point3f, direction : Vector3f) -> Option<(Point3f, Vector3f)> {
+        // The lens space has the film at z = 0 and the scene toward -z
+        let mut o = Point3f::new(origin.x, origin.y, -origin.z);
+        let mut d = Vector3f::new(direction.x, direction.y, -direction.z);
+
+        let mut element_z = 0.0;
+        for i in (0..self.element_interfaces.len()).rev() {
+            let element = &self.element_interfaces[i];
+            element_z -= element.thickness;
+
+            let eta_t = if i > 0 && self.element_interfaces[i - 1].eta != 0.0 { self.element_interfaces[i - 1].eta } else { 1.0 };
+            (o, d) = self.trace_interface(element, element_z, o, d, element.eta, eta_t)?;
+        }
+
+        Some((Point3f::new(o.x, o.y, -o.z), Vector3f::new(d.x, d.y, -d.z)))
+    }
+
+    /// Follow a camera space ray coming from the scene through every interface up to the film
+    fn trace_lenses_from_scene(&self, origin : Point3f, direction : Vector3f) -> Option<(Point3f, Vector3f)> {
+        let mut o = Point3f::new(origin.x, origin.y, -origin.z);
+        let mut d = Vector3f::new(direction.x, direction.y, -direction.z);
+
+        let mut element_z = -self.lens_front_z();
+        for i in 0..self.element_interfaces.len() {
+            let element = &self.element_interfaces[i];
+            let eta_i = if i == 0 || self.element_interfaces[i - 1].eta == 0.0 { 1.0 } else { self.element_interfaces[i - 1].eta };
+            let eta_t = if element.eta != 0.0 { element.eta } else { 1.0 };
+            (o, d) = self.trace_interface(element, element_z, o, d, eta_i, eta_t)?;
+
+            element_z += element.thickness;
+        }
+
+        Some((Point3f::new(o.x, o.y, -o.z), Vector3f::new(d.x, d.y, -d.z)))
+    }
+
+    /// Intersect a lens space ray with the interface at `element_z` on the optical axis,
+    /// and refract it from the medium of index `eta_i` into the one of index `eta_t`
+    fn trace_interface(
+        &self, element : &LensElementInterface, element_z : f32, o : Point3f, d : Vector3f, eta_i : f32, eta_t : f32
+    ) -> Option<(Point3f, Vector3f)> {
+        let is_stop = element.curvature_radius == 0.0;
+        let (t, n) = if is_stop {
+            // The aperture stop is a plane, the ray must keep going toward it
+            if d.z == 0.0 {
+                return None;
+            }
+            let t = (element_z - o.z) / d.z;
+            if t < 0.0 {
+                return None;
+            }
+            (t, Vector3f::new(0.0, 0.0, 1.0))
+        } else {
+            intersect_spherical_element(element.curvature_radius, element_z + element.curvature_radius, o, d)?
+        };
+
+        let p_hit = o + d * t;
+        if p_hit.x * p_hit.x + p_hit.y * p_hit.y > element.aperture_radius * element.aperture_radius {
+            return None;
+        }
+        if is_stop {
+            return Some((p_hit, d));
+        }
+
+        let wt = refract(&d.normalize().neg(), &n, eta_i / eta_t)?;
+        Some((p_hit, wt))
+    }
+
+    /// Positions in lens space of the principal and focal planes, from a ray parallel to the axis
+    /// entering the lens system and the ray leaving it
+    fn compute_cardinal_points(r_in : (Point3f, Vector3f), r_out : (Point3f, Vector3f)) -> (f32, f32) {
+        let (o_out, d_out) = r_out;
+        // The focal point is where the ray crosses the axis
+        let tf = -o_out.x / d_out.x;
+        let fz = -(o_out + d_out * tf).z;
+        // The principal plane is where the ray reaches the height at which it entered
+        let tp = (r_in.0.x - o_out.x) / d_out.x;
+        let pz = -(o_out + d_out * tp).z;
+        (pz, fz)
+    }
+
+    /// Principal and focal planes of the thick lens approximating the system,
+    /// on the scene side first and then on the film side
+    fn compute_thick_lens_approximation(&self) -> Result<([f32; 2], [f32; 2]), String> {
+        // A ray close to the axis, so only the paraxial behaviour of the lenses is measured
+        let x = 0.001 * self.film.diagonal;
+
+        let r_scene = (Point3f::new(x, 0.0, self.lens_front_z() + 1.0), Vector3f::new(0.0, 0.0, -1.0));
+        let r_film = self.trace_lenses_from_scene(r_scene.0, r_scene.1)
+            .ok_or("no ray parallel to the axis goes through the lens from the scene")?;
+        let (pz0, fz0) = Self::compute_cardinal_points(r_scene, r_film);
+
+        let r_film = (Point3f::new(x, 0.0, self.lens_rear_z() - 1.0), Vector3f::new(0.0, 0.0, 1.0));
+        let r_scene = self.trace_lenses_from_film(r_film.0, r_film.1)
+            .ok_or("no ray parallel to the axis goes through the lens from the film")?;
+        let (pz1, fz1) = Self::compute_cardinal_points(r_film, r_scene);
+
+        Ok(([pz0, pz1], [fz0, fz1]))
+    }
+
+    /// Distance between the rear element and the film focusing the lens at `focus_distance` from the film
+    fn focus_thick_lens(&self, focus_distance : f32) -> Result<f32, String> {
+        let (pz, fz) = self.compute_thick_lens_approximation()?;
+        let f = fz[0] - pz[0];
+        let z = -focus_distance;
+
+        // Solve the thin lens equation between the principal planes for the move of the film
+        let c = (pz[1] - z - pz[0]) * (pz[1] - z - 4.0 * f - pz[0]);
+        if c <= 0.0 {
+            return Err(format!("the lens of focal length {}m can't focus at {}m", f, focus_distance));
+        }
+        let delta = 0.5 * (pz[1] - z + pz[0] - c.sqrt());
+        Ok(self.lens_rear_z() + delta)
+    }
+
+    /// Bound on the rear element the directions of the rays that leave the lens system
+    /// from the points of the film at a distance between `r0` and `r1` of its center
+    fn bound_exit_pupil(&self, r0 : f32, r1 : f32) -> Bound2f {
+        let rear_radius = self.rear_element_radius();
+        let proj_rear_bounds = Bound2f::from_points(
+            &Point2f::new(-1.5 * rear_radius, -1.5 * rear_radius),
+            &Point2f::new(1.5 * rear_radius, 1.5 * rear_radius)
+        );
+
+        let mut pupil_bounds = Bound2f::new();
+        let mut n_exiting_rays = 0;
+        for i in 0..EXIT_PUPIL_SAMPLES {
+            let p_film = Point3f::new(lerp((i as f32 + 0.5) / EXIT_PUPIL_SAMPLES as f32, r0, r1), 0.0, 0.0);
+            let u = Point2f::new(radical_inverse(2, i as u64), radical_inverse(3, i as u64));
+            let p_rear = proj_rear_bounds.lerp(&u);
+
+            // Points inside the current bounds don't grow them, no need to trace them
+            if pupil_bounds.inside(p_rear)
+                || self.trace_lenses_from_film(p_film, Point3f::new(p_rear.x, p_rear.y, self.lens_rear_z()) - p_film).is_some() {
+                pupil_bounds = pupil_bounds.union(&p_rear);
+                n_exiting_rays += 1;
+            }
+        }
+
+        if n_exiting_rays == 0 {
+            return proj_rear_bounds;
+        }
+        // Pad the bounds by the spacing between the samples
+        pupil_bounds.expands(2.0 * proj_rear_bounds.diagonal().length() / (EXIT_PUPIL_SAMPLES as f32).sqrt())
+    }
+
+    /// Sample a point of the rear element toward which the point of the film sends light,
+    /// along with the area of the sampled bounds
+    fn sample_exit_pupil(&self, p_film : Point2f, lens_sample : &Point2f) -> (Point3f, f32) {
+        let r_film = (p_film.x * p_film.x + p_film.y * p_film.y).sqrt();
+        let r_index = ((r_film / (self.film.diagonal / 2.0) * EXIT_PUPIL_INTERVALS as f32) as usize).min(EXIT_PUPIL_INTERVALS - 1);
+        let pupil_bounds = &self.exit_pupil_bounds[r_index];
+        let p_lens = pupil_bounds.lerp(lens_sample);
+
+        // The bounds were computed along +x, rotate them to the angle of the point on the film
+        let (sin_theta, cos_theta) = if r_film != 0.0 { (p_film.y / r_film, p_film.x / r_film) } else { (0.0, 1.0) };
+        (
+            Point3f::new(
+                cos_theta * p_lens.x - sin_theta * p_lens.y,
+                sin_theta * p_lens.x + cos_theta * p_lens.y,
+                self.lens_rear_z()
+            ),
+            pupil_bounds.volume()
+        )
+    }
+}
+
+/// Intersect a lens space ray with the sphere of the interface centered on the axis at `z_center`,
+/// returning the distance along the ray and the normal on the side of the incoming ray
+fn intersect_spherical_element(radius : f32, z_center : f32, o : Point3f, d : Vector3f) -> Option<(f32, Vector3f)> {
+    let o = o - Vector3f::new(0.0, 0.0, z_center);
+    let o = Vector3f::new(o.x, o.y, o.z);
+    let a = d.dot(&d);
+    let b = 2.0 * d.dot(&o);
+    let c = o.dot(&o) - radius * radius;
+    let (t0, t1) = quadratic(a, b, c)?;
+
+    // Only one of the two hits is on the part of the sphere used by the element
+    let use_closer_t = (d.z > 0.0) ^ (radius < 0.0);
+    let t = if use_closer_t { t0.min(t1) } else { t0.max(t1) };
+    if t < 0.0 {
+        return None;
+    }
+
+    let n = (o + d * t).normalize();
+    let n = if n.dot(&d) > 0.0 { n.neg() } else { n };
+    Some((t, n))
+}
+
+/// Direction refracted from `wi`, both pointing away from the surface, through a surface
+/// of normal `n` on the side of `wi`, with `eta` the ratio of the incident and transmitted indices.
+/// Returns None on total internal reflection
+fn refract(wi : &Vector3f, n : &Vector3f, eta : f32) -> Option<Vector3f> {
+    let cos_theta_i = n.dot(wi);
+    let sin2_theta_i = (1.0 - cos_theta_i * cos_theta_i).max(0.0);
+    let sin2_theta_t = eta * eta * sin2_theta_i;
+    if sin2_theta_t >= 1.0 {
+        return None;
+    }
+    let cos_theta_t = (1.0 - sin2_theta_t).sqrt();
+    Some(wi.neg() * eta + *n * (eta * cos_theta_i - cos_theta_t))
+}
+
+impl Camera for RealisticCamera{
+    fn generate_ray(&self, sample : &CameraSample) -> Option<(f32, Ray)> {
+        // The lens flips the image, so the film is flipped in x to keep the image upright
+        let resolution = self.film.full_resolution;
+        let s = Point2f::new(sample.p_film.x / resolution.x as f32, sample.p_film.y / resolution.y as f32);
+        let p_film2 = self.film.get_physical_extent().lerp(&s);
+        let p_film = Point3f::new(-p_film2.x, p_film2.y, 0.0);
+
+        let (p_rear, exit_pupil_area) = self.sample_exit_pupil(Point2f::new(p_film.x, p_film.y), &sample.p_lens);
+        let direction = p_rear - p_film;
+        let (o, d) = self.trace_lenses_from_film(p_film, direction)?;
+
+        // Irradiance falloff of the points of the film seeing the exit pupil at a grazing angle
+        let cos_theta = direction.normalize().z;
+        let cos4_theta = (cos_theta * cos_theta) * (cos_theta * cos_theta);
+        let weight = if self.simple_weighting {
+            cos4_theta * exit_pupil_area / self.exit_pupil_bounds[0].volume()
+        } else {
+            self.shutter.exposure() * cos4_theta * exit_pupil_area / (self.lens_rear_z() * self.lens_rear_z())
+        };
+
+        let time = self.shutter.sample_time(sample.time, sample.p_film.y, self.film.full_resolution.y);
+        let ray = Ray::new(o, d.normalize(), f32::INFINITY, time, None);
+        Some((weight, self.camera_to_world.apply(&ray)))
+    }
+
+    fn get_film(&self) -> &Film {
+        &self.film
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::cameras::test_cameras::{box_film, lens_sample};
+    use crate::engine::math::Point::Point2i;
+
+    // Double Gauss lens scaled to a 50mm focal length
+    const DOUBLE_GAUSS_50MM : &str = "
+        # radius  thickness  eta  aperture
+        29.475  3.76   1.67   25.2
+        84.83   0.12   1      25.2
+        19.275  4.025  1.67   23
+        40.77   3.275  1.699  23
+        12.75   5.705  1      18
+        0       4.5    0      17.1
+        -14.495 1.18   1.603  17
+        40.77   6.065  1.658  20
+        -20.385 0.19   1      20
+        437.065 3.22   1.717  20
+        -39.73  0      1      20
+    ";
+
+    fn camera(aperture_diameter : f32, focus_distance : f32) -> RealisticCamera {
+        let lens = parse_lens_prescription(DOUBLE_GAUSS_50MM).unwrap();
+        let film = box_film(Point2i{ x: 100, y: 100 });
+        RealisticCamera::new(Transform::identity(), Shutter::default(), lens, aperture_diameter, focus_distance, true, film).unwrap()
+    }
+
+    #[test]
+    fn test_parse_lens_prescription() {
+        let lens = parse_lens_prescription(DOUBLE_GAUSS_50MM).unwrap();
+        assert_eq!(lens.len(), 11);
+        assert_eq!(lens[5].curvature_radius, 0.0);
+        assert_eq!(lens[5].eta, 0.0);
+        assert!((lens[5].thickness - 0.0045).abs() < 1e-7 && (lens[5].aperture_radius - 0.00855).abs() < 1e-7);
+
+        assert!(parse_lens_prescription("1 2 3").is_err());
+        assert!(parse_lens_prescription("# comment\n1 2 x 4").unwrap_err().starts_with("line 2"));
+    }
+
+    #[test]
+    fn test_rays_converge_at_focus_distance() {
+        let camera = camera(17.1, 2.0);
+
+        // Rays leaving the center of the film through different points of the lens meet on the axis
+        // at the focus distance, and are spread apart in front of it
+        let mut hits = 0;
+        for (u, v) in [(0.3, 0.5), (0.7, 0.5), (0.5, 0.3), (0.5, 0.7)] {
+            let Some((weight, ray)) = camera.generate_ray(&lens_sample(50.0, 50.0, u, v)) else { continue };
+            assert!(weight > 0.0);
+            let (o, d) = (ray.get_origin(), ray.get_direction());
+            assert!(d.z > 0.0);
+
+            let in_focus = o + d * ((2.0 - o.z) / d.z);
+            assert!((in_focus.x * in_focus.x + in_focus.y * in_focus.y).sqrt() < 1e-3, "{:?}", in_focus);
+            let out_of_focus = o + d * ((1.0 - o.z) / d.z);
+            assert!((out_of_focus.x * out_of_focus.x + out_of_focus.y * out_of_focus.y).sqrt() > 2e-3, "{:?}", out_of_focus);
+            hits += 1;
+        }
+        assert!(hits >= 3);
+    }
+
+    #[test]
+    fn test_vignetting_and_distortion() {
+        let camera = camera(17.1, 5.0);
+        // The exit pupil seen from the edge of the film is smaller than from its center
+        assert!(camera.exit_pupil_bounds[EXIT_PUPIL_INTERVALS - 1].volume() < camera.exit_pupil_bounds[0].volume());
+
+        let transmitted = |x : f32, y : f32| {
+            let mut weight = 0.0;
+            for i in 0..64 {
+                let u = Point2f::new(radical_inverse(2, i), radical_inverse(3, i));
+                weight += camera.generate_ray(&lens_sample(x, y, u.x, u.y)).map_or(0.0, |(w, _)| w);
+            }
+            weight
+        };
+        assert!(transmitted(0.0, 0.0) < transmitted(50.0, 50.0));
+
+        // The image is flipped by the lens, the left of the film sees the left of the scene
+        let (_, ray) = camera.generate_ray(&lens_sample(10.0, 50.0, 0.5, 0.5)).unwrap();
+        assert!(ray.get_direction().x < 0.0);
+    }
+}
@@ -0,0 +1,30 @@
+use crate::engine::cameras::CameraSample;
+use crate::engine::film::Film;
+use crate::engine::filters::box_filter::BoxFilter;
+use crate::engine::filters::Filter;
+use crate::engine::math::Point::{Point2f, Point2i};
+use crate::engine::math::Vector::{Vector2f, Vector3f};
+
+/// Filter covering a single pixel
+pub(crate) fn pixel_box_filter() -> Box<dyn Filter> {
+    Box::new(BoxFilter::new(Vector2f::new(0.5, 0.5)))
+}
+
+/// Full frame film of the given resolution with a 35mm diagonal
+pub(crate) fn box_film(resolution : Point2i) -> Film {
+    Film::full_frame(resolution, pixel_box_filter(), 35.0)
+}
+
+/// Sample at a film position, through the center of the lens at time zero
+pub(crate) fn film_sample(x : f32, y : f32) -> CameraSample {
+    CameraSample{ p_film: Point2f::new(x, y), ..Default::default() }
+}
+
+/// Sample at a film position, through the lens position `(u, v)` at time zero
+pub(crate) fn lens_sample(x : f32, y : f32, u : f32, v : f32) -> CameraSample {
+    CameraSample{ p_film: Point2f::new(x, y), p_lens: Point2f::new(u, v), time: 0.0 }
+}
+
+pub(crate) fn assert_vector_eq(a : Vector3f, b : Vector3f) {
+    assert!((a - b).length() < 1e-4, "{:?} != {:?}", a, b);
+}
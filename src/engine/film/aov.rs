@@ -0,0 +1,144 @@
+use crate::engine::Interactions::surface_interaction::SurfaceInteraction;
+use crate::engine::math::Normal::Normal3f;
+use crate::engine::math::Point::{Point2f, Point3f};
+use crate::engine::math::rays::BaseRay;
+use crate::engine::math::rays::Ray::Ray;
+
+/// The auxiliary buffers the film can record alongside the radiance
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Aov{
+    Depth,
+    Normal,
+    Albedo,
+    Position,
+    Uv,
+    PrimitiveId,
+    MaterialId,
+    SampleCount,
+}
+
+impl Aov{
+    pub const ALL : [Aov; 8] = [
+        Aov::Depth, Aov::Normal, Aov::Albedo, Aov::Position, Aov::Uv, Aov::PrimitiveId, Aov::MaterialId, Aov::SampleCount
+    ];
+
+    /// Names of the channels of the buffer in the OpenEXR output
+    pub fn channel_names(&self) -> &'static [&'static str] {
+        match self {
+            Aov::Depth => &["Z"],
+            Aov::Normal => &["N.X", "N.Y", "N.Z"],
+            Aov::Albedo => &["albedo.R", "albedo.G", "albedo.B"],
+            Aov::Position => &["P.X", "P.Y", "P.Z"],
+            Aov::Uv => &["uv.U", "uv.V"],
+            Aov::PrimitiveId => &["primitiveId"],
+            Aov::MaterialId => &["materialId"],
+            Aov::SampleCount => &["sampleCount"],
+        }
+    }
+}
+
+/// What a camera sample found at the first surface it hit
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct AovSample{
+    // Distance from the origin of the camera ray to the hit
+    pub depth : f32,
+    pub normal : Normal3f,
+    pub albedo : [f32; 3],
+    pub position : Point3f,
+    pub uv : Point2f,
+    pub primitive_id : u32,
+    pub material_id : u32,
+}
+
+impl AovSample{
+    /// `ray` is the camera ray that found the interaction
+    pub fn from_interaction(ray : &Ray, si : &SurfaceInteraction) -> AovSample{
+        Self{
+            depth: (si.point - ray.get_origin()).length(),
+            normal: si.shading.normal,
+            albedo: si.material.as_ref().map_or([1.0; 3], |material| material.albedo(si)),
+            position: si.point,
+            uv: si.uv,
+            primitive_id: si.primitive_id,
+            material_id: si.material_id,
+        }
+    }
+}
+
+/// The AOVs of the samples taken in a pixel. They are not filtered but averaged over the samples
+/// of the pixel that hit a surface, and the IDs are the ones of the first hit since they can't be blended
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct AovPixel{
+    pub sample_count : u32,
+    pub hit_count : u32,
+    pub depth_sum : f32,
+    pub normal_sum : [f32; 3],
+    pub albedo_sum : [f32; 3],
+    pub position_sum : [f32; 3],
+    pub uv_sum : [f32; 2],
+    pub primitive_id : u32,
+    pub material_id : u32,
+}
+
+impl AovPixel{
+    /// Record a sample of the pixel, None when the camera ray escaped the scene
+    pub fn add_sample(&mut self, sample : Option<&AovSample>) {
+        self.sample_count += 1;
+        let Some(s) = sample else {
+            return;
+        };
+
+        if self.hit_count == 0 {
+            self.primitive_id = s.primitive_id;
+            self.material_id = s.material_id;
+        }
+        self.hit_count += 1;
+        self.depth_sum += s.depth;
+        add_to(&mut self.normal_sum, [s.normal.x, s.normal.y, s.normal.z]);
+        add_to(&mut self.albedo_sum, s.albedo);
+        add_to(&mut self.position_sum, [s.position.x, s.position.y, s.position.z]);
+        add_to(&mut self.uv_sum, [s.uv.x, s.uv.y]);
+    }
+
+    /// Add the samples recorded for the same pixel by another tile
+    pub fn merge(&mut self, other : &AovPixel) {
+        if self.hit_count == 0 {
+            self.primitive_id = other.primitive_id;
+            self.material_id = other.material_id;
+        }
+        self.sample_count += other.sample_count;
+        self.hit_count += other.hit_count;
+        self.depth_sum += other.depth_sum;
+        add_to(&mut self.normal_sum, other.normal_sum);
+        add_to(&mut self.albedo_sum, other.albedo_sum);
+        add_to(&mut self.position_sum, other.position_sum);
+        add_to(&mut self.uv_sum, other.uv_sum);
+    }
+
+    /// Values of the channels of an AOV, in the order of its channel names. Pixels where no sample
+    /// hit a surface are infinitely far away and have zero for the other AOVs
+    pub fn values(&self, aov : Aov) -> Vec<f32> {
+        let inv_hits = if self.hit_count > 0 { 1.0 / self.hit_count as f32 } else { 0.0 };
+        match aov {
+            Aov::Depth if self.hit_count == 0 => vec![f32::INFINITY],
+            Aov::Depth => vec![self.depth_sum * inv_hits],
+            Aov::Normal => {
+                let length = self.normal_sum.iter().map(|v| v * v).sum::<f32>().sqrt();
+                let inv_length = if length > 0.0 { 1.0 / length } else { 0.0 };
+                self.normal_sum.iter().map(|v| v * inv_length).collect()
+            }
+            Aov::Albedo => self.albedo_sum.iter().map(|v| v * inv_hits).collect(),
+            Aov::Position => self.position_sum.iter().map(|v| v * inv_hits).collect(),
+            Aov::Uv => self.uv_sum.iter().map(|v| v * inv_hits).collect(),
+            Aov::PrimitiveId => vec![self.primitive_id as f32],
+            Aov::MaterialId => vec![self.material_id as f32],
+            Aov::SampleCount => vec![self.sample_count as f32],
+        }
+    }
+}
+
+fn add_to<const N : usize>(sum : &mut [f32; N], v : [f32; N]) {
+    for (s, v) in sum.iter_mut().zip(v) {
+        *s += v;
+    }
+}
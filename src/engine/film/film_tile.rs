@@ -0,0 +1,100 @@
+use crate::engine::film::aov::{AovPixel, AovSample};
+use crate::engine::film::FILTER_TABLE_WIDTH;
+use crate::engine::math::bounding_box::Bound2i;
+use crate::engine::math::Point::{Point2f, Point2i};
+use crate::engine::math::Vector::Vector2f;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct FilmTilePixel{
+    pub contrib_sum : [f32; 3],
+    pub filter_weight_sum : f32,
+}
+
+/// The pixels of a part of the film, so that each thread accumulates its samples
+/// without locking the film until the tile is merged back into it
+pub(crate) struct FilmTile<'a>{
+    pixel_bounds : Bound2i,
+    filter_radius : Vector2f,
+    inv_filter_radius : Vector2f,
+    filter_table : &'a [f32],
+    pixels : Vec<FilmTilePixel>,
+    // Only allocated when the film records AOVs
+    aov_pixels : Option<Vec<AovPixel>>,
+}
+
+impl<'a> FilmTile<'a>{
+    pub fn new(pixel_bounds : Bound2i, filter_radius : Vector2f, filter_table : &'a [f32], record_aovs : bool) -> FilmTile<'a>{
+        let pixel_count = pixel_bounds.area().max(0) as usize;
+        Self{
+            pixel_bounds,
+            filter_radius,
+            inv_filter_radius: Vector2f::new(1.0 / filter_radius.x, 1.0 / filter_radius.y),
+            filter_table,
+            pixels: vec![FilmTilePixel::default(); pixel_count],
+            aov_pixels: record_aovs.then(|| vec![AovPixel::default(); pixel_count]),
+        }
+    }
+
+    /// Add the radiance `l` found by a sample at the raster position `p_film` to the pixels
+    /// of the tile inside the radius of the filter
+    pub fn add_sample(&mut self, p_film : &Point2f, l : [f32; 3], sample_weight : f32) {
+        // The pixel centers are at half integer coordinates
+        let p_film_discrete = Point2f::new(p_film.x - 0.5, p_film.y - 0.5);
+        let x0 = ((p_film_discrete.x - self.filter_radius.x).ceil() as i32).max(self.pixel_bounds.p_min.x);
+        let y0 = ((p_film_discrete.y - self.filter_radius.y).ceil() as i32).max(self.pixel_bounds.p_min.y);
+        let x1 = ((p_film_discrete.x + self.filter_radius.x).floor() as i32 + 1).min(self.pixel_bounds.p_max.x);
+        let y1 = ((p_film_discrete.y + self.filter_radius.y).floor() as i32 + 1).min(self.pixel_bounds.p_max.y);
+        if x1 <= x0 || y1 <= y0 {
+            return;
+        }
+
+        // Entries of the filter table for each column and row reached by the sample
+        let table_index = |d : f32, inv_radius : f32| {
+            ((d * inv_radius * FILTER_TABLE_WIDTH as f32).abs().floor() as usize).min(FILTER_TABLE_WIDTH - 1)
+        };
+        let ifx : Vec<usize> = (x0..x1).map(|x| table_index(x as f32 - p_film_discrete.x, self.inv_filter_radius.x)).collect();
+        let ify : Vec<usize> = (y0..y1).map(|y| table_index(y as f32 - p_film_discrete.y, self.inv_filter_radius.y)).collect();
+
+        for y in y0..y1 {
+            for x in x0..x1 {
+                let filter_weight = self.filter_table[ify[(y - y0) as usize] * FILTER_TABLE_WIDTH + ifx[(x - x0) as usize]];
+                let offset = self.pixel_offset(&Point2i{ x, y });
+                let pixel = &mut self.pixels[offset];
+                for (sum, l) in pixel.contrib_sum.iter_mut().zip(l) {
+                    *sum += l * sample_weight * filter_weight;
+                }
+                pixel.filter_weight_sum += filter_weight;
+            }
+        }
+    }
+
+    /// Record what the sample at `p_film` hit first in the pixel containing it, `sample` is None
+    /// when the camera ray escaped the scene. Does nothing when the film doesn't record AOVs
+    pub fn add_aov_sample(&mut self, p_film : &Point2f, sample : Option<&AovSample>) {
+        let p = Point2i{ x: p_film.x.floor() as i32, y: p_film.y.floor() as i32 };
+        if !self.pixel_bounds.contains(&p) {
+            return;
+        }
+        let offset = self.pixel_offset(&p);
+        if let Some(aov_pixels) = &mut self.aov_pixels {
+            aov_pixels[offset].add_sample(sample);
+        }
+    }
+
+    pub fn get_aov_pixel(&self, p : &Point2i) -> Option<&AovPixel> {
+        self.aov_pixels.as_ref().map(|aov_pixels| &aov_pixels[self.pixel_offset(p)])
+    }
+
+    pub fn get_pixel(&self, p : &Point2i) -> &FilmTilePixel {
+        &self.pixels[self.pixel_offset(p)]
+    }
+
+    pub fn get_pixel_bounds(&self) -> Bound2i {
+        self.pixel_bounds
+    }
+
+    fn pixel_offset(&self, p : &Point2i) -> usize {
+        let width = self.pixel_bounds.p_max.x - self.pixel_bounds.p_min.x;
+        ((p.y - self.pixel_bounds.p_min.y) * width + (p.x - self.pixel_bounds.p_min.x)) as usize
+    }
+}
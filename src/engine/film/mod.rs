@@ -0,0 +1,405 @@
+pub(crate) mod film_tile;
+pub(crate) mod aov;
+
+use std::fs::File;
+use std::io;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::Mutex;
+use crate::engine::film::aov::{Aov, AovPixel};
+use crate::engine::film::film_tile::FilmTile;
+use crate::engine::filters::Filter;
+use crate::engine::math::bounding_box::{Bound2f, Bound2i};
+use crate::engine::math::Point::{Point2f, Point2i};
+use crate::engine::math::Vector::Vector2f;
+use crate::util::image_io::{rgb_channels, write_exr, write_image, write_ldr_image, ExrPixelType, LdrEncoding};
+use crate::util::tone_mapping::ToneMapping;
+
+/// Number of entries along each axis of the precomputed filter values
+pub(crate) const FILTER_TABLE_WIDTH : usize = 16;
+
+/// Weighted sum of the radiance of the samples around a pixel
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Pixel{
+    pub rgb : [f32; 3],
+    pub filter_weight_sum : f32,
+}
+
+/// The sensor of the camera, reconstructing the image from the radiance samples traced through it.
+/// The pixel array only covers the part of the image inside of the crop window
+pub(crate) struct Film{
+    pub full_resolution : Point2i,
+    // Length of the diagonal of the film in meters
+    pub diagonal : f32,
+    pub filter : Box<dyn Filter>,
+    cropped_pixel_bounds : Bound2i,
+    pixels : Mutex<Vec<Pixel>>,
+    // First hit information of the cropped pixels, None unless enabled with `with_aovs`
+    aov_pixels : Option<Mutex<Vec<AovPixel>>>,
+    // Values of the filter over the positive quadrant of its support
+    filter_table : Vec<f32>,
+}
+
+impl Film{
+    /// `crop_window` is given in normalized coordinates of the image, `diagonal` in millimeters
+    pub fn new(full_resolution : Point2i, crop_window : Bound2f, filter : Box<dyn Filter>, diagonal : f32) -> Film{
+        let cropped_pixel_bounds = Bound2i::from_corners(
+            Point2i{
+                x: (full_resolution.x as f32 * crop_window.p_min.x).ceil() as i32,
+                y: (full_resolution.y as f32 * crop_window.p_min.y).ceil() as i32,
+            },
+            Point2i{
+                x: (full_resolution.x as f32 * crop_window.p_max.x).ceil() as i32,
+                y: (full_resolution.y as f32 * crop_window.p_max.y).ceil() as i32,
+            }
+        );
+
+        let radius = filter.radius();
+        let mut filter_table = Vec::with_capacity(FILTER_TABLE_WIDTH * FILTER_TABLE_WIDTH);
+        for y in 0..FILTER_TABLE_WIDTH {
+            for x in 0..FILTER_TABLE_WIDTH {
+                let p = Point2f::new(
+                    (x as f32 + 0.5) * radius.x / FILTER_TABLE_WIDTH as f32,
+                    (y as f32 + 0.5) * radius.y / FILTER_TABLE_WIDTH as f32
+                );
+                filter_table.push(filter.evaluate(&p));
+            }
+        }
+
+        Self{
+            full_resolution,
+            diagonal: diagonal * 0.001,
+            filter,
+            cropped_pixel_bounds,
+            pixels: Mutex::new(vec![Pixel::default(); cropped_pixel_bounds.area() as usize]),
+            aov_pixels: None,
+            filter_table,
+        }
+    }
+
+    /// A film covering the whole image
+    pub fn full_frame(full_resolution : Point2i, filter : Box<dyn Filter>, diagonal : f32) -> Film{
+        let crop_window = Bound2f::from_points(&Point2f::new(0.0, 0.0), &Point2f::new(1.0, 1.0));
+        Self::new(full_resolution, crop_window, filter, diagonal)
+    }
+
+    /// Also record the AOVs of the first surfaces hit by the camera samples
+    pub fn with_aovs(mut self) -> Film{
+        self.aov_pixels = Some(Mutex::new(vec![AovPixel::default(); self.cropped_pixel_bounds.area() as usize]));
+        self
+    }
+
+    pub fn records_aovs(&self) -> bool {
+        self.aov_pixels.is_some()
+    }
+
+    /// Ratio between the width and the height of the image
+    pub fn aspect_ratio(&self) -> f32 {
+        self.full_resolution.x as f32 / self.full_resolution.y as f32
+    }
+
+    /// Area of the film in meters, centered on the optical axis
+    pub fn get_physical_extent(&self) -> Bound2f {
+        let aspect = self.full_resolution.y as f32 / self.full_resolution.x as f32;
+        let x = (self.diagonal * self.diagonal / (1.0 + aspect * aspect)).sqrt();
+        let y = aspect * x;
+        Bound2f::from_points(&Point2f::new(-x / 2.0, -y / 2.0), &Point2f::new(x / 2.0, y / 2.0))
+    }
+
+    /// The pixels stored by the film
+    pub fn get_cropped_pixel_bounds(&self) -> Bound2i {
+        self.cropped_pixel_bounds
+    }
+
+    /// The pixels that must be sampled, the filter spreads the samples of the pixels around the
+    /// cropped bounds into them
+    pub fn get_sample_bounds(&self) -> Bound2i {
+        let radius = self.filter.radius();
+        let b = self.cropped_pixel_bounds;
+        Bound2i::from_corners(
+            Point2i{
+                x: (b.p_min.x as f32 + 0.5 - radius.x).floor() as i32,
+                y: (b.p_min.y as f32 + 0.5 - radius.y).floor() as i32,
+            },
+            Point2i{
+                x: (b.p_max.x as f32 - 0.5 + radius.x).ceil() as i32,
+                y: (b.p_max.y as f32 - 0.5 + radius.y).ceil() as i32,
+            }
+        )
+    }
+
+    /// A tile accumulating the samples of the pixels in `sample_bounds`, to be filled by a single thread
+    /// and merged back with `merge_film_tile`
+    pub fn get_film_tile(&self, sample_bounds : &Bound2i) -> FilmTile<'_> {
+        // Bound the pixels the samples can contribute to
+        let radius = self.filter.radius();
+        let p0 = Point2i{
+            x: (sample_bounds.p_min.x as f32 - 0.5 - radius.x).ceil() as i32,
+            y: (sample_bounds.p_min.y as f32 - 0.5 - radius.y).ceil() as i32,
+        };
+        let p1 = Point2i{
+            x: (sample_bounds.p_max.x as f32 - 0.5 + radius.x).floor() as i32 + 1,
+            y: (sample_bounds.p_max.y as f32 - 0.5 + radius.y).floor() as i32 + 1,
+        };
+        let tile_pixel_bounds = Bound2i::from_corners(p0, p1).overlap(&self.cropped_pixel_bounds);
+        FilmTile::new(tile_pixel_bounds, radius, &self.filter_table, self.records_aovs())
+    }
+
+    /// Add the contributions accumulated by the tile to the pixels of the film
+    pub fn merge_film_tile(&self, tile : FilmTile) {
+        let mut pixels = self.pixels.lock().unwrap();
+        for p in tile.get_pixel_bounds().pixels() {
+            let tile_pixel = tile.get_pixel(&p);
+            let pixel = &mut pixels[self.pixel_offset(&p)];
+            for (rgb, contrib) in pixel.rgb.iter_mut().zip(tile_pixel.contrib_sum) {
+                *rgb += contrib;
+            }
+            pixel.filter_weight_sum += tile_pixel.filter_weight_sum;
+        }
+        drop(pixels);
+
+        if let Some(aov_pixels) = &self.aov_pixels {
+            let mut aov_pixels = aov_pixels.lock().unwrap();
+            for p in tile.get_pixel_bounds().pixels() {
+                if let Some(tile_pixel) = tile.get_aov_pixel(&p) {
+                    aov_pixels[self.pixel_offset(&p)].merge(tile_pixel);
+                }
+            }
+        }
+    }
+
+    /// Reconstructed value of a pixel inside of the cropped bounds
+    pub fn get_pixel_rgb(&self, p : &Point2i) -> [f32; 3] {
+        let pixel = self.pixels.lock().unwrap()[self.pixel_offset(p)];
+        if pixel.filter_weight_sum == 0.0 {
+            return [0.0; 3];
+        }
+        let inv_weight = 1.0 / pixel.filter_weight_sum;
+        pixel.rgb.map(|c| (c * inv_weight).max(0.0))
+    }
+
+    /// Reconstructed values of the cropped pixels, row by row from the top
+    pub fn get_rgb_image(&self) -> Vec<[f32; 3]> {
+        self.cropped_pixel_bounds.pixels().map(|p| self.get_pixel_rgb(&p)).collect()
+    }
+
+    /// Write the cropped image in the format given by the extension of the path,
+    /// `ldr` only applies to the 8 bits formats
+    pub fn write_image(&self, path : &Path, ldr : &LdrEncoding) -> io::Result<()> {
+        let (width, height) = self.cropped_resolution();
+        write_image(path, &self.get_rgb_image(), width, height, ldr)
+    }
+
+    /// Write the cropped image through a display transform, as an 8 bits `ppm` or `png`
+    pub fn write_tone_mapped_image(&self, path : &Path, tone_mapping : &ToneMapping) -> io::Result<()> {
+        let (width, height) = self.cropped_resolution();
+        let encoded : Vec<u8> = self.get_rgb_image().into_iter().flat_map(|rgb| tone_mapping.encode(rgb)).collect();
+        write_ldr_image(path, &encoded, width, height)
+    }
+
+    /// Values of an AOV for the cropped pixels, row by row from the top, with the channels missing
+    /// from the AOV set to zero. None when the film doesn't record AOVs
+    pub fn get_aov_image(&self, aov : Aov) -> Option<Vec<[f32; 3]>> {
+        let aov_pixels = self.aov_pixels.as_ref()?.lock().unwrap();
+        Some(aov_pixels.iter().map(|pixel| {
+            let mut rgb = [0.0; 3];
+            for (c, v) in rgb.iter_mut().zip(pixel.values(aov)) {
+                *c = v;
+            }
+            rgb
+        }).collect())
+    }
+
+    /// Write a single AOV as its own image, in the format given by the extension of the path
+    pub fn write_aov_image(&self, aov : Aov, path : &Path, ldr : &LdrEncoding) -> io::Result<()> {
+        let Some(image) = self.get_aov_image(aov) else {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "the film doesn't record AOVs"));
+        };
+        let (width, height) = self.cropped_resolution();
+        write_image(path, &image, width, height, ldr)
+    }
+
+    /// Write the image along with every AOV as extra channels of a single OpenEXR file. The values are
+    /// stored as 32 bits floats so that the depth and the IDs keep their precision
+    pub fn write_exr_with_aovs(&self, path : &Path) -> io::Result<()> {
+        let Some(aov_pixels) = &self.aov_pixels else {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "the film doesn't record AOVs"));
+        };
+        let (width, height) = self.cropped_resolution();
+        let [r, g, b] = rgb_channels(&self.get_rgb_image());
+        let mut planes : Vec<(&str, Vec<f32>)> = vec![("R", r), ("G", g), ("B", b)];
+        {
+            let aov_pixels = aov_pixels.lock().unwrap();
+            for aov in Aov::ALL {
+                let names = aov.channel_names();
+                let mut aov_planes = vec![Vec::with_capacity(aov_pixels.len()); names.len()];
+                for pixel in aov_pixels.iter() {
+                    for (plane, v) in aov_planes.iter_mut().zip(pixel.values(aov)) {
+                        plane.push(v);
+                    }
+                }
+                planes.extend(names.iter().copied().zip(aov_planes));
+            }
+        }
+
+        let channels : Vec<(&str, &[f32])> = planes.iter().map(|(name, values)| (*name, values.as_slice())).collect();
+        let mut writer = BufWriter::new(File::create(path)?);
+        write_exr(&mut writer, width, height, &channels, ExrPixelType::Float)?;
+        writer.flush()
+    }
+
+    fn cropped_resolution(&self) -> (usize, usize) {
+        let b = &self.cropped_pixel_bounds;
+        ((b.p_max.x - b.p_min.x) as usize, (b.p_max.y - b.p_min.y) as usize)
+    }
+
+    fn pixel_offset(&self, p : &Point2i) -> usize {
+        let b = &self.cropped_pixel_bounds;
+        let width = b.p_max.x - b.p_min.x;
+        ((p.y - b.p_min.y) * width + (p.x - b.p_min.x)) as usize
+    }
+
+    pub fn filter_radius(&self) -> Vector2f {
+        self.filter.radius()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use crate::engine::cameras::test_cameras::{box_film, pixel_box_filter};
+    use crate::engine::film::aov::AovSample;
+    use crate::engine::filters::box_filter::BoxFilter;
+    use crate::engine::filters::triangle::TriangleFilter;
+    use crate::engine::math::Point::Point3f;
+    use crate::engine::math::rays::BaseRay;
+    use crate::engine::math::rays::Ray::Ray;
+    use crate::engine::math::transformations::Transform;
+    use crate::engine::math::Vector::Vector3f;
+    use crate::engine::primitives::{GeometricPrimitive, Primitive};
+    use crate::engine::primitives::sphere::Sphere;
+    use crate::util::tone_mapping::{OutputColorSpace, ToneCurve};
+
+    #[test]
+    fn test_crop_window_and_sample_bounds() {
+        let crop_window = Bound2f::from_points(&Point2f::new(0.25, 0.5), &Point2f::new(0.75, 1.0));
+        let film = Film::new(Point2i{ x: 100, y: 40 }, crop_window, Box::new(BoxFilter::new(Vector2f::new(1.5, 0.5))), 35.0);
+
+        let cropped = film.get_cropped_pixel_bounds();
+        assert_eq!((cropped.p_min, cropped.p_max), (Point2i{ x: 25, y: 20 }, Point2i{ x: 75, y: 40 }));
+        assert_eq!(cropped.area(), 50 * 20);
+
+        // The samples of the neighbour pixels reach the cropped pixels through the filter
+        let sample_bounds = film.get_sample_bounds();
+        assert_eq!((sample_bounds.p_min, sample_bounds.p_max), (Point2i{ x: 24, y: 20 }, Point2i{ x: 76, y: 40 }));
+    }
+
+    #[test]
+    fn test_tiles_merge_filtered_samples() {
+        let film = Film::full_frame(Point2i{ x: 8, y: 8 }, Box::new(TriangleFilter::new(Vector2f::new(1.0, 1.0))), 35.0);
+
+        let top = Bound2i::from_corners(Point2i{ x: 0, y: 0 }, Point2i{ x: 8, y: 4 });
+        let bottom = Bound2i::from_corners(Point2i{ x: 0, y: 4 }, Point2i{ x: 8, y: 8 });
+        let mut top_tile = film.get_film_tile(&top);
+        let mut bottom_tile = film.get_film_tile(&bottom);
+        // The tiles overlap on the rows reached by the filter from the other tile
+        assert_eq!(top_tile.get_pixel_bounds().p_max.y, 5);
+        assert_eq!(bottom_tile.get_pixel_bounds().p_min.y, 3);
+
+        // The filter of the pixels two columns away is zero at the sample
+        top_tile.add_sample(&Point2f::new(2.5, 1.5), [1.0, 2.0, 3.0], 1.0);
+        // Samples on the border between two tiles contribute to pixels of both of them
+        top_tile.add_sample(&Point2f::new(5.5, 3.9), [4.0, 0.0, 0.0], 1.0);
+        bottom_tile.add_sample(&Point2f::new(5.5, 4.1), [0.0, 4.0, 0.0], 1.0);
+        film.merge_film_tile(top_tile);
+        film.merge_film_tile(bottom_tile);
+
+        let center = film.get_pixel_rgb(&Point2i{ x: 2, y: 1 });
+        assert!((0..3).all(|c| (center[c] - (c + 1) as f32).abs() < 1e-5), "{:?}", center);
+        assert_eq!(film.get_pixel_rgb(&Point2i{ x: 4, y: 1 }), [0.0, 0.0, 0.0]);
+
+        // Both pixels receive both samples, weighted by their distance
+        let upper = film.get_pixel_rgb(&Point2i{ x: 5, y: 3 });
+        let lower = film.get_pixel_rgb(&Point2i{ x: 5, y: 4 });
+        assert!(upper[0] > upper[1] && upper[1] > 0.0, "{:?}", upper);
+        assert!(lower[1] > lower[0] && lower[0] > 0.0, "{:?}", lower);
+        assert!((upper[0] + upper[1] - 4.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_write_cropped_image() {
+        let crop_window = Bound2f::from_points(&Point2f::new(0.5, 0.0), &Point2f::new(1.0, 1.0));
+        let film = Film::new(Point2i{ x: 4, y: 2 }, crop_window, pixel_box_filter(), 35.0);
+        let mut tile = film.get_film_tile(&film.get_sample_bounds());
+        tile.add_sample(&Point2f::new(3.5, 0.5), [1.0, 0.5, 0.25], 1.0);
+        film.merge_film_tile(tile);
+
+        let path = std::env::temp_dir().join("film_write_test.pfm");
+        film.write_image(&path, &LdrEncoding::default()).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(bytes.starts_with(b"PF\n2 2\n-1\n"));
+        assert_eq!(bytes.len(), 10 + 2 * 2 * 12);
+
+        // The top right pixel is the last one of the last row written
+        let value = |i : usize| f32::from_le_bytes(bytes[10 + 4 * i..14 + 4 * i].try_into().unwrap());
+        assert_eq!((value(9), value(10), value(11)), (1.0, 0.5, 0.25));
+        assert_eq!(value(0), 0.0);
+    }
+
+    #[test]
+    fn test_write_tone_mapped_image() {
+        let film = box_film(Point2i{ x: 1, y: 1 });
+        let mut tile = film.get_film_tile(&film.get_sample_bounds());
+        tile.add_sample(&Point2f::new(0.5, 0.5), [1.0, 4.0, 0.0], 1.0);
+        film.merge_film_tile(tile);
+
+        let path = std::env::temp_dir().join("film_tone_mapped_test.ppm");
+        let tone_mapping = ToneMapping::new(ToneCurve::Reinhard, OutputColorSpace::Srgb).with_exposure(-1.0);
+        film.write_tone_mapped_image(&path, &tone_mapping).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(bytes, [b"P6\n1 1\n255\n".as_slice(), &tone_mapping.encode([1.0, 4.0, 0.0])].concat());
+        assert!(film.write_tone_mapped_image(&std::env::temp_dir().join("film_tone_mapped_test.exr"), &tone_mapping).is_err());
+    }
+
+    #[test]
+    fn test_aovs_of_first_hits() {
+        let sphere = Sphere::new(Arc::new(Transform::identity()), Arc::new(Transform::identity()), false, 1.0, -1.0, 1.0, 360.0);
+        let primitive = GeometricPrimitive::new(Arc::new(sphere), None, None, None).with_ids(7, 3);
+        let ray = Ray::new(Point3f::new(0.0, 0.0, -5.0), Vector3f::new(0.0, 0.0, 1.0), f32::INFINITY, 0.0, None);
+        let hit = AovSample::from_interaction(&ray, &primitive.intersect(&ray).unwrap());
+
+        let film = box_film(Point2i{ x: 2, y: 1 }).with_aovs();
+        let mut tile = film.get_film_tile(&film.get_sample_bounds());
+        tile.add_aov_sample(&Point2f::new(0.25, 0.5), Some(&hit));
+        tile.add_aov_sample(&Point2f::new(0.75, 0.5), None);
+        tile.add_aov_sample(&Point2f::new(1.5, 0.5), None);
+        film.merge_film_tile(tile);
+
+        // Misses only count as samples
+        let depth = film.get_aov_image(Aov::Depth).unwrap();
+        assert!((depth[0][0] - 4.0).abs() < 1e-4);
+        assert_eq!(depth[1][0], f32::INFINITY);
+        let normal = film.get_aov_image(Aov::Normal).unwrap()[0];
+        assert!((normal[2] + 1.0).abs() < 1e-4, "{:?}", normal);
+        assert_eq!(film.get_aov_image(Aov::Albedo).unwrap()[0], [1.0; 3]);
+        assert_eq!(film.get_aov_image(Aov::PrimitiveId).unwrap()[0][0], 7.0);
+        assert_eq!(film.get_aov_image(Aov::MaterialId).unwrap()[0][0], 3.0);
+        assert_eq!(film.get_aov_image(Aov::SampleCount).unwrap().iter().map(|p| p[0]).collect::<Vec<_>>(), vec![2.0, 1.0]);
+
+        let path = std::env::temp_dir().join("film_aov_test.exr");
+        film.write_exr_with_aovs(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let channel_count = 3 + Aov::ALL.iter().map(|aov| aov.channel_names().len()).sum::<usize>();
+        // The single scanline ends the file with its number, its size and a float per channel and pixel
+        let line_size = channel_count * 2 * 4;
+        let line_start = bytes.len() - line_size - 8;
+        assert_eq!(bytes[line_start..line_start + 8], [0, 0, 0, 0, line_size as u8, 0, 0, 0]);
+        assert!(bytes.windows(12).any(|w| w == b"sampleCount\0"));
+
+        assert!(box_film(Point2i{ x: 2, y: 1 }).get_aov_image(Aov::Depth).is_none());
+    }
+}
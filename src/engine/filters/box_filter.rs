@@ -0,0 +1,24 @@
+use crate::engine::filters::Filter;
+use crate::engine::math::Point::Point2f;
+use crate::engine::math::Vector::Vector2f;
+
+/// Weight equally every sample inside of the radius
+pub(crate) struct BoxFilter{
+    radius : Vector2f,
+}
+
+impl BoxFilter{
+    pub fn new(radius : Vector2f) -> BoxFilter{
+        Self{radius}
+    }
+}
+
+impl Filter for BoxFilter{
+    fn evaluate(&self, _p : &Point2f) -> f32 {
+        1.0
+    }
+
+    fn radius(&self) -> Vector2f {
+        self.radius
+    }
+}
@@ -0,0 +1,36 @@
+use crate::engine::filters::Filter;
+use crate::engine::math::Point::Point2f;
+use crate::engine::math::Vector::Vector2f;
+
+/// A Gaussian of falloff `alpha`, shifted down by its value at the radius so it reaches zero there
+pub(crate) struct GaussianFilter{
+    radius : Vector2f,
+    alpha : f32,
+    exp_x : f32,
+    exp_y : f32,
+}
+
+impl GaussianFilter{
+    pub fn new(radius : Vector2f, alpha : f32) -> GaussianFilter{
+        Self{
+            radius,
+            alpha,
+            exp_x: (-alpha * radius.x * radius.x).exp(),
+            exp_y: (-alpha * radius.y * radius.y).exp(),
+        }
+    }
+
+    fn gaussian(&self, d : f32, exp_v : f32) -> f32 {
+        ((-self.alpha * d * d).exp() - exp_v).max(0.0)
+    }
+}
+
+impl Filter for GaussianFilter{
+    fn evaluate(&self, p : &Point2f) -> f32 {
+        self.gaussian(p.x, self.exp_x) * self.gaussian(p.y, self.exp_y)
+    }
+
+    fn radius(&self) -> Vector2f {
+        self.radius
+    }
+}
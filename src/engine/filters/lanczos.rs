@@ -0,0 +1,42 @@
+use std::f32::consts::PI;
+use crate::engine::filters::Filter;
+use crate::engine::math::Point::Point2f;
+use crate::engine::math::Vector::Vector2f;
+
+/// A sinc function windowed by a wider sinc with `tau` cycles over the radius
+pub(crate) struct LanczosSincFilter{
+    radius : Vector2f,
+    tau : f32,
+}
+
+impl LanczosSincFilter{
+    pub fn new(radius : Vector2f, tau : f32) -> LanczosSincFilter{
+        Self{radius, tau}
+    }
+
+    fn windowed_sinc(&self, x : f32, radius : f32) -> f32 {
+        let x = x.abs();
+        if x > radius {
+            return 0.0;
+        }
+        sinc(x) * sinc(x / self.tau)
+    }
+}
+
+fn sinc(x : f32) -> f32 {
+    let x = x.abs();
+    if x < 1e-5 {
+        return 1.0;
+    }
+    (PI * x).sin() / (PI * x)
+}
+
+impl Filter for LanczosSincFilter{
+    fn evaluate(&self, p : &Point2f) -> f32 {
+        self.windowed_sinc(p.x, self.radius.x) * self.windowed_sinc(p.y, self.radius.y)
+    }
+
+    fn radius(&self) -> Vector2f {
+        self.radius
+    }
+}
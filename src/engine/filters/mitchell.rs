@@ -0,0 +1,46 @@
+use crate::engine::filters::Filter;
+use crate::engine::math::Point::Point2f;
+use crate::engine::math::Vector::Vector2f;
+
+/// Mitchell-Netravali cubic filter, trading blurring for ringing with its `b` and `c` parameters.
+/// The values satisfying `b + 2c = 1` are recommended
+pub(crate) struct MitchellFilter{
+    radius : Vector2f,
+    inv_radius : Vector2f,
+    b : f32,
+    c : f32,
+}
+
+impl MitchellFilter{
+    pub fn new(radius : Vector2f, b : f32, c : f32) -> MitchellFilter{
+        Self{
+            radius,
+            inv_radius: Vector2f::new(1.0 / radius.x, 1.0 / radius.y),
+            b,
+            c,
+        }
+    }
+
+    /// The cubic spline over [-2, 2]
+    fn mitchell_1d(&self, x : f32) -> f32 {
+        let (b, c) = (self.b, self.c);
+        let x = x.abs();
+        if x > 1.0 {
+            ((-b - 6.0 * c) * x * x * x + (6.0 * b + 30.0 * c) * x * x
+                + (-12.0 * b - 48.0 * c) * x + (8.0 * b + 24.0 * c)) / 6.0
+        } else {
+            ((12.0 - 9.0 * b - 6.0 * c) * x * x * x + (-18.0 + 12.0 * b + 6.0 * c) * x * x + (6.0 - 2.0 * b)) / 6.0
+        }
+    }
+}
+
+impl Filter for MitchellFilter{
+    fn evaluate(&self, p : &Point2f) -> f32 {
+        // Scale the radius to the [-2, 2] support of the spline
+        self.mitchell_1d(2.0 * p.x * self.inv_radius.x) * self.mitchell_1d(2.0 * p.y * self.inv_radius.y)
+    }
+
+    fn radius(&self) -> Vector2f {
+        self.radius
+    }
+}
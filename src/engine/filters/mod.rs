@@ -0,0 +1,86 @@
+pub(crate) mod box_filter;
+pub(crate) mod triangle;
+pub(crate) mod gaussian;
+pub(crate) mod mitchell;
+pub(crate) mod lanczos;
+
+use crate::engine::math::Point::Point2f;
+use crate::engine::math::Vector::Vector2f;
+
+// A Class weighting the contribution of a sample to the pixels around it when reconstructing the image
+pub(crate) trait Filter : Send + Sync {
+
+    // Value of the filter at the point relative to its center, zero outside of its radius
+    fn evaluate(&self, p : &Point2f) -> f32;
+
+    // Distance from the center beyond which the filter is zero, in pixels
+    fn radius(&self) -> Vector2f;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::filters::box_filter::BoxFilter;
+    use crate::engine::filters::gaussian::GaussianFilter;
+    use crate::engine::filters::lanczos::LanczosSincFilter;
+    use crate::engine::filters::mitchell::MitchellFilter;
+    use crate::engine::filters::triangle::TriangleFilter;
+
+    /// Numerical integral of the filter over its support
+    fn integral(filter : &dyn Filter) -> f32 {
+        let r = filter.radius();
+        let n = 200;
+        let mut sum = 0.0;
+        for i in 0..n {
+            for j in 0..n {
+                let p = Point2f::new(
+                    -r.x + 2.0 * r.x * (i as f32 + 0.5) / n as f32,
+                    -r.y + 2.0 * r.y * (j as f32 + 0.5) / n as f32
+                );
+                sum += filter.evaluate(&p);
+            }
+        }
+        sum * 4.0 * r.x * r.y / (n * n) as f32
+    }
+
+    #[test]
+    fn test_filters_vanish_at_radius() {
+        let radius = Vector2f::new(2.0, 1.0);
+        let filters : Vec<Box<dyn Filter>> = vec![
+            Box::new(TriangleFilter::new(radius)),
+            Box::new(GaussianFilter::new(radius, 2.0)),
+            Box::new(MitchellFilter::new(radius, 1.0 / 3.0, 1.0 / 3.0)),
+            Box::new(LanczosSincFilter::new(radius, 3.0)),
+        ];
+        for filter in &filters {
+            assert_eq!(filter.radius(), radius);
+            assert!(filter.evaluate(&Point2f::new(0.0, 0.0)) > 0.0);
+            assert!(filter.evaluate(&Point2f::new(2.0, 0.0)).abs() < 1e-5);
+            assert!(filter.evaluate(&Point2f::new(0.0, -1.0)).abs() < 1e-5);
+            // Symmetric around the center
+            assert!((filter.evaluate(&Point2f::new(0.7, -0.3)) - filter.evaluate(&Point2f::new(-0.7, 0.3))).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_filter_values() {
+        let radius = Vector2f::new(1.0, 1.0);
+        let box_filter = BoxFilter::new(Vector2f::new(0.5, 0.5));
+        assert_eq!(box_filter.evaluate(&Point2f::new(0.4, -0.2)), 1.0);
+        assert!((integral(&box_filter) - 1.0).abs() < 1e-3);
+
+        let triangle = TriangleFilter::new(radius);
+        assert_eq!(triangle.evaluate(&Point2f::new(0.5, 0.0)), 0.5);
+        assert!((integral(&triangle) - 1.0).abs() < 1e-3);
+
+        // The Mitchell filter with B + 2C = 1 integrates to one and has negative lobes
+        let mitchell = MitchellFilter::new(Vector2f::new(2.0, 2.0), 1.0 / 3.0, 1.0 / 3.0);
+        assert!((integral(&mitchell) - 1.0).abs() < 1e-2);
+        assert!(mitchell.evaluate(&Point2f::new(1.5, 0.0)) < 0.0);
+
+        let lanczos = LanczosSincFilter::new(Vector2f::new(3.0, 3.0), 3.0);
+        assert!((lanczos.evaluate(&Point2f::new(0.0, 0.0)) - 1.0).abs() < 1e-6);
+        assert!(lanczos.evaluate(&Point2f::new(1.0, 0.0)).abs() < 1e-6);
+        assert!(lanczos.evaluate(&Point2f::new(1.5, 0.0)) < 0.0);
+    }
+}
@@ -0,0 +1,24 @@
+use crate::engine::filters::Filter;
+use crate::engine::math::Point::Point2f;
+use crate::engine::math::Vector::Vector2f;
+
+/// Weight falling off linearly from the center to the radius
+pub(crate) struct TriangleFilter{
+    radius : Vector2f,
+}
+
+impl TriangleFilter{
+    pub fn new(radius : Vector2f) -> TriangleFilter{
+        Self{radius}
+    }
+}
+
+impl Filter for TriangleFilter{
+    fn evaluate(&self, p : &Point2f) -> f32 {
+        (self.radius.x - p.x.abs()).max(0.0) * (self.radius.y - p.y.abs()).max(0.0)
+    }
+
+    fn radius(&self) -> Vector2f {
+        self.radius
+    }
+}
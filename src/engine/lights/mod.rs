@@ -2,6 +2,11 @@ pub(crate) trait Light{
     fn preprocess(&self);
 }
 
+// Light emitted by the surface of a shape
+pub(crate) trait AreaLight : Light + Send + Sync{
+
+}
+
 
 pub struct GeneralLight{
 
@@ -0,0 +1,21 @@
+use crate::engine::SurfaceInteraction;
+
+/// Whether the rays traced carry radiance from the lights or importance from the camera,
+/// some scattering functions are not symmetric between both
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum TransportMode{
+    Radiance,
+    Importance,
+}
+
+pub(crate) trait Material : Send + Sync{
+
+    // Compute the scattering functions at the surface interaction, accounting for the shading geometry
+    fn compute_scattering_functions(&self, si : &mut SurfaceInteraction, mode : TransportMode, allow_multiple_lobes : bool);
+
+    // Overall reflectance of the surface at the interaction, used for the albedo AOV of the film.
+    // Materials that can't estimate it report a white surface
+    fn albedo(&self, _si : &SurfaceInteraction) -> [f32; 3] {
+        [1.0; 3]
+    }
+}
@@ -20,6 +20,10 @@ impl<T> Normal3<T> where T: Signed + Clone + Real{
         Self{x, y, z}
     }
 
+    pub fn from_vector(v : Vector3<T>) -> Self{
+        Self{x: v.x, y: v.y, z: v.z}
+    }
+
     pub fn neg(&self) -> Self{
         Self{
             x: -self.x,
@@ -28,12 +32,20 @@ impl<T> Normal3<T> where T: Signed + Clone + Real{
         }
     }
 
+    pub fn abs(&self) -> Self{
+        Self{
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs(),
+        }
+    }
+
     pub(crate) fn length(&self) -> T{
-        self.x * self.x + self.y * self.y + self.z * self.z
+        self.length_sq().sqrt()
     }
 
     fn length_sq(&self) -> T{
-        self.length().sqrt()
+        self.x * self.x + self.y * self.y + self.z * self.z
     }
 
     pub fn normalize(&self) -> Self{
@@ -60,8 +72,8 @@ impl<T> Normal3<T> where T: Signed + Clone + Real{
         self.dot(rhs).abs()
     }
 
-    pub fn face_forward(&self, v : &Normal3f) -> Self{
-        if self.dot(&v) < T::zero(){
+    pub fn face_forward(&self, v : &Normal3<T>) -> Self{
+        if self.dot(&Vector3::new(v.x, v.y, v.z)) < T::zero(){
             self.neg()
         }else {
             self.clone()
@@ -1,6 +1,6 @@
 use crate::engine::math::Vector::{Vector2, Vector3};
 use std::f32;
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub};
 use num_traits::real::Real;
 use num_traits::Signed;
 
@@ -42,15 +42,16 @@ impl<T> Point2<T> where T: Copy + Signed + Clone + Real{
     }
 
     fn distance_sq(&self, p2 : Point2<T>) -> T{
-        self.distance(p2).sqrt()
+        let d = self.distance(p2);
+        d * d
     }
 
     fn length(&self) -> T{
-        self.x * self.x + self.y * self.y
+        self.length_sq().sqrt()
     }
 
     fn length_sq(&self) -> T{
-        self.length().sqrt()
+        self.x * self.x + self.y * self.y
     }
 
     fn normalize(&self) -> Self{
@@ -139,7 +140,10 @@ impl<T> Sub<Vector2<T>> for Point2<T> where T: Sub<Output = T>{
     type Output = Point2<T>;
 
     fn sub(self, rhs: Vector2<T>) -> Self::Output {
-        self - rhs
+        Self{
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
     }
 }
 
@@ -194,11 +198,11 @@ impl<T> Point3<T> where T: Signed + Clone + Real{
     }
 
     fn length(&self) -> T{
-        self.x.clone() * self.x.clone() + self.y.clone() * self.y.clone() + self.z.clone() * self.z.clone()
+        self.length_sq().sqrt()
     }
 
     fn length_sq(&self) -> T{
-        self.length().sqrt()
+        self.x.clone() * self.x.clone() + self.y.clone() * self.y.clone() + self.z.clone() * self.z.clone()
     }
 
     pub(crate) fn distance(&self, p2 : Point3<T>) -> T{
@@ -209,8 +213,12 @@ impl<T> Point3<T> where T: Signed + Clone + Real{
         }.length()
     }
 
-    fn distance_sq(&self, p2 : Point3<T>) -> T{
-        self.distance(p2).sqrt()
+    pub(crate) fn distance_sq(&self, p2 : Point3<T>) -> T{
+        Vector3{
+            x: self.x - p2.x,
+            y: self.y - p2.y,
+            z: self.z - p2.z
+        }.length_sq()
     }
 
     pub fn normalize(&self) -> Self{
@@ -340,6 +348,30 @@ impl<T> Div<f32> for Point3<T> where T: Mul<f32, Output = T>{
 }
 
 
+impl<T> Index<usize> for Point3<T>{
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Index out of bounds for Point3: {}", index),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Point3<T>{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Index out of bounds for Point3: {}", index),
+        }
+    }
+}
+
 pub type Point2f = Point2<f32>;
 pub type Point2i = Point2<i32>;
 pub type Point3f = Point3<f32>;
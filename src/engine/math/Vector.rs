@@ -1,7 +1,8 @@
 use std::f32;
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::ops::{Add, Div, Index, Mul, Neg, Sub};
 use num_traits::real::Real;
 use num_traits::Signed;
+use crate::engine::math::Normal::Normal3;
 
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub(crate) struct Vector2<T>{
@@ -36,11 +37,11 @@ impl<T> Vector2<T> where T: Signed + Clone + Real{
     }
 
     pub(crate) fn length(&self) -> T{
-        self.x.clone() * self.x.clone() + self.y.clone() * self.y.clone()
+        self.length_sq().sqrt()
     }
 
     fn length_sq(&self) -> T{
-        self.length().sqrt()
+        self.x.clone() * self.x.clone() + self.y.clone() * self.y.clone()
     }
 
     fn normalize(&self) -> Self{
@@ -118,7 +119,7 @@ impl<T> Div<f32> for Vector2<T> where T: Mul<f32, Output = T>{
         }
     }
 }
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub(crate) struct Vector3<T>{
     pub x: T,
     pub y: T,
@@ -129,6 +130,10 @@ impl<T> Vector3<T> where T: Signed + Clone + Real{
         Self{x, y, z}
     }
 
+    pub fn from_normal(n : Normal3<T>) -> Self{
+        Self{x: n.x, y: n.y, z: n.z}
+    }
+
     pub fn neg(&self) -> Self{
         Self{
             x: -self.x.clone(),
@@ -186,11 +191,11 @@ impl<T> Vector3<T> where T: Signed + Clone + Real{
     }
 
     pub(crate) fn length(&self) -> T{
-        self.x.clone() * self.x.clone() + self.y.clone() * self.y.clone() + self.z.clone() * self.z.clone()
+        self.length_sq().sqrt()
     }
 
-    fn length_sq(&self) -> T{
-        self.length().sqrt()
+    pub(crate) fn length_sq(&self) -> T{
+        self.x.clone() * self.x.clone() + self.y.clone() * self.y.clone() + self.z.clone() * self.z.clone()
     }
 
     pub fn normalize(&self) -> Self{
@@ -210,6 +215,25 @@ impl<T> Vector3<T> where T: Signed + Clone + Real{
         self.x.max(self.y.max(self.z))
     }
 
+    /// Index of the component with the largest value
+    pub fn max_dimension(&self) -> usize{
+        if self.x > self.y {
+            if self.x > self.z { 0 } else { 2 }
+        } else {
+            if self.y > self.z { 1 } else { 2 }
+        }
+    }
+
+    /// Build a new vector by selecting the components at the given indices
+    pub fn permute(&self, x : usize, y : usize, z : usize) -> Vector3<T>{
+        let v = [self.x, self.y, self.z];
+        Self{
+            x: v[x],
+            y: v[y],
+            z: v[z],
+        }
+    }
+
     pub fn max(&self, rhs : Vector3<T>) -> Vector3<T> {
         Self{
             x: self.x.clone().max(rhs.x.clone()),
@@ -277,6 +301,19 @@ impl<T> Div<f32> for Vector3<T> where T: Mul<f32, Output = T>{
 }
 
 
+impl<T> Index<usize> for Vector3<T>{
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Index out of bounds for Vector3: {}", index),
+        }
+    }
+}
+
 pub type Vector2f = Vector2<f32>;
 pub type Vector2i = Vector2<i32>;
 pub type Vector3f = Vector3<f32>;
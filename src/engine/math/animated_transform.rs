@@ -0,0 +1,282 @@
+use crate::engine::math::bounding_box::Bound3f;
+use crate::engine::math::lerp;
+use crate::engine::math::Point::Point3f;
+use crate::engine::math::quaternion::Quaternion;
+use crate::engine::math::rays::BaseRay;
+use crate::engine::math::rays::Ray::Ray;
+use crate::engine::math::transformations::{Apply, Matrix4x4, Transform};
+use crate::engine::math::Vector::Vector3f;
+
+/// A transformation moving between two keyframes over a time range.
+/// Each keyframe is decomposed into a translation, a rotation and a scale which are interpolated
+/// independently, so that rotations don't shrink the geometry the way a matrix interpolation would
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AnimatedTransform{
+    start_transform : Transform,
+    end_transform : Transform,
+    start_time : f32,
+    end_time : f32,
+    actually_animated : bool,
+
+    translations : [Vector3f; 2],
+    rotations : [Quaternion; 2],
+    scales : [Matrix4x4; 2],
+    has_rotation : bool,
+}
+
+impl AnimatedTransform{
+    /// Both keyframes must be invertible when they differ, to be decomposed
+    pub fn new(start_transform : Transform, start_time : f32, end_transform : Transform, end_time : f32) -> AnimatedTransform{
+        let actually_animated = start_transform != end_transform;
+        // A static transformation is used as it is, without being decomposed
+        let ((t0, mut r0, s0), (t1, mut r1, s1)) = if actually_animated {
+            (decompose(&start_transform), decompose(&end_transform))
+        } else {
+            let identity = (Vector3f::default(), Quaternion::default(), Matrix4x4::identity());
+            (identity, identity)
+        };
+
+        // Flip one of the rotations if needed so the interpolation takes the shortest path
+        if r0.dot(&r1) < 0.0 {
+            r1 = -r1;
+        }
+        r0 = r0.normalize();
+        r1 = r1.normalize();
+
+        Self{
+            start_transform,
+            end_transform,
+            start_time,
+            end_time,
+            actually_animated,
+            translations: [t0, t1],
+            rotations: [r0, r1],
+            scales: [s0, s1],
+            has_rotation: r0.dot(&r1) < 0.9995,
+        }
+    }
+
+    /// A transformation that doesn't change over time
+    pub fn from_transform(transform : Transform) -> AnimatedTransform{
+        Self::new(transform, 0.0, transform, 1.0)
+    }
+
+    pub fn is_animated(&self) -> bool {
+        self.actually_animated
+    }
+
+    /// Transformation at the given time, clamped to the keyframes outside of the time range.
+    /// The keyframes are returned as they are, in between the interpolated scale is inverted
+    /// to give the transformation its inverse
+    pub fn interpolate(&self, time : f32) -> Transform {
+        if !self.actually_animated || time <= self.start_time {
+            return self.start_transform;
+        }
+        if time >= self.end_time {
+            return self.end_transform;
+        }
+
+        let dt = (time - self.start_time) / (self.end_time - self.start_time);
+        let translation = self.translations[0] * (1.0 - dt) + self.translations[1] * dt;
+        let rotation = self.rotations[0].slerp(&self.rotations[1], dt);
+
+        let mut scale = Matrix4x4::zeros();
+        for i in 0..3 {
+            for j in 0..3 {
+                scale[(i, j)] = lerp(dt, self.scales[0][(i, j)], self.scales[1][(i, j)]);
+            }
+        }
+        scale[(3, 3)] = 1.0;
+
+        Transform::translate(translation) * rotation.to_transform() * Transform::new(scale, scale.inverse())
+    }
+
+    /// Transform the ray with the transformation at the time of the ray
+    pub fn apply_ray(&self, r : &Ray) -> Ray {
+        self.interpolate(r.get_time()).apply(r)
+    }
+
+    pub fn apply_point(&self, time : f32, p : &Point3f) -> Point3f {
+        self.interpolate(time).apply(p)
+    }
+
+    pub fn apply_vector(&self, time : f32, v : &Vector3f) -> Vector3f {
+        self.interpolate(time).apply(v)
+    }
+
+    /// Conservative bounds of the box over the whole time range
+    pub fn motion_bounds(&self, b : &Bound3f) -> Bound3f {
+        if !self.actually_animated {
+            return self.start_transform.apply(b);
+        }
+        if !self.has_rotation {
+            // Interpolating the translation and scale keeps the box between both keyframes
+            return self.start_transform.apply(b).union_with_box(self.end_transform.apply(b));
+        }
+
+        // The scale is interpolated linearly, so the scaled box stays inside the box bounding both scaled keyframes
+        let scaled = Transform::new(self.scales[0], self.scales[0].inverse()).apply(b)
+            .union_with_box(Transform::new(self.scales[1], self.scales[1].inverse()).apply(b));
+
+        // Every corner of the scaled box moves along an arc around the axis of the relative rotation
+        let r0 = self.rotations[0].to_transform();
+        let relative = relative_rotation(&self.rotations[0], &self.rotations[1]);
+        let mut bounds = Bound3f::new();
+        for corner in 0..8 {
+            let p = r0.apply(&scaled.corner(corner));
+            bounds = bounds.union_with_box(bound_arc(&p, &relative.0, relative.1));
+        }
+
+        // Sweep the rotated bounds along the translation
+        let t0 = self.translations[0];
+        let t1 = self.translations[1];
+        Bound3f::from_points(
+            &(bounds.p_min + Vector3f::new(t0.x.min(t1.x), t0.y.min(t1.y), t0.z.min(t1.z))),
+            &(bounds.p_max + Vector3f::new(t0.x.max(t1.x), t0.y.max(t1.y), t0.z.max(t1.z))),
+        )
+    }
+
+    /// Conservative bounds of the positions of a point over the whole time range
+    pub fn bound_point_motion(&self, p : &Point3f) -> Bound3f {
+        self.motion_bounds(&Bound3f::from_point(p))
+    }
+}
+
+/// Split a transformation into its translation, rotation and scale `M = T R S`,
+/// using a polar decomposition for the rotation
+fn decompose(transform : &Transform) -> (Vector3f, Quaternion, Matrix4x4) {
+    let m = transform.get_matrix();
+    let translation = Vector3f::new(m[(0, 3)], m[(1, 3)], m[(2, 3)]);
+
+    // Keep only the upper 3x3 part
+    let mut mat = *m;
+    for i in 0..3 {
+        mat[(i, 3)] = 0.0;
+        mat[(3, i)] = 0.0;
+    }
+    mat[(3, 3)] = 1.0;
+
+    // Average the matrix with its inverse transpose until it converges to the rotation
+    let mut r = mat;
+    for _ in 0..100 {
+        let r_it = r.inverse().transpose();
+        let mut r_next = Matrix4x4::zeros();
+        let mut norm : f32 = 0.0;
+        for i in 0..4 {
+            for j in 0..4 {
+                r_next[(i, j)] = 0.5 * (r[(i, j)] + r_it[(i, j)]);
+            }
+        }
+        for i in 0..3 {
+            let n = (r[(i, 0)] - r_next[(i, 0)]).abs()
+                + (r[(i, 1)] - r_next[(i, 1)]).abs()
+                + (r[(i, 2)] - r_next[(i, 2)]).abs();
+            norm = norm.max(n);
+        }
+        r = r_next;
+        if norm <= 0.0001 {
+            break;
+        }
+    }
+
+    let rotation = Quaternion::from_transform(&Transform::new(r, r.transpose()));
+    let scale = r.inverse().mul(&mat);
+    (translation, rotation, scale)
+}
+
+/// Axis and angle of the rotation taking `q0` to `q1`
+fn relative_rotation(q0 : &Quaternion, q1 : &Quaternion) -> (Vector3f, f32) {
+    // q1 * conjugate(q0)
+    let (v0, w0) = (q0.v.neg(), q0.w);
+    let (v1, w1) = (q1.v, q1.w);
+    let v = v0 * w1 + v1 * w0 + v1.cross(&v0);
+    let w = w1 * w0 - v1.dot(&v0);
+
+    let sin_half = v.length();
+    if sin_half == 0.0 {
+        return (Vector3f::new(0.0, 0.0, 1.0), 0.0);
+    }
+    (v / sin_half, 2.0 * sin_half.atan2(w))
+}
+
+/// Bound the arc traced by `p` rotating by `theta` radians around `axis`.
+/// Each half of the arc spans at most 90 degrees, so it lies inside the triangle formed by its
+/// end points and the intersection of the tangents at these end points
+fn bound_arc(p : &Point3f, axis : &Vector3f, theta : f32) -> Bound3f {
+    let v = Vector3f::new(p.x, p.y, p.z);
+    let center = *axis * v.dot(axis);
+    let u = v - center;
+    let w = axis.cross(&u);
+    let at = |phi : f32, radius_scale : f32| {
+        let d = center + (u * phi.cos() + w * phi.sin()) * radius_scale;
+        Point3f::new(d.x, d.y, d.z)
+    };
+
+    let tangent_scale = 1.0 / (theta / 4.0).cos();
+    Bound3f::from_points(&at(0.0, 1.0), &at(theta, 1.0))
+        .union(&at(theta / 2.0, 1.0))
+        .union(&at(theta / 4.0, tangent_scale))
+        .union(&at(3.0 * theta / 4.0, tangent_scale))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_point_eq(a : Point3f, b : Point3f) {
+        assert!(a.distance(b) < 1e-4, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn test_interpolate_keyframes() {
+        let start = Transform::translate(Vector3f::new(1.0, 0.0, 0.0));
+        let end = Transform::translate(Vector3f::new(3.0, 2.0, 0.0)) * Transform::rotate_z(90.0) * Transform::scale(2.0, 2.0, 2.0);
+        let animated = AnimatedTransform::new(start, 0.0, end, 1.0);
+
+        let p = Point3f::new(1.0, 0.0, 0.0);
+        assert_point_eq(animated.apply_point(0.0, &p), start.apply(&p));
+        assert_point_eq(animated.apply_point(1.0, &p), end.apply(&p));
+        // Translation and scale are lerped while the rotation is slerped
+        assert_point_eq(animated.apply_point(0.5, &p), Point3f::new(2.0 + 1.5 * 0.5_f32.sqrt(), 1.0 + 1.5 * 0.5_f32.sqrt(), 0.0));
+        // Clamped outside of the time range
+        assert_point_eq(animated.apply_point(-1.0, &p), start.apply(&p));
+        assert_point_eq(animated.apply_point(2.0, &p), end.apply(&p));
+
+        // The inverse brings the ray back to where it was at its time
+        let r = Ray::new(p, Vector3f::new(0.0, 1.0, 0.0), f32::INFINITY, 0.5, None);
+        let back = animated.interpolate(0.5).inverse().apply(&animated.apply_ray(&r));
+        assert_point_eq(back.get_origin(), p);
+        assert_point_eq(Point3f::new(0.0, 0.0, 0.0) + back.get_direction(), Point3f::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn test_motion_bounds_contain_every_position() {
+        let start = Transform::rotate(-20.0, &Vector3f::new(0.3, 1.0, 0.2)) * Transform::scale(1.0, 2.0, 1.0);
+        let end = Transform::translate(Vector3f::new(0.5, -1.0, 2.0)) * Transform::rotate(150.0, &Vector3f::new(0.3, 1.0, 0.2));
+        let animated = AnimatedTransform::new(start, 0.0, end, 1.0);
+
+        let b = Bound3f::from_points(&Point3f::new(-1.0, 0.5, -0.5), &Point3f::new(2.0, 1.0, 0.5));
+        let bounds = animated.motion_bounds(&b).expands(1e-4);
+        for i in 0..=100 {
+            let t = animated.interpolate(i as f32 / 100.0);
+            for corner in 0..8 {
+                assert!(bounds.inside(t.apply(&b.corner(corner))));
+            }
+        }
+    }
+
+    #[test]
+    fn test_static_transform() {
+        let t = Transform::translate(Vector3f::new(1.0, 2.0, 3.0));
+        let animated = AnimatedTransform::from_transform(t);
+        assert!(!animated.is_animated());
+        assert_eq!(animated.interpolate(0.7), t);
+
+        let b = Bound3f::from_points(&Point3f::new(0.0, 0.0, 0.0), &Point3f::new(1.0, 1.0, 1.0));
+        assert_eq!(animated.motion_bounds(&b), t.apply(&b));
+
+        // A flattening transformation can't be decomposed, but doesn't need to be when static
+        let flat = AnimatedTransform::from_transform(Transform::scale(1.0, 1.0, 0.0));
+        assert_eq!(flat.interpolate(0.5), Transform::scale(1.0, 1.0, 0.0));
+    }
+}
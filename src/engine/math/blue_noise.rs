@@ -0,0 +1,143 @@
+use std::sync::OnceLock;
+use crate::engine::math::rng::Rng;
+
+/// Side of the tileable blue noise texture, in pixels
+pub const BLUE_NOISE_RESOLUTION : usize = 64;
+
+// Standard deviation in pixels of the gaussian measuring how clustered the points are
+const SIGMA : f32 = 1.5;
+
+/// Value of the blue noise texture at a pixel, tiled over the plane. The values are uniformly spread over
+/// `[0, 1)`, and neighbouring pixels get values far apart, so the texture has almost no low frequencies
+pub fn blue_noise(x : i32, y : i32) -> f32 {
+    let r = BLUE_NOISE_RESOLUTION as i32;
+    blue_noise_texture()[y.rem_euclid(r) as usize * BLUE_NOISE_RESOLUTION + x.rem_euclid(r) as usize]
+}
+
+/// The texture is generated once, with a fixed seed
+fn blue_noise_texture() -> &'static [f32] {
+    static TEXTURE : OnceLock<Vec<f32>> = OnceLock::new();
+    TEXTURE.get_or_init(|| {
+        let n = BLUE_NOISE_RESOLUTION * BLUE_NOISE_RESOLUTION;
+        void_and_cluster(BLUE_NOISE_RESOLUTION, &mut Rng::default())
+            .into_iter()
+            .map(|rank| (rank as f32 + 0.5) / n as f32)
+            .collect()
+    })
+}
+
+/// Ulichney's void and cluster method: the rank of each pixel of a `resolution` by `resolution` dither array.
+/// A random pattern is relaxed by moving its tightest cluster to its largest void, then its points are ranked
+/// by removing the tightest clusters, and the remaining pixels by filling the largest voids
+fn void_and_cluster(resolution : usize, rng : &mut Rng) -> Vec<u32> {
+    let n = resolution * resolution;
+    let mut prototype = Pattern::new(resolution);
+    let mut ones = 0;
+    while ones < n / 10 {
+        let p = rng.uniform_u32_bounded(n as u32) as usize;
+        if !prototype.set[p] {
+            prototype.toggle(p);
+            ones += 1;
+        }
+    }
+    loop {
+        let cluster = prototype.tightest_cluster();
+        prototype.toggle(cluster);
+        let void = prototype.largest_void();
+        prototype.toggle(void);
+        if void == cluster {
+            break;
+        }
+    }
+
+    let mut ranks = vec![0; n];
+    let mut pattern = prototype.clone();
+    for rank in (0..ones).rev() {
+        let cluster = pattern.tightest_cluster();
+        pattern.toggle(cluster);
+        ranks[cluster] = rank as u32;
+    }
+    // With the gaussian summed over the whole torus, the tightest cluster of the empty pixels is the
+    // largest void of the set ones, so the second half of the ranks is filled the same way as the first
+    let mut pattern = prototype;
+    for rank in ones..n {
+        let void = pattern.largest_void();
+        pattern.toggle(void);
+        ranks[void] = rank as u32;
+    }
+    ranks
+}
+
+/// A binary pattern on the torus, with the sum of the gaussians centered on its set pixels
+#[derive(Clone)]
+struct Pattern{
+    resolution : usize,
+    set : Vec<bool>,
+    energy : Vec<f32>,
+    kernel : Vec<f32>,
+}
+
+impl Pattern{
+    fn new(resolution : usize) -> Pattern{
+        let kernel = (0..resolution * resolution).map(|i| {
+            let (dx, dy) = (i % resolution, i / resolution);
+            let (dx, dy) = (dx.min(resolution - dx) as f32, dy.min(resolution - dy) as f32);
+            (-(dx * dx + dy * dy) / (2.0 * SIGMA * SIGMA)).exp()
+        }).collect();
+        Self{
+            resolution,
+            set: vec![false; resolution * resolution],
+            energy: vec![0.0; resolution * resolution],
+            kernel,
+        }
+    }
+
+    fn toggle(&mut self, p : usize) {
+        let r = self.resolution;
+        let sign = if self.set[p] { -1.0 } else { 1.0 };
+        self.set[p] = !self.set[p];
+        let (px, py) = (p % r, p / r);
+        for (q, energy) in self.energy.iter_mut().enumerate() {
+            let (dx, dy) = ((q % r + r - px) % r, (q / r + r - py) % r);
+            *energy += sign * self.kernel[dy * r + dx];
+        }
+    }
+
+    /// The set pixel with the most set pixels around it
+    fn tightest_cluster(&self) -> usize {
+        (0..self.set.len()).filter(|&p| self.set[p])
+            .max_by(|&a, &b| self.energy[a].total_cmp(&self.energy[b]))
+            .unwrap()
+    }
+
+    /// The empty pixel with the fewest set pixels around it
+    fn largest_void(&self) -> usize {
+        (0..self.set.len()).filter(|&p| !self.set[p])
+            .min_by(|&a, &b| self.energy[a].total_cmp(&self.energy[b]))
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_values_are_uniform_and_blue() {
+        let r = BLUE_NOISE_RESOLUTION as i32;
+        let n = (r * r) as usize;
+        let mut values : Vec<f32> = (0..r).flat_map(|y| (0..r).map(move |x| blue_noise(x, y))).collect();
+        values.sort_by(f32::total_cmp);
+        for (i, v) in values.iter().enumerate() {
+            assert_eq!(*v, (i as f32 + 0.5) / n as f32);
+        }
+        assert_eq!(blue_noise(-1, 3), blue_noise(r - 1, r + 3));
+
+        // The means of 4 by 4 blocks vary much less than for white noise, 1 / (12 * 16)
+        let means : Vec<f32> = (0..r / 4).flat_map(|by| (0..r / 4).map(move |bx| {
+            (0..16).map(|i| blue_noise(4 * bx + i % 4, 4 * by + i / 4)).sum::<f32>() / 16.0
+        })).collect();
+        let variance = means.iter().map(|m| (m - 0.5) * (m - 0.5)).sum::<f32>() / means.len() as f32;
+        assert!(variance < 0.25 / (12.0 * 16.0), "{}", variance);
+    }
+}
@@ -1,8 +1,9 @@
 use std::ops::{Div, Index};
 use num_traits::{Bounded, FromPrimitive, Signed};
-use crate::engine::math::lerp;
+use crate::engine::math::{gamma, lerp};
 use crate::engine::math::Point::{Point2, Point3, Point3f};
-use crate::engine::math::Vector::{Vector2, Vector3};
+use crate::engine::math::rays::BaseRay;
+use crate::engine::math::Vector::{Vector2, Vector3, Vector3f};
 
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub(crate) struct Bound2<T>{
@@ -121,9 +122,10 @@ where T: Signed + Bounded + Copy + num_traits::Float + FromPrimitive
         self.p_max - self.p_min
     }
 
+    /// Area covered by the rectangle
     pub fn surface_area(&self) -> T{
         let d = self.diagonal();
-        T::from_u8(2).unwrap() * (d.x * d.x + d.y * d.y)
+        d.x * d.y
     }
 
     pub fn volume(&self) -> T{
@@ -313,9 +315,10 @@ where T: Signed + Bounded + Copy + num_traits::Float + FromPrimitive
         self.p_max - self.p_min
     }
 
+    /// Total area of the six faces of the box
     pub fn surface_area(&self) -> T{
         let d = self.diagonal();
-        T::from_u8(2).unwrap() * (d.x * d.x + d.y * d.y + d.z * d.z)
+        T::from_u8(2).unwrap() * (d.x * d.y + d.y * d.z + d.z * d.x)
     }
 
     pub fn volume(&self) -> T{
@@ -383,7 +386,110 @@ impl<T> Index<usize> for Bound3<T>{
     }
 }
 
-type Bound2f = Bound2<f32>;
-type Bound3f = Bound3<f32>;
-type Bound2i = Bound2<i32>;
-type Bound3i = Bound3<i32>;
\ No newline at end of file
+impl Bound3<f32>{
+    /// Compute the parametric range of the ray that is inside the box, if any
+    pub fn intersect_p(&self, ray : &impl BaseRay) -> Option<(f32, f32)> {
+        let o = ray.get_origin();
+        let d = ray.get_direction();
+        let mut t0 = 0.0;
+        let mut t1 = ray.get_t_max();
+
+        for i in 0..3 {
+            // Update the interval with the slab of the i-th axis
+            let inv_ray_dir = 1.0 / d[i];
+            let mut t_near = (self.p_min[i] - o[i]) * inv_ray_dir;
+            let mut t_far = (self.p_max[i] - o[i]) * inv_ray_dir;
+            if t_near > t_far {
+                std::mem::swap(&mut t_near, &mut t_far);
+            }
+
+            // Make the far distance conservative so rays grazing the box aren't missed
+            t_far *= 1.0 + 2.0 * gamma(3);
+            t0 = if t_near > t0 { t_near } else { t0 };
+            t1 = if t_far < t1 { t_far } else { t1 };
+            if t0 > t1 {
+                return None;
+            }
+        }
+
+        Some((t0, t1))
+    }
+
+    /// Faster version of the slab test for traversals that precompute the reciprocal
+    /// of the ray direction and whether each of its components is negative
+    pub fn intersect_p_with_inv_dir(&self, ray : &impl BaseRay, inv_dir : &Vector3f, dir_is_neg : &[usize; 3]) -> bool {
+        let o = ray.get_origin();
+
+        let mut t_min = (self[dir_is_neg[0]].x - o.x) * inv_dir.x;
+        let mut t_max = (self[1 - dir_is_neg[0]].x - o.x) * inv_dir.x;
+        let ty_min = (self[dir_is_neg[1]].y - o.y) * inv_dir.y;
+        let ty_max = (self[1 - dir_is_neg[1]].y - o.y) * inv_dir.y * (1.0 + 2.0 * gamma(3));
+        t_max *= 1.0 + 2.0 * gamma(3);
+
+        if t_min > ty_max || ty_min > t_max {
+            return false;
+        }
+        if ty_min > t_min { t_min = ty_min; }
+        if ty_max < t_max { t_max = ty_max; }
+
+        let tz_min = (self[dir_is_neg[2]].z - o.z) * inv_dir.z;
+        let tz_max = (self[1 - dir_is_neg[2]].z - o.z) * inv_dir.z * (1.0 + 2.0 * gamma(3));
+        if t_min > tz_max || tz_min > t_max {
+            return false;
+        }
+        if tz_min > t_min { t_min = tz_min; }
+        if tz_max < t_max { t_max = tz_max; }
+
+        t_min < ray.get_t_max() && t_max > 0.0
+    }
+}
+
+/// Integer bounds are used for ranges of pixels, the upper corner being excluded
+impl Bound2<i32>{
+    pub fn from_corners(p_min : Point2<i32>, p_max : Point2<i32>) -> Self{
+        Self{p_min, p_max}
+    }
+
+    /// Number of pixels inside the bounds
+    pub fn area(&self) -> i32 {
+        (self.p_max.x - self.p_min.x).max(0) * (self.p_max.y - self.p_min.y).max(0)
+    }
+
+    /// The pixels that are inside both bounds
+    pub fn overlap(&self, other : &Bound2<i32>) -> Self {
+        Self{
+            p_min: Point2{ x: self.p_min.x.max(other.p_min.x), y: self.p_min.y.max(other.p_min.y) },
+            p_max: Point2{ x: self.p_max.x.min(other.p_max.x), y: self.p_max.y.min(other.p_max.y) },
+        }
+    }
+
+    pub fn contains(&self, p : &Point2<i32>) -> bool {
+        p.x >= self.p_min.x && p.x < self.p_max.x && p.y >= self.p_min.y && p.y < self.p_max.y
+    }
+
+    /// Iterate over the pixels row by row
+    pub fn pixels(&self) -> impl Iterator<Item = Point2<i32>> {
+        let (x0, x1) = (self.p_min.x, self.p_max.x);
+        (self.p_min.y..self.p_max.y).flat_map(move |y| (x0..x1).map(move |x| Point2{ x, y }))
+    }
+}
+
+pub(crate) type Bound2f = Bound2<f32>;
+pub(crate) type Bound3f = Bound3<f32>;
+pub(crate) type Bound2i = Bound2<i32>;
+pub(crate) type Bound3i = Bound3<i32>;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::math::Point::Point2f;
+
+    #[test]
+    fn test_surface_area() {
+        let b = Bound3f::from_points(&Point3f::new(1.0, -1.0, 2.0), &Point3f::new(2.0, 1.0, 5.0));
+        assert_eq!(b.surface_area(), 22.0);
+        assert_eq!(b.volume(), 6.0);
+        let flat = Bound3f::from_points(&Point3f::new(0.0, 0.0, 0.0), &Point3f::new(4.0, 4.0, 0.0));
+        assert_eq!(flat.surface_area(), 32.0);
+        assert_eq!(Bound2f::from_points(&Point2f::new(0.0, 1.0), &Point2f::new(3.0, 3.0)).surface_area(), 6.0);
+    }
+}
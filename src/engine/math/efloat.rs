@@ -0,0 +1,235 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use crate::engine::math::{next_float_down, next_float_up, MACHINE_EPSILON};
+
+/// A float that keeps track of the interval its exact value lies in,
+/// growing the interval conservatively with every rounded operation
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct EFloat{
+    v : f32,
+    low : f32,
+    high : f32,
+}
+
+impl EFloat{
+    pub fn new(v : f32, err : f32) -> EFloat{
+        if err == 0.0 {
+            Self{
+                v,
+                low: v,
+                high: v,
+            }
+        } else {
+            Self{
+                v,
+                low: next_float_down(v - err),
+                high: next_float_up(v + err),
+            }
+        }
+    }
+
+    pub fn value(&self) -> f32{
+        self.v
+    }
+
+    pub fn lower_bound(&self) -> f32{
+        self.low
+    }
+
+    pub fn upper_bound(&self) -> f32{
+        self.high
+    }
+
+    /// Largest distance between the computed value and the bounds of the interval
+    pub fn absolute_error(&self) -> f32{
+        next_float_up((self.high - self.v).abs().max((self.v - self.low).abs()))
+    }
+
+    pub fn sqrt(&self) -> EFloat{
+        Self{
+            v: self.v.sqrt(),
+            low: next_float_down(self.low.sqrt()),
+            high: next_float_up(self.high.sqrt()),
+        }
+    }
+
+    pub fn abs(&self) -> EFloat{
+        if self.low >= 0.0 {
+            *self
+        } else if self.high <= 0.0 {
+            -*self
+        } else {
+            // The interval straddles zero
+            Self{
+                v: self.v.abs(),
+                low: 0.0,
+                high: (-self.low).max(self.high),
+            }
+        }
+    }
+
+    /// Solve the quadratic equation `a*t^2 + b*t + c = 0` keeping track of the error of both roots,
+    /// which are returned ordered by their lower bound
+    pub fn quadratic(a : EFloat, b : EFloat, c : EFloat) -> Option<(EFloat, EFloat)> {
+        let discrim = (b.v as f64) * (b.v as f64) - 4.0 * (a.v as f64) * (c.v as f64);
+        if discrim < 0.0 {
+            return None;
+        }
+        let root_discrim = discrim.sqrt();
+        let float_root_discrim = EFloat::new(root_discrim as f32, MACHINE_EPSILON * root_discrim as f32);
+
+        let q = if b.v < 0.0 {
+            (b - float_root_discrim) * -0.5
+        } else {
+            (b + float_root_discrim) * -0.5
+        };
+        let t0 = q / a;
+        let t1 = c / q;
+
+        if t0.lower_bound() > t1.lower_bound() {
+            Some((t1, t0))
+        } else {
+            Some((t0, t1))
+        }
+    }
+}
+
+impl From<f32> for EFloat{
+    fn from(v : f32) -> Self {
+        EFloat::new(v, 0.0)
+    }
+}
+
+impl From<EFloat> for f32{
+    fn from(e : EFloat) -> Self {
+        e.v
+    }
+}
+
+impl Add for EFloat{
+    type Output = EFloat;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self{
+            v: self.v + rhs.v,
+            low: next_float_down(self.low + rhs.low),
+            high: next_float_up(self.high + rhs.high),
+        }
+    }
+}
+
+impl Sub for EFloat{
+    type Output = EFloat;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self{
+            v: self.v - rhs.v,
+            low: next_float_down(self.low - rhs.high),
+            high: next_float_up(self.high - rhs.low),
+        }
+    }
+}
+
+impl Mul for EFloat{
+    type Output = EFloat;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let products = [
+            self.low * rhs.low,
+            self.high * rhs.low,
+            self.low * rhs.high,
+            self.high * rhs.high,
+        ];
+        Self{
+            v: self.v * rhs.v,
+            low: next_float_down(products.iter().cloned().fold(f32::INFINITY, f32::min)),
+            high: next_float_up(products.iter().cloned().fold(f32::NEG_INFINITY, f32::max)),
+        }
+    }
+}
+
+impl Mul<f32> for EFloat{
+    type Output = EFloat;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * EFloat::from(rhs)
+    }
+}
+
+impl Div for EFloat{
+    type Output = EFloat;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.low < 0.0 && rhs.high > 0.0 {
+            // Dividing by an interval that straddles zero can give any value
+            return Self{
+                v: self.v / rhs.v,
+                low: f32::NEG_INFINITY,
+                high: f32::INFINITY,
+            };
+        }
+
+        let quotients = [
+            self.low / rhs.low,
+            self.high / rhs.low,
+            self.low / rhs.high,
+            self.high / rhs.high,
+        ];
+        Self{
+            v: self.v / rhs.v,
+            low: next_float_down(quotients.iter().cloned().fold(f32::INFINITY, f32::min)),
+            high: next_float_up(quotients.iter().cloned().fold(f32::NEG_INFINITY, f32::max)),
+        }
+    }
+}
+
+impl Neg for EFloat{
+    type Output = EFloat;
+
+    fn neg(self) -> Self::Output {
+        Self{
+            v: -self.v,
+            low: -self.high,
+            high: -self.low,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_float() {
+        assert!(next_float_up(1.0) > 1.0);
+        assert!(next_float_down(1.0) < 1.0);
+        assert!(next_float_up(-0.0) > 0.0);
+        assert!(next_float_down(0.0) < 0.0);
+        assert_eq!(next_float_up(f32::INFINITY), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_efloat_bounds_contain_exact_result() {
+        let a = EFloat::new(0.1, 1e-6);
+        let b = EFloat::from(3.0);
+        let exact = 0.1_f64 * 3.0 - 1.0 / 3.0;
+        let r = a * b - EFloat::from(1.0) / b;
+        assert!((r.lower_bound() as f64) <= exact && exact <= (r.upper_bound() as f64));
+        assert!(r.absolute_error() > 0.0);
+    }
+
+    #[test]
+    fn test_efloat_division_by_interval_around_zero() {
+        let r = EFloat::from(1.0) / EFloat::new(0.0, 1.0);
+        assert_eq!(r.lower_bound(), f32::NEG_INFINITY);
+        assert_eq!(r.upper_bound(), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_efloat_quadratic() {
+        // (t - 1)(t - 3) = t^2 - 4t + 3
+        let (t0, t1) = EFloat::quadratic(EFloat::from(1.0), EFloat::from(-4.0), EFloat::from(3.0)).unwrap();
+        assert!(t0.lower_bound() <= 1.0 && 1.0 <= t0.upper_bound());
+        assert!(t1.lower_bound() <= 3.0 && 3.0 <= t1.upper_bound());
+        assert!(EFloat::quadratic(EFloat::from(1.0), EFloat::from(0.0), EFloat::from(1.0)).is_none());
+    }
+}
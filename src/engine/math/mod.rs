@@ -3,8 +3,18 @@ pub(crate) mod Normal;
 pub(crate) mod Vector;
 pub(crate) mod Point;
 pub mod rays;
-mod bounding_box;
-mod transformations;
+pub(crate) mod bounding_box;
+pub(crate) mod transformations;
+pub(crate) mod efloat;
+pub(crate) mod quaternion;
+pub(crate) mod animated_transform;
+pub(crate) mod sampling;
+pub(crate) mod rng;
+pub(crate) mod sobol;
+pub(crate) mod sobol_directions;
+pub(crate) mod pmj02;
+pub(crate) mod pmj02_tables;
+pub(crate) mod blue_noise;
 
 use std::ops::{Add, Mul, Sub};
 use std::process::Output;
@@ -12,6 +22,11 @@ use num_traits::{FromPrimitive, Signed};
 use crate::engine::lights::GeneralLight;
 use crate::engine::primitives::GeneralPrimitive;
 use crate::engine::Scene;
+use crate::engine::cameras::CameraSample;
+use crate::engine::film::Film;
+use crate::engine::math::rays::BaseRay;
+use crate::engine::math::rays::Ray::Ray;
+use crate::engine::math::rays::ray_differential::RayDifferential;
 
 // A Class responsible for rendering the scene
 pub(crate) trait Integrator{
@@ -20,8 +35,95 @@ pub(crate) trait Integrator{
 }
 
 
-pub(crate) trait Camera{
+// A Class generating the rays leaving the camera for the points sampled on the film
+pub(crate) trait Camera : Send + Sync {
 
+    // World space ray for the sample, along with the weight of its radiance on the image.
+    // Returns None if the sample doesn't produce any ray
+    fn generate_ray(&self, sample : &CameraSample) -> Option<(f32, Ray)>;
+
+    // Same as generate_ray, with the rays of the samples one pixel away in x and y on the film.
+    // By default the offset rays are generated again, so the cameras should override it when they can do better
+    fn generate_ray_differential(&self, sample : &CameraSample) -> Option<(f32, RayDifferential)> {
+        let (weight, ray) = self.generate_ray(sample)?;
+        let mut ret = RayDifferential::new(ray.get_origin(), ray.get_direction(), ray.get_t_max(), ray.get_time(), ray.get_medium());
+
+        let mut shifted = *sample;
+        shifted.p_film.x += 1.0;
+        let (_, rx) = self.generate_ray(&shifted)?;
+        shifted = *sample;
+        shifted.p_film.y += 1.0;
+        let (_, ry) = self.generate_ray(&shifted)?;
+
+        ret.rx_orig = Some(rx.get_origin());
+        ret.rx_direction = Some(rx.get_direction());
+        ret.ry_orig = Some(ry.get_origin());
+        ret.ry_direction = Some(ry.get_direction());
+        ret.is_differential = true;
+        Some((weight, ret))
+    }
+
+    fn get_film(&self) -> &Film;
+}
+
+/// Half of the distance between 1 and the next representable float,
+/// a bound on the relative error of a single rounded operation
+pub const MACHINE_EPSILON : f32 = f32::EPSILON * 0.5;
+
+/// Largest float below 1, the upper bound of the sampled values in [0, 1)
+pub const ONE_MINUS_EPSILON : f32 = 1.0 - MACHINE_EPSILON;
+
+/// Conservative bound on the relative error accumulated by `n` floating point operations
+pub fn gamma(n : i32) -> f32 {
+    (n as f32 * MACHINE_EPSILON) / (1.0 - n as f32 * MACHINE_EPSILON)
+}
+
+/// The smallest representable float greater than `v`
+pub fn next_float_up(v : f32) -> f32 {
+    if v.is_infinite() && v > 0.0 {
+        return v;
+    }
+    // Skip -0 so that the next float after it is the smallest positive one
+    let v = if v == -0.0 { 0.0 } else { v };
+
+    let bits = v.to_bits();
+    let bits = if v >= 0.0 { bits + 1 } else { bits - 1 };
+    f32::from_bits(bits)
+}
+
+/// The largest representable float lower than `v`
+pub fn next_float_down(v : f32) -> f32 {
+    if v.is_infinite() && v < 0.0 {
+        return v;
+    }
+    let v = if v == 0.0 { -0.0 } else { v };
+
+    let bits = v.to_bits();
+    let bits = if v > 0.0 { bits - 1 } else { bits + 1 };
+    f32::from_bits(bits)
+}
+
+/// Solve the quadratic equation `a*t^2 + b*t + c = 0` and return both roots in ascending order.
+/// The discriminant is evaluated in double precision to reduce cancellation errors
+pub fn quadratic(a : f32, b : f32, c : f32) -> Option<(f32, f32)> {
+    let discrim = (b as f64) * (b as f64) - 4.0 * (a as f64) * (c as f64);
+    if discrim < 0.0 {
+        return None;
+    }
+    let root_discrim = discrim.sqrt();
+
+    let q = if b < 0.0 {
+        -0.5 * (b as f64 - root_discrim)
+    } else {
+        -0.5 * (b as f64 + root_discrim)
+    };
+    let mut t0 = (q / a as f64) as f32;
+    let mut t1 = (c as f64 / q) as f32;
+    if t0 > t1 {
+        std::mem::swap(&mut t0, &mut t1);
+    }
+
+    Some((t0, t1))
 }
 
 pub fn lerp<T>(t : T, v1 : T, v2 : T) -> T
@@ -0,0 +1,46 @@
+use crate::engine::math::Point::Point2f;
+use crate::engine::math::pmj02_tables::PMJ02_TABLES;
+
+/// Number of independent tables of progressive multi-jittered (0, 2) points
+pub const PMJ02_SETS : usize = 5;
+
+/// Number of points of each table
+pub const PMJ02_SET_SIZE : usize = 1024;
+
+/// The point `index` of the table `set`. Every prefix of a table whose length is a power of two is stratified
+/// over all of the elementary intervals of that many cells, so any count of samples is well distributed
+pub fn pmj02_sample(set : usize, index : usize) -> Point2f {
+    let [x, y] = PMJ02_TABLES[set % PMJ02_SETS][index % PMJ02_SET_SIZE];
+    Point2f::new(to_f32(x), to_f32(y))
+}
+
+/// Truncate to the float below instead of rounding, so the points stay in the finest cells they were placed in
+fn to_f32(v : u32) -> f32 {
+    (v >> 8) as f32 * (-24.0f32).exp2()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefixes_are_02_nets() {
+        for set in 0..PMJ02_SETS {
+            let points : Vec<Point2f> = (0..PMJ02_SET_SIZE).map(|i| pmj02_sample(set, i)).collect();
+            let mut n = 1;
+            while n <= PMJ02_SET_SIZE {
+                let m = n.trailing_zeros();
+                for x_bits in 0..=m {
+                    let mut cells : Vec<(u32, u32)> = points[..n].iter()
+                        .map(|p| ((p.x * (1u32 << x_bits) as f32) as u32, (p.y * (1u32 << (m - x_bits)) as f32) as u32))
+                        .collect();
+                    cells.sort();
+                    cells.dedup();
+                    assert_eq!(cells.len(), n, "set {} prefix {} shape {}", set, n, x_bits);
+                }
+                n *= 2;
+            }
+        }
+        assert_ne!(pmj02_sample(0, 1), pmj02_sample(1, 1));
+    }
+}
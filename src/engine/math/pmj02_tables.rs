@@ -0,0 +1,1298 @@
+use crate::engine::math::pmj02::{PMJ02_SETS, PMJ02_SET_SIZE};
+
+/// Progressive multi-jittered (0, 2) points of Christensen, Kensler and Kilpatrick, as 32 bits fixed point
+/// coordinates. Each table was built from a random first point: every power of four count of points is doubled by
+/// placing a point in the sub quadrant diagonally opposite to each point in its stratum, then doubled again by
+/// filling the two remaining sub quadrants, each new point taking a random cell free in every elementary interval
+pub(crate) static PMJ02_TABLES : [[[u32; 2]; PMJ02_SET_SIZE]; PMJ02_SETS] = [
+    [
+        [1774745655, 1721023772], [3518872931, 2878771766], [3176799353, 781134305], [530265056, 3333656329],
+        [713679300, 169734467], [2316562193, 3782438108], [3921496595, 1241837540], [1292314752, 2306170226],
+        [227995689, 1496577464], [2936810342, 2522083981], [2462716254, 2016809564], [1068842244, 3117420510],
+        [1342749370, 814018567], [4227335967, 3651631292], [3457653348, 406246264], [1942137705, 4193134827],
+        [1128319069, 1146972764], [3857879942, 2222530714], [2181662626, 123406095], [663046726, 3919155763],
+        [362428315, 595052831], [2962115484, 3379069060], [3682453645, 1796058646], [1730937061, 2721738829],
+        [933765454, 1887725728], [2661971483, 3010471203], [2686904148, 1343282343], [95629645, 2605107588],
+        [2079945483, 325019413], [3265230325, 4101519924], [4142081308, 1053431658], [1491653973, 3557228455],
+        [1969447374, 1476204794], [4045000256, 3181534527], [2836499911, 397172756], [172239714, 4036703218],
+        [1005086119, 942386562], [2541144390, 3523265499], [4265754017, 1966909210], [1411422107, 3049921480],
+        [571182725, 1324373302], [2246681737, 2393855196], [2374963139, 1109132059], [753537312, 2148633269],
+        [1251547157, 22809624], [3992011962, 3983239250], [3591726833, 664147414], [1862661977, 3475474914],
+        [1575188500, 2124049677], [3373646805, 2624974050], [2572738871, 880674418], [816248442, 3727128680],
+        [15143031, 482282586], [2809523099, 4285074244], [3336638079, 1548213854], [2140433510, 2458960402],
+        [453592538, 1859144523], [3121709512, 2771150231], [3050869084, 1621260658], [298651363, 2939816311],
+        [1652742872, 702750930], [3754558619, 3232890904], [3807638442, 210031570], [1173825790, 3849967546],
+        [2098229028, 1981196120], [3288975454, 3086424240], [2770483840, 989589574], [63350610, 3520402237],
+        [851747396, 350249264], [2605035196, 4061994692], [4070154246, 1412306857], [1600176837, 2674001123],
+        [309819119, 1096334317], [3072604413, 2206310732], [2263802836, 1820256958], [559577378, 2794593329],
+        [1180567826, 623937798], [3763965434, 3436317830], [3700292419, 58434780], [1632306167, 4016917694],
+        [1474521529, 1597508091], [4244017962, 2433039437], [2497385268, 504996429], [969631529, 4249270410],
+        [153906778, 920137567], [2857667909, 3692319993], [3419743367, 2086312135], [2003018779, 3199774221],
+        [790491719, 1677270972], [2409401577, 2905484630], [3098809882, 1279339123], [409261178, 2361556178],
+        [1843703937, 258352208], [3563077775, 3864161151], [4009822048, 719220737], [1209818932, 3257429874],
+        [1703122213, 1214298942], [3945950554, 2715616127], [2998882362, 157261673], [375635461, 3818512747],
+        [605332370, 766093155], [2169535059, 3312591137], [3886841153, 1710776038], [1095973411, 2821522357],
+        [1039534035, 1401689551], [2442445586, 2580838541], [2625681898, 1516268078], [888273970, 2497018810],
+        [1532575616, 454984978], [4125138732, 4211455144], [3248205921, 858616922], [2017451255, 3679495889],
+        [1312666335, 1761168715], [3635642794, 2346650754], [2311831790, 558722815], [672433878, 3421131378],
+        [490848706, 67730562], [3198568196, 3933631020], [3529708478, 1192586164], [1790943469, 2272139426],
+        [103541725, 2075678104], [2723159993, 3141973928], [2905320724, 1919246245], [248655047, 2958993189],
+        [1903482666, 1026362319], [3434949914, 3605517381], [4169750794, 289955848], [1379017274, 4136345116],
+        [1917597159, 1840279463], [3716986071, 3100069479], [2934032272, 613352445], [210526081, 3453835589],
+        [1050024299, 47807336], [2478522797, 4004966877], [4203574921, 1082004322], [1204871291, 2537918715],
+        [505125799, 1427560091], [2758125431, 2289064317], [2592120447, 1733869649], [723423979, 3058415051],
+        [1280195183, 993045409], [4084813579, 3347724146], [3491385701, 353940391], [2092480039, 3771415147],
+        [1228814173, 1359266077], [4007460801, 2587568250], [2394193293, 302506650], [784978067, 4115918716],
+        [71219776, 721655483], [2708493188, 3281196247], [3584306282, 1908805446], [1812030229, 2999459962],
+        [641093556, 2104856732], [2205167491, 3205909128], [2883883775, 1165920499], [336853663, 2429937860],
+        [1715461704, 534643660], [3403477596, 3908965742], [3826789560, 930944569], [1453150275, 3366009795],
+        [1851409539, 1538448156], [4181029705, 2919518392], [3152212894, 444318796], [451558572, 4194713644],
+        [763277840, 841844784], [2650257362, 3461600453], [3972628402, 2053942090], [1259519323, 3127257870],
+        [832171088, 1181726503], [2292404231, 2469971987], [2150547336, 1458375193], [975233496, 2327904333],
+        [1082974558, 371456584], [4288032465, 3803562230], [3641151189, 958464480], [1685210562, 3556625270],
+        [1394357460, 1638020209], [3227783213, 2175812651], [2431576010, 671958030], [1007611646, 3252025773],
+        [282346746, 275147249], [3033245812, 4160266219], [3448035298, 1327332819], [1880233085, 2403898689],
+        [387760020, 1957918776], [2826894812, 2846989680], [2788453271, 1777144466], [473914510, 3157360913],
+        [1808864741, 893820636], [3546506028, 3753743913], [3940155122, 488815711], [1550244262, 3944682571],
+        [1616650512, 2043714072], [3483290968, 2807751243], [3057958918, 829483731], [329107470, 3624879050],
+        [544239111, 434504456], [2267854044, 4169294943], [3783064786, 1491675483], [1365132102, 2183883842],
+        [42249026, 1270301061], [3160847938, 2656925025], [2348669570, 2001278866], [857055505, 2864273577],
+        [1580588223, 793691436], [3894570733, 3496585496], [3318541479, 199800131], [1745052779, 4080509804],
+        [1494557879, 1291987237], [4155874180, 2376101314], [2680962440, 242308647], [921407737, 3889527325],
+        [431981123, 1057351535], [3112476165, 3585522742], [3287458304, 1646848716], [2058182949, 2901808976],
+        [949767032, 1794163376], [2503667803, 2746479222], [2970310079, 1589043553], [148246025, 2236224517],
+        [1992919257, 115448796], [3669090589, 4234670745], [4253191229, 578878166], [1114524702, 3723359228],
+        [2041626011, 1130879115], [3814576091, 2971095706], [2738434167, 9970304], [118212806, 3969471208],
+        [899151564, 653413787], [2364019106, 3672903121], [4103201669, 1864342869], [1516161203, 2754810905],
+        [699685471, 1565401431], [2561295386, 2259008004], [2522887526, 1240255834], [626839060, 2647291286],
+        [1429545422, 145861463], [3869273379, 4052879881], [3356801332, 751474243], [1954571515, 3304372637],
+        [1149180129, 1933067711], [3615673753, 2509406332], [2224535090, 1009437172], [589980261, 3618906389],
+        [251682238, 220546460], [2887319794, 3841019155], [3731659898, 1389193618], [1661458677, 2562791552],
+        [197707698, 1681815413], [3003325391, 3029859412], [3219878057, 2143247925], [17359542, 2696978428],
+        [2115183170, 546254316], [3350531807, 3396598038], [4035322338, 100400522], [1337711947, 4263523764],
+        [1737652459, 1807273007], [3686898963, 2734108867], [2955121330, 602568213], [357892110, 3383268218],
+        [657660065, 133933951], [2192432725, 3914991684], [3843826230, 1151119271], [1137875593, 2223731279],
+        [84874859, 1355003748], [2694012623, 2616418876], [2654390085, 1886900442], [923275388, 3012291630],
+        [1484177861, 1046161861], [4132764331, 3569944751], [3258693637, 327408132], [2068931541, 4102823703],
+        [1300929377, 1254730918], [3912794387, 2311375696], [2324135490, 181985844], [711229274, 3790547111],
+        [521245678, 774974390], [3183109125, 3323107175], [3507356384, 1711386540], [1764020420, 2876414279],
+        [1057296283, 2028245768], [2452165683, 3104170287], [2947153288, 1507770528], [219873186, 2528623989],
+        [1934317880, 411570839], [3465959000, 4178826001], [4213793609, 812160794], [1357435661, 3642625709],
+        [2132267401, 1552213383], [4277721875, 3044193970], [2813752119, 476352818], [5824391, 4288114721],
+        [807239531, 888689610], [2577732188, 3739694640], [4052593249, 2121721867], [1560744050, 3177233041],
+        [745657818, 1119772731], [2370929575, 2159225217], [2235939950, 1314069601], [580183941, 2382966453],
+        [1159274765, 206630831], [3795972078, 3851387262], [3741667061, 695089039], [1646600972, 3225252087],
+        [1423525307, 1977264154], [3328170380, 2454568961], [2549643297, 952317239], [994176750, 3534262547],
+        [177360862, 391966302], [2845976107, 4031870916], [3383926361, 1467053961], [1973260797, 2627868907],
+        [286942465, 1616950333], [3037732212, 2947100022], [3136466607, 1850293621], [469424555, 2777737953],
+        [1874462956, 658912514], [3603079416, 3485068732], [3980741989, 31444002], [1241636354, 3988948187],
+        [2010226269, 2092704827], [3412477374, 3189099170], [2866794063, 910373718], [165035058, 3701930370],
+        [958067058, 519255316], [2483650605, 4259005420], [4233398268, 1608092097], [1466001585, 2442753622],
+        [415310989, 1286112872], [3094569766, 2356518491], [2402620020, 1662501990], [797560450, 2914858744],
+        [1218105800, 708289561], [4019387952, 3269713984], [3573105186, 261000759], [1829546325, 3875401893],
+        [1603640734, 1417888750], [4067449214, 2683907078], [2615490818, 343821142], [839890304, 4069044331],
+        [53380050, 980310903], [2777354568, 3511223719], [3304453930, 1993667456], [2105573104, 3072564535],
+        [570406249, 1827709098], [2250149269, 2785966156], [3080043569, 1102469977], [317704261, 2206028142],
+        [1642508340, 65033503], [3698032078, 4024843593], [3774119420, 636637168], [1188263042, 3429810610],
+        [1784429219, 1207271443], [3876862587, 2830372956], [3187759820, 78120954], [495745368, 3941994970],
+        [687022327, 563805966], [2298550862, 3411615466], [3951977576, 1751232758], [1323425374, 2707293563],
+        [874926088, 1525791728], [2623308837, 2489958248], [2434395667, 1394256447], [1028747407, 2572055950],
+        [1384797029, 295921589], [4164105334, 4133320498], [3427541190, 1034429235], [1905913325, 3597590887],
+        [1103157439, 1698087821], [3537716092, 2279985791], [2176941871, 762369291], [612740337, 3317967432],
+        [380547166, 167067936], [2994500560, 3814346708], [3629560215, 1218373457], [1697074693, 2333166045],
+        [242291561, 1923492832], [2913527198, 2963546173], [2732471143, 2066451263], [117242175, 3148458500],
+        [2029475420, 870536831], [3238166955, 3683979409], [4112845662, 463722528], [1540833269, 4223562536],
+        [2049539741, 1653368034], [3579484163, 2986942925], [2711819029, 731666626], [77426047, 3272310727],
+        [913690929, 248008251], [2669800850, 3878732089], [4145638494, 1305929511], [1239891429, 2592246994],
+        [345072047, 1578695664], [2872008089, 2244599419], [2513394459, 1785425098], [650488282, 3213439297],
+        [1122736249, 934908460], [4251005235, 3363777237], [3665519299, 527087563], [1986009026, 3898909119],
+        [1194068451, 1481335575], [3785866657, 2543203205], [2278452868, 422384527], [551902347, 4168793313],
+        [203850105, 606970162], [2925380180, 3446846034], [3712284306, 2031609771], [1622697045, 3090355859],
+        [737791417, 2005971876], [2335413658, 3069029312], [2764424758, 1260245982], [516372682, 2667210932],
+        [1758034222, 365789374], [3309885035, 3761255180], [3907229420, 1005988387], [1586791864, 3345586541],
+        [1669910090, 1378718046], [4093895106, 2764585508], [3022626860, 283336551], [272102049, 4150596737],
+        [600174546, 1021295393], [2423210036, 3244958067], [3822520895, 1941461058], [1151189268, 2982222411],
+        [984497923, 1230133020], [2156000441, 2638294969], [2288400876, 1570889207], [829786417, 2249597698],
+        [1331735071, 499970626], [4029797581, 3955792189], [3554796895, 903613061], [1796039239, 3745164885],
+        [1526391286, 1876518804], [3439566744, 2410555111], [2637582256, 642842748], [889492182, 3470888383],
+        [438276043, 449165563], [3142232588, 4203534483], [3235755874, 1136894385], [2031167052, 2165338548],
+        [481404532, 2133689133], [2797090098, 2686280483], [2829011865, 1692194834], [401306649, 3021370069],
+        [1694466093, 970203721], [3650370720, 3543569772], [3866831814, 383217112], [1442081750, 3793015579],
+        [1826181067, 1902726104], [3279905893, 2889197774], [3108228621, 1067965247], [426857343, 3575994678],
+        [773890613, 318010150], [2384517415, 4122734577], [3997129912, 1369212555], [1501975076, 2368827115],
+        [141115037, 1170079909], [2986318677, 2423292528], [2211472444, 2106604139], [942611537, 2740541908],
+        [1448250503, 576710185], [3840498390, 3711374089], [3390263600, 101623032], [1723019372, 4242172389],
+        [1373454991, 1083359802], [4196114325, 2196885286], [2470163867, 40372044], [1044376343, 3995490527],
+        [326231072, 838749755], [3062595993, 3640200862], [3475282674, 1833127554], [1929343458, 2812001500],
+        [870927913, 1741863706], [2588996954, 2856098745], [3170328782, 1438691300], [34152263, 2295258677],
+        [2087073054, 191890260], [3499253768, 4090084026], [4087987384, 797568002], [1287947719, 3498983617],
+        [1890102535, 1334484569], [3967674833, 3134006308], [2899997950, 229743024], [268318811, 3825878789],
+        [1017802339, 682889427], [2217252669, 3607243006], [4191693656, 1629159923], [1405115540, 2930797242],
+        [633367461, 1446540367], [2525768838, 2317205083], [2557111275, 1187843045], [688939208, 2476399047],
+        [1552385837, 86669030], [3928061568, 4276889121], [3343771748, 543927519], [2123682970, 3399902222],
+        [1273267760, 2057510885], [3740481230, 2555493922], [2349580625, 855186719], [764823860, 3661376629],
+        [126109898, 8156617], [2744507971, 3962864519], [3607619278, 1532969398], [1854776141, 2501557945],
+        [26277798, 1766138676], [3208270589, 3168676989], [3016412096, 1950251477], [191769785, 2837129744],
+        [1954435161, 743550629], [3364714667, 3291734979], [4283353153, 142016648], [1076403083, 4043697830],
+        [1649307740, 1625090326], [3748644047, 2941958750], [3126756536, 654863645], [460965085, 3488666982],
+        [585273758, 215774320], [2381552611, 3985798831], [3792525042, 1320572816], [1162887417, 2395969719],
+        [1480933, 1544358316], [2842587168, 2632972494], [2581011307, 2130699645], [810480157, 3183516945],
+        [1567139251, 873968205], [4057448395, 3729066770], [3380007503, 389215471], [2136905218, 4280173359],
+        [1245811396, 1114200413], [3803341490, 2386730287], [2367369605, 18924515], [738890410, 3979637396],
+        [462155703, 669042560], [3047727009, 3228574091], [3752976639, 1610751943], [1877698727, 2775821614],
+        [818729306, 2118014954], [2542253121, 3046703765], [2848639403, 1470760457], [10581418, 2450305492],
+        [1976810171, 399387597], [3333600670, 4291157914], [4273026070, 946508456], [1417844646, 3529490657],
+        [2066532631, 1348098630], [4218291078, 3116014619], [2942253448, 418252869], [233621893, 4183273482],
+        [929394860, 1051293046], [2458383206, 3647596385], [4127421158, 1895488482], [1349821463, 3108989119],
+        [708377520, 1249609993], [2189235533, 2228213852], [2195430925, 1143825467], [662315576, 2218653876],
+        [1298257547, 179264994], [3923530936, 3786227569], [3523207014, 777688909], [1770769103, 3326743401],
+        [1353069515, 2020080066], [3462741401, 2530956006], [2667362965, 1040699775], [1062135894, 3657189194],
+        [97996805, 332500434], [2952209218, 4186325063], [3469190455, 1500944064], [1929676159, 2516640705],
+        [535867188, 1719228955], [2959253938, 2722979947], [3185785207, 1727248373], [368654024, 2726936975],
+        [1768692163, 786133689], [3512324525, 3336908887], [3852267093, 128716188], [1308311562, 3775837828],
+        [2019194728, 2071755735], [3423918113, 2956931952], [2719098428, 867568858], [238818112, 3600314239],
+        [1025082939, 286375057], [2630684344, 4226193339], [4167356847, 1408896833], [1528620046, 2486490347],
+        [373065618, 1224720803], [3193281636, 2266283395], [2165687126, 1699338547], [610661993, 2835146138],
+        [1092621106, 755162612], [3958247508, 3417087876], [3534722314, 72321087], [1707341877, 3810709300],
+        [1380445597, 1398368159], [4117756273, 2494194500], [2446315406, 298292923], [1035846530, 4129384451],
+        [111179989, 861365706], [2729922916, 3678117396], [3242346885, 2079258908], [1898637230, 2965583420],
+        [616751146, 1706940860], [2174467626, 2826916839], [2989282835, 1211651686], [383248036, 2342103422],
+        [1699289233, 151000146], [3624586352, 3822921386], [3881038931, 769337565], [1313795199, 3405841134],
+        [1630562015, 1103429389], [3768438009, 2798243518], [3074628747, 58738390], [414309823, 3861206836],
+        [802535706, 716596961], [2407091990, 3259058164], [4023613371, 1672008629], [1221165915, 2910740866],
+        [844547810, 1417240840], [2609483134, 2670250400], [2488717191, 1598697942], [961443013, 2438590958],
+        [1463167962, 510132686], [4230625794, 4246827877], [3295089599, 975999038], [2005372094, 3696648485],
+        [1215510171, 1667026706], [3704180603, 2200492127], [2255371537, 627844971], [562249659, 3431139140],
+        [405491965, 264967797], [3101923921, 3870892805], [3556815384, 1290457245], [1840603904, 2349608065],
+        [62446577, 1989125417], [2860649374, 3203417952], [2852506699, 2096435789], [56957132, 3081953912],
+        [2113070047, 981560969], [3298744036, 3518660894], [4061727096, 347852374], [1468611224, 4256186556],
+        [2127789907, 1770040508], [3647987351, 3024103041], [2799309140, 551181596], [193985634, 3295229051],
+        [825671742, 93595364], [2518124136, 3798268017], [4291158204, 1225923542], [1086554297, 2637986754],
+        [447281019, 1529444337], [2894594037, 2400463061], [2419110089, 1642731492], [597348209, 2977897610],
+        [1156447852, 1014941777], [4107734809, 3468670821], [3621289946, 448353528], [2043269096, 3965887299],
+        [1340074123, 1576782997], [3936750093, 2482212194], [2297034217, 495051538], [701877933, 4270240784],
+        [187652143, 746715015], [2790531661, 3404772274], [3655318525, 1961369740], [1687336114, 3035855394],
+        [767843402, 2049750525], [2229057933, 2979836359], [2890019026, 1341175384], [443996998, 2516065864],
+        [1860606205, 438719582], [3452946867, 3830312139], [3961920788, 843379086], [1520277763, 3460291298],
+        [1749114668, 1436497855], [4093321344, 2868107813], [3155523460, 361705084], [349830990, 4228356908],
+        [727716049, 1000892884], [2510448062, 3368284046], [3898857077, 2010191411], [1118111454, 3211982730],
+        [917125073, 1302193712], [2396768582, 2596928986], [2389371106, 1364810286], [1054418601, 2192221726],
+        [1235026050, 307576896], [4151537865, 3886154545], [3721725444, 831011737], [1613860712, 3635547780],
+        [1593034411, 1729510541], [3395196644, 2231700065], [2587507771, 788639278], [866013546, 3352836377],
+        [515449780, 359611781], [2976868941, 4240230764], [3307325068, 1271477410], [1990187809, 2242449893],
+        [332253176, 2034320883], [2716557598, 2897646633], [2701984503, 1658286344], [432990804, 2993329026],
+        [1623944880, 822679994], [3587256134, 3578907627], [4005277824, 314446319], [1370958097, 4006315176],
+        [1802249205, 2145344348], [3361674359, 2841091039], [3210740596, 891259265], [485577575, 3756210244],
+        [624256307, 380159762], [2153131247, 4047904461], [3874656096, 1449150244], [1558423529, 2264133329],
+        [124083134, 1133310239], [3147419127, 2504380557], [2359247887, 2059744062], [905871040, 2760878806],
+        [1511264910, 638200019], [3820274372, 3623484545], [3223415558, 936846], [1846685773, 4207409082],
+        [1547348132, 1183974300], [4040499903, 2254000788], [2532769228, 149362088], [988270180, 3806766029],
+        [397053612, 961110887], [3213688256, 3745971974], [3372175423, 1682454064], [2119181695, 2692438550],
+        [896200690, 1869227282], [2639681880, 2758481613], [3030235149, 1379994359], [258249985, 2414134859],
+        [2035693342, 16102666], [3725189972, 4146651462], [4099123561, 648653805], [1270140565, 3670015906],
+        [2093293115, 1263783560], [3830944160, 3218408176], [2753067029, 185909203], [49476482, 3764329119],
+        [947497324, 584460338], [2207762283, 3717840011], [4247530535, 1788037025], [1583986592, 2860351817],
+        [549270762, 1485248860], [2679249975, 2370207512], [2674529877, 1297513429], [777869272, 2590763979],
+        [1507292853, 238695966], [3998404305, 4110743254], [3275737656, 726420198], [1915309538, 3441805089],
+        [1284859808, 1999105245], [3661557852, 2426091791], [2201277383, 938096990], [642853610, 3713716091],
+        [38937495, 195728056], [2877330760, 3892666631], [3502971044, 1432153150], [1753415867, 2654015484],
+        [215842141, 1830077594], [3107612269, 2995016912], [3117452040, 1896280426], [206971082, 2803247198],
+        [2062552265, 737137707], [3283064172, 3276932179], [4198918169, 44201071], [1199298312, 4177404399],
+        [1870224615, 1847193342], [3598619922, 2782753460], [3042382587, 696946828], [292044729, 3237101172],
+        [748413196, 28723609], [2233586197, 3844837709], [3987186224, 1122459586], [1255377730, 2161517257],
+        [170719717, 1462751714], [2816176440, 2462387334], [2534359107, 1974657115], [1002358411, 3037429242],
+        [1417653270, 948060141], [4262127618, 3537100110], [3325438707, 482427612], [1965734276, 4030224950],
+        [1166115814, 1312703510], [3977877289, 2154896995], [2242256095, 204304443], [575876863, 3856521482],
+        [294761001, 690216785], [3129904171, 3477859848], [3600789008, 1854544587], [1658494953, 2952007299],
+        [991951898, 1970605487], [2567311213, 3173722247], [2804232717, 1559162968], [183508361, 2617897107],
+        [2143450838, 473553283], [3387470838, 4039235137], [4051158581, 882826102], [1571293197, 3736753210],
+        [1939317636, 1505270530], [4138298640, 3018701591], [2697139446, 318967922], [89778954, 4096035892],
+        [1073723974, 806910830], [2657060255, 3564648850], [4222160718, 2021936071], [1480163984, 3003578559],
+        [669261221, 1157349610], [2330462319, 2301560586], [2322162104, 1250998781], [721403809, 2309539091],
+        [1135219379, 121246657], [3849445349, 3925260356], [3686008933, 587324229], [1743181772, 3373529961],
+        [1486638327, 1880237823], [3261701786, 2603792540], [2454182714, 820721984], [939164810, 3566378422],
+        [226294782, 407385669], [2691965247, 4106897927], [3269062042, 1354536305], [2075542535, 2609817788],
+        [354771480, 1800721541], [3173224594, 2871937505], [2966784866, 1809442327], [527397597, 2883115507],
+        [1733543413, 598224804], [3674212398, 3386708259], [3916620770, 175110906], [1126018013, 3911746527],
+        [1910802981, 1916444740], [3251094024, 3152157657], [2916626909, 1029810633], [107549292, 3690106347],
+        [884985877, 469679929], [2439251860, 4142945328], [4121686726, 1521949767], [1392467347, 2576640815],
+        [502932303, 1196836183], [3002146648, 2336293036], [2302817367, 1756903265], [682914203, 2711986601],
+        [1318896068, 555164499], [3889976710, 3314662819], [3639660148, 160909748], [1781343815, 3927207641],
+        [1535854862, 1513261267], [4174514163, 2569641556], [2617350972, 460893444], [880126322, 4215917812],
+        [244352588, 1038938592], [2907537872, 3590751654], [3436239352, 1926568942], [2024828266, 3140089192],
+        [677762977, 1747095573], [2309668798, 2702118425], [3203800655, 1202070414], [487998861, 2277090711],
+        [1794715301, 82952680], [3527367249, 3937603910], [3949370534, 566516439], [1099424864, 3308698184],
+        [1833909558, 1277952818], [4015644069, 2916196538], [3088314466, 252625549], [305435401, 4020770456],
+        [555459586, 632817327], [2259969398, 3424782533], [3758669263, 1822309293], [1176836169, 2790077924],
+        [967720212, 1606169841], [2493994267, 2446973832], [2603236415, 1425947112], [850263464, 2679230720],
+        [1595704404, 339014244], [4076285421, 4075013769], [3409812057, 915220392], [2102815634, 3509897416],
+        [1184357188, 1812995405], [3567075793, 2359600995], [2412650130, 709982248], [794864847, 3263196400],
+        [312428327, 51599082], [3083370552, 4013176210], [3692726094, 1092493081], [1639038137, 2212286576],
+        [159968915, 2081833985], [2774516229, 3076279907], [2781128918, 1987990079], [158525649, 3195595833],
+        [1999185775, 907130619], [3415390916, 3706196731], [4237435527, 514929819], [1609972959, 4064300769],
+        [1960185341, 1689226937], [3549622486, 3160812235], [2821265769, 738700960], [30438062, 3391669739],
+        [979793330, 135866517], [2554019798, 3949821245], [4031149893, 1175833009], [1326127925, 2473341104],
+        [274123063, 1384817924], [2740091146, 2169117802], [2644808284, 1874411874], [758982922, 3132469630],
+        [1265956387, 848963539], [4189816758, 3248357585], [3733805038, 272062215], [1891871472, 3834059439],
+        [1079030572, 1454903604], [3862624882, 2645740270], [2163767113, 377387708], [629869678, 4056446733],
+        [24701359, 539931394], [2832858521, 3296897090], [3542082146, 2138713562], [1806984763, 3166056214],
+        [592192082, 1942959490], [2354017471, 3122992265], [2747864826, 1126141008], [277151141, 2554248387],
+        [1666270641, 279657969], [3232919826, 3973568254], [3809739730, 1015174200], [1399582919, 3238873831],
+        [1724163557, 1589701993], [4258734186, 2737494107], [2980460553, 529886658], [510980188, 4087167998],
+        [648426266, 925418596], [2599501411, 3340782166], [3836108924, 2100648865], [1275603419, 3062469521],
+        [1046978993, 1073773106], [2277031864, 2535119742], [2270954096, 1477495120], [906084721, 2380979102],
+        [1201187401, 424341116], [4210648236, 3997788684], [3575629654, 1065097505], [1823947838, 3589871611],
+        [1459237992, 1780686605], [3316837395, 2292469387], [2506521731, 574323856], [954169645, 3359082789],
+        [342612806, 520902675], [3166173430, 4081881696], [3398888024, 1173100995], [2081296349, 2283995254],
+        [420523671, 1904347363], [2931395581, 2816140798], [2920479055, 1843658162], [319534861, 3098772392],
+        [1817732002, 1072212257], [3710366786, 3628995927], [3790304968, 430591846], [1499416675, 3881688437],
+        [1679465575, 1951328949], [3339510738, 2696860648], [3010318558, 964973892], [392087352, 3546829460],
+        [694222371, 492879923], [2283188934, 4265956983], [3933023030, 1561716448], [1430903685, 2322158552],
+        [263262419, 1332993813], [3024827276, 2561474928], [2221067342, 1934359273], [1023278961, 2924412154],
+        [1404747434, 678775925], [3971097441, 3664538976], [3446106258, 222527837], [1674676778, 4155382424],
+        [1438102565, 1234089588], [4282347028, 2327003916], [2563117875, 89574261], [836847370, 3952059006],
+        [476945158, 901697365], [3012978056, 3551472227], [3353415460, 1761889437], [1947437938, 2851874628],
+        [1012887197, 1634834001], [2427699530, 2933359006], [3138450862, 1542359672], [132093606, 2179415876],
+        [1887431064, 230820578], [3615045606, 4201664152], [4185031366, 686828798], [1144560918, 3612751036],
+        [1983855059, 1157872716], [3902442711, 3056521336], [2873211300, 112954944], [135037121, 3903152934],
+        [862730285, 804786320], [2341573013, 3504092633], [4078179763, 1737104385], [1443267567, 2748876747],
+        [784038561, 1374805641], [2470791758, 2187183254], [2480796124, 1087557828], [536926610, 2546348135],
+        [1360042038, 36176031], [3777392576, 4161469815], [3489337982, 611029091], [2052088121, 3288220101],
+        [1111490013, 2110736415], [3494106254, 2662270584], [2337790463, 994315188], [732722482, 3490831339],
+        [144152890, 108597321], [2761025989, 3769905967], [3670789336, 1585051684], [1717505534, 2418186829],
+        [80172058, 1651517352], [3053536026, 3093884418], [3067632855, 2040606214], [72427464, 2892023671],
+        [1924844973, 620478700], [3479264872, 3449918753], [4158670228, 247455170], [1232477319, 4126499868],
+    ],
+    [
+        [1942134747, 4222230519], [3984659835, 158047430], [203914263, 1578086920], [2280204473, 2455285701],
+        [840237611, 3100982558], [3070091950, 1706286962], [1081017599, 773245524], [3647846455, 3544995077],
+        [486190333, 3361452521], [2459358086, 1060918458], [788080818, 382622995], [4240208740, 2768017841],
+        [1431042496, 2330327823], [3293976512, 1172518324], [1723220450, 2071133322], [2776854115, 3924030997],
+        [1335299661, 3635692255], [3499314964, 647309391], [1055876009, 1848520146], [3140127492, 2966142957],
+        [23098576, 2607483888], [2350596448, 1382855240], [2120059552, 30456292], [3789188167, 4085020170],
+        [634676199, 3776311345], [2875048884, 468332869], [368207470, 896320377], [3367593940, 2263132789],
+        [1784571242, 2845266267], [4086520946, 2011350472], [1554322574, 1337704355], [2622668478, 3223290679],
+        [1612544923, 3315071234], [4215009355, 818183661], [585577898, 1113100541], [2538626446, 2923923737],
+        [725757714, 2152582297], [2700500802, 1250954074], [1398220539, 480887732], [3237280989, 3888515845],
+        [194062836, 4107111048], [3165554127, 691238672], [907651300, 558209563], [3896264225, 2635262375],
+        [2073899966, 2518065933], [3866059080, 1521861518], [1260318135, 1656442776], [2971202428, 3739076753],
+        [1526603010, 4002033135], [3471576690, 288565870], [432248906, 1926741492], [2917355625, 2404579067],
+        [312611854, 2698613077], [2591375476, 2138279069], [1842844299, 966402686], [4135846012, 3428609529],
+        [1006344921, 3605207179], [2211796246, 120317012], [104919485, 223037868], [3609692354, 3169669460],
+        [1152470461, 3064804075], [3698037246, 1803813870], [1969119216, 1456397667], [2323507519, 4291718690],
+        [1864013668, 3845300210], [4112492169, 511946807], [288412181, 1218241333], [2562554565, 2198257602],
+        [558524473, 2914396320], [2937927531, 1902224463], [1489450631, 866140835], [3443554744, 3348123950],
+        [70710680, 3715023352], [2299427392, 573931243], [939955506, 72925180], [3853023269, 3036425997],
+        [1211311356, 2677133016], [3572905391, 1414088525], [2030499184, 1773300353], [3208853098, 4149447408],
+        [1374540719, 3485620940], [3262802087, 984662949], [700550059, 2089578389], [2730207920, 2732939150],
+        [454085758, 2382113472], [2499272449, 1104568669], [1671121634, 327578133], [4175103845, 3983646449],
+        [882843809, 4254243433], [3019862311, 247170901], [139822487, 734013292], [3739731200, 2502738601],
+        [1983993125, 3202735330], [3953223615, 1612841858], [1185944882, 1503871209], [2153974563, 3559398629],
+        [2083472922, 3502426306], [3800833975, 738936298], [819006764, 1345266278], [2389752368, 3132629619],
+        [1025135473, 2440990494], [3087046731, 1560780318], [1298607095, 185372042], [3527454659, 4163347100],
+        [374525418, 3926490232], [2789042803, 917293090], [662730293, 1036582500], [4050987283, 2291589101],
+        [1688552750, 2225360017], [4276359607, 1275994542], [1474626656, 1951849708], [2834755594, 3409016215],
+        [1138013656, 4041027655], [3676216083, 50677345], [39333763, 1734634861], [3035994500, 2570897157],
+        [250181511, 2999520869], [2232666883, 1813318026], [1885307409, 630492584], [4000269066, 3688787511],
+        [768511561, 3266043480], [2663837494, 346153364], [509778647, 407416586], [3330373059, 2881899721],
+        [1599152366, 2788027646], [3391319830, 2018575227], [1755982107, 1193675501], [2439264363, 3824416785],
+        [1740202689, 4138475852], [3836664938, 388319604], [87326021, 1148838508], [2283829949, 2332187090],
+        [966737744, 2769135972], [2762103301, 1758265679], [1420046020, 599303692], [3581031035, 3379294598],
+        [270092227, 3524337920], [2261797671, 843860757], [868879258, 534251653], [3968240524, 2893908132],
+        [1509540500, 2468168252], [3635899534, 1233639799], [1851922951, 1691309603], [2929110081, 4209441826],
+        [1195797445, 3246820213], [3744471442, 888176874], [610082740, 1643945070], [2855772109, 3217037319],
+        [164261997, 2281203810], [2641362623, 1489621662], [2003647734, 440077704], [3929291965, 3772127743],
+        [677399354, 4075662512], [2740127460, 9496819], [451759400, 658787109], [3516404312, 2354741324],
+        [2144117640, 2751300796], [3771009837, 2112420898], [1308865433, 1084599262], [2371631673, 3472846457],
+        [1767133257, 3749902574], [3919906247, 1017199347], [738534797, 1538471143], [2197243379, 2812345784],
+        [641430624, 2627920144], [2849713436, 1469851899], [1163942227, 361703004], [3713043917, 3949755646],
+        [408875977, 4193295640], [2887187673, 620177536], [718180402, 761835937], [3815557767, 2166154893],
+        [1817087584, 2557809104], [4025251119, 1138571783], [1533328629, 1832750391], [2713426653, 3514185180],
+        [1455035555, 4270420002], [3345589231, 204582599], [179033276, 2034426012], [2803513108, 2544555826],
+        [534646186, 3173128951], [2337543056, 1979383020], [2055163561, 930925824], [3879278014, 3278958314],
+        [600087322, 3665461293], [2527178469, 168344600], [326361761, 43741080], [3665478409, 2705383189],
+        [1389957923, 3150210797], [3248058755, 1726051510], [2113331011, 1271238732], [2215773547, 4021949358],
+        [2022986781, 3897238048], [4250480150, 90410968], [489098586, 1427700403], [2481501163, 2661024907],
+        [794203811, 3050278666], [3202454417, 2060393319], [1236163111, 1046872119], [3307870745, 3691123395],
+        [229231934, 3329414469], [2567024310, 803363633], [551389501, 136105776], [4106271179, 3118410088],
+        [1095404460, 2186167523], [3427608765, 1609510008], [1925394721, 1879304225], [3077507352, 3837568993],
+        [1574213432, 3580989494], [3378763669, 718203970], [889987675, 1985782231], [2995200056, 2820055551],
+        [349741387, 2483532977], [2166926384, 1315769405], [1809862513, 259849408], [4063599027, 4239669002],
+        [1073665350, 3971632529], [3127633105, 304756302], [2648710, 992209618], [3272065470, 2591589085],
+        [1651426431, 2977540599], [4189948501, 1875154189], [1356387763, 1408836210], [2503863029, 3654823900],
+        [1960279937, 3405042272], [4031701854, 547837924], [983217651, 1297352032], [2668978855, 3084143806],
+        [936923684, 2302345073], [2964768477, 1180302229], [1577268942, 101248593], [3415025119, 4121094980],
+        [58653980, 3869439826], [3051763792, 955724295], [1010194959, 835953836], [4202333955, 2422231077],
+        [1901584256, 2396940740], [4149230072, 1363861614], [1119942447, 2126908024], [3107141776, 3298684328],
+        [1247310654, 3806703738], [3600759556, 433497962], [386100184, 1789646091], [3178460067, 2232576764],
+        [133697631, 2865762099], [2423817900, 1674631097], [1694571051, 682854855], [4278749683, 3614847822],
+        [825499025, 3441601333], [2415487095, 494327047], [265668654, 271311313], [3478364174, 3012443749],
+        [1289193622, 2939756094], [3542691145, 1930566233], [1640065660, 1551836512], [2609578429, 4043538556],
+        [2126134148, 4091347978], [3775192552, 18612187], [25865211, 1391491183], [2364070567, 2616052191],
+        [1042430429, 2954575481], [3151434563, 1860740861], [1328537423, 645558642], [3493029925, 3629041389],
+        [356349668, 3229949070], [2626171221, 898481656], [623353551, 460514663], [4079066231, 2836653937],
+        [1550242151, 2250218328], [3362292168, 1326475995], [1793012261, 1998154977], [2883661225, 3790792253],
+        [1085574507, 3552582911], [3651484625, 783026378], [852263471, 1694736666], [3055565814, 3092685665],
+        [218067415, 2459430171], [2267922277, 1591511018], [1937070269, 163451769], [3984048385, 4217743853],
+        [774078952, 3912956691], [2774094927, 377062477], [477720539, 1071766042], [3298409807, 2318325249],
+        [1713405638, 2753680122], [4231497677, 2073257814], [1437727274, 1159533677], [2450601693, 3369451095],
+        [1832638000, 3432920656], [4133126863, 963158012], [737426179, 1243949523], [2598288105, 2685187849],
+        [571201389, 2411106413], [2903920572, 1119960940], [1515250220, 297769453], [3462929875, 3997987239],
+        [117435598, 4283689446], [2980664632, 565505244], [993138351, 702356820], [3868922420, 2528870354],
+        [1977139088, 2648928952], [3903421395, 1448080260], [1146177341, 1800838475], [3155537001, 3595379312],
+        [1406779465, 3881415866], [3226853613, 474132357], [304674136, 2143184880], [2685901736, 2161386419],
+        [425365749, 2928291567], [2546672248, 1920467828], [1619749976, 810295735], [4222418015, 3305274570],
+        [917931557, 3724762282], [2331691945, 228337847], [185576775, 131393082], [3704547273, 3057952776],
+        [1272952309, 3154663503], [3621028209, 1652340906], [2068877859, 1513081711], [2199968064, 4099439110],
+        [1665263689, 3987642259], [4163366716, 319296944], [461877579, 1097080659], [2489666401, 2367110320],
+        [691948484, 2723484799], [2724201544, 2084242449], [1359201650, 975015440], [3265769822, 3480803964],
+        [149454355, 3572989950], [2156511728, 721629462], [876809590, 239068358], [3949051909, 3189514739],
+        [1176618055, 2509802612], [3731176775, 1501005594], [1993864068, 1627173886], [3004634581, 4251954430],
+        [1483439572, 3339037298], [3450786445, 859535229], [569003232, 1907650842], [2946599511, 2906167588],
+        [296552757, 2213025806], [2558137929, 1213341083], [1874625169, 504658014], [4120184994, 3855917054],
+        [950952973, 4156163607], [3212930846, 76550337], [80592157, 582180433], [3560955532, 2669140743],
+        [2041084692, 3021241645], [3846793576, 1765637676], [1224031535, 1418928174], [2313649911, 3720235660],
+        [1891948508, 3676872945], [4002420723, 622681258], [1035250170, 1570716344], [2248043105, 2991192739],
+        [806025310, 2577932809], [3021831237, 1357725740], [1124720438, 62171327], [3686841969, 4032592275],
+        [511879860, 3809080645], [2826321617, 1025766297], [763084753, 908805018], [4261786405, 2216167508],
+        [1751612051, 2287458336], [4057182263, 1200059044], [1603235924, 2028793984], [2799994629, 3261275619],
+        [1303681993, 4171016318], [3538922106, 197979310], [239766974, 1821961836], [3097454330, 2449282033],
+        [42820898, 3121155924], [2391421849, 1737888555], [2093212623, 754080223], [3792469308, 3492657165],
+        [654620486, 3421282338], [2443206626, 414779379], [379988510, 336707296], [3402004871, 2799900044],
+        [1465355507, 2869815049], [3325059712, 1958857939], [1682261348, 1290944209], [2657922225, 3941938136],
+        [1800991336, 4233681225], [3937794844, 450550302], [155739938, 1317040742], [2175232069, 2270396476],
+        [898892834, 2830117342], [2862993153, 1630731791], [1563842542, 708892183], [3754999216, 3243559675],
+        [440350897, 3642858351], [2381176333, 1002314528], [1059382272, 310756183], [3761976492, 2741162377],
+        [1346184140, 2596649553], [3511846363, 1081240598], [1660045386, 1870036831], [2744964133, 4068175420],
+        [1230485084, 3386738280], [3582877262, 1052152826], [803517502, 1745650546], [2753730199, 3044011987],
+        [92904445, 2341901362], [2466526120, 1440355182], [2015000311, 401652294], [3830345110, 3906453383],
+        [543539543, 4194314142], [2927339213, 150989536], [283425586, 795163957], [3624257820, 2191298755],
+        [1912858522, 2900974522], [3965287626, 1893123375], [1106237217, 1229513480], [2253152407, 3330727607],
+        [1707226939, 3620228215], [3889687596, 938867355], [646364290, 1466483423], [2340492475, 2854754328],
+        [749633691, 2536536494], [2811941996, 1533047968], [1257997242, 420726909], [3592242688, 3795216635],
+        [330043029, 4051774600], [2705008963, 763832758], [589337851, 610028651], [4011463657, 2383964285],
+        [1631024209, 2425346806], [3816965437, 1260216179], [1380929123, 1713758134], [2895295010, 3665855678],
+        [1590072077, 4111455175], [3414115098, 114573333], [117474004, 1970608201], [2842848419, 2622288454],
+        [397337030, 3075632467], [2181509109, 2041582870], [1949248159, 1006987207], [3915501410, 3392652271],
+        [707687768, 3521567390], [2602009405, 33789614], [416746935, 178904204], [3553977420, 2950912380],
+        [1536331067, 3011325597], [3485439662, 1840326269], [1912128050, 1124815490], [2400961063, 3860159113],
+        [2006166168, 3761357057], [4075154514, 260694190], [337578246, 1481906603], [2642692283, 2492812211],
+        [614955485, 3205047768], [2994034195, 1993380266], [1203690138, 875814493], [3388621380, 3583517141],
+        [11078031, 3462760482], [2512127390, 668860982], [679968593, 3698488], [4177761582, 2980708782],
+        [1324164410, 2365565188], [3284225601, 1394394360], [2132451532, 2100454836], [3136960772, 3963982340],
+        [1416571501, 3703544540], [3319156003, 592468235], [962110703, 2050999043], [3187712249, 2777540034],
+        [498419439, 2655767111], [2296238595, 1156378553], [1734622779, 95955527], [4254505362, 4129073554],
+        [860169934, 3826803805], [3085330608, 525177784], [221885696, 852241505], [3431226450, 2474887072],
+        [1855276324, 3107866558], [4093857173, 1679461468], [1496673784, 1595568766], [2583526484, 3536654877],
+        [2062597165, 3285338819], [4294607504, 672421479], [923317674, 1187940950], [2424472495, 3182528894],
+        [977533534, 2242723546], [3186252225, 1305648085], [1445353109, 213619137], [3355229021, 4264142282],
+        [257149274, 4011117010], [3116713522, 825194185], [837024692, 943326117], [4157953185, 2559518545],
+        [2099375248, 2179775648], [4207831345, 1555314811], [1275679045, 1945711407], [3043077128, 3450235733],
+        [1167911470, 3953787097], [3717264547, 357723751], [521302833, 1665458735], [2957547049, 2315098509],
+        [168199322, 2806452617], [2677577947, 1780151515], [1775970068, 542752412], [4040534111, 3745835906],
+        [1018766735, 3295419904], [2230557935, 280477738], [63993225, 496197793], [3245372195, 3141248066],
+        [1111505735, 2709819070], [3671642969, 2118627444], [1825074678, 1373200768], [2523473277, 4180807347],
+        [1963366062, 4094146605], [3872049353, 221583493], [198723358, 1443392790], [2208771884, 2642777362],
+        [913102688, 3055696379], [3158389457, 1659614828], [1154927118, 568436315], [3692715405, 3730999070],
+        [431738843, 3311273458], [2592107853, 968903209], [574806490, 291241862], [4128184203, 2694083660],
+        [1393971913, 2158378590], [3459366352, 1110711679], [1615796780, 1913390681], [2910445508, 4007158600],
+        [1263777442, 3594496911], [3703404232, 556371271], [998601751, 1645516031], [2982267251, 3067719338],
+        [100902881, 2533043188], [2203754287, 1453849253], [1974474643, 124781218], [3907039620, 4104602210],
+        [733854959, 3884202750], [2912419460, 300812222], [420354804, 817393495], [3222389792, 2148031012],
+        [1626495233, 2920476120], [4142029979, 2144554647], [1403565984, 1256367121], [2542090777, 3317911495],
+        [1727183705, 3366221441], [4081584572, 891352387], [632866225, 1331807572], [2464368130, 2757598165],
+        [781907756, 2320824539], [2879612426, 1339272190], [1429813614, 370407408], [3358196223, 3780211223],
+        [30170868, 4080887837], [3060883360, 778050373], [846723596, 786962422], [3989135467, 2463604590],
+        [1933435956, 2452110168], [3977356408, 1584724371], [1341224297, 1855400799], [3063872045, 3550021903],
+        [1439444489, 3919836809], [3370904903, 456623702], [480408794, 2076419168], [2771432205, 2324592961],
+        [473243403, 2760638057], [2453862105, 2067265369], [1715523013, 1065198526], [4234354164, 3355998727],
+        [848117238, 3541568937], [2359420650, 27953300], [213892795, 153705499], [3502427352, 2960027843],
+        [1333546979, 2964397884], [3495693633, 1851648697], [2128347741, 1377493474], [2355849000, 4088416335],
+        [1680062016, 3818566325], [4266246851, 404546980], [518143698, 1281648336], [2445436505, 2227336577],
+        [756553945, 2877628283], [2796070726, 1949127545], [1597164439, 1029023138], [3396791015, 3417534874],
+        [235376256, 3683872571], [2385942408, 747718043], [813234081, 57371458], [3807423315, 3126123665],
+        [1131759807, 2573138808], [3526555401, 1576546750], [1888097081, 1818828883], [3091990802, 4176574975],
+        [1472152559, 3257361260], [3399340799, 1033081341], [766662994, 1956504514], [2822256731, 2792327598],
+        [384217146, 2297815203], [2653538640, 1199054695], [1692283559, 419012316], [4272044300, 3814002904],
+        [813821544, 4026665960], [3029466445, 65725998], [49285838, 743758594], [3681066363, 2583561337],
+        [1883215787, 2989660151], [3796531296, 1729716980], [1135644203, 1351062532], [2239091415, 3681569618],
+        [1991510561, 3561845693], [3946161921, 733092994], [955710515, 1411988096], [2162077939, 3197464649],
+        [947248077, 2672956499], [3011058966, 1508548411], [1181332239, 250096257], [3726996607, 4260462956],
+        [468772244, 3977033062], [2731455638, 979225206], [553895760, 859939979], [4165754668, 2375737044],
+        [1674532884, 2373350482], [4172726252, 1092157333], [1480353591, 1897952298], [2720543598, 3483205145],
+        [1218190481, 4147896962], [3562356179, 68080025], [145778546, 1617601478], [3015421906, 2515199443],
+        [77826209, 3030741186], [2148427900, 1621447414], [1982237129, 729201091], [3955716527, 3569300448],
+        [562767092, 3353613767], [2484430548, 334856430], [457741266, 323232884], [3455821255, 2916919328],
+        [1487787527, 2909909502], [3441137078, 1910955894], [1867646757, 1210971437], [2493947598, 3990528424],
+        [1821819931, 4050839589], [3808529957, 500346950], [51753697, 1264828806], [2223784228, 2394640822],
+        [834105063, 2702354041], [2717696066, 1716784004], [1542747531, 613966100], [3666498904, 3447376144],
+        [527680731, 3607181954], [2190027144, 1011017223], [973712631, 431030727], [3922815491, 2805302047],
+        [1449101605, 2549687247], [3597778299, 1295618136], [1762630996, 1784823851], [2817009607, 4274740432],
+        [1280946750, 3304612589], [3659845931, 943931840], [711481299, 1721250879], [2890587368, 3006253919],
+        [58811623, 2171002859], [2517223150, 1547640939], [1897822139, 285133688], [3823576981, 3874431320],
+        [653742338, 4125351113], [2807152871, 216046063], [531615193, 675327400], [3604718068, 2244926063],
+        [1954391494, 2816917352], [3880537319, 1963989073], [1171367362, 1176283526], [2332033724, 3283493681],
+        [1849002281, 3533349929], [3973240474, 847722644], [539627741, 1604290388], [2376119372, 2745947130],
+        [684722137, 2586804613], [2750713693, 1402207192], [1091900449, 521476753], [3507380218, 3973841208],
+        [490962574, 4134639467], [2859170977, 707608045], [789698910, 587464856], [3942145998, 2276695945],
+        [1805729641, 2497911092], [3827228414, 1141998705], [1412306894, 1757196097], [2868636655, 3575088897],
+        [1499219152, 4206586421], [3276950471, 5530354], [14861939, 2106538323], [2920719128, 2470490224],
+        [279648520, 3113326681], [2366461234, 2104991149], [2140934985, 1004881855], [3769431798, 3458127567],
+        [799790805, 3696062637], [2477349170, 99580381], [346984519, 266017957], [3745728830, 2831380023],
+        [1424103965, 3039764980], [3310503517, 1751879503], [2027596842, 1150200497], [2292817131, 3896081017],
+        [1905718948, 4024132449], [4196867736, 184401023], [403587700, 1558459574], [2531951486, 2432152228],
+        [716743622, 3143724180], [3108569812, 1938924803], [1110582639, 949842232], [3485602934, 3659162548],
+        [121768474, 3273479758], [2431085363, 687480113], [754785506, 209702774], [4028967518, 3073838271],
+        [1160667613, 2307510224], [3421497173, 1459748982], [2055287042, 1974044107], [2965881100, 3956689251],
+        [1528736607, 3673190900], [3240855146, 756009147], [1023390357, 1935195537], [3049165163, 2717410849],
+        [413475188, 2417745181], [2221178968, 1132096179], [1812210574, 40152584], [4147353130, 4059059978],
+        [927423975, 3946587113], [2954713793, 364935431], [129470032, 931619568], [3406755628, 2630495924],
+        [1698825268, 3184309644], [4283851706, 1664711372], [1589022846, 1475170088], [2684129877, 3754604806],
+        [2136849933, 3466804436], [4103660903, 791267674], [855681793, 1238801256], [2572725683, 3108472603],
+        [1065281248, 2349765110], [3130343948, 1088340373], [1503883309, 143098029], [3425384755, 4200588589],
+        [90217364, 3903751985], [3193546917, 1040505294], [904519860, 880829372], [4246559687, 2652523565],
+        [1998656281, 2266817203], [4258033731, 1434012742], [1224751921, 2056767155], [2989965982, 3254506068],
+        [1313449342, 3963404580], [3628156867, 529692375], [443194061, 1870668237], [3123422311, 2357219441],
+        [8117938, 2738436345], [2578073709, 1690103346], [1862002232, 798462544], [4101102223, 3529410320],
+        [894543237, 3239931271], [2179712321, 442552896], [162630567, 445741350], [3375016240, 3210704119],
+        [1205779408, 2823699312], [3577092911, 2051549101], [1797456932, 1485290189], [2473647510, 4140928652],
+        [2064952698, 4287315653], [3898503059, 129047938], [109631541, 1525006663], [2323773057, 2524192905],
+        [994401040, 3164287888], [2974334620, 1796521347], [1269491642, 693246318], [3617249354, 3602197152],
+        [307651740, 3423636328], [2536762017, 806725805], [724374493, 470258382], [4218061417, 2932273886],
+        [1512929375, 2400946385], [3230035985, 1246989241], [1833482217, 2131513166], [2695048891, 3879619187],
+        [1141185827, 3735416614], [3612143292, 698331384], [919632107, 1809687180], [3166828601, 3160456844],
+        [192569943, 2641472594], [2318147667, 1516211549], [2078518606, 233539085], [3859533195, 4281003476],
+        [579337657, 3995751363], [2689415854, 486045032], [315906673, 958522073], [3468309318, 2414682947],
+        [1837118087, 2690055125], [4224482257, 1921437727], [1521337106, 1118603917], [2584659820, 3437200885],
+        [1789512144, 3225513038], [4244179152, 1066240788], [778153243, 1167459807], [2633916454, 2848679689],
+        [625343351, 2257214446], [2784412092, 1163168127], [1547511913, 465282483], [3289620212, 3913443019],
+        [207126670, 4215112888], [3142238894, 639405053], [1047125822, 653458825], [3780516671, 2601701323],
+        [2117409745, 2612674746], [3784602016, 1387504009], [1089402640, 1709101973], [3148087050, 3637216086],
+        [1559100695, 3786730898], [3305057696, 381111764], [360531066, 2006501680], [2871605073, 2255090643],
+        [363068228, 2842774550], [2620202711, 2001746768], [1778578468, 902123171], [4091255235, 3235848055],
+        [1049855558, 3627607317], [2276943639, 163869494], [19673665, 24837917], [3655998998, 3097694780],
+        [1075118836, 3088536634], [3641211718, 1699162299], [1941035206, 1589428041], [2271727847, 4224971912],
+        [1761272070, 3937747170], [4044319470, 343806080], [369401043, 1205666935], [2661856377, 2284274892],
+        [670210418, 2793460618], [2828763110, 2025739499], [1460937931, 921736817], [3326567657, 3270794851],
+        [36914202, 3493946693], [2239941868, 636006681], [1031402671, 193293531], [4007543538, 2995285192],
+        [1292325678, 2442283693], [3682633362, 1347484576], [2088751193, 1742871543], [3028196408, 4035528086],
+        [1607148139, 3412916801], [3338351105, 911218415], [659476693, 2014707121], [2792184210, 2876391915],
+        [504792812, 2219575035], [2434716637, 1284289019], [1745783757, 348439102], [4054423787, 3931325932],
+        [1038817185, 4168595342], [3103462193, 190363865], [246342029, 629101902], [3534170425, 2433890042],
+        [2089328927, 3135938733], [3996158269, 1828626809], [1308501938, 1567805170], [2396234935, 3499834491],
+        [2038336072, 3720728273], [3857373401, 586289620], [887046464, 1493474295], [2305602617, 3026393788],
+        [872699324, 2505307463], [3206982536, 1425961162], [1213082171, 83097135], [3568974416, 4159923396],
+        [289694565, 3851497790], [2951532360, 868793144], [693650262, 989042487], [4117078829, 2209667976],
+        [1875505199, 2206189178], [4125229774, 1221441477], [1367425606, 2085025603], [2942174986, 3345940125],
+        [1189671310, 4245814747], [3733818849, 241613605], [73765628, 1766893300], [3219362904, 2681578763],
+        [135008090, 3193758764], [2308443786, 1777277206], [2045650242, 577327745], [3842589344, 3710450087],
+        [697491900, 3474903138], [2565831951, 508525715], [301559234, 519466359], [3257300311, 2720579768],
+        [1364665317, 2730394839], [3269231632, 2094525376], [1664360642, 1099637041], [2550767830, 3850264299],
+        [1636486032, 4182659200], [4017352981, 275928825], [254966880, 1132795001], [2410485417, 2173540429],
+        [1012480484, 2946551450], [2899056940, 1834413228], [1387792361, 767725350], [3546277413, 3289521361],
+        [390542260, 3743859314], [2347111218, 923398510], [932222745, 354090570], [3886210396, 2864469646],
+        [1583846109, 2620519358], [3708278049, 1186692531], [1703449266, 1671886781], [2847254195, 4116258815],
+        [1117243330, 3453072430], [3549851445, 832804864], [597238913, 1842000322], [2707354631, 3145894482],
+        [261248024, 2390544424], [2613737924, 1370747086], [2105305141, 489183648], [4022305500, 4015289247],
+        [745303658, 4266341850], [2838619600, 108735666], [401782221, 550527928], [3724171727, 2303108065],
+        [2049500421, 2857687574], [3911499592, 2032380202], [1244625970, 1301051512], [2185628091, 3399620281],
+        [1653790604, 3653014561], [3764252093, 997694744], [673340099, 1397862641], [2257399364, 2897627278],
+        [545985059, 2479216127], [2931973061, 1598966601], [1318435251, 306271699], [3638582188, 3833313927],
+        [340433031, 4244165556], [2757793223, 601560092], [617243975, 713329020], [3841717302, 2345419515],
+        [1728688720, 2663809794], [3932460289, 1324999631], [1571149834, 1635412502], [2765220876, 3707283517],
+        [1351876539, 4062256290], [3439296019, 140813993], [234869738, 1887836593], [2736979391, 2592436679],
+        [446501535, 2986279364], [2248751679, 1884894888], [1919796368, 840593752], [3961226975, 3325160097],
+        [606946188, 3586469809], [2648229023, 253706627], [501431954, 85199704], [3587402798, 2773204252],
+        [1565262471, 3216865447], [3382333644, 1635809513], [2012254176, 1312551578], [2172633185, 3765305718],
+        [2107284334, 3867106412], [4153729168, 49955069], [332496570, 1361964205], [2607209212, 2553632732],
+        [591931186, 3018527093], [3040004424, 2125575713], [1285867353, 828628311], [3253676567, 3516164589],
+        [182530297, 3394090050], [2672687278, 540112097], [645215278, 110244415], [4290176327, 3176590470],
+        [1252303095, 2239593628], [3349231279, 1539436173], [1955670092, 2043932511], [3179624345, 3803925849],
+        [1377971283, 3507773685], [3474072503, 606989654], [829812534, 2115307091], [3113603546, 2940825371],
+        [321357298, 2563146403], [2405445806, 1270810802], [1635056397, 173690563], [4205425560, 4186257193],
+        [986470577, 3797074492], [3173760130, 425214178], [175704775, 1019914364], [3342125698, 2541627626],
+        [1773435737, 3082477513], [4038191338, 1794973538], [1457995065, 1527957123], [2416269158, 3619537084],
+        [1921174930, 3337213307], [4183630861, 657412656], [1066737950, 1077059750], [2513427332, 2973738260],
+        [864831660, 2195432579], [3070319742, 1226837218], [1348261061, 16292761], [3283185550, 4070721418],
+        [152126913, 3770218487], [3001432151, 877650575], [970641650, 1053088231], [4071910137, 2487238982],
+        [2021130377, 2338176259], [4066226097, 1479355351], [1192516925, 1991789909], [3196799077, 3381545531],
+        [1100121831, 3837869203], [3519515624, 316529247], [274260266, 1682091879], [3080498430, 2182539555],
+        [225550825, 2889085097], [2506102068, 1862667655], [1644548275, 663354013], [4190320205, 3647857353],
+        [960017780, 3372848492], [2285961357, 397251486], [100249538, 391327714], [3317414129, 3045545157],
+        [1241479127, 2782629635], [3751837037, 1983114495], [1741419414, 1438233313], [2637065221, 4230600745],
+    ],
+    [
+        [302268183, 481129168], [4221002864, 2972294261], [1372655446, 4058577288], [2200370633, 1365799079],
+        [2109900618, 1975172987], [3186325129, 3281448531], [873496947, 2284059360], [3719081161, 728627023],
+        [1214753272, 1014965879], [3300057216, 3767615699], [112511590, 3746602046], [3978835337, 1689173719],
+        [553639162, 1120386829], [2550804035, 2552675438], [1788622004, 2819744613], [2919318986, 228155890],
+        [1017941022, 544419107], [3539069023, 2189484546], [1973238252, 3478542672], [2987287441, 2147304086],
+        [1574197794, 1521352840], [2319000014, 4291816086], [467977649, 3189556932], [4027154586, 370969849],
+        [1681362091, 65158498], [3875326165, 3501158709], [743194898, 3921843684], [3407287525, 1307298345],
+        [154962909, 1794678145], [2741764089, 2751562809], [1163997710, 2419751046], [2460038276, 939391801],
+        [27387938, 844948718], [3948931909, 2533677539], [1277998801, 3611330896], [2624637068, 1843541231],
+        [1816967929, 1269271418], [2497280693, 3641759021], [651964797, 2738048273], [3760964803, 961678973],
+        [1925933993, 790053546], [4115891463, 4140057177], [397228955, 4190611477], [3606711745, 1916116658],
+        [809612097, 2077068567], [2277594635, 3130918417], [2026268947, 2268948005], [2387208365, 404469464],
+        [735627957, 185878132], [3423727538, 2891956011], [1643126349, 3831946633], [2774703360, 1146903047],
+        [1115745996, 1642605785], [2867396135, 3986525148], [256770585, 2680140563], [3273204587, 112234871],
+        [1414865578, 315648373], [3662227852, 3402835852], [973739635, 3342539961], [4242011500, 1585741801],
+        [469848876, 1476144814], [3030968235, 2413836023], [1527753165, 3081632617], [3090642213, 650786143],
+        [205947738, 75512327], [3802689874, 2687505125], [1074623152, 4015762195], [2527513643, 1238017935],
+        [1660816864, 1845625038], [2792435892, 3572430738], [703423014, 2514574329], [3481122409, 811978268],
+        [1489127230, 631528208], [3559624414, 4227466061], [520131797, 3377035168], [4154201808, 2019908912],
+        [941664118, 1561684722], [2366496351, 2223505760], [1885125335, 3106212633], [3080876467, 273448043],
+        [605281258, 976325914], [3245161696, 2642289242], [1873160466, 3680672801], [2841089630, 1658998395],
+        [1339273398, 1181015506], [2671889347, 3864155543], [40583869, 2926763211], [3909621559, 157226246],
+        [2064938572, 460892908], [4263204284, 3321277625], [853393232, 4123589981], [3626356310, 1440528795],
+        [358735580, 1892176345], [3153466304, 3022248982], [1459324452, 2355385806], [2244011781, 740424225],
+        [406648968, 683820967], [4091989482, 2322848268], [1596049186, 3243965464], [2289911495, 2013251654],
+        [2010608908, 1383467182], [2151807270, 3431028138], [1043305694, 3006704025], [4179180779, 590370460],
+        [1753277289, 893791776], [3996263302, 3945709388], [192185254, 3793968974], [3335910563, 1753551505],
+        [804077194, 1738762100], [2435772498, 2863357325], [1742796685, 2598103077], [2584635304, 21104270],
+        [928851252, 344176852], [3736789037, 3154485249], [2128221194, 4238923692], [3210509511, 1504627202],
+        [1379354478, 2080626670], [2973411033, 4066223896], [274150568, 2178149923], [3501586104, 514475790],
+        [1200541378, 260330581], [3371097704, 3719752533], [599999836, 3534638337], [3847095993, 1102697835],
+        [77002938, 1337216361], [2906703992, 2466241582], [1273429887, 2802037934], [2697567456, 1064453390],
+        [121950346, 290354916], [4140093854, 2835779723], [1238330705, 4208792847], [2570033939, 1545506341],
+        [1902003213, 1700044452], [2939513107, 3361246652], [564264807, 2238686704], [3319779713, 604465569],
+        [1350228657, 834599579], [3458122675, 4038861964], [333278297, 3590260661], [4194872856, 1867074988],
+        [676899249, 1357178579], [2535031209, 2298954758], [2095129097, 2701400722], [3164116187, 99538185],
+        [864418595, 919354467], [3649556132, 2441328005], [2058503038, 3522682136], [3122446424, 1806237581],
+        [1150563061, 1424597601], [2480522009, 4106412831], [136918903, 3038094012], [3888089781, 443170171],
+        [1954202608, 147339338], [3907191153, 3464801328], [1031181264, 3881528645], [3227960825, 1538748329],
+        [63888167, 2129824291], [3006425181, 2951453489], [1543736206, 2631130703], [2339513344, 1005367537],
+        [374061638, 1056152357], [4255822460, 2613605081], [1427887824, 3698015898], [2251044520, 1723794371],
+        [1724853049, 1596413507], [2410445790, 3545793779], [778425085, 3137859009], [4095903208, 887352873],
+        [1625791318, 573782763], [4175898371, 3852416842], [429179972, 3971825610], [3442391694, 2110682553],
+        [1058880332, 1825956148], [2645069905, 2995314567], [1993770376, 2528788436], [2515276439, 357899908],
+        [997285353, 10879327], [3354721907, 3055852899], [1764617650, 4150768364], [2894079835, 1445300137],
+        [1256301564, 1938621090], [3104866183, 3809043822], [488592496, 2480295410], [3680241562, 238071372],
+        [1589529433, 131274903], [3517382183, 3590614891], [920483222, 3624635822], [3927767773, 1403123530],
+        [301818415, 1158791867], [2767763356, 2155132387], [1136367632, 3180107051], [2874539939, 695814184],
+        [518029536, 212918970], [3959572107, 3087199133], [1498389841, 3786876794], [2353220406, 1125218780],
+        [1799047098, 2031591020], [3056225018, 3725048050], [959355607, 2572227218], [3589057495, 1038319482],
+        [1096491027, 712192755], [3698535309, 4002426210], [223165164, 3269434278], [3821136819, 1959237003],
+        [895595659, 1218766812], [2190345294, 2492439665], [1677346667, 2965778415], [2815720560, 502353855],
+        [759177927, 771205871], [3398456622, 2372646798], [1705492108, 3300209221], [2719054012, 1907908090],
+        [1473990991, 1282618647], [2229470349, 3905167037], [335861634, 2770044709], [4293122972, 41172892],
+        [1859315314, 391166716], [4043687571, 3668899074], [627123107, 4266767751], [3544148146, 1197335343],
+        [451663831, 1665096718], [2822721190, 3220020350], [1317102663, 2208976742], [2664156488, 565612385],
+        [170841711, 656505229], [3839257769, 2248373515], [1189550600, 3408205134], [2421601503, 2062809092],
+        [2033421920, 1084425354], [2608306636, 3330247884], [832825362, 2881015793], [4022947229, 787613678],
+        [2135542526, 950817610], [3791566079, 4256741919], [13371958, 4088873060], [3741406415, 1625137972],
+        [654868001, 1990835949], [2306844079, 2729675399], [1838053433, 2341729149], [2175660512, 168609451],
+        [584691180, 429576071], [3613925687, 2791372217], [1942768580, 3932491664], [3045863044, 1319105270],
+        [1511973541, 1774605268], [2711603324, 4167958339], [91699888, 2394001786], [3384226957, 324261710],
+        [1303749848, 526143836], [3266570654, 3235300519], [713237018, 3449491301], [4065044057, 1243017066],
+        [241295021, 1486567229], [3191278644, 2652255373], [1393676129, 2917345426], [2963132870, 867423893],
+        [459301164, 380122016], [4041045697, 3197695619], [1564650250, 4284841994], [2326190760, 1517211705],
+        [1970774496, 2138586086], [3000797836, 3482703007], [1009309501, 2182678798], [3524369616, 553363704],
+        [1168816838, 924980365], [3421324098, 3917099616], [161280360, 3492199135], [3860690360, 1779985091],
+        [752710684, 1296048612], [2455698206, 2424633220], [1687363752, 2762876368], [2748738781, 56116101],
+        [884085269, 732826506], [3710701538, 2294491736], [2103640924, 3274343926], [3176831909, 1969252364],
+        [1364064695, 1375244566], [2207346091, 4048626240], [318607449, 2978999104], [4219116768, 476705661],
+        [1782011949, 224296920], [3991232481, 3754713576], [541777466, 3762103144], [3294085343, 1109904024],
+        [106191259, 1684267017], [2934779758, 2827712858], [1224612833, 2561140560], [2562445996, 1021222355],
+        [267374418, 971757699], [3772400980, 2673171043], [1113137358, 3650983386], [2487699548, 1630342976],
+        [1632220120, 1150585335], [2631512298, 3615986124], [723212244, 2900637474], [3953006001, 848720242],
+        [2018942475, 641367014], [4232045862, 4178073292], [480477365, 4131409853], [3665828279, 2066259685],
+        [989446616, 1921299736], [2392411699, 3072145271], [1917143722, 2400929002], [2266132654, 307722163],
+        [637860235, 108305501], [3282764932, 2750299424], [1827954711, 3982820244], [2858436709, 1259278740],
+        [1284193269, 1830358130], [2784543397, 3834774740], [24542298, 2544364794], [3434998613, 195327674],
+        [1539767783, 419089255], [3591481672, 3354209912], [816568138, 3395393555], [4122061280, 1465740158],
+        [387107783, 1582758811], [3095632736, 2273637198], [1422004749, 3126847674], [3026310358, 803985317],
+        [41999071, 167087054], [3918716296, 2931537225], [1331626319, 3872638147], [2678450899, 1184354382],
+        [1869639443, 1647561285], [2844864594, 3683978207], [620080903, 2647756066], [3253583568, 987537922],
+        [1444157025, 749350254], [3633162249, 4112417901], [367206973, 3312220843], [4277906770, 1882608894],
+        [846405797, 1431828005], [2234661563, 2365469931], [2074814621, 3030497336], [3143870141, 466465276],
+        [689104131, 821563666], [3483888973, 2506592950], [1648942967, 3563903513], [2800795458, 1856822843],
+        [1087965544, 1226025282], [2516828921, 4024960629], [211492351, 2698413887], [3798622323, 70588475],
+        [1889189560, 281698109], [4146927552, 3387595867], [955159094, 4213314011], [3570863864, 1572812804],
+        [534438697, 2023572174], [3077088003, 3119619109], [1480624208, 2215908585], [2374605261, 626077132],
+        [284717942, 603363447], [4189832875, 2168932091], [1388069159, 3429054141], [2156126310, 2094988308],
+        [2118972658, 1499542120], [2290939038, 3253969555], [931791252, 3164348791], [4081628414, 677029698],
+        [1731499440, 1065690001], [3855656886, 3801046929], [70437867, 3952246895], [3362980752, 1731873562],
+        [589871585, 1747516084], [2594517119, 2813248655], [1747450471, 2454334582], [2446767585, 252768815],
+        [1051955421, 505262086], [3490137908, 3013908026], [1996569977, 4070421734], [2980544523, 1391109619],
+        [1610424864, 1996756322], [3215786932, 4229547014], [417303656, 2327914633], [3724928927, 335944798],
+        [1259555483, 30003478], [3330105432, 3523322141], [789480567, 3715468218], [4001705778, 1328869597],
+        [196592513, 1095137170], [2691124081, 2590646007], [1194102285, 2859205986], [2918495451, 900556804],
+        [147988951, 451541949], [4282363169, 2783842465], [1146029053, 4101039526], [2473917883, 1411636146],
+        [2048189164, 1802244218], [2728407306, 3293808150], [769766681, 2378526546], [3396680137, 757531427],
+        [1557037079, 993576064], [3237095478, 4276179449], [440424256, 3662284192], [4056987728, 1673912025],
+        [637393244, 1531674112], [2656767558, 2202407039], [1957182969, 2942863960], [3014380044, 140510099],
+        [969324444, 1029583611], [3574339352, 2581660862], [1912212614, 3736386282], [3063708575, 1705554091],
+        [1230542180, 1556578624], [2579978141, 4200536963], [131987764, 3095824790], [3976051246, 297147913],
+        [2081454018, 87676373], [3812330778, 3261244715], [904695724, 3999795385], [3468569289, 1350455256],
+        [231599074, 1948536362], [3160387848, 2710479676], [1352398150, 2483729802], [2182506178, 829390183],
+        [496984760, 880349823], [4109655023, 2469580668], [1521178914, 3554091167], [2399356989, 1769771840],
+        [1772266035, 1458185179], [2264730574, 3706694118], [576188761, 3066618472], [4248460823, 1046645431],
+        [1831165978, 703693722], [4068528898, 3965922804], [293026667, 3849583750], [3261616222, 1983617562],
+        [906038070, 1615230700], [2500430128, 3173531983], [2141627450, 2664509703], [2638541337, 533754345],
+        [829551009, 251395258], [3373798529, 3151384612], [1712318577, 4176478577], [2701783736, 1609692318],
+        [1175574393, 2052456543], [3043099062, 3939914185], [380670968, 2605473319], [3623505846, 8195775],
+        [1405364828, 181725477], [3751072198, 3632641636], [1068431798, 3601023170], [3777172131, 1483580474],
+        [420379145, 1251527186], [2885403288, 2332587827], [1300188944, 2992588110], [2752241488, 582156140],
+        [345095697, 47658846], [3880762350, 3046890236], [1464855287, 3892757067], [2220494212, 1283687692],
+        [1695512865, 1903107547], [3132287067, 3512464231], [856807947, 2438270395], [3645362684, 911999655],
+        [1311806554, 557899103], [3551045133, 3889099680], [54627036, 3459293781], [3893995286, 2118036003],
+        [1036287147, 1201873277], [2343196706, 2620184562], [1845901188, 3207408542], [2827804374, 397722981],
+        [554573806, 614747140], [3310429851, 2247952232], [1808016656, 3371360969], [2946059742, 2046356313],
+        [1502450905, 1139291350], [2363556204, 3778640085], [503774681, 2848315946], [4130714446, 205220292],
+        [1665273598, 488160664], [4208522718, 3578046921], [680223602, 4030145804], [3703643423, 1214555433],
+        [321527777, 1871655691], [2809228002, 2960102453], [1104293699, 2311465500], [2542589047, 717165871],
+        [98383694, 773531118], [4016857675, 2384271317], [1245666957, 3335626588], [2610492266, 1932130908],
+        [1936599625, 1311172311], [2432249572, 3422495237], [1004064405, 2801506814], [3829486053, 665100885],
+        [1981695621, 855802338], [3938973519, 4080503760], [251104887, 4247390232], [3511169563, 1812337042],
+        [709800191, 2100767522], [2169005351, 2909462317], [1614100763, 2156076934], [2314376431, 121939487],
+        [781418135, 328547878], [3683514952, 2869971646], [2038751237, 3824806011], [3115917521, 1075216638],
+        [1441347885, 1712734423], [2890325189, 4154902032], [181746630, 2264529483], [3339246603, 425555022],
+        [1128962398, 368081968], [3455023270, 3446596364], [665086990, 3227528922], [4164099609, 1170567367],
+        [5878193, 1393791846], [2957842238, 2523112276], [1577634760, 2718486946], [3198227436, 941162802],
+        [401706840, 303344493], [4236775396, 3120626835], [1412093978, 4182873490], [2275291472, 1578540717],
+        [1916178262, 1920622001], [3021837071, 3346259999], [807618494, 2280321389], [3596668078, 793043307],
+        [1109579856, 958669180], [3277039211, 3980363338], [30330209, 3621858302], [3770082879, 1636641988],
+        [649242043, 1262874722], [2486265950, 2682367235], [1811970536, 2745775526], [2777632521, 191238982],
+        [820904167, 648031256], [3670841134, 2270531800], [1925086938, 3348974435], [3035117331, 1925829542],
+        [1418198350, 1591800273], [2386338119, 4128655258], [391178309, 3136803504], [4233202303, 313677605],
+        [1636543610, 199995066], [3955461377, 3608993283], [644629681, 3991646780], [3427072157, 1154782633],
+        [252024678, 1631996412], [2771968564, 2894665353], [1290880631, 2541218166], [2493002739, 968355154],
+        [115847607, 1017720144], [3981743402, 2565817589], [1173984313, 3502503332], [2555278721, 1681768028],
+        [1692119109, 1302611640], [2563022407, 3744142018], [547091500, 2834133397], [3986921438, 1010635914],
+        [2098919824, 722631386], [4213799599, 4054595692], [456479689, 4288910545], [3535384460, 2132680837],
+        [1013286751, 2141771553], [2328742948, 3195586193], [1966598984, 2196070661], [2321831562, 384624510],
+        [540061307, 230887787], [3291863689, 2823146624], [1683684004, 3909309973], [2929338331, 1118690093],
+        [1217292389, 1690414847], [2925215778, 3765939143], [101309024, 2558224489], [3414392610, 59695200],
+        [1560882749, 377106878], [3722252074, 3276430773], [886456379, 3287157712], [4226097204, 1370696672],
+        [465426588, 1514021767], [2990676062, 2188813774], [1359046947, 2977260685], [3181802495, 736483687],
+        [72230894, 19228538], [3844354606, 2855067009], [1264281724, 3948432242], [2438887286, 1090534242],
+        [1750622079, 1758162932], [2914500137, 3536382317], [591907610, 2461962929], [3325641179, 892829743],
+        [1599671077, 673267325], [3729061226, 4244550645], [279676991, 3435546362], [4191234586, 2085894375],
+        [1047215213, 1385562807], [2162983325, 2173408684], [2117921416, 3160530095], [3219648148, 349571640],
+        [598627644, 902422057], [3330401566, 2452834949], [1759765320, 3531020568], [2685021980, 1743276828],
+        [1197707781, 1107170794], [2441292358, 3797883234], [198185742, 2867582869], [3851519425, 266951901],
+        [2004601687, 516278784], [4183786374, 3426624419], [1054642483, 4061058950], [3496294320, 1379793990],
+        [270600782, 2090230262], [2983491583, 3009420506], [1381998673, 2166667579], [2148117366, 597950045],
+        [524710330, 623109493], [4271267507, 2349907488], [1447065677, 3316855221], [2371811434, 2026800606],
+        [2078308065, 1436899615], [2241857949, 3309212843], [947532271, 3112918955], [4269404112, 752150034],
+        [1644721997, 807683244], [3794516189, 4011277428], [203355491, 4021176094], [3488721874, 1852297074],
+        [698614954, 1860064233], [2530834205, 2694983192], [1655143093, 2502030799], [2670601577, 163288053],
+        [849333598, 461998688], [3565367703, 3111536587], [2068389564, 4118804655], [3146629668, 1427692307],
+        [1454031175, 1887396951], [3083049750, 4217983139], [352867053, 2358235324], [3639178885, 454757261],
+        [1078959438, 74548644], [3476011609, 3560339754], [608820752, 3687315641], [3806885812, 1229812230],
+        [36238232, 1187102779], [2835515168, 2643564710], [1334338683, 2935917559], [2786817920, 814898450],
+        [245559095, 352584557], [4062282377, 2723762723], [1307998941, 4084838439], [2504473443, 1492543707],
+        [2143952162, 1621912230], [2757193446, 3453832987], [661460821, 2338583496], [3448403242, 577740478],
+        [1524172072, 883986644], [3343216755, 4144656915], [371521147, 3703000931], [4105194197, 1772702967],
+        [571470595, 1447120190], [2615047812, 2399132014], [1930770923, 2787103417], [3040034910, 15193110],
+        [1072633855, 869176497], [3745935043, 2662456570], [2133310449, 3639931842], [2953682441, 1820385218],
+        [1138295324, 1479741809], [2637146651, 4091701451], [236874842, 3175529511], [3783650337, 364894377],
+        [1940616605, 2511435], [4021597103, 3332637727], [990073175, 3936570324], [3377838708, 1601774216],
+        [179600542, 2057951510], [3052739267, 2793873735], [1437854149, 2609179875], [2413165165, 875978127],
+        [330490307, 826121948], [4200246846, 2487784651], [1343617222, 3577321410], [2196079163, 1878786314],
+        [1849705065, 1539578754], [2185257162, 3582085526], [633126665, 3213303151], [4048393637, 997748393],
+        [1707789561, 759523249], [4286789727, 3896571229], [512857130, 3779686234], [3402688977, 1897553031],
+        [860629409, 1808160031], [2572195337, 3100268698], [2052916635, 2449086608], [2578814387, 285386551],
+        [899649163, 96305629], [3223545916, 3208833508], [1664632844, 4042493239], [2823955162, 1530090770],
+        [1100839588, 1956248829], [3018927198, 3876187184], [447207843, 2621522071], [3692648510, 90735529],
+        [1461881791, 37022925], [3644479237, 3516724789], [966786845, 3731872054], [3964188929, 1552881218],
+        [511492224, 1131610830], [2949729661, 2233873243], [1153465887, 3050808160], [2940826128, 617144364],
+        [435754837, 119783547], [3782553414, 3183912310], [1591621032, 3961602352], [2164658928, 1162582572],
+        [1845317353, 1986852763], [3203749818, 3628641766], [1064530583, 2520561830], [3756538739, 952362495],
+        [1246220367, 783587525], [3676933734, 3819072191], [94265361, 3322305990], [3834457167, 2048803874],
+        [835158694, 1078210897], [2254542078, 2601751344], [1775964670, 3060665952], [2886687493, 436186076],
+        [720366800, 585328850], [3446045353, 2162161187], [1836167991, 3231069150], [2877560059, 1994547962],
+        [1583922302, 1246840876], [2179712071, 3842675657], [425555619, 2734183639], [4076813478, 128470530],
+        [1769766462, 420235076], [4249771322, 3691288167], [785736739, 4163579079], [3609869402, 1314922696],
+        [381946663, 1727504824], [2901956939, 3064409831], [1181033042, 2254141214], [2425246844, 659858136],
+        [51195349, 569758965], [3817856886, 2310129238], [1092783048, 3257505574], [2541457481, 1951135927],
+        [1962536658, 1194296304], [2659946443, 3463475447], [891569380, 2714985145], [3816372624, 705046137],
+        [2061077810, 907601032], [3889916487, 4097458867], [119639894, 4194323080], [3655150755, 1796531631],
+        [763894705, 1911143522], [2215897251, 2772913361], [1802276055, 2240891651], [2358367646, 217274055],
+        [623988078, 399811326], [3544403181, 2937795742], [1946242191, 3886058982], [3010079317, 1204933994],
+        [1549932091, 1670283759], [2832629901, 4263836883], [229511676, 2305302742], [3233032164, 387019890],
+        [1233997511, 299224703], [3389527709, 3301341564], [558120590, 3357654797], [4138168556, 1136235782],
+        [126440700, 1551681506], [3128893449, 2435708012], [1507827646, 2842335865], [3061520265, 1027127552],
+        [485195213, 410306385], [4125533420, 3082828811], [1536830992, 4138377525], [2398117443, 1471591242],
+        [2022914063, 2070355913], [3094578519, 3389570755], [983197254, 2408802639], [3658409399, 642467972],
+        [1281711219, 854538778], [3437219337, 3826199038], [263245828, 3648926783], [3943044758, 1833727927],
+        [729488010, 1142053474], [2619429076, 2546089267], [1629403813, 2888174551], [2862405921, 102944939],
+        [979984690, 798391971], [3600273682, 2404375715], [2016938352, 3401007355], [3103615015, 2072043867],
+        [1531128287, 1461641357], [2272610191, 4189075688], [475207025, 3074883820], [4111222848, 412556663],
+        [1824426540, 114720378], [3765102426, 3655487074], [732774220, 3841864516], [3284735001, 1272322318],
+        [20399065, 1838114311], [2853622501, 2741417362], [1120168187, 2670023773], [2629172604, 840606318],
+        [165965083, 931246684], [3862956440, 2423455327], [1211786154, 3749900440], [2452456070, 1789456712],
+        [1794114029, 1112115018], [2462812712, 3496579797], [746855821, 2757496315], [3868444719, 927013136],
+        [1979514727, 547659738], [4033862454, 4280323490], [309907982, 4045383689], [3713202087, 1975855178],
+        [879927623, 1966676115], [2204377915, 2985054300], [2108276419, 2293671832], [2212310206, 483326121],
+        [741667032, 51361259], [3410696587, 2764063015], [1783380914, 3771846761], [2737649201, 1294969999],
+        [1158044046, 1786681821], [2745654411, 3921447522], [157278396, 2430402422], [3304945864, 219567395],
+        [1368825069, 471427937], [3528452455, 3473586585], [1021691521, 3488123489], [4037088950, 1522933332],
+        [313223214, 1360015413], [3171598479, 2288813484], [1572151214, 3203866613], [2996108020, 537672030],
+        [187799587, 259910764], [4008362025, 2809932623], [1204764269, 3807357752], [2599640494, 1341546506],
+        [1737107517, 1732963034], [2695242529, 3708579908], [797979934, 2585823882], [3365027254, 1071576313],
+        [1391651973, 595066956], [3503691745, 4075073472], [408974896, 3249290585], [4085368482, 2003705549],
+        [936101833, 1509716935], [2283153364, 2327439385], [2007982596, 3019197309], [2975501830, 508808228],
+        [794555542, 1057633658], [3356698429, 2593743574], [1732902081, 3721556706], [2902520121, 1749736556],
+        [1270333221, 1330927743], [2589463798, 3955770021], [82984523, 2818389370], [4001026910, 28529869],
+        [2124947105, 343109482], [4079984077, 3240903242], [924051173, 4235756420], [3737154766, 1497251789],
+        [413263403, 2006334020], [3207439058, 3166815035], [1603293532, 2317985004], [2296965490, 680236084],
+        [363301012, 745357875], [4158497639, 2219151801], [1486550785, 3382719076], [2236463426, 1889280772],
+        [1880415023, 1576264858], [2381659110, 3375818718], [841690288, 3025630179], [4148946325, 636404448],
+        [1878424961, 983352713], [3915105799, 3868162317], [47853699, 3860508737], [3238819472, 1649922793],
+        [615671278, 1654597946], [2682936506, 2922648519], [1866278227, 2636013562], [2524699152, 82615847],
+        [949920514, 268668277], [3629796939, 3034098900], [1892048246, 4219647153], [3074131236, 1564840104],
+        [1479612195, 2013311395], [3138615116, 4122089153], [531783121, 2228853595], [3562484231, 280963034],
+        [1328766424, 154440533], [3249510417, 3677725180], [693083701, 3568545336], [3924615352, 1174715797],
+        [214288346, 1235041392], [2797564319, 2509108224], [1086242726, 2689757052], [2849041382, 977288266],
+        [9926283, 529047865], [4165395615, 2913395210], [1124196955, 4257958233], [2649690147, 1397898358],
+        [1991890660, 1817280191], [2870317004, 3221329349], [704690391, 2151315898], [3269751186, 699769167],
+        [1433432956, 1042166126], [3388716351, 4173179805], [499644853, 3543063301], [4259529506, 1719497431],
+        [772381404, 1605987075], [2417954022, 2256792405], [2034925701, 2873558356], [3108732967, 244603915],
+        [917208537, 944116894], [3508467055, 2531885361], [1984013797, 3596306620], [3192105728, 1613539002],
+        [1295592155, 1406718896], [2510638386, 4251707056], [474794, 3001232055], [3936866320, 522498441],
+        [2044596577, 240062641], [3825569050, 3413981047], [824459705, 3812878851], [3349600916, 1451548830],
+        [87650324, 1933734071], [3119418925, 2883734255], [1515834796, 2473122405], [2260370788, 1049444480],
+        [436380258, 999198694], [4054630581, 2628231733], [1553900238, 3671899134], [2346496823, 1667224142],
+        [1671248866, 1343396740], [2334277650, 3660545074], [671793695, 2955668500], [4204567758, 834847218],
+        [1804481464, 608248917], [4133613801, 3790613610], [350224495, 3903185175], [3306253784, 2036431579],
+        [961754207, 1710233363], [2467383230, 3044486772], [1897649080, 2578857222], [2477122010, 447861571],
+        [1027396653, 134504959], [3470998628, 2963691447], [1856017876, 4269864509], [2805940353, 1353067910],
+        [1324440557, 2122057191], [3168100870, 3995504249], [325896025, 2496385621], [3554626312, 145944638],
+        [1497149448, 208650755], [3585479179, 3737861066], [871117630, 3509482341], [3877608291, 1419533962],
+        [342998285, 1290737900], [2723729915, 2375903380], [1226237830, 3092724661], [2733963988, 767421425],
+        [286214314, 173380299], [3930802821, 2988542390], [1401280192, 3854571605], [2300422988, 1254693273],
+        [1617455069, 2106794182], [2969036044, 3604320722], [913176653, 2655348385], [3519959338, 863626922],
+        [1183517387, 670478005], [3617114511, 3926141379], [173281214, 3416753133], [4012194036, 1944199423],
+        [999052651, 1323867855], [2406093892, 2475984793], [1723734463, 3145962052], [2716246174, 335445815],
+        [670161940, 688697930], [3256057991, 2347784658], [1621005002, 3440119394], [2760405195, 2104712404],
+        [1397851164, 1166145546], [2310013329, 3972831782], [296512476, 2902884803], [4170849971, 176470135],
+        [1718557785, 319048390], [4098858529, 3551443918], [580727895, 4157258307], [3688280435, 1087577536],
+        [493662665, 1764112118], [2706420782, 3141576412], [1251600876, 2390469634], [2602438872, 776352428],
+        [218303074, 717523672], [3900640607, 2213498875], [1314904386, 3470373352], [2654347088, 2122845226],
+        [2091563738, 1210030840], [2546175037, 3265913397], [1034051348, 2947954191], [3902208370, 556684691],
+        [1908340503, 1035338634], [3968362703, 4204519254], [145874546, 4103115143], [3579248622, 1695075177],
+        [567991125, 2041190052], [2352331078, 2844781584], [1702819597, 2366409266], [2224101304, 42092438],
+        [687115738, 494938438], [3700533268, 2707675395], [2087535490, 4007947303], [3156152919, 1221074408],
+        [1356888078, 1863519817], [2811782384, 4031123026], [62405751, 2201929595], [3464136226, 492045891],
+        [1141020885, 438654538], [3315460299, 3364034010], [757033621, 3291819725], [4282986317, 1276550024],
+        [142263319, 1416309127], [3069781327, 2570104098], [1469144216, 2780204435], [3135332525, 916032311],
+    ],
+    [
+        [3248537212, 396185951], [2070293427, 3981726320], [67523832, 1988539467], [2213626701, 3210915538],
+        [2784688462, 1589900267], [895406874, 2233542595], [1099715705, 613494489], [4066267217, 3284798313],
+        [2587407188, 943191251], [1498612373, 2695973097], [1876340732, 1293881524], [2966652478, 4085841579],
+        [3837105745, 1777635364], [505619503, 3608877857], [556766699, 223567677], [3536655106, 2471734848],
+        [4219737444, 672228553], [1290612003, 3419512911], [1062798809, 1463430175], [2844171276, 2402247999],
+        [2329004550, 2018888277], [145678197, 3078078995], [1904207102, 509163710], [3465299928, 3788915175],
+        [3124938331, 73164593], [1696652674, 2647954747], [1371475745, 1659177503], [2502994054, 3754873563],
+        [3641128758, 1082716746], [765983148, 4246568431], [324239676, 814367920], [3921033287, 2818963425],
+        [3588289070, 886065481], [1456915957, 4097998325], [457637136, 1159443805], [3196169205, 2805951860],
+        [2461336321, 1222987364], [648111408, 2909549682], [1657309314, 1060662112], [3767012154, 4219164424],
+        [2257658438, 424633430], [1981021465, 3130645491], [1242719387, 1524033614], [2737869538, 3480003816],
+        [3397583769, 1939558641], [997371016, 3350029895], [837762292, 740973071], [4237977775, 2194921856],
+        [4020102674, 177305323], [1770519290, 3669668461], [737233760, 1849458673], [2648951511, 2604255961],
+        [3073026220, 1713190407], [368120789, 2531952362], [1600848524, 42361707], [3724469457, 3499683578],
+        [2915239807, 586608561], [1202012070, 2346438750], [2114879463, 2132018218], [2403414279, 3931048875],
+        [4147590921, 1348920839], [39477602, 3832667172], [252879006, 316789711], [3351452983, 2965424985],
+        [3729273283, 9274850], [1615040772, 4171010128], [394464967, 1693054472], [2437770257, 2931033508],
+        [3157594025, 1199159165], [687691273, 2554471742], [1439255196, 933651652], [3992128618, 3654669092],
+        [2355306763, 794778153], [1210369614, 2986808710], [1947057809, 1380172525], [2938936876, 3865856222],
+        [4261737359, 2100261465], [224385063, 3428772167], [962103488, 441441789], [3380732555, 2307578833],
+        [3792803873, 1036675833], [1575220284, 3531823123], [623232601, 1264110555], [3030146873, 2506996020],
+        [2667164179, 1836472433], [420461988, 2781255415], [1810165399, 164446095], [3616544353, 4140357657],
+        [2716929228, 271366824], [2083804903, 2175600021], [1147318457, 1882610772], [2238026650, 3309046556],
+        [3309355342, 1482351176], [839995516, 3910776698], [22757129, 568494046], [4122536373, 3088345329],
+        [3431108591, 650376971], [1115879042, 3793683973], [176014337, 1565178028], [2785958363, 3050921393],
+        [2180608423, 1422744932], [1035902690, 3166680820], [2025626353, 707682039], [4191822624, 4000722504],
+        [2549723322, 241865414], [1833821189, 2870306621], [1513481759, 1129624584], [3106066019, 3587492137],
+        [3517711863, 1610790336], [597039019, 3712310478], [794054760, 974419610], [3890805563, 2668878520],
+        [4047537053, 496554303], [1937394325, 3249525518], [907920485, 2069260449], [2285165141, 2258335093],
+        [2877276064, 1963587351], [101424932, 2354054417], [1338919095, 342662947], [3284463106, 3375841477],
+        [2992589239, 857111255], [1392840661, 2417743316], [1721414918, 1781461189], [2559704549, 4285078093],
+        [3939159024, 1336131632], [279607238, 4046757692], [497032355, 113425920], [3689715837, 2738710185],
+        [3551879716, 456287763], [1859558018, 3888295656], [536651994, 2088988151], [2372631248, 2705968888],
+        [2984046716, 1403856435], [551845382, 2284609881], [1490343084, 782401344], [3853627261, 3449348347],
+        [2417068401, 628413043], [1075591670, 2937750160], [1631756097, 1607269877], [3176145519, 3972659997],
+        [4091542193, 1701116408], [87688937, 3633491593], [876601734, 20474893], [3223771154, 2570749297],
+        [4106104486, 835408013], [1343296041, 3291309529], [870981356, 1098370253], [2695792854, 2625909335],
+        [2215815109, 1661930922], [305712155, 3119070452], [1692425418, 293059020], [3295482908, 4236321797],
+        [3044690209, 521504666], [1879500918, 2489454932], [1551431085, 2044360598], [2333727083, 3541138802],
+        [3591897351, 1450078436], [607688075, 3758783439], [405612653, 692615214], [3817773080, 3062721461],
+        [3321955869, 1001581634], [1262866804, 4275578885], [285830070, 1359044080], [3013233643, 3152472122],
+        [2582277209, 1537594400], [813972008, 2728415417], [1816375687, 595023011], [4135919668, 4039194260],
+        [2631948026, 355632102], [2037927668, 2794287737], [1138581788, 1237797669], [3057322685, 3225936802],
+        [3697365057, 2057910157], [928791114, 3517820778], [1009506858, 902472816], [4008527717, 2379232918],
+        [4259067857, 125154716], [1739209485, 3469041278], [585186112, 1928358864], [2281414688, 2435950399],
+        [3089930914, 2126469376], [235290302, 2655926394], [1190517991, 263550314], [3502693319, 3322353129],
+        [2810824291, 1048796847], [1590599340, 2267157587], [1927042811, 1728799043], [2156588653, 4120263092],
+        [4176697776, 1147453657], [449964883, 4024271937], [121381570, 194087786], [3453918143, 2901884039],
+        [3364135130, 210895742], [1971518064, 4076241595], [203352863, 1759024256], [2615199892, 3003890123],
+        [2930409262, 1286300834], [949906348, 2451988609], [1241312436, 971356341], [4281100220, 3599855465],
+        [2181639062, 913311032], [1420499322, 3200741401], [2050847849, 1182036932], [2767561088, 4188236991],
+        [3966794491, 2006103068], [384867171, 3268895193], [691788832, 383467120], [3757155994, 2227369213],
+        [3899606939, 538245067], [1172151780, 3739681029], [751940445, 1501105255], [3140462538, 2162655219],
+        [2494244730, 1900675461], [9932404, 2841192538], [2112363263, 88078327], [3629400059, 3894530537],
+        [2830949948, 134795162], [1785617461, 2393905152], [1307210382, 1821437354], [2683394826, 3400188121],
+        [3487807400, 1258021751], [1044205564, 4152097366], [165444103, 1013768337], [4211066635, 2758540883],
+        [3667025000, 769324603], [1528819138, 3943020852], [64648145, 1323923704], [2896882592, 2867028586],
+        [2387790610, 1110054140], [786077288, 2979274447], [1996768974, 847766730], [3945475593, 3855295040],
+        [2308135980, 63332429], [1661645443, 3026481814], [1469422621, 1437272701], [2852229559, 3675180539],
+        [3262623539, 1866423363], [711165157, 3355493989], [664699810, 669723273], [4035314275, 2536603642],
+        [3859324379, 321703751], [2134926947, 3566979180], [975420825, 1641354415], [2527930691, 2324164937],
+        [2730490239, 1808526582], [482155252, 2205248875], [1379452976, 418779530], [3409114860, 3695072267],
+        [3213064930, 727680737], [1321188532, 2591914413], [1757487819, 1955309102], [2482481785, 3812517009],
+        [3791489884, 1554583988], [195716461, 4200294373], [336247909, 472635290], [3559497766, 3175462106],
+        [3460351487, 515400873], [1909434908, 3781979104], [139804428, 2025391595], [2320658230, 3086482661],
+        [2836164170, 1469715197], [1073131060, 2408229150], [1282227749, 681628184], [4213139527, 3410438440],
+        [2512497308, 806157889], [1361534152, 2831751006], [1704552850, 1079995801], [3134528858, 4260981187],
+        [3912479276, 1652295434], [328791181, 3749459060], [763129883, 78910784], [3650756220, 2640844883],
+        [4074753405, 611212017], [1097162228, 3273984463], [905199847, 1579135722], [2770935599, 2245870332],
+        [2204643531, 1987040115], [76175478, 3219319985], [2079470846, 392853955], [3241620635, 3986677205],
+        [2957015814, 229876004], [1868057820, 2478649759], [1503273869, 1768082033], [2592722580, 3620426033],
+        [3524647010, 1300618442], [562064827, 4083967778], [512248458, 948702801], [3826711168, 2688819119],
+        [3713786658, 1071476195], [1606299033, 4220261185], [359116982, 1212324425], [3086317323, 2916248755],
+        [2634039259, 1168423311], [724831902, 2812981252], [1769778633, 873962978], [4010107605, 4103691580],
+        [2411942140, 303007750], [2126818707, 2957867823], [1191575351, 1355335799], [2902594999, 3339317454],
+        [3341792598, 2144403401], [829835072, 3486549626], [1001775400, 576907851], [4154698337, 2335306962],
+        [3758577916, 35524198], [1650707869, 3494253283], [642999895, 1725917487], [2453546229, 2520319061],
+        [3193822810, 1861754412], [468953516, 2614156625], [1443661095, 167958018], [3575405435, 3660230366],
+        [2748537471, 747893946], [1250819765, 2181211844], [1990560209, 1934947244], [2250513175, 3833994308],
+        [4231731680, 1518227315], [266806736, 3938964420], [46891364, 434369810], [3389774769, 3120808757],
+        [3607162851, 157645537], [1803251764, 4128902289], [433549180, 1838345208], [2656913151, 2770253675],
+        [3020132970, 1267621021], [631020368, 2509415517], [1560439456, 1024819378], [3803905782, 3523324483],
+        [2240792385, 559573127], [1157288775, 3095695529], [2093252104, 1488827090], [2706294758, 3921438719],
+        [4111435848, 1889342110], [30215072, 3317249336], [852277886, 280826199], [3313727642, 2164488910],
+        [3983059556, 926578615], [1432305031, 3645525162], [675842653, 1199855051], [3167223025, 2560696126],
+        [2443183781, 1684948213], [390817192, 2925494863], [1619957646, 8256642], [3736182835, 4164227032],
+        [2949016783, 450547444], [1959751362, 2300016721], [1223300122, 2107541338], [2358605670, 3432172967],
+        [3379738436, 1391094455], [967142323, 3870337036], [233882846, 803372711], [4278183139, 2999909774],
+        [3274174386, 716144172], [1329124158, 4007601345], [113978166, 1411213327], [2881740395, 3157140012],
+        [2290590117, 1568949363], [919708532, 3044619072], [1944990447, 643580814], [4055282327, 3804384863],
+        [2558274971, 106704826], [1714179605, 2751009575], [1408416645, 1327231650], [2999451033, 3724184342],
+        [3674683892, 1789417925], [803840395, 3580762753], [591546315, 867057435], [3933125717, 2427363744],
+        [4184816091, 345402646], [2015050654, 3385602890], [1023935434, 1978069123], [2168583147, 2357976004],
+        [2800017268, 2077333708], [179143593, 2253102432], [1110056024, 488646395], [3426209123, 3243786416],
+        [3115551640, 987022246], [1522565250, 2679616571], [1843184659, 1625406938], [2535080858, 4055786872],
+        [3877108279, 1139988508], [487576059, 4288118920], [272064077, 247242725], [3506442352, 2885498828],
+        [3634519120, 293939315], [1684096137, 3905695304], [317492718, 1904866774], [2227932542, 2848107899],
+        [3146521768, 1501977095], [745159391, 2155023753], [1353168198, 550533912], [3903231749, 3304683800],
+        [2668044129, 703975733], [1297459198, 2761976031], [1793171250, 1455250610], [3045553361, 3771401472],
+        [4197443281, 1815964194], [155521942, 3551596781], [1048956416, 143274745], [3477373850, 2493627838],
+        [4286904371, 958533581], [1478402586, 3441158400], [947700990, 1279304948], [2922809256, 2463343142],
+        [2380642888, 1751318952], [521589676, 3016548260], [1851821482, 469646509], [3356942391, 4067606031],
+        [3186088053, 371195857], [2058576796, 2582493084], [1409762158, 1998563873], [2190909837, 3626778479],
+        [3745561308, 1600804272], [700916862, 3967418771], [374548659, 631941215], [3968269208, 3191114860],
+        [3421080279, 841151471], [1177444333, 4030913201], [475099651, 1529303404], [3101172055, 2969735893],
+        [2517641705, 1370523719], [985485482, 2852971494], [1732806781, 758121214], [4249758604, 4266957501],
+        [2474244434, 486141814], [1915502046, 2888321180], [1313501954, 1156855587], [3210134138, 3365621513],
+        [3568794195, 1951876583], [1017317532, 3684538385], [938337863, 1044498588], [3778238201, 2277239354],
+        [4133001469, 257153262], [1828481496, 3336659383], [773570823, 2120829695], [2393755636, 2666808679],
+        [3004313304, 1918311795], [53764583, 2446648456], [1273087809, 129944747], [3664235292, 3456815352],
+        [2861711359, 897482752], [1466593328, 2372960380], [2043774439, 1872243619], [2304761268, 4210617293],
+        [4030717326, 1230225877], [347758474, 3824782493], [187033746, 54588365], [3270782877, 2792581229],
+        [3297375967, 100052590], [2100835753, 4234225664], [6284275, 1675328489], [2489140531, 3105965447],
+        [2692022901, 1104651527], [857176844, 2623600087], [1162030623, 823298117], [4098235809, 3728575542],
+        [2342712060, 1022900358], [1555550169, 3059803179], [1894659914, 1242550792], [2819798779, 4160711344],
+        [3815450493, 2034674365], [416962394, 3392293947], [620254000, 534321569], [3599310902, 2388932523],
+        [3847267504, 778257878], [1225275612, 3597069251], [539847603, 1394436543], [2977318572, 2293299573],
+        [2607085376, 2081150086], [212264272, 2713358642], [1963682879, 204522998], [3541101229, 3883878370],
+        [2751926083, 28245656], [1639616950, 2216612116], [1086590662, 1707925151], [2432260315, 3262974097],
+        [3232557867, 1186747855], [881685260, 4183543178], [95864914, 920630455], [4079556403, 2952614941],
+        [3495437363, 596445631], [1391957544, 3843165663], [246622664, 1119082389], [2723342821, 2722983522],
+        [2266466846, 1312597185], [575093255, 3138299003], [2142257219, 995827295], [3869264574, 3958396665],
+        [2154765118, 188846422], [1750533743, 3186693787], [1583713919, 1550425799], [2806246646, 3513573955],
+        [3443254577, 1743179217], [660376362, 3230032962], [714665107, 735482282], [4165307628, 2598284940],
+        [3955610220, 410970146], [2006491441, 3704180342], [808747139, 1798877081], [2568915208, 2208546318],
+        [2885722915, 1630737503], [299479252, 2318546292], [1536545540, 331616445], [3333891616, 3563795135],
+        [3069925092, 661867708], [1128758173, 2547691324], [1672343904, 2049598579], [2620933993, 4011472905],
+        [3996749884, 1426751859], [127552324, 4112387799], [441449017, 365856877], [3704751246, 3033344527],
+        [3350013160, 428491756], [1996037230, 3927678917], [34703455, 2141968507], [2410431289, 3134829342],
+        [2739009400, 1351728001], [1006158179, 2191643721], [1257810180, 581376726], [4232520949, 3351802146],
+        [2645506535, 876728031], [1447321208, 2808757038], [1776458675, 1173657759], [3191105188, 4093822969],
+        [4015191952, 1849735754], [355057749, 3505780418], [725662506, 180631449], [3709880773, 2525220457],
+        [4243613230, 571213295], [1246159464, 3344789774], [993382269, 1512255332], [2745306894, 2342465353],
+        [2401883934, 1931925361], [45598669, 2963918911], [2122412823, 421477437], [3343161310, 3828123276],
+        [3204071407, 175240065], [1764966099, 2608457270], [1452407283, 1856214052], [2465013104, 3491026412],
+        [3719004913, 1211855524], [733621061, 4107069096], [362158078, 1068599484], [3765493056, 2904682253],
+        [3534406964, 954718483], [1507383119, 4089874918], [332739861, 1088363865], [2956056245, 2699057632],
+        [2599581576, 1296297117], [759112120, 2823000845], [1699110551, 809729827], [3915298722, 4250724821],
+        [2206384705, 389976128], [1898808025, 3081333126], [1286141638, 1475163484], [2777487960, 3278393831],
+        [3242263477, 1996297886], [899717507, 3280505377], [1067372171, 677195283], [4227391500, 2413133587],
+        [3832717858, 220375977], [1707944545, 3750862557], [570318266, 1770395360], [2511371790, 2644968633],
+        [3131598870, 1655168021], [320460738, 2634595911], [1496783466, 233306641], [3648891716, 3743992699],
+        [2775716947, 617370163], [1275664475, 2405421500], [2066613729, 1980529917], [2201398119, 3979783401],
+        [4215879634, 1465098075], [137655977, 3786599972], [147270942, 517516916], [3456969553, 3073566040],
+        [3683472366, 248355344], [1726006712, 4059725002], [492912174, 1617901261], [2552656913, 2874144963],
+        [2997661166, 1124410156], [590452618, 2674083362], [1521347620, 862401362], [3880003241, 3708278035],
+        [2289252162, 637739429], [1113007003, 3047033017], [2018296139, 1421195272], [2793775418, 3799935308],
+        [4055968694, 2065469167], [171095490, 3239416184], [916798221, 499684167], [3276795006, 2349258778],
+        [3886198679, 872071182], [1515874385, 3717270067], [799902557, 1340976398], [2990309616, 2680775493],
+        [2537903606, 1785810099], [502101711, 2880586619], [1832277275, 104403461], [3682587766, 4049420742],
+        [2881111404, 335874495], [1940717862, 2263279452], [1333146603, 1968436760], [2298345670, 3253803853],
+        [3426842948, 1560615746], [911879336, 3803432110], [104955906, 718688993], [4045920888, 3040370642],
+        [3317899649, 562961293], [1143100710, 3923909320], [219509212, 1384473949], [2713069085, 3100816632],
+        [2234719451, 1492152432], [956996234, 2997291547], [2088507920, 553758664], [4116113478, 3916046536],
+        [2434089828, 2176468], [1625869668, 2933233105], [1429979758, 1194341508], [3024792661, 3538251838],
+        [3623278172, 1841584774], [671669667, 3649850360], [637206630, 1033649267], [3976474258, 2550258285],
+        [4123124991, 281784354], [2092376608, 3313031954], [846749820, 1892959346], [2352994195, 2304573716],
+        [2943916418, 2110626784], [227432389, 2313460323], [1214372789, 448690811], [3372418486, 3423896907],
+        [3036339912, 1031416349], [1568650399, 2500413614], [1797603704, 1832447735], [2660801638, 4133407590],
+        [3986431882, 1205585360], [429997488, 4136115992], [424366821, 153371366], [3725384315, 2920789858],
+        [3562715250, 364328602], [1754122658, 4013984200], [349042793, 2060755049], [2312433488, 2891959294],
+        [3058476429, 1544135174], [720413386, 2375679541], [1459628868, 721979364], [4001169364, 3360017737],
+        [2572395283, 589106600], [1267643681, 2863462384], [1823418471, 1539773826], [3009838740, 3948819668],
+        [4245189955, 1637958106], [248469177, 3697709621], [821239840, 253934581], [3328057432, 2443044576],
+        [4040068511, 1042591276], [1579137980, 3224554756], [925318932, 1226658037], [2817683615, 2545665766],
+        [2163400151, 1875385127], [436428343, 3171024958], [1745600934, 360641335], [3444038252, 4195322109],
+        [3096673487, 413697373], [2144318964, 2437667901], [1386861455, 2129413180], [2382664471, 3699705324],
+        [3659058355, 1363703398], [579108887, 3951443274], [294989739, 767350428], [3951043283, 2982888013],
+        [3473429916, 1009809966], [1301406103, 4153690298], [411470478, 1443345378], [3179739428, 3199995759],
+        [2676264873, 1457803556], [1055813001, 2751865225], [1641130138, 622166827], [4202308502, 4146448160],
+        [2485045377, 285682928], [1969591802, 2701912254], [1166329878, 1102063676], [3153791728, 3293763383],
+        [3626349963, 1911681295], [940450928, 3602981004], [860862612, 832391432], [3894591000, 2150193776],
+        [4085004772, 24515245], [1629766116, 3258141686], [608620089, 2030207620], [2345149372, 2484727462],
+        [3049620992, 2038632203], [161799684, 2499182260], [1292922423, 139696065], [3744348575, 3266344123],
+        [2698816882, 830287073], [1485870141, 2296256839], [2102660590, 1667182253], [2374890428, 4068876300],
+        [4282654469, 1279247179], [312186883, 3896512373], [15295427, 96220370], [3301513628, 2836061243],
+        [3266481945, 200501916], [2039299572, 4123118984], [197637759, 1740304475], [2468967789, 3023375101],
+        [2805884942, 1235664655], [935257205, 2538629271], [1321941319, 889468109], [4033629424, 3521837771],
+        [2277423835, 990786551], [1380770228, 2974773637], [2009411356, 1114272692], [2733788376, 4028421174],
+        [3872925465, 1923810033], [291844361, 3460606147], [780334079, 404879297], [3671055689, 2322029032],
+        [4002776409, 730680037], [1311214299, 3679547038], [705636375, 1430490308], [3065090173, 2269339166],
+        [2477002789, 2051684038], [122986551, 2787420469], [2031838519, 187541836], [3573484129, 4020085256],
+        [2898649424, 130369053], [1812914276, 2211178461], [1178802578, 1806059944], [2579369568, 3332943183],
+        [3412348927, 1121524385], [979029656, 4265295152], [242246877, 846376630], [4253709354, 2860211879],
+        [3753342183, 635625536], [1413600536, 3971793671], [91296171, 1190239170], [2832345694, 2766662855],
+        [2340082743, 1247285747], [694435214, 3192347228], [2062542629, 909085507], [3961364184, 3961820201],
+        [2224110414, 91529809], [1846482837, 3008845158], [1481651522, 1405861044], [2685337064, 3736642737],
+        [3360273003, 1755681684], [543917498, 3452407870], [741824734, 542232260], [4294760739, 2456996265],
+        [3821447115, 530680975], [2054689700, 3629204722], [887164879, 1697902719], [2421921613, 2219820449],
+        [2824453956, 1826525827], [407293166, 2385096450], [1557621321, 526237913], [3484654657, 3555239795],
+        [2969757104, 786805859], [1236804165, 2459947095], [1855606963, 2087757458], [2498003420, 3904736968],
+        [3856899744, 1397448361], [208078067, 4062343961], [527297108, 460840860], [3638693144, 3112515924],
+        [3395082936, 313944462], [2121030730, 3839527758], [263411864, 1944466795], [2262329433, 2953532138],
+        [2909826646, 1520155601], [832989808, 2340345516], [1205927079, 754154980], [4151887632, 3482714339],
+        [2453967987, 1062792026], [1594777063, 2914996122], [1648078990, 1219081512], [3076526638, 4225965497],
+        [3772127905, 1721311091], [455456694, 3663020402], [650341489, 40524986], [3583228446, 2609932279],
+        [4157056464, 744499157], [1198278297, 3475705301], [824364553, 1346352421], [2912738322, 2186369022],
+        [2254066921, 2136711234], [256466450, 3128245722], [1985971522, 307139514], [3404334617, 3937727406],
+        [3079512449, 46626774], [1654247867, 2521339907], [1607399097, 1717423240], [2638645612, 3671965037],
+        [3581732936, 1163098364], [640444321, 4214061721], [461426561, 881757821], [4024527904, 2814835803],
+        [3656911966, 818335195], [1375619261, 4255657399], [510748015, 1305524666], [3121407681, 2829064072],
+        [2508123326, 1076846553], [558389913, 2685025809], [1863865185, 945952161], [3840818579, 4078067550],
+        [2318967091, 507173896], [2072571852, 3205050947], [1093112673, 1586096017], [2841257443, 3415274901],
+        [3469392616, 2028309499], [1058940717, 3407542274], [889353491, 607043895], [4071556302, 2237573887],
+        [3922160678, 80107576], [1871917348, 3617715466], [770649291, 1646100845], [2591183049, 2481232928],
+        [2962691637, 1763805509], [517767198, 2469979079], [1366321410, 70342010], [3528719859, 3615026112],
+        [2848478375, 687856634], [1106026314, 2242797387], [1906057350, 2015111833], [2324280647, 3779032679],
+        [4062033808, 1582497194], [73702571, 3992190693], [83645293, 399197133], [3251570367, 3213411066],
+        [3514652602, 109077133], [1837600279, 4278740154], [282864276, 1792251948], [2544153089, 2743556577],
+        [3109602633, 1330198499], [791367013, 2432112125], [1398399785, 981531716], [3937816772, 3576056881],
+        [2165679916, 709687924], [1336620458, 3159843254], [1933117157, 1574073858], [2872854472, 4002269578],
+        [4186734585, 1971839136], [109056085, 3378507682], [1037274221, 351856005], [3436254344, 2251493209],
+        [3926593376, 978340085], [1401267555, 3582831079], [601578152, 1134512370], [3119284469, 2422306808],
+        [2564874411, 1620681838], [272663548, 2740835987], [1715535711, 236810187], [3523183708, 4293177160],
+        [2792464964, 494326986], [2027091750, 2365567242], [1119915422, 2073753747], [2173771283, 3382607670],
+        [3282026387, 1417519586], [1029955103, 3996696070], [180369828, 648568805], [4178660293, 3170277640],
+        [3388032035, 798908294], [1219747340, 3859813506], [25338111, 1480506886], [2946909813, 2994437238],
+        [2362111776, 1375867227], [851143067, 3091593959], [1957808578, 792484075], [4269021014, 3875025428],
+        [2652774128, 160667948], [1803967338, 2775095014], [1570280781, 1274218289], [3162108834, 3644469929],
+        [3737396214, 1687842885], [628796029, 3529732380], [682511534, 928238139], [3799392490, 2516034826],
+        [4273593366, 439063260], [1953936212, 3438300705], [971426339, 2104587014], [2246370651, 2180131268],
+        [2703744430, 1884762081], [17060152, 2172175536], [1152538480, 274335953], [3307425138, 3317977933],
+        [3164442410, 939437809], [1436560387, 2564796954], [1614274951, 1678189191], [2448611356, 4174128463],
+        [3807068741, 1260378323], [398931188, 4165060779], [386039654, 14120825], [3615453915, 2780392862],
+        [3703328485, 475411528], [1674109452, 3815986083], [448375547, 1948537216], [2148294735, 2801657655],
+        [3208159496, 1438995988], [669846063, 2280931165], [1587141324, 655794400], [3787097194, 3235043692],
+        [2531740389, 762308648], [1185223102, 2720456424], [1742364372, 1374028280], [3091240082, 3848328854],
+        [4140334023, 1800879593], [58891261, 3556784711], [986108908, 119512018], [3414296007, 2651673062],
+        [4163543916, 900647889], [1474755213, 3370575633], [1023280702, 1142662775], [2868392894, 2586376540],
+        [2300694253, 1735053331], [342497689, 3030670565], [1668867710, 482304292], [3256862425, 4115275227],
+        [3018859971, 330177358], [2003609752, 2661554371], [1533499344, 1913010064], [2271263421, 3573081142],
+        [3501344084, 1534883327], [779707066, 3853939897], [483717709, 601114323], [3866666155, 3142519700],
+        [3229255472, 915529206], [1083435690, 4192344041], [381242437, 1596352911], [3038420011, 3056307848],
+        [2424553954, 1602313902], [879211130, 2944716734], [1780290943, 699076887], [4085987457, 4181314806],
+        [2612618220, 462356650], [2106072189, 2847575509], [1232271488, 1287971314], [2979455346, 3447292318],
+        [3544659200, 2094008129], [867757114, 3729179609], [953326643, 961060931], [3844479873, 2286882700],
+        [4203230836, 150669937], [1788129244, 3402969747], [698375171, 2009584375], [2186322514, 2577445075],
+        [3172169591, 2002619146], [97625455, 2573002543], [1079450152, 31268120], [3597226215, 3395457068],
+        [2924162163, 967144984], [1357581393, 2158150836], [1977942712, 1746589519], [2220586743, 4228796251],
+        [4097830310, 1094539282], [530877076, 3879128033], [215574375, 216095915], [3368530233, 2715777458],
+        [3448968476, 50495319], [1921821587, 4204482767], [132130399, 1867801821], [2629223583, 3180031231],
+        [2858726783, 1150807610], [1011092199, 2592287429], [1127378828, 1054981036], [4172667897, 3687565401],
+        [2397200395, 852421619], [1542177399, 3149134862], [2130795652, 1314886075], [2891936068, 4271986676],
+        [3947860392, 2117414118], [473740987, 3326860575], [572521486, 326681355], [3493299172, 2198389997],
+        [3779278616, 664256898], [1133075498, 3508644835], [656683576, 1558519978], [3219662011, 2366192126],
+        [2624618982, 1959806182], [188751270, 2894310496], [1916840157, 60219047], [3693812190, 3818036537],
+        [2719120780, 266616575], [1729500396, 2331299209], [1261053308, 1633114962], [2522341220, 3466314591],
+        [3334835347, 1320351135], [812662800, 4035937028], [56651402, 1005905372], [4129319452, 2731289733],
+        [3603368485, 688658890], [1545288278, 3769546140], [151629549, 1253012316], [2757399381, 2940774093],
+        [2194776956, 1178188719], [616179476, 3067803135], [1887137624, 1016595021], [3810626495, 3762661860],
+        [2366908945, 209095227], [1689960970, 3111141970], [1347896221, 1505943378], [2932695033, 3590856690],
+        [3289068444, 1672321381], [749766186, 3298768899], [545598225, 772791056], [4108400041, 2619283140],
+        [3972297798, 380349800], [1887623188, 3546490658], [1047335120, 1818082108], [2673156679, 2395515120],
+        [2763028604, 1705686487], [370806116, 2224280090], [1425236205, 373703852], [3233865628, 3636780184],
+        [3141623723, 546833970], [1157852842, 2633119987], [1679985182, 1898676642], [2602276180, 3887952284],
+        [3904958661, 1495043039], [3390636, 4241209226], [309771707, 300939739], [3555939684, 3013766282],
+    ],
+    [
+        [2369206831, 4262496285], [880902471, 1260010], [4007723938, 1147687725], [1864163632, 2464892401],
+        [3229821512, 3198797294], [1455485202, 2126226714], [2945029407, 920037347], [513826369, 3240266944],
+        [3096810107, 2334896761], [186145160, 1550522068], [2437325236, 1809678876], [686006982, 2783282074],
+        [4198878450, 3595419715], [1890441291, 628014110], [3619249184, 477403182], [1114470761, 3808908030],
+        [2740871145, 3365243853], [273469957, 1041260573], [3398891195, 1955822036], [1478291524, 3059824614],
+        [3880976674, 2651822306], [1744550543, 1265245065], [2257670234, 142236675], [992947457, 4060699091],
+        [2677761586, 2916977186], [636698064, 1700992695], [3000291305, 1421591045], [14153183, 2249199138],
+        [3696730495, 3919943241], [1311595061, 396840163], [4139140986, 708480049], [2070376267, 3644395287],
+        [2512766761, 3695003518], [88061045, 452545705], [3663242771, 1542324448], [1271106950, 2404928822],
+        [3527801162, 2176564324], [2147268580, 1865023365], [2587632905, 575686618], [589396282, 3543183532],
+        [2846151771, 2995435540], [1006755943, 1077199103], [2772231895, 2038166043], [849951627, 2552378657],
+        [3437359230, 3347904672], [1763799417, 222164026], [3773661622, 83171323], [1355168914, 3439879886],
+        [3045113116, 3840347809], [745793234, 798564538], [4263226313, 1666615267], [1956303424, 2824634758],
+        [4038984182, 2695127440], [1193128850, 1401579384], [3158698622, 280952545], [243897357, 3988909260],
+        [2192683478, 2520034112], [462029920, 1903900855], [2297490524, 1278896648], [364475359, 3127955966],
+        [3947611463, 4144560014], [1596753946, 826307763], [3342806104, 964649912], [1657741517, 4186388480],
+        [2556289091, 3994185681], [564821711, 308893234], [4091588380, 1370618881], [2093941235, 2212003911],
+        [3643749251, 2873802622], [1229543478, 1611656899], [3086160257, 761308983], [131421960, 3735801488],
+        [2801152582, 2588601812], [391922102, 1322012901], [2153585342, 1942499996], [1048857182, 2962400371],
+        [3807457926, 3481450898], [1621349465, 984758159], [3481727335, 237505064], [1566265972, 4126981834],
+        [3194303208, 3504442193], [223114559, 538924507], [3502521330, 1843354553], [1158440747, 2724283479],
+        [4256474677, 2350473469], [1986656019, 1477550973], [2541327404, 433591364], [796083546, 3873581413],
+        [2332899589, 3108199622], [818703529, 2067981940], [2868654618, 1121845175], [410267014, 2510159733],
+        [3308242568, 4212256323], [1377802621, 121073427], [3919044004, 850179778], [1799243081, 3303881176],
+        [2238522745, 3267589328], [490100051, 173998529], [3274020048, 1208743014], [1413758630, 2627252213],
+        [3373100467, 2420180551], [1837582460, 1989910877], [2403106035, 1023739628], [926942972, 3416691311],
+        [2973897808, 2790891909], [708073684, 1461658923], [3126365161, 1717419173], [653626683, 2307053966],
+        [3585916791, 3686025309], [2019093046, 512140178], [4177900075, 343486408], [1277090582, 3575589944],
+        [2907815635, 4033645469], [947162241, 897656922], [3829065529, 2110684780], [1705055492, 3182847749],
+        [3980897113, 3025905955], [1519345134, 1203733833], [2694592797, 65384526], [334507971, 4237489318],
+        [2482444585, 2220751620], [52349973, 1749530157], [2618183686, 1601489921], [161357636, 2919484163],
+        [4101938713, 3759633573], [1082023961, 699500061], [3747775896, 662573915], [1919056848, 3936457205],
+        [2166820180, 3802164803], [688751207, 268266993], [4219074269, 1333790181], [1901702876, 2612380340],
+        [3467192801, 2767355964], [1549791195, 1783274012], [2814898695, 617438484], [370968382, 3607238246],
+        [3063162254, 2467803949], [115905221, 1166013571], [2358439439, 1637572891], [548374109, 3214569750],
+        [4062291543, 3232341126], [1851654236, 740170009], [3240949226, 322451814], [1224647923, 4293954283],
+        [3018965581, 3309145951], [21189898, 867719219], [3292044247, 1685292061], [1404800820, 2900954806],
+        [4147667072, 2484924967], [1787792480, 1439231038], [2324100471, 384001824], [609246729, 4195378429],
+        [2279003781, 2736078129], [775725590, 1967700234], [2730212667, 1493699119], [213263375, 2667912081],
+        [3512468232, 4093293093], [1141628434, 167183919], [3870114006, 562410319], [2009856748, 3380270765],
+        [2637022860, 3428024300], [349872738, 365753707], [3446348700, 1452352464], [1589052508, 2243020030],
+        [3330996419, 2285581670], [1675974507, 1729326069], [2458838300, 821657049], [725461129, 3330839835],
+        [2706037242, 2846945623], [911657828, 1516216143], [2896924938, 1851985489], [763375071, 2446234569],
+        [3689681323, 3391449163], [1685685910, 289999843], [3966102139, 464466214], [1174458788, 3279467928],
+        [3137670532, 4161947060], [871699949, 648261414], [4119030024, 1885147490], [2041148401, 3004937416],
+        [4163165184, 3154065271], [1299274426, 1298824037], [2822326381, 522121989], [450666958, 3784044231],
+        [2397803267, 2389290720], [309989271, 1657991202], [2484334855, 1184983604], [74590815, 3042430015],
+        [3856852209, 3971422170], [1434202720, 590591111], [3357165944, 774526967], [2121301367, 4048320141],
+        [2425323072, 4108846399], [1072086191, 491033527], [3811668050, 1567797792], [1643171309, 2328276082],
+        [3603837297, 2972959780], [1137244448, 1919743232], [3119595631, 995615451], [173053270, 3463563705],
+        [2929629755, 2195775839], [536100936, 1357621282], [2582833797, 2133787061], [898175048, 2852777260],
+        [4018351849, 3746733441], [2110972069, 926897267], [3634907322, 30113643], [1473778084, 4018565236],
+        [2884369297, 3640325828], [427632665, 723381642], [3720788247, 2060952568], [1339462642, 3088099582],
+        [3905699397, 2277192117], [2051590022, 1138014108], [2661852204, 107340598], [829262916, 3900077214],
+        [2518809161, 3070752171], [986348998, 1820055077], [3220583438, 1251302718], [286694207, 2366086728],
+        [3418258947, 3887002577], [1495705446, 416662733], [4231976379, 1064599789], [1720629449, 3520412743],
+        [2304544421, 3561965887], [145170314, 93775031], [3567907098, 1096137014], [1102570174, 2546066281],
+        [3734120100, 2578108748], [1942209611, 2021791654], [2210434385, 673725622], [1039275625, 3662540444],
+        [3171328939, 3160210680], [573450000, 1226985946], [3025289552, 2004887796], [962451654, 2153299273],
+        [3258105815, 3532635416], [1977519435, 35862952], [4052946536, 185364007], [1533894504, 3720013888],
+        [2763348344, 3947823357], [664713199, 955672187], [3927576623, 1770840936], [1761514005, 2815402454],
+        [3780618144, 2948795224], [1363545328, 1589312618], [2961546008, 212973333], [36889896, 4136827849],
+        [2607072271, 2641376148], [256377100, 2094971779], [2220642082, 1390105502], [472788008, 2714634433],
+        [4288086322, 4260718953], [1247377568, 1021589499], [3543516221, 884421892], [1814098680, 3852296897],
+        [2252649776, 4071253792], [1002130406, 144983681], [3889031155, 1267708000], [1731512197, 2660237820],
+        [3395749667, 3069810882], [1493135753, 1947639521], [2748828573, 1056609036], [278290688, 3356999571],
+        [2989664685, 2263333128], [2163398, 1409446722], [2671918884, 1703356029], [621657043, 2904036412],
+        [4132878586, 3653584912], [2079754900, 721405606], [3706378182, 387469448], [1320045003, 3914274309],
+        [2940423583, 3253804594], [508896951, 908046158], [3226760355, 2122045557], [1448619126, 3195482902],
+        [3997924539, 2450054957], [1878562360, 1150668623], [2381426889, 13137248], [873889015, 4274024867],
+        [2448922289, 2775236300], [676993192, 1803357325], [3090530474, 1558122110], [197735141, 2342489484],
+        [3613653280, 3822572665], [1123329713, 483216383], [4209073736, 630880776], [1887430390, 3605742757],
+        [2599214363, 3556059556], [240423842, 272676313], [3533045446, 1400859053], [1206169371, 2172032460],
+        [3670365237, 2415904862], [1951343163, 1671475278], [2501300325, 795969115], [751116674, 3706835629],
+        [2779634539, 3129131189], [843060446, 1285733176], [2836446183, 1911548100], [1021177397, 2525899122],
+        [3349128373, 3449608092], [1644203705, 70173465], [3956957115, 232790696], [1609707365, 3342786445],
+        [3169373875, 3980247156], [601331138, 580249896], [4029166971, 1872571631], [2131414982, 2684621072],
+        [4270388795, 2831442189], [1265402843, 1532056768], [3041846931, 442335334], [95550679, 3830231255],
+        [2282272809, 2564543628], [360513454, 2039864974], [2186212006, 1082280838], [458277077, 2988097885],
+        [3759636212, 4180353304], [1344159983, 969523488], [3429809729, 833091217], [1778290854, 4160673948],
+        [2542238134, 3861146504], [800522427, 424807153], [4251800586, 1487370738], [1994209655, 2361540909],
+        [3492881734, 2726718237], [1173475355, 1831445171], [3201409526, 552175500], [233484894, 3495532990],
+        [2853978396, 2503897676], [417511984, 1111120994], [2347901790, 2076478464], [813421426, 3112259466],
+        [3916182055, 3291438582], [1807660422, 845569303], [3314012537, 130574057], [1384929459, 4222009973],
+        [3070233255, 3731676761], [124465108, 769100233], [3652643262, 1626378227], [1233364618, 2878692409],
+        [4078974156, 2204448620], [2084437857, 1364842000], [2564107815, 316442500], [557800285, 4005948360],
+        [2158723510, 2960995419], [1041946500, 1936757396], [2790476365, 1316295819], [398177755, 2592353046],
+        [3475869562, 4118727664], [1574806637, 247215380], [3797595685, 976852372], [1614719183, 3475342575],
+        [2413588372, 3411067022], [320147859, 52041492], [3387037034, 1199348316], [1516520686, 2424488758],
+        [3288278126, 2620130454], [1694991740, 2101848572], [2243395119, 891009639], [951756100, 3256134799],
+        [3135109704, 2935201202], [637644650, 1605981722], [2985534862, 1753834821], [721053599, 2229927908],
+        [3754375515, 3588438772], [1923462147, 351737100], [4105049708, 505687428], [1089800414, 3677550047],
+        [2690812213, 4231593858], [936544106, 1037685612], [3990598154, 1987227216], [1835045788, 3028462306],
+        [3837450635, 3173109279], [1419438966, 1220060836], [2912490081, 178197258], [495645740, 4037986818],
+        [2625845273, 2301348469], [155389738, 1725211120], [2470047459, 1468915045], [63081201, 2801156676],
+        [4193281619, 3932194982], [1284933354, 665876390], [3577960465, 694365446], [2024173293, 3772445073],
+        [2320552181, 3904713035], [613664936, 117028507], [4159247408, 1129867100], [2059861450, 2494826397],
+        [3304080658, 2887283368], [1397581931, 1691750262], [2870696445, 736105779], [433164491, 3628840721],
+        [3208516920, 2682136489], [202733725, 1245958050], [2266193273, 1825765218], [786798180, 3084473344],
+        [4239315128, 3383578402], [1718375772, 554466699], [3412446219, 405737239], [1150295493, 4077590350],
+        [3111386955, 3472649606], [176645241, 1000785960], [3463391990, 1788651697], [1559478388, 2759160170],
+        [4224344240, 2602583194], [1628417254, 1568740468], [2175990987, 500890417], [696712244, 4098686899],
+        [2354177879, 2861867252], [538194688, 2144201856], [2920109633, 1346644616], [102592537, 2479365100],
+        [3625142885, 4283891512], [1211421196, 18613619], [4010846277, 747016087], [2102675526, 3227516310],
+        [2455550697, 3328042899], [440122370, 533241863], [3323559267, 1583130085], [1372432529, 2290543340],
+        [3453062104, 2236739145], [1749607061, 1766936394], [2650309582, 940038824], [661853851, 3431533514],
+        [2889154310, 2703578590], [965820004, 1378909302], [2714218394, 1645842756], [579007224, 2644268751],
+        [3551892403, 3287065636], [1828685818, 460583039], [3846836983, 296617373], [1252641957, 3401221266],
+        [2959806262, 4129940691], [1029470608, 684561521], [4169403589, 2015498487], [1934296889, 3137721376],
+        [4116719762, 3014865397], [1094307983, 1102381591], [2758855845, 356633546], [336219330, 3954175344],
+        [2227311182, 2156384311], [478226464, 1857709510], [2611665247, 1240608554], [266056337, 3162714774],
+        [3974573492, 3848561857], [1537662394, 783292158], [3270702434, 595724764], [1966120452, 4247069322],
+        [2652966169, 4205000904], [835305854, 371358274], [3898835407, 1427140580], [1779640813, 2271248087],
+        [3713372556, 3097110943], [1330407977, 2049674141], [3005055138, 858345169], [31474484, 3319431017],
+        [2723532281, 2374700415], [298079535, 1506624773], [2530292852, 1976998614], [974291909, 2747993242],
+        [3862089577, 3509639659], [1999114757, 1070301697], [3521760557, 155159818], [1509735546, 3882167834],
+        [2803301666, 3621344132], [383808577, 604413681], [3595126258, 1928442110], [1131628140, 2984459588],
+        [3820724096, 2322924618], [1908731320, 1328083264], [2421868275, 257554803], [1059149136, 3792702031],
+        [2574381512, 3206883352], [895748622, 1635192635], [3061421743, 1167958035], [520596836, 2187373285],
+        [3250967788, 4013843637], [1466723267, 329823543], [4076485450, 936650478], [1859993097, 3756210062],
+        [2201960727, 3667393047], [49333259, 204303657], [3732079952, 1302196778], [1307468439, 2570859295],
+        [3563207426, 2534818636], [2030753060, 1894119889], [2310084914, 641658123], [860545569, 3568295038],
+        [3035112377, 3048804314], [762691103, 1179254114], [3186270261, 2082597332], [921866031, 2395337020],
+        [3370835922, 3709448902], [2127599233, 198063913], [4286200008, 42170094], [1441593445, 3526912821],
+        [2828051120, 3775019368], [737727707, 810961225], [3787141453, 1739421218], [1664213209, 2944337048],
+        [3939043520, 2804522881], [1577723396, 1449204453], [3145869004, 91404026], [140100134, 4169879812],
+        [2496804149, 2436379305], [83154447, 1999880526], [2383956196, 1519655435], [311430798, 2842791274],
+        [4049437362, 4054466858], [1187108976, 873307073], [3680895283, 1009948694], [1692524901, 3959823382],
+        [2182099388, 4190352900], [852242982, 228726746], [3944426684, 1288723464], [1768275482, 2560312664],
+        [3422653133, 3123921415], [1353186445, 1906491482], [2782370092, 825683789], [469171751, 3454260486],
+        [3156696379, 2166900503], [251170077, 1394382590], [2595298299, 1869737662], [599087195, 2700380126],
+        [4032671847, 3545110201], [1962868814, 789363260], [3666844996, 448754258], [1260108795, 3834923746],
+        [2843165350, 3445056354], [455109820, 957217217], [3353185591, 1899892097], [1600556743, 3135247267],
+        [3766758157, 2532808321], [1652589877, 1087053040], [2196723216, 75043369], [842097935, 4148420986],
+        [2589826277, 2691030148], [747158245, 1661709523], [3165138006, 1407857780], [91250752, 2409649186],
+        [3658814846, 3827955711], [1267555174, 438270838], [4042127254, 583686917], [1947110834, 3697278233],
+        [2436392277, 3601132610], [11743823, 390073187], [3695066670, 1417571391], [1316577680, 2259657554],
+        [3701519021, 2254286529], [1880794269, 1805791494], [2442424338, 622043297], [633106367, 3652895614],
+        [2746661998, 3053675597], [994284056, 1271109134], [2938025305, 2126830004], [885653322, 2454964273],
+        [3405309631, 3361244485], [1866840921, 9274028], [3996497434, 4540185], [1452600175, 3247092223],
+        [3094222224, 3814914939], [625076623, 712430023], [4194584505, 1796728344], [1893771085, 2770076664],
+        [4204199374, 2779669526], [1110814887, 1553537047], [2994023580, 400062070], [5041984, 3924187974],
+        [2374556786, 2461180613], [284693970, 1959166186], [2371251477, 1157292289], [271891624, 3062977380],
+        [3884758727, 4065372580], [1483234602, 1048652253], [3392361723, 1048527215], [1874310733, 4268617381],
+        [2473256220, 3940692868], [716963751, 339213643], [4188113048, 1467065171], [2016757975, 2303507748],
+        [3574426137, 2926502660], [1085564293, 1713237071], [3131751041, 701201969], [57853613, 3584802689],
+        [2916271053, 2632119980], [327203872, 1193201627], [2234361988, 2097829292], [941332102, 3176132802],
+        [3832707660, 3259965298], [1707513874, 894644484], [3377140194, 58463881], [1426046161, 4027652227],
+        [2979054274, 3580383741], [61469430, 654769970], [3753887500, 1748910532], [1288157976, 2785994894],
+        [4182595957, 2225302410], [1915509440, 1473919593], [2476955473, 344570966], [643046229, 3764853379],
+        [2246599468, 3187638778], [926645687, 1980453574], [2684894482, 1204610870], [326966066, 2419483128],
+        [3383625972, 4242234721], [1413278303, 184285980], [3839847919, 902563393], [1699576018, 3265153521],
+        [2148899700, 3479257119], [402236904, 239968151], [3480327092, 1317223574], [1570040070, 2585417649],
+        [3317746932, 2512985497], [1617441609, 1939840100], [2338965455, 841036573], [814042948, 3295272529],
+        [3196406939, 2720409340], [789601609, 1492541250], [3190877741, 1834538100], [567566259, 2201430156],
+        [3499086350, 3490695838], [2091272842, 311891274], [4257603624, 423140559], [1168595352, 3499209845],
+        [2786012268, 4120233297], [1056776515, 980054642], [3800293954, 1930521816], [1625384244, 2956047915],
+        [3794353389, 2966388865], [1391217137, 1117166848], [2858177106, 124314769], [388688624, 4112505570],
+        [2536315568, 2360429374], [227570416, 1841223080], [2552029278, 1360482679], [118243009, 2871033014],
+        [4087159750, 4001746250], [1239186673, 756087528], [3494386900, 541528528], [1980550762, 3864386103],
+        [2388945086, 3855413182], [586699912, 190453006], [4045356856, 1231385908], [1973371443, 2437939057],
+        [3264684792, 2712599250], [1437892909, 1848612244], [2702542701, 784419908], [304996676, 3712263008],
+        [3153446878, 2542784147], [136216841, 1094065353], [2301373312, 1764724632], [733103538, 3148704642],
+        [4175867776, 3337257509], [1668832908, 677370730], [3335301261, 532575458], [1097402072, 4165645971],
+        [3183129884, 3275059526], [67626432, 1011469132], [3259980389, 1859703029], [1539320813, 2849396421],
+        [4278422626, 2444783911], [1819795580, 1525353890], [2225369318, 288515018], [755537789, 4255887619],
+        [2209076665, 2939493297], [658071674, 1881776614], [2835343877, 1590943170], [43199351, 2582072297],
+        [3569559908, 4176762905], [1302445320, 216152862], [3936624688, 654298728], [1939254604, 3325956289],
+        [2525769969, 3375389822], [524904588, 326821308], [3421168440, 1502569028], [1501090742, 2380595970],
+        [3409061677, 2372154644], [1725423891, 1824459763], [2566952315, 931006864], [776030047, 3387606059],
+        [2813954042, 2755414472], [1062715829, 1561540843], [2878039910, 1689664532], [693081212, 2605241862],
+        [3593030768, 3456683984], [1639913733, 497055914], [3823322703, 488521746], [1327830496, 3311910195],
+        [3056306025, 4289431075], [904511897, 753439632], [4242869286, 1964613710], [2108733998, 3210560205],
+        [4232278756, 3079313890], [1218641167, 1170233640], [2721476637, 413486810], [297176115, 3889617478],
+        [2328344854, 2267352520], [428425271, 1679701127], [2658714132, 1134824059], [182087656, 2974071123],
+        [3816254525, 3796528294], [1553336138, 614327138], [3299262166, 729770412], [1905999584, 4102624563],
+        [2487624112, 4249894495], [960124381, 300208864], [3850762950, 1380948436], [1681096723, 2160146089],
+        [3677099111, 3037646603], [1255594760, 2013121644], [3179092994, 880528897], [255499110, 3282088922],
+        [2823042687, 2298451707], [343707582, 1458367617], [2640026448, 1887858629], [1025677296, 2944622588],
+        [3933910531, 3571406759], [2045246039, 946383911], [3560010304, 98644480], [1584166471, 3943623984],
+        [2901379427, 3527608632], [475159391, 602887414], [3554842383, 2090422389], [1183067240, 3156027829],
+        [3845004188, 2150943021], [2116212242, 1233413946], [2602308319, 195002953], [972285769, 3973221528],
+        [2646471914, 3010906175], [856167169, 1778131881], [3142730055, 1105102495], [441838592, 2288861897],
+        [3443069423, 3956969186], [1369242494, 528236246], [4168949757, 807122078], [1672837053, 3671759634],
+        [2362794404, 3752392848], [214197691, 155951153], [3629580699, 1160821054], [1148375337, 2676287364],
+        [3638251102, 2475346832], [2100122751, 2138322488], [2351085679, 746545194], [892687252, 3745204573],
+        [3116344825, 2979118631], [704298633, 1342000255], [3014371671, 2051319278], [830536189, 2278091205],
+        [3296173548, 3627627092], [1896192652, 254979854], [4150217618, 111463847], [1546667592, 3618218111],
+        [2734383107, 3877641419], [549585042, 934440978], [4022479754, 1628780117], [1712090688, 2739664096],
+        [3871521338, 2744421264], [1471453030, 1343264614], [3068112810, 22496287], [112043694, 4280914608],
+        [2419692605, 2613159395], [27371065, 2055282443], [2316523052, 1438542540], [379445037, 2760459124],
+        [4213949797, 4094467424], [1127140985, 992902290], [3601165884, 1003060780], [1633834369, 3806120925],
+        [2292009205, 4153761513], [1016516275, 76721558], [3765408440, 1080889706], [1649121452, 2522511472],
+        [3344562140, 2994614246], [1603697610, 2033048358], [2850386231, 968193426], [354904733, 3353354714],
+        [3038351641, 2401440487], [99095995, 1535395008], [2510787209, 1677605318], [738702422, 2830358990],
+        [4269000283, 3702786396], [2136077049, 574201707], [3524849870, 269236371], [1198803997, 3983936103],
+        [2774151167, 3344297629], [368996586, 837320980], [3433591464, 2042930931], [1350306593, 3001742453],
+        [3954972379, 2555596536], [1772189155, 1282795960], [2289652735, 225818595], [1013339184, 4184189629],
+        [2506054112, 2818949182], [592498611, 1876409327], [3051065029, 1530040316], [236711388, 2177743762],
+        [3538999381, 3986754225], [1202602301, 284104408], [4277117950, 804636428], [2142483848, 3551716856],
+        [2671595370, 3646638345], [195010405, 472508348], [3608207062, 1544287676], [1118329275, 2336669270],
+        [3620656356, 2345504101], [2065883247, 1709079410], [2682405712, 715339997], [675244029, 3592630164],
+        [2952590035, 3190639984], [877668520, 1144210802], [2737480396, 1951172819], [1006563953, 2655350882],
+        [3222463962, 3244961915], [1733524020, 135322308], [3877326445, 149388399], [1486031712, 3368562142],
+        [2998764148, 3911707050], [680792694, 637156571], [4130783400, 1694541927], [2076095031, 2914523119],
+        [4141550730, 2908770789], [1324703360, 1423828784], [3102554380, 480727277], [191349124, 3820110824],
+        [2261349787, 2664247759], [519850011, 2115236388], [2248646580, 1261813683], [507022614, 3200736362],
+        [4001920770, 4271320110], [1444067513, 916355785], [3235233600, 910463969], [1739427841, 4073187798],
+        [2625563826, 3768596205], [650035169, 511127980], [4093684648, 1609721691], [1928831700, 2216662235],
+        [3742161243, 2796184695], [1280771828, 1761158899], [2973165221, 670919017], [153587572, 3687507305],
+        [2697566692, 2430951068], [502462145, 1215925072], [2407841674, 1992571126], [934879267, 3022282283],
+        [3986779311, 3421029937], [1828981461, 1029167964], [3280668348, 171710685], [1522849270, 4235031834],
+        [3123307909, 3680183212], [164712789, 690375353], [3589823012, 1722184324], [1076500749, 2929459848],
+        [4107367708, 2312170300], [2027197561, 1597059228], [2634014938, 516686628], [710520619, 3925933614],
+        [2404254140, 3034692378], [954137154, 2105751222], [2906362612, 1222991927], [491594109, 2621443180],
+        [3276310542, 4041405155], [1513886966, 60731825], [3979752026, 1032033766], [1842784440, 3405856974],
+        [2344585718, 3297193388], [405378342, 129830422], [3310462954, 1114458054], [1380147686, 2506583697],
+        [3486208178, 2597061162], [1799624588, 2075145933], [2163296993, 985958148], [1044974181, 3488200416],
+        [3079764672, 2885203268], [561454773, 1372367848], [3077292561, 1616158248], [801982523, 2356927169],
+        [3655499054, 3737908100], [1988132707, 431624463], [4082527420, 302645439], [1225457643, 3725376967],
+        [2861726555, 4225839400], [807390857, 855326388], [3909629617, 2065185176], [1810401684, 3107486399],
+        [3923265237, 3117157165], [1563354599, 1310174114], [2795886905, 248050912], [414380207, 4216272755],
+        [2560788528, 2206992903], [126316947, 1621957691], [2547103336, 1483922914], [221606498, 2734181254],
+        [4247159554, 3869261717], [1163306197, 546598196], [3647123053, 767448297], [2086740607, 3999918430],
+        [2216260842, 3964453353], [771341413, 49379342], [4292281202, 1177756409], [2124704582, 2634284309],
+        [3361007091, 2836944585], [1527535483, 1650345541], [2891049974, 594695201], [484534293, 3536169742],
+        [2966855684, 2572887006], [40482605, 1308378058], [2204005191, 1734023478], [671027243, 3019706137],
+        [4125267719, 3435336921], [1755602350, 644551094], [3450648851, 361840018], [1293723342, 4135317111],
+        [3021064655, 3405469822], [263711545, 885902185], [3367985821, 1653264122], [1428163720, 2705793221],
+        [4056645298, 2647570459], [1689797263, 1387859000], [2392625855, 455352756], [576526155, 4057114591],
+        [2313928007, 2814214067], [729015307, 2020442212], [2765222432, 1444689005], [148259399, 2540494680],
+        [3725819870, 4141566722], [1104689880, 84779898], [3775631868, 683498050], [2035655251, 3424563695],
+        [2578714279, 3222823409], [293092476, 410954233], [3247907799, 1352185332], [1461055814, 2191114946],
+        [3245577447, 2181902076], [1855847233, 1640840380], [2523648051, 1066140284], [542201482, 3234409525],
+        [2875254296, 2895162583], [825410251, 1430438983], [2807213405, 1779004993], [619584811, 2490944977],
+        [3718715061, 3315222517], [1786386323, 380071050], [3903435431, 375989479], [1136163741, 3465123736],
+        [3216908578, 4082957596], [979470544, 568354255], [4067038808, 2140179698], [2002767978, 3076392399],
+        [4070998955, 3217792168], [1155860790, 1255852692], [2935373446, 335315817], [531122309, 4017774265],
+        [2177307864, 2326024458], [376082441, 1793134870], [2429077891, 1330707304], [19753160, 3102456017],
+        [3896028891, 3893485387], [1409037072, 730354954], [3469951589, 610325708], [2049690743, 4207363657],
+        [2613658393, 4046009677], [915830312, 468561459], [3970630640, 1513077514], [1823535306, 2383912458],
+        [3546925295, 3167716677], [1181833678, 2085813831], [3031525576, 1016831553], [76253512, 3393583396],
+        [2752488870, 2245043750], [447623924, 1579559581], [2451288439, 2026465620], [866637986, 2810170840],
+        [3788645198, 3661263380], [1932065558, 815076848], [3739758349, 205572617], [1360820872, 3782458482],
+        [2712558108, 3723119698], [316747898, 778491711], [3685732450, 2003935616], [1244205930, 3051625525],
+        [3963019942, 2394536614], [1969491118, 1188948237], [2492107065, 38824990], [909816603, 3844661880],
+        [2465230155, 3144492145], [1034262593, 1742674505], [2956892316, 1291962760], [346957314, 2234348255],
+        [3330120563, 3788806236], [1590842319, 355769228], [4114470738, 947956851], [1748818523, 3558166905],
+        [2272193973, 3516130561], [105504016, 29278540], [3508484996, 1243896385], [1216272127, 2473174616],
+        [3518870408, 2672856270], [2005657753, 1975433655], [2273797041, 560386013], [983621213, 3514701253],
+        [3007336142, 3092031726], [606843313, 1127356021], [3105937766, 1914034804], [1065641034, 2319370513],
+        [3458002764, 3611693979], [2056408701, 103726308], [4223629887, 264118604], [1393530459, 3635298098],
+        [2927426011, 4024247989], [781411113, 1058936251], [3865209281, 1814669395], [1847059343, 2867091095],
+        [4015997656, 2856978436], [1503338066, 1498127946], [3212539158, 159727639], [208363309, 4085782338],
+        [2667271419, 2496091183], [169826559, 1922583643], [2170487762, 1574568117], [423221616, 2890055240],
+        [4154926971, 4199584624], [1334704479, 859906719], [3709738877, 868489081], [1793934869, 3908136745],
+    ],
+];
@@ -0,0 +1,197 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use crate::engine::math::transformations::{Matrix4x4, Transform};
+use crate::engine::math::Vector::Vector3f;
+
+/// A rotation represented as the unit quaternion `(v, w)`, with `v` the imaginary part
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Quaternion{
+    pub v : Vector3f,
+    pub w : f32,
+}
+
+impl Default for Quaternion{
+    fn default() -> Self {
+        Self{
+            v: Vector3f::default(),
+            w: 1.0,
+        }
+    }
+}
+
+impl Quaternion{
+    pub fn new(v : Vector3f, w : f32) -> Quaternion{
+        Self{v, w}
+    }
+
+    /// Extract the rotation of a transformation whose upper 3x3 part is orthonormal
+    pub fn from_transform(t : &Transform) -> Quaternion{
+        let m = t.get_matrix();
+        let trace = m[(0, 0)] + m[(1, 1)] + m[(2, 2)];
+        if trace > 0.0 {
+            let s = (trace + 1.0).sqrt();
+            let w = s / 2.0;
+            let s = 0.5 / s;
+            Self{
+                v: Vector3f::new(
+                    (m[(2, 1)] - m[(1, 2)]) * s,
+                    (m[(0, 2)] - m[(2, 0)]) * s,
+                    (m[(1, 0)] - m[(0, 1)]) * s,
+                ),
+                w,
+            }
+        } else {
+            // Compute from the largest diagonal component to stay numerically stable
+            let i = if m[(1, 1)] > m[(0, 0)] { 1 } else { 0 };
+            let i = if m[(2, 2)] > m[(i, i)] { 2 } else { i };
+            let j = (i + 1) % 3;
+            let k = (j + 1) % 3;
+
+            let mut s = (m[(i, i)] - (m[(j, j)] + m[(k, k)]) + 1.0).sqrt();
+            let mut q = [0.0; 3];
+            q[i] = s * 0.5;
+            if s != 0.0 {
+                s = 0.5 / s;
+            }
+            q[j] = (m[(j, i)] + m[(i, j)]) * s;
+            q[k] = (m[(k, i)] + m[(i, k)]) * s;
+            Self{
+                v: Vector3f::new(q[0], q[1], q[2]),
+                w: (m[(k, j)] - m[(j, k)]) * s,
+            }
+        }
+    }
+
+    pub fn dot(&self, q : &Quaternion) -> f32{
+        self.v.dot(&q.v) + self.w * q.w
+    }
+
+    pub fn normalize(&self) -> Quaternion{
+        *self / self.dot(self).sqrt()
+    }
+
+    /// Rotation matrix of a unit quaternion
+    pub fn to_transform(self) -> Transform{
+        let (x, y, z, w) = (self.v.x, self.v.y, self.v.z, self.w);
+        let (xx, yy, zz) = (x * x, y * y, z * z);
+        let (xy, xz, yz) = (x * y, x * z, y * z);
+        let (wx, wy, wz) = (x * w, y * w, z * w);
+
+        let mat = Matrix4x4::new(
+            1.0 - 2.0 * (yy + zz), 2.0 * (xy - wz), 2.0 * (xz + wy), 0.0,
+            2.0 * (xy + wz), 1.0 - 2.0 * (xx + zz), 2.0 * (yz - wx), 0.0,
+            2.0 * (xz - wy), 2.0 * (yz + wx), 1.0 - 2.0 * (xx + yy), 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        );
+        // The inverse of a rotation is its transpose
+        Transform::new(mat, mat.transpose())
+    }
+
+    /// Spherical linear interpolation, rotating at constant angular speed from `self` to `q`
+    pub fn slerp(&self, q : &Quaternion, t : f32) -> Quaternion{
+        let cos_theta = self.dot(q);
+        if cos_theta > 0.9995 {
+            // The quaternions are almost parallel, fall back to a linear interpolation
+            (*self * (1.0 - t) + *q * t).normalize()
+        } else {
+            let theta = cos_theta.clamp(-1.0, 1.0).acos();
+            let theta_p = theta * t;
+            let q_perp = (*q - *self * cos_theta).normalize();
+            *self * theta_p.cos() + q_perp * theta_p.sin()
+        }
+    }
+}
+
+impl Add for Quaternion{
+    type Output = Quaternion;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self{
+            v: self.v + rhs.v,
+            w: self.w + rhs.w,
+        }
+    }
+}
+
+impl Sub for Quaternion{
+    type Output = Quaternion;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self{
+            v: self.v - rhs.v,
+            w: self.w - rhs.w,
+        }
+    }
+}
+
+impl Mul<f32> for Quaternion{
+    type Output = Quaternion;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self{
+            v: self.v * rhs,
+            w: self.w * rhs,
+        }
+    }
+}
+
+impl Div<f32> for Quaternion{
+    type Output = Quaternion;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        Self{
+            v: self.v / rhs,
+            w: self.w / rhs,
+        }
+    }
+}
+
+impl Neg for Quaternion{
+    type Output = Quaternion;
+
+    fn neg(self) -> Self::Output {
+        Self{
+            v: self.v.neg(),
+            w: -self.w,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::math::transformations::Apply;
+
+    fn assert_transform_eq(a : &Transform, b : &Transform) {
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((a.get_matrix()[(i, j)] - b.get_matrix()[(i, j)]).abs() < 1e-5, "{:?} != {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_transform_round_trip() {
+        for t in [
+            Transform::rotate_z(30.0),
+            Transform::rotate_x(170.0),
+            Transform::rotate_y(-100.0),
+            Transform::rotate(135.0, &Vector3f::new(1.0, 2.0, -0.5)),
+        ] {
+            let q = Quaternion::from_transform(&t);
+            assert!((q.dot(&q) - 1.0).abs() < 1e-5);
+            assert_transform_eq(&q.to_transform(), &t);
+        }
+    }
+
+    #[test]
+    fn test_slerp_halfway() {
+        let q0 = Quaternion::from_transform(&Transform::rotate_z(0.0));
+        let q1 = Quaternion::from_transform(&Transform::rotate_z(90.0));
+        assert_transform_eq(&q0.slerp(&q1, 0.5).to_transform(), &Transform::rotate_z(45.0));
+        assert_transform_eq(&q0.slerp(&q1, 1.0).to_transform(), &Transform::rotate_z(90.0));
+
+        // Constant angular speed, not a linear interpolation of the rotated vectors
+        let v = q0.slerp(&q1, 1.0 / 3.0).to_transform().apply(&Vector3f::new(1.0, 0.0, 0.0));
+        assert!((v.y.atan2(v.x).to_degrees() - 30.0).abs() < 1e-3);
+    }
+}
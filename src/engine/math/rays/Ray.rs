@@ -1,12 +1,14 @@
+use std::cell::Cell;
 use crate::engine::math::Point::Point3f;
 use crate::engine::math::rays::{BaseRay, Medium};
 use crate::engine::math::Vector::Vector3f;
 
 
+#[derive(Debug, Clone)]
 pub(crate) struct Ray{
     origin: Point3f,
     direction: Vector3f,
-    t_max : f32,
+    t_max : Cell<f32>,// Shortened by the primitives as closer hits are found
     time : f32,// For animations
     medium : Option<Medium>,
 }
@@ -16,7 +18,7 @@ impl BaseRay for Ray{
         Self{
             origin,
             direction,
-            t_max,
+            t_max: Cell::new(t_max),
             time,
             medium
         }
@@ -29,6 +31,22 @@ impl BaseRay for Ray{
     fn get_direction(&self) -> Vector3f {
         self.direction
     }
+
+    fn get_t_max(&self) -> f32 {
+        self.t_max.get()
+    }
+
+    fn set_t_max(&self, t_max : f32) {
+        self.t_max.set(t_max)
+    }
+
+    fn get_time(&self) -> f32 {
+        self.time
+    }
+
+    fn get_medium(&self) -> Option<Medium> {
+        self.medium
+    }
 }
 
 
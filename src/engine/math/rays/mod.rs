@@ -2,20 +2,30 @@ use crate::engine::math::Point::Point3f;
 use crate::engine::math::Vector::Vector3f;
 
 pub mod Ray;
-mod ray_differential;
+pub(crate) mod ray_differential;
 
-struct Medium{
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Medium{
 
 }
 
 
-trait BaseRay{
+pub(crate) trait BaseRay{
     fn new(origin : Point3f, direction : Vector3f, t_max : f32, time : f32, medium: Option<Medium>) -> Self;
 
     fn get_origin(&self) -> Point3f;
 
     fn get_direction(&self) -> Vector3f;
 
+    fn get_t_max(&self) -> f32;
+
+    // Shorten the ray, used by the aggregates to skip anything past the closest hit found so far
+    fn set_t_max(&self, t_max : f32);
+
+    fn get_time(&self) -> f32;
+
+    fn get_medium(&self) -> Option<Medium>;
+
     fn point_at(&self, t : f32) -> Point3f{
         self.get_origin() + self.get_direction() * t
     }
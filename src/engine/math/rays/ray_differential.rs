@@ -1,19 +1,21 @@
+use std::cell::Cell;
 use crate::engine::math::Point::Point3f;
 use crate::engine::math::rays::{BaseRay, Medium};
 use crate::engine::math::Vector::Vector3f;
 
-struct RayDifferential{
+#[derive(Debug, Clone)]
+pub(crate) struct RayDifferential{
     origin: Point3f,
     direction: Vector3f,
-    t_max : f32,
+    t_max : Cell<f32>,// Shortened by the primitives as closer hits are found
     time : f32,// For animations
     medium : Option<Medium>,
 
-    is_differential : bool,
-    rx_orig : Option<Point3f>,
-    rx_direction : Option<Vector3f>,
-    ry_orig : Option<Point3f>,
-    ry_direction : Option<Vector3f>,
+    pub is_differential : bool,
+    pub rx_orig : Option<Point3f>,
+    pub rx_direction : Option<Vector3f>,
+    pub ry_orig : Option<Point3f>,
+    pub ry_direction : Option<Vector3f>,
 }
 
 impl BaseRay for RayDifferential {
@@ -21,7 +23,7 @@ impl BaseRay for RayDifferential {
         Self{
             origin,
             direction,
-            t_max,
+            t_max: Cell::new(t_max),
             time,
             medium,
             is_differential: false,
@@ -39,6 +41,22 @@ impl BaseRay for RayDifferential {
     fn get_direction(&self) -> Vector3f {
         self.direction
     }
+
+    fn get_t_max(&self) -> f32 {
+        self.t_max.get()
+    }
+
+    fn set_t_max(&self, t_max : f32) {
+        self.t_max.set(t_max)
+    }
+
+    fn get_time(&self) -> f32 {
+        self.time
+    }
+
+    fn get_medium(&self) -> Option<Medium> {
+        self.medium
+    }
 }
 
 impl RayDifferential {
@@ -0,0 +1,94 @@
+use crate::engine::math::ONE_MINUS_EPSILON;
+
+const PCG32_DEFAULT_STATE : u64 = 0x853c49e6748fea9b;
+const PCG32_DEFAULT_STREAM : u64 = 0xda3e39cb94b95bdb;
+const PCG32_MULT : u64 = 0x5851f42d4c957f2d;
+
+/// PCG32 pseudo random number generator. Generators seeded with different sequence indices
+/// give independent streams, so each thread can own one and still get reproducible results
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Rng{
+    state : u64,
+    inc : u64,
+}
+
+impl Default for Rng{
+    fn default() -> Self {
+        Self{
+            state: PCG32_DEFAULT_STATE,
+            inc: PCG32_DEFAULT_STREAM,
+        }
+    }
+}
+
+impl Rng{
+    pub fn new(sequence_index : u64) -> Rng{
+        let mut rng = Self::default();
+        rng.set_sequence(sequence_index);
+        rng
+    }
+
+    /// Restart the generator on the stream of `sequence_index`
+    pub fn set_sequence(&mut self, sequence_index : u64) {
+        self.state = 0;
+        self.inc = (sequence_index << 1) | 1;
+        self.uniform_u32();
+        self.state = self.state.wrapping_add(PCG32_DEFAULT_STATE);
+        self.uniform_u32();
+    }
+
+    pub fn uniform_u32(&mut self) -> u32 {
+        let old_state = self.state;
+        self.state = old_state.wrapping_mul(PCG32_MULT).wrapping_add(self.inc);
+        let xor_shifted = (((old_state >> 18) ^ old_state) >> 27) as u32;
+        let rotation = (old_state >> 59) as u32;
+        xor_shifted.rotate_right(rotation)
+    }
+
+    /// Uniform integer in [0, bound), without the bias of a plain modulo
+    pub fn uniform_u32_bounded(&mut self, bound : u32) -> u32 {
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let r = self.uniform_u32();
+            if r >= threshold {
+                return r % bound;
+            }
+        }
+    }
+
+    /// Uniform float in [0, 1)
+    pub fn uniform_f32(&mut self) -> f32 {
+        (self.uniform_u32() as f32 * (-32.0f32).exp2()).min(ONE_MINUS_EPSILON)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequences_are_reproducible_and_independent() {
+        let mut a = Rng::new(1);
+        let mut b = Rng::new(1);
+        let mut c = Rng::new(2);
+        let from_a : Vec<u32> = (0..16).map(|_| a.uniform_u32()).collect();
+        assert_eq!(from_a, (0..16).map(|_| b.uniform_u32()).collect::<Vec<_>>());
+        assert_ne!(from_a, (0..16).map(|_| c.uniform_u32()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_uniform_values() {
+        let mut rng = Rng::new(7);
+        let n = 10000;
+        let mut sum = 0.0;
+        let mut counts = [0; 5];
+        for _ in 0..n {
+            let v = rng.uniform_f32();
+            assert!((0.0..1.0).contains(&v));
+            sum += v as f64;
+            counts[rng.uniform_u32_bounded(5) as usize] += 1;
+        }
+        assert!((sum / n as f64 - 0.5).abs() < 0.01);
+        assert!(counts.iter().all(|&c| (c as f32 - n as f32 / 5.0).abs() < 150.0), "{:?}", counts);
+    }
+}
@@ -0,0 +1,292 @@
+use std::f32::consts::PI;
+use crate::engine::math::Point::Point2f;
+use crate::engine::math::rng::Rng;
+use crate::engine::math::ONE_MINUS_EPSILON;
+
+/// Map a point of the unit square onto the unit disk, keeping the relative areas
+/// and the strata of the samples by mapping concentric squares to concentric circles
+pub fn concentric_sample_disk(u : &Point2f) -> Point2f {
+    // Remap to [-1, 1]^2
+    let offset = Point2f::new(2.0 * u.x - 1.0, 2.0 * u.y - 1.0);
+    if offset.x == 0.0 && offset.y == 0.0 {
+        return Point2f::new(0.0, 0.0);
+    }
+
+    let (r, theta) = if offset.x.abs() > offset.y.abs() {
+        (offset.x, PI / 4.0 * (offset.y / offset.x))
+    } else {
+        (offset.y, PI / 2.0 - PI / 4.0 * (offset.x / offset.y))
+    };
+    Point2f::new(r * theta.cos(), r * theta.sin())
+}
+
+/// Mirror the digits of `a` written in `base` around the radix point,
+/// giving well distributed values in [0, 1) for consecutive indices
+pub fn radical_inverse(base : u64, mut a : u64) -> f32 {
+    let inv_base = 1.0 / base as f64;
+    let mut reversed_digits : u64 = 0;
+    let mut inv_base_n = 1.0;
+    while a > 0 {
+        let next = a / base;
+        let digit = a - next * base;
+        reversed_digits = reversed_digits * base + digit;
+        inv_base_n *= inv_base;
+        a = next;
+    }
+    ((reversed_digits as f64 * inv_base_n) as f32).min(ONE_MINUS_EPSILON)
+}
+
+/// Number of tabulated primes, the bases of the radical inverses of each dimension of the Halton sequence
+pub const PRIME_TABLE_SIZE : usize = 1000;
+
+/// The first primes, in increasing order
+pub const PRIMES : [u64; PRIME_TABLE_SIZE] = compute_primes();
+
+const fn compute_primes() -> [u64; PRIME_TABLE_SIZE] {
+    let mut primes = [0; PRIME_TABLE_SIZE];
+    let mut count = 0;
+    let mut candidate = 2;
+    while count < PRIME_TABLE_SIZE {
+        let mut is_prime = true;
+        let mut i = 0;
+        while i < count && primes[i] * primes[i] <= candidate {
+            if candidate % primes[i] == 0 {
+                is_prime = false;
+                break;
+            }
+            i += 1;
+        }
+        if is_prime {
+            primes[count] = candidate;
+            count += 1;
+        }
+        candidate += 1;
+    }
+    primes
+}
+
+/// Radical inverse with the digits remapped by `permutation`, a permutation of the digits of `base`.
+/// The infinite tail of zero digits is also permuted, so it adds a geometric series of `permutation[0]`
+pub fn scrambled_radical_inverse(base : u64, mut a : u64, permutation : &[u16]) -> f32 {
+    let inv_base = 1.0 / base as f64;
+    let mut reversed_digits : u64 = 0;
+    let mut inv_base_n = 1.0;
+    while a > 0 {
+        let next = a / base;
+        let digit = a - next * base;
+        reversed_digits = reversed_digits * base + permutation[digit as usize] as u64;
+        inv_base_n *= inv_base;
+        a = next;
+    }
+    let tail = inv_base * permutation[0] as f64 / (1.0 - inv_base);
+    ((inv_base_n * (reversed_digits as f64 + tail)) as f32).min(ONE_MINUS_EPSILON)
+}
+
+/// The index whose radical inverse has the `n_digits` digits `inverse` once scaled by `base^n_digits`
+pub fn inverse_radical_inverse(base : u64, mut inverse : u64, n_digits : u32) -> u64 {
+    let mut index = 0;
+    for _ in 0..n_digits {
+        let digit = inverse % base;
+        inverse /= base;
+        index = index * base + digit;
+    }
+    index
+}
+
+/// A random permutation of the digits of each prime base, for the scrambled radical inverses
+pub fn compute_radical_inverse_permutations(rng : &mut Rng) -> Vec<Vec<u16>> {
+    PRIMES.iter().map(|&base| {
+        let mut permutation : Vec<u16> = (0..base as u16).collect();
+        shuffle(&mut permutation, rng);
+        permutation
+    }).collect()
+}
+
+/// Hash of 64 bits with all of its bits depending on all of the bits of `v`
+pub fn mix_bits(mut v : u64) -> u64 {
+    v ^= v >> 31;
+    v = v.wrapping_mul(0x7fb5d329728ea185);
+    v ^= v >> 27;
+    v = v.wrapping_mul(0x81dadef4bc2dd44d);
+    v ^= v >> 33;
+    v
+}
+
+/// Owen scrambling of a fixed point value in [0, 1): each digit is flipped or not depending on a hash
+/// of the digits before it, which randomizes the points while keeping their stratification
+pub fn owen_scramble(mut v : u32, seed : u32) -> u32 {
+    if seed & 1 != 0 {
+        v ^= 1 << 31;
+    }
+    for b in 1..32 {
+        let mask = u32::MAX << (32 - b);
+        if (mix_bits(((v & mask) ^ seed) as u64) as u32) & (1 << b) != 0 {
+            v ^= 1 << (31 - b);
+        }
+    }
+    v
+}
+
+/// Element `i` of a random permutation of [0, l) chosen by the seed `p`, without storing the permutation
+pub fn permutation_element(mut i : u32, l : u32, p : u32) -> u32 {
+    let mut w = l - 1;
+    w |= w >> 1;
+    w |= w >> 2;
+    w |= w >> 4;
+    w |= w >> 8;
+    w |= w >> 16;
+    // Permute the values in [0, w] until landing in [0, l)
+    loop {
+        i ^= p;
+        i = i.wrapping_mul(0xe170893d);
+        i ^= p >> 16;
+        i ^= (i & w) >> 4;
+        i ^= p >> 8;
+        i = i.wrapping_mul(0x0929eb3f);
+        i ^= p >> 23;
+        i ^= (i & w) >> 1;
+        i = i.wrapping_mul(1 | p >> 27);
+        i = i.wrapping_mul(0x6935fa69);
+        i ^= (i & w) >> 11;
+        i = i.wrapping_mul(0x74dcb303);
+        i ^= (i & w) >> 2;
+        i = i.wrapping_mul(0x9e501cc3);
+        i ^= (i & w) >> 2;
+        i = i.wrapping_mul(0xc860a3df);
+        i &= w;
+        i ^= i >> 5;
+        if i < l {
+            break;
+        }
+    }
+    (i.wrapping_add(p)) % l
+}
+
+/// One sample in each of the `n` strata of [0, 1), placed at random in its stratum when `jitter`
+/// and at its center otherwise
+pub fn stratified_sample_1d(n : usize, rng : &mut Rng, jitter : bool) -> Vec<f32> {
+    let inv_n = 1.0 / n as f32;
+    (0..n).map(|i| {
+        let delta = if jitter { rng.uniform_f32() } else { 0.5 };
+        ((i as f32 + delta) * inv_n).min(ONE_MINUS_EPSILON)
+    }).collect()
+}
+
+/// One sample in each cell of a `nx` by `ny` grid over [0, 1)^2, row by row
+pub fn stratified_sample_2d(nx : usize, ny : usize, rng : &mut Rng, jitter : bool) -> Vec<Point2f> {
+    let (dx, dy) = (1.0 / nx as f32, 1.0 / ny as f32);
+    let mut samples = Vec::with_capacity(nx * ny);
+    for y in 0..ny {
+        for x in 0..nx {
+            let (jx, jy) = if jitter { (rng.uniform_f32(), rng.uniform_f32()) } else { (0.5, 0.5) };
+            samples.push(Point2f::new(
+                ((x as f32 + jx) * dx).min(ONE_MINUS_EPSILON),
+                ((y as f32 + jy) * dy).min(ONE_MINUS_EPSILON)
+            ));
+        }
+    }
+    samples
+}
+
+/// Randomly permute the items, so that the samples of different dimensions aren't correlated
+pub fn shuffle<T>(items : &mut [T], rng : &mut Rng) {
+    for i in (1..items.len()).rev() {
+        let other = rng.uniform_u32_bounded(i as u32 + 1) as usize;
+        items.swap(i, other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_concentric_disk_stays_inside() {
+        assert_eq!(concentric_sample_disk(&Point2f::new(0.5, 0.5)), Point2f::new(0.0, 0.0));
+        let corner = concentric_sample_disk(&Point2f::new(1.0, 1.0));
+        assert!((corner.x - 0.5_f32.sqrt()).abs() < 1e-5 && (corner.y - 0.5_f32.sqrt()).abs() < 1e-5);
+
+        for i in 0..=20 {
+            for j in 0..=20 {
+                let p = concentric_sample_disk(&Point2f::new(i as f32 / 20.0, j as f32 / 20.0));
+                assert!(p.x * p.x + p.y * p.y <= 1.0 + 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_stratified_samples_cover_every_stratum() {
+        let mut rng = Rng::new(3);
+        let mut samples = stratified_sample_1d(8, &mut rng, true);
+        shuffle(&mut samples, &mut rng);
+        let mut strata : Vec<usize> = samples.iter().map(|v| (v * 8.0) as usize).collect();
+        strata.sort();
+        assert_eq!(strata, (0..8).collect::<Vec<_>>());
+
+        let grid = stratified_sample_2d(4, 2, &mut rng, false);
+        assert_eq!(grid[0], Point2f::new(0.125, 0.25));
+        assert_eq!(grid[5], Point2f::new(0.375, 0.75));
+    }
+
+    #[test]
+    fn test_primes() {
+        assert_eq!(PRIMES[..8], [2, 3, 5, 7, 11, 13, 17, 19]);
+        assert_eq!(PRIMES[PRIME_TABLE_SIZE - 1], 7919);
+    }
+
+    #[test]
+    fn test_scrambled_radical_inverse() {
+        // The identity permutation gives back the radical inverse
+        let identity : Vec<u16> = (0..3).collect();
+        for a in 0..50 {
+            assert!((scrambled_radical_inverse(3, a, &identity) - radical_inverse(3, a)).abs() < 1e-6);
+        }
+
+        // Swapping the digits 0 and 1 of base 2 mirrors the values
+        for a in 0..50 {
+            let mirrored = scrambled_radical_inverse(2, a, &[1, 0]);
+            assert!((mirrored - (1.0 - radical_inverse(2, a))).abs() < 1e-6, "{} {}", a, mirrored);
+        }
+
+        // The first base^k values are still evenly spaced by base^-k
+        let mut rng = Rng::new(5);
+        let permutations = compute_radical_inverse_permutations(&mut rng);
+        let mut values : Vec<f32> = (0..25).map(|a| scrambled_radical_inverse(5, a, &permutations[2])).collect();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert!(values.windows(2).all(|w| (w[1] - w[0] - 0.04).abs() < 1e-5), "{:?}", values);
+
+        for a in [0, 1, 17, 123] {
+            let digits = (radical_inverse(3, a) * 243.0).round() as u64;
+            assert_eq!(inverse_radical_inverse(3, digits, 5), a);
+        }
+    }
+
+    #[test]
+    fn test_owen_scrambling_keeps_strata() {
+        for seed in [0, 1, 0xdeadbeef] {
+            // Scrambling is a bijection that preserves each dyadic interval
+            let mut scrambled : Vec<u32> = (0..64u32).map(|i| owen_scramble(i << 26, seed) >> 26).collect();
+            scrambled.sort();
+            assert_eq!(scrambled, (0..64).collect::<Vec<_>>());
+        }
+        assert_ne!(owen_scramble(1 << 31, 3), owen_scramble(1 << 31, 4));
+    }
+
+    #[test]
+    fn test_permutation_element() {
+        for (l, p) in [(1, 7), (10, 3), (64, 12345), (100, 0xabcdef)] {
+            let mut permuted : Vec<u32> = (0..l).map(|i| permutation_element(i, l, p)).collect();
+            permuted.sort();
+            assert_eq!(permuted, (0..l).collect::<Vec<_>>());
+        }
+        assert_ne!((0..16).map(|i| permutation_element(i, 16, 1)).collect::<Vec<_>>(), (0..16).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_radical_inverse() {
+        assert_eq!(radical_inverse(2, 0), 0.0);
+        assert_eq!(radical_inverse(2, 1), 0.5);
+        assert_eq!(radical_inverse(2, 6), 0.375);
+        assert!((radical_inverse(3, 5) - 7.0 / 9.0).abs() < 1e-6);
+    }
+}
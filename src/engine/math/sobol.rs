@@ -0,0 +1,207 @@
+use std::sync::OnceLock;
+use crate::engine::math::sampling::owen_scramble;
+use crate::engine::math::ONE_MINUS_EPSILON;
+use crate::engine::math::sobol_directions::SOBOL_DIRECTIONS;
+
+/// Number of dimensions of the Sobol sequence
+pub const NUM_SOBOL_DIMENSIONS : usize = 1024;
+
+/// Number of columns of each generator matrix, one per bit of the index of the points
+pub const SOBOL_MATRIX_SIZE : usize = 64;
+
+/// How the points of the Sobol sequence are randomized, the stratification of the points is kept by all of them
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum SobolRandomization{
+    None,
+    // Flip the same digits of every point
+    Xor,
+    // Flip each digit depending on the digits before it
+    Owen,
+}
+
+impl SobolRandomization{
+    pub fn apply(&self, v : u32, seed : u32) -> u32 {
+        match self {
+            SobolRandomization::None => v,
+            SobolRandomization::Xor => v ^ seed,
+            SobolRandomization::Owen => owen_scramble(v, seed),
+        }
+    }
+}
+
+/// The generator matrices of each dimension, stored by columns of 64 bits fixed point values.
+/// Dimension 0 is the van der Corput sequence, the other ones come from the direction numbers of `SOBOL_DIRECTIONS`
+pub fn sobol_matrices() -> &'static [[u64; SOBOL_MATRIX_SIZE]] {
+    static MATRICES : OnceLock<Vec<[u64; SOBOL_MATRIX_SIZE]>> = OnceLock::new();
+    MATRICES.get_or_init(|| {
+        let mut matrices = Vec::with_capacity(NUM_SOBOL_DIMENSIONS);
+        let mut van_der_corput = [0; SOBOL_MATRIX_SIZE];
+        for (c, column) in van_der_corput.iter_mut().enumerate() {
+            *column = 1 << (63 - c);
+        }
+        matrices.push(van_der_corput);
+        for &(s, a, m) in SOBOL_DIRECTIONS.iter() {
+            matrices.push(direction_numbers(s, a, m));
+        }
+        matrices
+    })
+}
+
+/// Columns of the generator matrix of the polynomial of degree `s` whose inner coefficients are the bits of `a`,
+/// from the initial direction numbers `m`
+fn direction_numbers(s : usize, a : u64, m : &[u64]) -> [u64; SOBOL_MATRIX_SIZE] {
+    let mut v = [0u64; SOBOL_MATRIX_SIZE];
+    for k in 0..SOBOL_MATRIX_SIZE {
+        v[k] = if k < s {
+            m[k] << (63 - k)
+        } else {
+            let mut column = v[k - s] ^ (v[k - s] >> s);
+            for j in 1..s {
+                if (a >> (s - 1 - j)) & 1 != 0 {
+                    column ^= v[k - j];
+                }
+            }
+            column
+        };
+    }
+    v
+}
+
+/// A dimension of the point at `index` of the Sobol sequence, as the 32 most significant bits of its fixed point value
+pub fn sobol_sample_bits(mut index : u64, dimension : usize) -> u32 {
+    let matrix = &sobol_matrices()[dimension];
+    let mut v = 0;
+    let mut c = 0;
+    while index != 0 {
+        if index & 1 != 0 {
+            v ^= matrix[c];
+        }
+        index >>= 1;
+        c += 1;
+    }
+    (v >> 32) as u32
+}
+
+/// A dimension of the point at `index` of the Sobol sequence in [0, 1), randomized with the given seed
+pub fn sobol_sample(index : u64, dimension : usize, randomization : SobolRandomization, seed : u32) -> f32 {
+    let v = randomization.apply(sobol_sample_bits(index, dimension), seed);
+    (v as f32 * (-32.0f32).exp2()).min(ONE_MINUS_EPSILON)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Whether the polynomial over GF(2) of degree `degree` with coefficients given by the bits of `p` is primitive,
+    /// that is x generates all the non zero elements of GF(2)[x]/p
+    fn is_primitive(p : u64, degree : usize) -> bool {
+        let mul_mod = |mut a : u64, mut b : u64| {
+            let mut result = 0;
+            while b != 0 {
+                if b & 1 != 0 {
+                    result ^= a;
+                }
+                b >>= 1;
+                a <<= 1;
+                if a & (1 << degree) != 0 {
+                    a ^= p;
+                }
+            }
+            result
+        };
+        let pow_mod = |mut e : u64| {
+            let mut base = if degree == 1 { 2 ^ p } else { 2 };
+            let mut result = 1;
+            while e != 0 {
+                if e & 1 != 0 {
+                    result = mul_mod(result, base);
+                }
+                base = mul_mod(base, base);
+                e >>= 1;
+            }
+            result
+        };
+
+        // The order of x must be the size of the multiplicative group and not one of its divisors
+        let order = (1u64 << degree) - 1;
+        if pow_mod(order) != 1 {
+            return false;
+        }
+        let mut n = order;
+        let mut q = 2;
+        while q * q <= n {
+            if n.is_multiple_of(q) {
+                if pow_mod(order / q) == 1 {
+                    return false;
+                }
+                while n.is_multiple_of(q) {
+                    n /= q;
+                }
+            }
+            q += 1;
+        }
+        n == 1 || pow_mod(order / n) != 1
+    }
+
+    #[test]
+    fn test_first_points() {
+        let points : Vec<(f32, f32, f32)> = (0..4)
+            .map(|i| (sobol_sample(i, 0, SobolRandomization::None, 0), sobol_sample(i, 1, SobolRandomization::None, 0), sobol_sample(i, 2, SobolRandomization::None, 0)))
+            .collect();
+        assert_eq!(points, vec![(0.0, 0.0, 0.0), (0.5, 0.5, 0.5), (0.25, 0.75, 0.75), (0.75, 0.25, 0.25)]);
+    }
+
+    #[test]
+    fn test_direction_numbers_follow_the_primitive_polynomials() {
+        assert!(is_primitive(0b1011, 3) && is_primitive(0b10011, 4) && !is_primitive(0b11111, 4) && !is_primitive(0b101, 2));
+
+        // Every primitive polynomial is used once, by increasing degree and coefficients
+        let mut expected = Vec::new();
+        let (mut degree, mut a) = (1, 0);
+        while expected.len() < SOBOL_DIRECTIONS.len() {
+            if is_primitive((1 << degree) | (a << 1) | 1, degree) {
+                expected.push((degree, a));
+            }
+            a += 1;
+            if a == 1 << (degree - 1) {
+                degree += 1;
+                a = 0;
+            }
+        }
+        for ((s, a, m), (degree, coefficients)) in SOBOL_DIRECTIONS.iter().zip(expected) {
+            assert_eq!((*s, *a), (degree, coefficients));
+            assert_eq!(m.len(), *s);
+            assert!(m.iter().enumerate().all(|(k, &v)| v % 2 == 1 && v < 1 << (k + 1)), "{:?}", m);
+        }
+    }
+
+    #[test]
+    fn test_every_dimension_is_stratified() {
+        assert_eq!(sobol_matrices().len(), NUM_SOBOL_DIMENSIONS);
+
+        for dimension in 0..NUM_SOBOL_DIMENSIONS {
+            let mut intervals : Vec<u32> = (0..64).map(|i| sobol_sample_bits(i, dimension) >> 26).collect();
+            intervals.sort();
+            assert_eq!(intervals, (0..64).collect::<Vec<_>>(), "dimension {}", dimension);
+        }
+    }
+
+    #[test]
+    fn test_first_dimensions_form_a_02_sequence() {
+        // Every elementary interval of area 1/64 holds one of the 64 first points, whatever the randomization
+        for randomization in [SobolRandomization::None, SobolRandomization::Xor, SobolRandomization::Owen] {
+            for x_bits in 0..=6 {
+                let mut cells : Vec<(u32, u32)> = (0..64)
+                    .map(|i| {
+                        let x = randomization.apply(sobol_sample_bits(i, 0), 0x1234567);
+                        let y = randomization.apply(sobol_sample_bits(i, 1), 0x7654321);
+                        (x.checked_shr(32 - x_bits).unwrap_or(0), y.checked_shr(26 + x_bits).unwrap_or(0))
+                    })
+                    .collect();
+                cells.sort();
+                cells.dedup();
+                assert_eq!(cells.len(), 64, "{:?} {}", randomization, x_bits);
+            }
+        }
+    }
+}
@@ -0,0 +1,1029 @@
+use crate::engine::math::sobol::NUM_SOBOL_DIMENSIONS;
+
+/// Degree `s`, inner coefficients `a` and initial direction numbers `m` of the dimensions following the first one,
+/// with the primitive polynomials taken by increasing degree and `a`. Generated by `examples/sobol_directions.rs`
+pub(crate) static SOBOL_DIRECTIONS : [(usize, u64, &[u64]); NUM_SOBOL_DIMENSIONS - 1] = [
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+    (5, 4, &[1, 1, 5, 5, 5]),
+    (5, 7, &[1, 1, 7, 11, 19]),
+    (5, 11, &[1, 1, 5, 1, 1]),
+    (5, 13, &[1, 1, 1, 3, 11]),
+    (5, 14, &[1, 3, 5, 5, 31]),
+    (6, 1, &[1, 3, 3, 9, 7, 49]),
+    (6, 13, &[1, 1, 1, 15, 21, 21]),
+    (6, 16, &[1, 3, 1, 13, 27, 49]),
+    (6, 19, &[1, 1, 3, 1, 31, 3]),
+    (6, 22, &[1, 3, 3, 7, 21, 13]),
+    (6, 25, &[1, 3, 3, 7, 9, 33]),
+    (7, 1, &[1, 1, 1, 13, 17, 25, 17]),
+    (7, 4, &[1, 1, 5, 5, 21, 7, 49]),
+    (7, 7, &[1, 3, 3, 15, 23, 21, 87]),
+    (7, 8, &[1, 3, 7, 7, 25, 59, 5]),
+    (7, 14, &[1, 3, 3, 3, 21, 21, 15]),
+    (7, 19, &[1, 3, 5, 9, 1, 37, 99]),
+    (7, 21, &[1, 1, 7, 11, 5, 11, 75]),
+    (7, 28, &[1, 1, 7, 9, 7, 33, 97]),
+    (7, 31, &[1, 3, 3, 5, 15, 25, 25]),
+    (7, 32, &[1, 1, 3, 7, 19, 59, 7]),
+    (7, 37, &[1, 1, 1, 3, 15, 41, 33]),
+    (7, 41, &[1, 1, 5, 7, 25, 41, 21]),
+    (7, 42, &[1, 3, 5, 5, 21, 1, 39]),
+    (7, 50, &[1, 3, 1, 5, 13, 39, 83]),
+    (7, 55, &[1, 1, 3, 13, 15, 51, 77]),
+    (7, 56, &[1, 3, 5, 15, 27, 43, 57]),
+    (7, 59, &[1, 1, 7, 11, 15, 61, 125]),
+    (7, 62, &[1, 3, 7, 11, 29, 27, 121]),
+    (8, 14, &[1, 3, 5, 1, 1, 19, 79, 173]),
+    (8, 21, &[1, 3, 3, 13, 23, 43, 79, 167]),
+    (8, 22, &[1, 3, 5, 11, 11, 23, 3, 63]),
+    (8, 38, &[1, 3, 1, 13, 1, 25, 41, 171]),
+    (8, 47, &[1, 3, 7, 5, 29, 11, 97, 19]),
+    (8, 49, &[1, 3, 7, 15, 29, 57, 105, 3]),
+    (8, 50, &[1, 1, 3, 7, 9, 45, 9, 59]),
+    (8, 52, &[1, 1, 3, 9, 17, 11, 55, 241]),
+    (8, 56, &[1, 3, 5, 1, 1, 57, 57, 149]),
+    (8, 67, &[1, 3, 1, 1, 3, 7, 1, 225]),
+    (8, 70, &[1, 3, 3, 15, 1, 29, 79, 167]),
+    (8, 84, &[1, 3, 1, 15, 23, 41, 47, 149]),
+    (8, 97, &[1, 1, 5, 15, 1, 29, 77, 105]),
+    (8, 103, &[1, 1, 7, 3, 9, 41, 71, 207]),
+    (8, 115, &[1, 3, 3, 7, 7, 49, 29, 5]),
+    (8, 122, &[1, 3, 7, 3, 7, 13, 43, 83]),
+    (9, 8, &[1, 1, 7, 9, 3, 29, 101, 229, 397]),
+    (9, 13, &[1, 1, 7, 1, 15, 41, 27, 5, 453]),
+    (9, 16, &[1, 1, 3, 11, 27, 3, 113, 157, 371]),
+    (9, 22, &[1, 1, 3, 9, 29, 9, 47, 17, 443]),
+    (9, 25, &[1, 1, 3, 11, 3, 29, 77, 185, 275]),
+    (9, 44, &[1, 3, 1, 3, 7, 29, 19, 89, 209]),
+    (9, 47, &[1, 3, 7, 5, 21, 15, 103, 225, 419]),
+    (9, 52, &[1, 1, 3, 15, 13, 51, 119, 181, 287]),
+    (9, 55, &[1, 3, 7, 7, 3, 51, 99, 163, 269]),
+    (9, 59, &[1, 1, 5, 3, 23, 29, 91, 209, 321]),
+    (9, 62, &[1, 3, 7, 5, 11, 55, 43, 207, 445]),
+    (9, 67, &[1, 3, 3, 15, 9, 39, 57, 201, 425]),
+    (9, 74, &[1, 3, 5, 5, 13, 7, 1, 209, 461]),
+    (9, 81, &[1, 1, 7, 9, 25, 29, 123, 231, 343]),
+    (9, 82, &[1, 3, 1, 9, 25, 3, 51, 237, 357]),
+    (9, 87, &[1, 3, 5, 9, 7, 63, 99, 247, 409]),
+    (9, 91, &[1, 1, 7, 3, 21, 25, 85, 151, 153]),
+    (9, 94, &[1, 1, 5, 3, 5, 49, 41, 243, 307]),
+    (9, 103, &[1, 1, 1, 3, 1, 43, 105, 29, 229]),
+    (9, 104, &[1, 3, 3, 1, 31, 49, 15, 197, 119]),
+    (9, 109, &[1, 1, 3, 5, 23, 33, 25, 117, 257]),
+    (9, 122, &[1, 3, 3, 7, 5, 43, 5, 43, 67]),
+    (9, 124, &[1, 1, 7, 11, 31, 3, 11, 211, 223]),
+    (9, 137, &[1, 1, 5, 1, 25, 5, 7, 161, 13]),
+    (9, 138, &[1, 3, 3, 3, 19, 25, 115, 97, 319]),
+    (9, 143, &[1, 1, 3, 13, 23, 55, 93, 61, 115]),
+    (9, 145, &[1, 1, 5, 15, 9, 19, 57, 193, 459]),
+    (9, 152, &[1, 3, 5, 1, 19, 43, 91, 185, 135]),
+    (9, 157, &[1, 3, 7, 11, 3, 19, 89, 209, 497]),
+    (9, 167, &[1, 1, 3, 15, 1, 59, 31, 223, 85]),
+    (9, 173, &[1, 1, 3, 5, 3, 5, 39, 29, 225]),
+    (9, 176, &[1, 3, 7, 1, 11, 53, 49, 31, 247]),
+    (9, 181, &[1, 3, 7, 13, 25, 61, 127, 187, 437]),
+    (9, 182, &[1, 3, 7, 15, 19, 13, 37, 69, 203]),
+    (9, 185, &[1, 1, 5, 5, 25, 61, 65, 33, 457]),
+    (9, 191, &[1, 3, 1, 1, 21, 5, 103, 79, 53]),
+    (9, 194, &[1, 3, 3, 7, 17, 9, 61, 135, 361]),
+    (9, 199, &[1, 1, 7, 7, 19, 23, 109, 231, 115]),
+    (9, 218, &[1, 3, 3, 5, 25, 13, 109, 83, 423]),
+    (9, 220, &[1, 1, 1, 3, 29, 39, 69, 213, 369]),
+    (9, 227, &[1, 1, 3, 5, 19, 55, 23, 19, 237]),
+    (9, 229, &[1, 1, 1, 3, 9, 7, 99, 141, 483]),
+    (9, 230, &[1, 1, 3, 7, 7, 29, 35, 171, 407]),
+    (9, 234, &[1, 1, 1, 7, 29, 33, 101, 77, 403]),
+    (9, 236, &[1, 3, 3, 9, 25, 31, 117, 43, 15]),
+    (9, 241, &[1, 3, 5, 3, 27, 17, 25, 137, 65]),
+    (9, 244, &[1, 3, 1, 3, 25, 21, 19, 101, 507]),
+    (9, 253, &[1, 3, 7, 11, 23, 13, 107, 71, 213]),
+    (10, 4, &[1, 1, 5, 13, 23, 1, 71, 21, 335, 913]),
+    (10, 13, &[1, 3, 7, 9, 27, 63, 77, 187, 445, 999]),
+    (10, 19, &[1, 3, 7, 5, 29, 59, 29, 53, 305, 355]),
+    (10, 22, &[1, 1, 3, 9, 7, 15, 115, 25, 223, 651]),
+    (10, 50, &[1, 1, 1, 7, 3, 63, 75, 241, 371, 657]),
+    (10, 55, &[1, 1, 5, 11, 17, 45, 83, 15, 147, 667]),
+    (10, 64, &[1, 1, 7, 9, 25, 21, 83, 75, 179, 599]),
+    (10, 69, &[1, 1, 1, 9, 21, 39, 35, 43, 409, 863]),
+    (10, 98, &[1, 3, 3, 15, 21, 15, 7, 25, 143, 647]),
+    (10, 107, &[1, 1, 7, 3, 5, 35, 71, 113, 397, 631]),
+    (10, 115, &[1, 3, 7, 7, 31, 23, 63, 119, 451, 885]),
+    (10, 121, &[1, 1, 3, 7, 7, 59, 63, 63, 133, 605]),
+    (10, 127, &[1, 3, 1, 13, 15, 47, 113, 91, 403, 189]),
+    (10, 134, &[1, 1, 7, 11, 13, 33, 101, 45, 487, 489]),
+    (10, 140, &[1, 3, 3, 15, 1, 9, 59, 173, 79, 367]),
+    (10, 145, &[1, 1, 7, 1, 23, 17, 19, 21, 397, 47]),
+    (10, 152, &[1, 3, 5, 3, 29, 31, 23, 219, 101, 369]),
+    (10, 158, &[1, 1, 3, 13, 9, 19, 91, 247, 369, 3]),
+    (10, 161, &[1, 3, 5, 11, 13, 49, 107, 51, 3, 27]),
+    (10, 171, &[1, 3, 3, 3, 5, 29, 29, 115, 143, 411]),
+    (10, 181, &[1, 1, 7, 7, 31, 11, 87, 127, 381, 3]),
+    (10, 194, &[1, 1, 5, 11, 21, 43, 5, 209, 129, 509]),
+    (10, 199, &[1, 3, 5, 7, 11, 27, 127, 175, 413, 951]),
+    (10, 203, &[1, 3, 5, 7, 19, 5, 17, 231, 315, 861]),
+    (10, 208, &[1, 3, 5, 15, 25, 7, 123, 173, 257, 319]),
+    (10, 227, &[1, 1, 7, 1, 5, 3, 95, 1, 449, 775]),
+    (10, 242, &[1, 3, 3, 9, 25, 31, 113, 157, 303, 1021]),
+    (10, 251, &[1, 3, 7, 9, 3, 27, 37, 71, 191, 563]),
+    (10, 253, &[1, 1, 1, 11, 29, 35, 9, 133, 209, 837]),
+    (10, 265, &[1, 3, 1, 11, 23, 7, 5, 243, 199, 699]),
+    (10, 266, &[1, 1, 7, 9, 31, 7, 7, 169, 347, 1009]),
+    (10, 274, &[1, 3, 1, 13, 3, 21, 71, 41, 391, 357]),
+    (10, 283, &[1, 3, 7, 5, 9, 37, 121, 33, 253, 817]),
+    (10, 289, &[1, 3, 5, 3, 27, 63, 115, 253, 333, 179]),
+    (10, 295, &[1, 3, 5, 7, 9, 53, 19, 117, 503, 741]),
+    (10, 301, &[1, 3, 5, 9, 31, 9, 57, 243, 127, 623]),
+    (10, 316, &[1, 3, 7, 13, 9, 61, 29, 253, 157, 873]),
+    (10, 319, &[1, 3, 3, 5, 3, 63, 121, 159, 59, 1007]),
+    (10, 324, &[1, 1, 1, 1, 13, 53, 83, 233, 263, 93]),
+    (10, 346, &[1, 3, 1, 15, 17, 27, 11, 61, 125, 25]),
+    (10, 352, &[1, 3, 5, 7, 3, 13, 49, 189, 155, 111]),
+    (10, 361, &[1, 3, 1, 9, 21, 63, 69, 51, 473, 667]),
+    (10, 367, &[1, 3, 3, 9, 29, 21, 77, 29, 255, 723]),
+    (10, 382, &[1, 1, 1, 15, 23, 23, 5, 203, 145, 749]),
+    (10, 395, &[1, 1, 3, 13, 23, 61, 63, 95, 207, 7]),
+    (10, 398, &[1, 3, 7, 9, 27, 25, 81, 243, 125, 541]),
+    (10, 400, &[1, 1, 7, 13, 9, 31, 87, 9, 429, 191]),
+    (10, 412, &[1, 3, 3, 5, 29, 39, 73, 225, 433, 107]),
+    (10, 419, &[1, 3, 1, 13, 21, 39, 35, 41, 481, 247]),
+    (10, 422, &[1, 1, 1, 15, 25, 55, 87, 203, 9, 553]),
+    (10, 426, &[1, 3, 3, 11, 13, 31, 107, 205, 297, 635]),
+    (10, 428, &[1, 1, 3, 7, 1, 27, 45, 85, 165, 5]),
+    (10, 433, &[1, 3, 3, 3, 13, 33, 5, 95, 445, 319]),
+    (10, 446, &[1, 3, 7, 7, 19, 39, 5, 87, 65, 981]),
+    (10, 454, &[1, 1, 3, 13, 11, 1, 53, 135, 367, 133]),
+    (10, 457, &[1, 1, 3, 9, 31, 15, 85, 237, 77, 233]),
+    (10, 472, &[1, 1, 5, 3, 23, 35, 93, 79, 111, 267]),
+    (10, 493, &[1, 3, 5, 7, 31, 31, 53, 129, 5, 653]),
+    (10, 505, &[1, 3, 3, 5, 11, 19, 61, 31, 273, 147]),
+    (10, 508, &[1, 1, 3, 9, 29, 45, 99, 7, 105, 949]),
+    (11, 2, &[1, 1, 1, 5, 17, 25, 37, 245, 201, 401, 387]),
+    (11, 11, &[1, 3, 1, 15, 3, 59, 89, 209, 479, 165, 1461]),
+    (11, 21, &[1, 1, 1, 5, 25, 43, 41, 217, 63, 323, 1777]),
+    (11, 22, &[1, 1, 1, 15, 3, 25, 109, 1, 363, 721, 989]),
+    (11, 35, &[1, 3, 7, 5, 15, 15, 121, 165, 27, 211, 1811]),
+    (11, 49, &[1, 3, 1, 15, 13, 57, 49, 123, 367, 293, 2047]),
+    (11, 50, &[1, 1, 7, 13, 27, 51, 59, 205, 421, 23, 655]),
+    (11, 56, &[1, 3, 5, 3, 31, 51, 83, 35, 119, 319, 237]),
+    (11, 61, &[1, 1, 5, 7, 17, 37, 111, 23, 93, 405, 303]),
+    (11, 70, &[1, 1, 5, 15, 25, 33, 79, 223, 383, 265, 1515]),
+    (11, 74, &[1, 1, 1, 15, 5, 1, 3, 155, 49, 393, 75]),
+    (11, 79, &[1, 3, 5, 13, 7, 55, 81, 107, 205, 681, 1333]),
+    (11, 84, &[1, 3, 7, 15, 3, 31, 37, 173, 55, 831, 873]),
+    (11, 88, &[1, 1, 7, 3, 1, 31, 35, 211, 101, 645, 601]),
+    (11, 103, &[1, 3, 1, 15, 17, 51, 87, 157, 369, 971, 1157]),
+    (11, 104, &[1, 3, 3, 3, 21, 59, 73, 239, 329, 787, 1389]),
+    (11, 112, &[1, 1, 3, 3, 25, 1, 61, 209, 125, 889, 975]),
+    (11, 115, &[1, 3, 7, 13, 19, 35, 45, 243, 221, 733, 1401]),
+    (11, 117, &[1, 1, 7, 1, 17, 13, 41, 201, 361, 359, 909]),
+    (11, 122, &[1, 1, 5, 11, 17, 47, 121, 35, 385, 843, 1061]),
+    (11, 134, &[1, 3, 5, 3, 5, 21, 93, 205, 21, 365, 791]),
+    (11, 137, &[1, 3, 1, 11, 27, 47, 125, 177, 23, 367, 2005]),
+    (11, 146, &[1, 1, 7, 1, 19, 17, 91, 149, 175, 471, 1677]),
+    (11, 148, &[1, 1, 1, 5, 5, 53, 53, 127, 7, 563, 1423]),
+    (11, 157, &[1, 1, 5, 13, 21, 45, 125, 249, 365, 411, 1985]),
+    (11, 158, &[1, 3, 5, 11, 5, 57, 73, 253, 369, 563, 1295]),
+    (11, 162, &[1, 1, 1, 3, 11, 57, 113, 131, 9, 121, 1089]),
+    (11, 164, &[1, 3, 1, 5, 27, 37, 75, 201, 167, 895, 657]),
+    (11, 168, &[1, 3, 7, 3, 9, 39, 111, 201, 23, 763, 159]),
+    (11, 173, &[1, 3, 1, 9, 25, 45, 105, 163, 507, 689, 1541]),
+    (11, 185, &[1, 1, 7, 15, 21, 21, 125, 119, 111, 995, 397]),
+    (11, 186, &[1, 3, 7, 3, 13, 19, 89, 235, 493, 959, 785]),
+    (11, 191, &[1, 1, 5, 5, 7, 11, 71, 81, 329, 359, 301]),
+    (11, 193, &[1, 3, 7, 3, 11, 43, 59, 53, 503, 705, 1149]),
+    (11, 199, &[1, 1, 3, 5, 21, 43, 3, 167, 251, 651, 391]),
+    (11, 213, &[1, 1, 5, 7, 31, 33, 1, 31, 173, 661, 889]),
+    (11, 214, &[1, 1, 1, 9, 11, 61, 115, 1, 347, 877, 1635]),
+    (11, 220, &[1, 3, 3, 15, 3, 33, 61, 219, 265, 407, 2045]),
+    (11, 227, &[1, 3, 7, 11, 31, 11, 97, 145, 353, 651, 903]),
+    (11, 236, &[1, 1, 5, 9, 11, 43, 71, 179, 433, 487, 1821]),
+    (11, 242, &[1, 1, 1, 1, 19, 17, 115, 239, 297, 447, 1421]),
+    (11, 251, &[1, 3, 3, 15, 5, 53, 95, 115, 47, 91, 35]),
+    (11, 256, &[1, 3, 1, 13, 5, 51, 77, 165, 55, 599, 1723]),
+    (11, 259, &[1, 1, 5, 13, 25, 57, 87, 59, 333, 419, 697]),
+    (11, 265, &[1, 1, 7, 7, 11, 23, 125, 161, 453, 547, 959]),
+    (11, 266, &[1, 3, 5, 13, 21, 45, 53, 247, 171, 775, 1449]),
+    (11, 276, &[1, 1, 7, 5, 25, 57, 85, 209, 239, 279, 237]),
+    (11, 292, &[1, 1, 7, 13, 7, 27, 9, 147, 289, 987, 373]),
+    (11, 304, &[1, 1, 3, 5, 3, 47, 3, 229, 487, 157, 1117]),
+    (11, 310, &[1, 1, 3, 13, 29, 49, 103, 11, 433, 639, 1427]),
+    (11, 316, &[1, 3, 1, 3, 15, 37, 127, 93, 263, 305, 1979]),
+    (11, 319, &[1, 1, 5, 11, 13, 1, 111, 249, 415, 133, 773]),
+    (11, 322, &[1, 3, 7, 11, 13, 59, 1, 237, 493, 555, 1767]),
+    (11, 328, &[1, 3, 3, 9, 31, 31, 109, 191, 19, 591, 1603]),
+    (11, 334, &[1, 3, 3, 13, 1, 7, 11, 157, 219, 595, 1903]),
+    (11, 339, &[1, 3, 1, 9, 9, 59, 95, 191, 437, 407, 669]),
+    (11, 341, &[1, 3, 3, 1, 21, 27, 111, 165, 245, 349, 927]),
+    (11, 345, &[1, 1, 3, 13, 11, 19, 25, 27, 255, 775, 1201]),
+    (11, 346, &[1, 3, 1, 7, 1, 53, 101, 239, 197, 657, 53]),
+    (11, 362, &[1, 1, 1, 1, 25, 1, 67, 47, 73, 989, 211]),
+    (11, 367, &[1, 3, 1, 5, 31, 31, 37, 181, 389, 103, 1927]),
+    (11, 372, &[1, 1, 5, 7, 3, 63, 31, 95, 173, 121, 545]),
+    (11, 375, &[1, 3, 7, 9, 29, 1, 107, 133, 315, 441, 883]),
+    (11, 376, &[1, 3, 7, 11, 7, 59, 49, 123, 317, 691, 1031]),
+    (11, 381, &[1, 3, 5, 15, 9, 15, 79, 91, 365, 551, 395]),
+    (11, 385, &[1, 1, 7, 9, 27, 43, 33, 91, 221, 301, 727]),
+    (11, 388, &[1, 3, 5, 5, 21, 43, 51, 25, 211, 851, 413]),
+    (11, 392, &[1, 1, 1, 7, 27, 35, 91, 201, 405, 963, 261]),
+    (11, 409, &[1, 1, 1, 13, 25, 43, 57, 231, 125, 989, 267]),
+    (11, 415, &[1, 1, 5, 7, 11, 11, 117, 123, 441, 419, 1145]),
+    (11, 416, &[1, 3, 1, 9, 19, 25, 63, 219, 129, 905, 383]),
+    (11, 421, &[1, 1, 5, 15, 15, 29, 115, 21, 253, 569, 1045]),
+    (11, 428, &[1, 3, 1, 9, 29, 33, 119, 45, 5, 945, 1687]),
+    (11, 431, &[1, 1, 1, 3, 9, 3, 121, 99, 311, 725, 1857]),
+    (11, 434, &[1, 3, 5, 5, 9, 43, 99, 59, 147, 831, 1121]),
+    (11, 439, &[1, 1, 5, 7, 23, 29, 109, 197, 293, 835, 1309]),
+    (11, 446, &[1, 3, 1, 1, 13, 47, 53, 57, 161, 445, 1935]),
+    (11, 451, &[1, 3, 5, 15, 27, 29, 97, 235, 417, 451, 1057]),
+    (11, 453, &[1, 3, 5, 1, 29, 57, 85, 15, 209, 469, 1167]),
+    (11, 457, &[1, 3, 1, 11, 27, 21, 75, 51, 207, 827, 437]),
+    (11, 458, &[1, 3, 3, 9, 3, 1, 115, 227, 103, 243, 427]),
+    (11, 471, &[1, 3, 7, 5, 31, 1, 65, 179, 429, 719, 203]),
+    (11, 475, &[1, 1, 1, 7, 15, 17, 15, 79, 223, 151, 1191]),
+    (11, 478, &[1, 3, 5, 1, 11, 3, 1, 79, 307, 669, 1369]),
+    (11, 484, &[1, 3, 7, 1, 3, 21, 117, 39, 429, 755, 135]),
+    (11, 493, &[1, 1, 3, 11, 19, 55, 101, 201, 437, 15, 951]),
+    (11, 494, &[1, 1, 5, 7, 13, 51, 93, 57, 289, 679, 979]),
+    (11, 499, &[1, 1, 5, 5, 19, 29, 85, 79, 201, 53, 1437]),
+    (11, 502, &[1, 1, 7, 5, 27, 15, 123, 89, 273, 139, 1779]),
+    (11, 517, &[1, 3, 5, 3, 11, 11, 17, 89, 149, 313, 843]),
+    (11, 518, &[1, 1, 1, 9, 25, 27, 105, 251, 249, 5, 365]),
+    (11, 524, &[1, 1, 1, 9, 3, 1, 121, 215, 225, 777, 1903]),
+    (11, 527, &[1, 1, 1, 1, 11, 31, 43, 85, 361, 11, 1379]),
+    (11, 555, &[1, 3, 5, 1, 21, 43, 67, 153, 61, 637, 409]),
+    (11, 560, &[1, 3, 7, 1, 3, 43, 79, 137, 339, 345, 1155]),
+    (11, 565, &[1, 1, 5, 9, 5, 11, 7, 223, 235, 827, 213]),
+    (11, 569, &[1, 3, 7, 11, 15, 61, 59, 157, 441, 755, 1943]),
+    (11, 578, &[1, 3, 1, 5, 11, 23, 29, 197, 75, 119, 1047]),
+    (11, 580, &[1, 1, 7, 13, 25, 61, 39, 185, 129, 289, 651]),
+    (11, 587, &[1, 3, 5, 11, 27, 37, 81, 237, 309, 1015, 1497]),
+    (11, 589, &[1, 1, 5, 11, 9, 57, 61, 191, 131, 355, 153]),
+    (11, 590, &[1, 3, 7, 5, 29, 15, 91, 195, 271, 957, 1371]),
+    (11, 601, &[1, 1, 5, 7, 7, 41, 23, 9, 115, 791, 953]),
+    (11, 607, &[1, 3, 3, 15, 27, 59, 27, 165, 105, 261, 207]),
+    (11, 611, &[1, 3, 3, 5, 25, 45, 77, 145, 393, 343, 413]),
+    (11, 614, &[1, 1, 1, 5, 29, 17, 31, 173, 477, 511, 133]),
+    (11, 617, &[1, 1, 1, 11, 29, 43, 9, 245, 93, 509, 767]),
+    (11, 618, &[1, 1, 3, 3, 7, 33, 85, 37, 291, 909, 1395]),
+    (11, 625, &[1, 1, 7, 7, 27, 35, 5, 75, 63, 427, 449]),
+    (11, 628, &[1, 1, 5, 1, 3, 55, 123, 223, 439, 573, 305]),
+    (11, 635, &[1, 3, 3, 3, 25, 17, 33, 73, 277, 163, 1891]),
+    (11, 641, &[1, 3, 1, 7, 27, 27, 105, 17, 17, 729, 461]),
+    (11, 647, &[1, 1, 1, 3, 5, 35, 37, 169, 233, 581, 1721]),
+    (11, 654, &[1, 3, 1, 7, 25, 45, 53, 219, 361, 1011, 933]),
+    (11, 659, &[1, 3, 5, 3, 21, 3, 109, 1, 443, 501, 639]),
+    (11, 662, &[1, 3, 3, 7, 13, 45, 37, 149, 311, 53, 1269]),
+    (11, 672, &[1, 3, 7, 15, 17, 29, 23, 181, 351, 877, 907]),
+    (11, 675, &[1, 1, 7, 13, 15, 57, 111, 253, 227, 35, 2043]),
+    (11, 682, &[1, 1, 1, 15, 27, 37, 3, 53, 361, 847, 433]),
+    (11, 684, &[1, 1, 1, 9, 23, 15, 47, 245, 341, 919, 1209]),
+    (11, 689, &[1, 3, 5, 7, 15, 59, 43, 195, 415, 709, 1849]),
+    (11, 695, &[1, 1, 3, 7, 23, 5, 127, 83, 85, 113, 1649]),
+    (11, 696, &[1, 1, 3, 13, 19, 7, 1, 149, 481, 455, 909]),
+    (11, 713, &[1, 1, 3, 9, 5, 9, 1, 87, 279, 613, 1921]),
+    (11, 719, &[1, 1, 5, 1, 17, 19, 101, 123, 307, 237, 541]),
+    (11, 724, &[1, 1, 1, 1, 1, 9, 57, 245, 121, 741, 1225]),
+    (11, 733, &[1, 3, 5, 15, 13, 33, 103, 151, 15, 171, 315]),
+    (11, 734, &[1, 1, 5, 13, 17, 27, 89, 137, 145, 849, 1367]),
+    (11, 740, &[1, 3, 1, 7, 3, 3, 19, 33, 241, 349, 1911]),
+    (11, 747, &[1, 1, 3, 7, 17, 21, 53, 239, 1, 1005, 1651]),
+    (11, 749, &[1, 1, 1, 3, 19, 21, 7, 45, 443, 385, 1187]),
+    (11, 752, &[1, 3, 5, 11, 21, 1, 27, 59, 81, 793, 2023]),
+    (11, 755, &[1, 1, 1, 1, 3, 43, 95, 27, 191, 971, 69]),
+    (11, 762, &[1, 1, 7, 1, 27, 3, 69, 225, 229, 977, 1471]),
+    (11, 770, &[1, 3, 1, 7, 1, 23, 85, 3, 455, 661, 1465]),
+    (11, 782, &[1, 3, 7, 13, 25, 3, 77, 147, 329, 743, 995]),
+    (11, 784, &[1, 3, 5, 7, 17, 1, 5, 189, 431, 885, 555]),
+    (11, 787, &[1, 1, 3, 13, 11, 31, 27, 135, 123, 131, 1923]),
+    (11, 789, &[1, 1, 3, 5, 23, 63, 13, 221, 231, 447, 877]),
+    (11, 793, &[1, 3, 1, 11, 23, 23, 79, 101, 225, 701, 1217]),
+    (11, 796, &[1, 3, 1, 3, 13, 45, 59, 11, 415, 293, 1191]),
+    (11, 803, &[1, 1, 3, 13, 11, 19, 125, 165, 257, 131, 1491]),
+    (11, 805, &[1, 3, 7, 3, 31, 41, 101, 215, 231, 583, 1499]),
+    (11, 810, &[1, 1, 5, 13, 3, 11, 105, 147, 103, 303, 1053]),
+    (11, 815, &[1, 3, 5, 7, 7, 11, 29, 223, 417, 551, 619]),
+    (11, 824, &[1, 3, 5, 11, 9, 63, 127, 73, 105, 173, 1109]),
+    (11, 829, &[1, 3, 3, 15, 31, 45, 99, 187, 167, 231, 1599]),
+    (11, 830, &[1, 3, 3, 11, 27, 29, 17, 29, 493, 527, 963]),
+    (11, 832, &[1, 3, 3, 3, 9, 27, 11, 195, 255, 351, 149]),
+    (11, 841, &[1, 3, 5, 5, 23, 9, 37, 101, 503, 813, 1147]),
+    (11, 847, &[1, 3, 5, 7, 1, 41, 41, 29, 453, 547, 927]),
+    (11, 849, &[1, 1, 7, 7, 7, 61, 3, 213, 187, 379, 857]),
+    (11, 861, &[1, 3, 1, 5, 29, 39, 125, 157, 379, 897, 1375]),
+    (11, 871, &[1, 1, 3, 3, 1, 39, 119, 45, 423, 437, 871]),
+    (11, 878, &[1, 1, 1, 9, 21, 17, 89, 183, 379, 213, 1405]),
+    (11, 889, &[1, 3, 5, 11, 7, 19, 115, 75, 273, 955, 669]),
+    (11, 892, &[1, 1, 7, 15, 17, 33, 95, 211, 283, 785, 423]),
+    (11, 901, &[1, 1, 5, 1, 31, 19, 53, 87, 159, 727, 755]),
+    (11, 908, &[1, 3, 5, 1, 17, 33, 39, 123, 115, 23, 727]),
+    (11, 920, &[1, 1, 3, 3, 7, 43, 73, 95, 411, 845, 2003]),
+    (11, 923, &[1, 1, 7, 3, 11, 61, 29, 253, 155, 403, 1537]),
+    (11, 942, &[1, 3, 1, 5, 1, 53, 105, 67, 7, 787, 1837]),
+    (11, 949, &[1, 3, 3, 13, 11, 33, 1, 195, 397, 471, 1589]),
+    (11, 950, &[1, 1, 1, 3, 1, 41, 71, 85, 111, 703, 1403]),
+    (11, 954, &[1, 3, 5, 11, 31, 43, 63, 105, 425, 495, 125]),
+    (11, 961, &[1, 1, 7, 9, 13, 51, 17, 55, 121, 1021, 1691]),
+    (11, 968, &[1, 1, 3, 3, 1, 51, 59, 165, 199, 587, 1239]),
+    (11, 971, &[1, 3, 7, 11, 15, 53, 111, 171, 167, 687, 1667]),
+    (11, 973, &[1, 3, 3, 5, 25, 23, 101, 195, 229, 273, 923]),
+    (11, 979, &[1, 1, 7, 7, 31, 61, 121, 65, 231, 727, 1483]),
+    (11, 982, &[1, 1, 5, 5, 13, 1, 95, 75, 3, 619, 1807]),
+    (11, 986, &[1, 3, 5, 11, 9, 23, 7, 149, 109, 1, 953]),
+    (11, 998, &[1, 1, 3, 9, 5, 7, 69, 59, 23, 727, 1443]),
+    (11, 1001, &[1, 1, 1, 3, 5, 7, 121, 17, 413, 767, 1411]),
+    (11, 1010, &[1, 1, 3, 15, 25, 11, 49, 215, 505, 343, 1333]),
+    (11, 1012, &[1, 1, 1, 3, 19, 45, 3, 41, 79, 675, 1999]),
+    (12, 41, &[1, 3, 1, 3, 13, 45, 81, 23, 37, 969, 591, 2923]),
+    (12, 52, &[1, 3, 5, 11, 1, 49, 43, 13, 159, 991, 729, 3805]),
+    (12, 61, &[1, 1, 7, 9, 5, 27, 111, 207, 391, 769, 711, 611]),
+    (12, 62, &[1, 3, 3, 15, 27, 39, 45, 119, 273, 887, 489, 2795]),
+    (12, 76, &[1, 3, 7, 13, 1, 45, 43, 3, 411, 41, 1009, 2679]),
+    (12, 104, &[1, 3, 5, 15, 5, 37, 57, 253, 185, 901, 1219, 1755]),
+    (12, 117, &[1, 1, 1, 9, 25, 19, 47, 101, 317, 783, 41, 3113]),
+    (12, 131, &[1, 3, 3, 11, 7, 15, 111, 83, 101, 749, 1569, 125]),
+    (12, 143, &[1, 1, 7, 7, 19, 1, 69, 175, 275, 205, 205, 461]),
+    (12, 145, &[1, 3, 5, 1, 5, 63, 119, 143, 355, 569, 1397, 3903]),
+    (12, 157, &[1, 3, 7, 5, 25, 7, 93, 101, 199, 959, 1761, 1165]),
+    (12, 167, &[1, 3, 3, 15, 31, 27, 125, 11, 303, 873, 1455, 1589]),
+    (12, 171, &[1, 3, 3, 9, 5, 21, 23, 179, 153, 665, 85, 1461]),
+    (12, 176, &[1, 1, 1, 7, 17, 63, 29, 189, 231, 463, 1589, 2297]),
+    (12, 181, &[1, 3, 5, 3, 5, 11, 23, 33, 101, 1, 17, 609]),
+    (12, 194, &[1, 3, 3, 1, 21, 23, 63, 71, 395, 429, 2039, 2713]),
+    (12, 217, &[1, 1, 1, 13, 27, 15, 121, 129, 199, 503, 957, 2347]),
+    (12, 236, &[1, 1, 7, 15, 19, 13, 101, 175, 381, 463, 165, 3701]),
+    (12, 239, &[1, 3, 5, 13, 15, 19, 49, 179, 77, 585, 1389, 2087]),
+    (12, 262, &[1, 1, 3, 5, 13, 25, 13, 161, 351, 813, 1611, 3239]),
+    (12, 283, &[1, 1, 5, 3, 23, 35, 17, 139, 319, 741, 809, 2063]),
+    (12, 286, &[1, 3, 3, 15, 27, 43, 59, 11, 413, 403, 385, 2675]),
+    (12, 307, &[1, 3, 3, 9, 13, 23, 71, 235, 261, 267, 1089, 361]),
+    (12, 313, &[1, 1, 7, 5, 7, 19, 115, 113, 417, 511, 1309, 1815]),
+    (12, 319, &[1, 1, 1, 7, 15, 47, 11, 51, 79, 659, 1655, 475]),
+    (12, 348, &[1, 1, 3, 13, 21, 53, 39, 55, 67, 935, 569, 3789]),
+    (12, 352, &[1, 3, 1, 9, 31, 33, 109, 41, 351, 573, 25, 1705]),
+    (12, 357, &[1, 1, 1, 15, 11, 57, 81, 53, 489, 923, 1861, 891]),
+    (12, 391, &[1, 3, 1, 15, 5, 9, 41, 77, 193, 505, 1675, 263]),
+    (12, 398, &[1, 3, 3, 9, 19, 1, 123, 223, 457, 803, 53, 293]),
+    (12, 400, &[1, 3, 1, 1, 15, 53, 75, 91, 417, 971, 1573, 1065]),
+    (12, 412, &[1, 3, 7, 3, 9, 13, 45, 191, 105, 837, 259, 497]),
+    (12, 415, &[1, 1, 5, 9, 15, 7, 69, 47, 101, 5, 1821, 1971]),
+    (12, 422, &[1, 1, 5, 9, 13, 13, 117, 225, 227, 675, 167, 3783]),
+    (12, 440, &[1, 3, 5, 7, 1, 53, 19, 11, 333, 643, 479, 813]),
+    (12, 460, &[1, 1, 7, 15, 19, 51, 47, 35, 85, 569, 61, 2941]),
+    (12, 465, &[1, 3, 3, 7, 13, 29, 13, 117, 103, 463, 693, 1281]),
+    (12, 468, &[1, 3, 5, 1, 27, 17, 27, 237, 79, 851, 157, 221]),
+    (12, 515, &[1, 1, 3, 9, 31, 11, 115, 17, 265, 707, 2007, 4025]),
+    (12, 536, &[1, 3, 7, 11, 23, 41, 43, 227, 91, 323, 1345, 3371]),
+    (12, 539, &[1, 1, 7, 15, 21, 39, 17, 191, 299, 473, 1947, 1349]),
+    (12, 551, &[1, 3, 3, 9, 9, 13, 19, 175, 259, 21, 541, 473]),
+    (12, 558, &[1, 3, 1, 9, 25, 43, 111, 63, 277, 907, 1813, 1707]),
+    (12, 563, &[1, 3, 5, 1, 23, 55, 125, 11, 489, 847, 1417, 819]),
+    (12, 570, &[1, 1, 1, 3, 19, 31, 29, 163, 25, 673, 1589, 361]),
+    (12, 595, &[1, 3, 3, 11, 19, 53, 61, 39, 505, 643, 1863, 1273]),
+    (12, 598, &[1, 1, 7, 15, 19, 33, 27, 103, 1, 661, 1929, 3575]),
+    (12, 617, &[1, 1, 7, 15, 9, 7, 91, 71, 67, 683, 1879, 409]),
+    (12, 647, &[1, 1, 7, 13, 1, 35, 71, 185, 59, 735, 395, 2153]),
+    (12, 654, &[1, 1, 1, 3, 21, 25, 105, 251, 111, 975, 1417, 67]),
+    (12, 678, &[1, 1, 5, 7, 19, 23, 115, 181, 489, 1023, 41, 745]),
+    (12, 713, &[1, 1, 3, 1, 29, 41, 121, 115, 271, 977, 7, 1399]),
+    (12, 738, &[1, 3, 3, 7, 23, 49, 103, 101, 455, 53, 869, 3155]),
+    (12, 747, &[1, 1, 1, 11, 29, 37, 93, 215, 117, 381, 439, 2357]),
+    (12, 750, &[1, 3, 3, 3, 21, 31, 41, 199, 9, 925, 155, 3243]),
+    (12, 757, &[1, 3, 5, 15, 7, 11, 95, 141, 231, 843, 709, 2993]),
+    (12, 772, &[1, 3, 1, 15, 23, 41, 45, 173, 171, 375, 421, 871]),
+    (12, 803, &[1, 1, 1, 5, 31, 19, 115, 79, 509, 491, 1761, 3501]),
+    (12, 810, &[1, 1, 1, 15, 17, 19, 71, 23, 155, 963, 917, 2563]),
+    (12, 812, &[1, 1, 7, 7, 9, 45, 61, 3, 51, 1023, 665, 2685]),
+    (12, 850, &[1, 1, 5, 1, 25, 53, 27, 203, 141, 121, 1089, 969]),
+    (12, 862, &[1, 3, 7, 9, 27, 23, 93, 255, 83, 239, 567, 995]),
+    (12, 906, &[1, 1, 3, 3, 5, 57, 5, 37, 77, 441, 611, 791]),
+    (12, 908, &[1, 3, 5, 15, 29, 37, 89, 3, 223, 779, 773, 243]),
+    (12, 929, &[1, 3, 3, 3, 19, 21, 3, 111, 127, 155, 1203, 2523]),
+    (12, 930, &[1, 1, 7, 3, 17, 7, 47, 183, 193, 209, 457, 1275]),
+    (12, 954, &[1, 1, 3, 15, 29, 5, 37, 173, 265, 847, 401, 643]),
+    (12, 964, &[1, 3, 7, 7, 31, 19, 23, 181, 25, 261, 255, 111]),
+    (12, 982, &[1, 3, 7, 7, 29, 31, 81, 127, 295, 853, 1435, 2321]),
+    (12, 985, &[1, 3, 5, 11, 13, 51, 127, 239, 511, 125, 2045, 2275]),
+    (12, 991, &[1, 1, 7, 11, 19, 11, 7, 75, 227, 847, 1241, 1969]),
+    (12, 992, &[1, 1, 1, 1, 17, 13, 99, 165, 285, 623, 739, 2679]),
+    (12, 1067, &[1, 1, 5, 1, 25, 29, 19, 61, 235, 63, 1175, 2975]),
+    (12, 1070, &[1, 1, 1, 13, 29, 43, 13, 19, 251, 719, 1715, 767]),
+    (12, 1096, &[1, 1, 7, 5, 29, 13, 75, 215, 133, 985, 1671, 2459]),
+    (12, 1099, &[1, 1, 3, 13, 27, 51, 77, 137, 91, 615, 219, 1131]),
+    (12, 1116, &[1, 1, 5, 3, 21, 35, 89, 1, 99, 739, 431, 3277]),
+    (12, 1143, &[1, 3, 1, 3, 23, 9, 63, 201, 145, 361, 1203, 4027]),
+    (12, 1165, &[1, 1, 7, 5, 19, 49, 111, 83, 213, 289, 643, 519]),
+    (12, 1178, &[1, 1, 1, 9, 21, 31, 31, 17, 71, 499, 1649, 4013]),
+    (12, 1184, &[1, 1, 1, 3, 1, 43, 37, 159, 399, 881, 791, 3329]),
+    (12, 1202, &[1, 3, 1, 11, 29, 25, 117, 207, 61, 985, 79, 2895]),
+    (12, 1213, &[1, 3, 1, 9, 13, 21, 61, 45, 119, 1019, 373, 2951]),
+    (12, 1221, &[1, 3, 1, 15, 13, 57, 117, 45, 257, 271, 1237, 1709]),
+    (12, 1240, &[1, 1, 3, 9, 13, 39, 79, 69, 411, 309, 343, 165]),
+    (12, 1246, &[1, 1, 5, 13, 11, 39, 113, 147, 461, 347, 513, 1045]),
+    (12, 1252, &[1, 1, 3, 7, 5, 23, 107, 37, 253, 921, 567, 163]),
+    (12, 1255, &[1, 3, 7, 11, 1, 19, 67, 9, 11, 287, 573, 3529]),
+    (12, 1267, &[1, 1, 5, 3, 15, 39, 101, 249, 423, 469, 935, 1201]),
+    (12, 1293, &[1, 1, 7, 11, 27, 35, 79, 133, 265, 375, 1133, 1125]),
+    (12, 1301, &[1, 1, 7, 15, 7, 35, 19, 211, 255, 111, 1047, 3423]),
+    (12, 1305, &[1, 3, 5, 7, 15, 27, 81, 41, 375, 989, 1659, 3015]),
+    (12, 1332, &[1, 1, 3, 11, 15, 47, 105, 153, 37, 177, 2041, 2119]),
+    (12, 1349, &[1, 1, 3, 13, 23, 7, 113, 231, 423, 953, 391, 3077]),
+    (12, 1384, &[1, 3, 7, 13, 27, 53, 53, 169, 13, 657, 789, 3251]),
+    (12, 1392, &[1, 1, 7, 5, 19, 5, 57, 227, 399, 1017, 103, 359]),
+    (12, 1402, &[1, 3, 7, 11, 15, 25, 71, 19, 217, 457, 411, 1721]),
+    (12, 1413, &[1, 3, 5, 5, 23, 47, 109, 189, 359, 793, 1519, 3413]),
+    (12, 1417, &[1, 1, 5, 9, 9, 1, 109, 61, 467, 845, 891, 3709]),
+    (12, 1423, &[1, 3, 3, 11, 31, 35, 7, 19, 71, 15, 647, 1907]),
+    (12, 1451, &[1, 3, 1, 1, 5, 1, 57, 29, 353, 367, 1149, 85]),
+    (12, 1480, &[1, 3, 5, 7, 9, 31, 85, 159, 187, 139, 1851, 2277]),
+    (12, 1491, &[1, 3, 1, 9, 27, 35, 125, 89, 239, 679, 251, 619]),
+    (12, 1503, &[1, 1, 7, 9, 13, 63, 73, 129, 311, 537, 1493, 2303]),
+    (12, 1504, &[1, 3, 3, 11, 11, 11, 41, 81, 365, 583, 83, 1635]),
+    (12, 1513, &[1, 3, 3, 11, 7, 55, 43, 107, 159, 927, 425, 2731]),
+    (12, 1538, &[1, 1, 5, 9, 7, 59, 79, 229, 17, 525, 769, 2727]),
+    (12, 1544, &[1, 3, 1, 15, 1, 19, 89, 99, 301, 395, 1025, 2345]),
+    (12, 1547, &[1, 1, 5, 1, 13, 47, 17, 13, 509, 987, 1877, 3371]),
+    (12, 1555, &[1, 3, 5, 11, 21, 31, 41, 179, 33, 337, 1747, 3071]),
+    (12, 1574, &[1, 1, 1, 11, 13, 39, 125, 189, 475, 749, 577, 1781]),
+    (12, 1603, &[1, 1, 7, 5, 5, 63, 51, 193, 433, 535, 339, 3537]),
+    (12, 1615, &[1, 3, 5, 1, 31, 57, 95, 105, 119, 919, 1697, 2845]),
+    (12, 1618, &[1, 3, 5, 15, 17, 63, 99, 139, 259, 749, 1929, 3415]),
+    (12, 1629, &[1, 3, 5, 7, 13, 43, 55, 177, 35, 179, 1587, 415]),
+    (12, 1634, &[1, 3, 5, 7, 7, 29, 43, 121, 221, 653, 249, 1849]),
+    (12, 1636, &[1, 3, 3, 7, 29, 61, 105, 1, 435, 893, 1059, 123]),
+    (12, 1639, &[1, 1, 3, 9, 1, 55, 87, 87, 163, 867, 357, 1543]),
+    (12, 1657, &[1, 1, 1, 5, 11, 59, 27, 209, 191, 783, 753, 2117]),
+    (12, 1667, &[1, 3, 1, 5, 9, 23, 1, 213, 379, 677, 569, 2173]),
+    (12, 1681, &[1, 3, 7, 15, 3, 11, 13, 203, 11, 539, 207, 2079]),
+    (12, 1697, &[1, 1, 1, 13, 7, 5, 91, 223, 119, 507, 1425, 379]),
+    (12, 1704, &[1, 3, 5, 1, 27, 27, 111, 137, 449, 835, 1245, 213]),
+    (12, 1709, &[1, 1, 3, 15, 15, 25, 93, 251, 107, 823, 2001, 2639]),
+    (12, 1722, &[1, 3, 7, 11, 17, 33, 33, 161, 105, 581, 857, 543]),
+    (12, 1730, &[1, 3, 1, 1, 23, 19, 9, 117, 501, 743, 867, 1093]),
+    (12, 1732, &[1, 3, 7, 11, 3, 1, 71, 49, 315, 245, 1877, 2155]),
+    (12, 1802, &[1, 1, 5, 5, 19, 11, 61, 235, 425, 711, 611, 4055]),
+    (12, 1804, &[1, 1, 7, 1, 17, 33, 11, 169, 427, 629, 1401, 3683]),
+    (12, 1815, &[1, 3, 1, 13, 21, 3, 45, 55, 103, 167, 1631, 2115]),
+    (12, 1826, &[1, 3, 3, 5, 19, 1, 91, 181, 383, 817, 943, 2153]),
+    (12, 1832, &[1, 3, 1, 7, 7, 29, 25, 91, 69, 597, 105, 649]),
+    (12, 1843, &[1, 1, 5, 3, 1, 7, 95, 77, 465, 123, 1603, 4079]),
+    (12, 1849, &[1, 1, 7, 15, 9, 29, 47, 175, 279, 893, 1787, 135]),
+    (12, 1863, &[1, 1, 3, 3, 5, 33, 49, 223, 173, 901, 195, 1603]),
+    (12, 1905, &[1, 3, 3, 1, 31, 31, 43, 83, 207, 83, 619, 2143]),
+    (12, 1928, &[1, 1, 5, 5, 1, 23, 55, 91, 267, 433, 1459, 3609]),
+    (12, 1933, &[1, 1, 1, 11, 7, 17, 53, 209, 145, 73, 1585, 2925]),
+    (12, 1939, &[1, 1, 5, 15, 27, 9, 15, 245, 195, 907, 693, 3601]),
+    (12, 1976, &[1, 3, 3, 3, 29, 57, 3, 77, 363, 873, 1015, 1]),
+    (12, 1996, &[1, 1, 7, 3, 17, 35, 49, 169, 43, 935, 1345, 2651]),
+    (12, 2013, &[1, 3, 7, 11, 1, 13, 29, 21, 481, 339, 449, 1347]),
+    (12, 2014, &[1, 1, 7, 3, 1, 37, 55, 165, 461, 751, 1187, 3723]),
+    (12, 2020, &[1, 1, 3, 5, 17, 49, 27, 119, 363, 105, 449, 4003]),
+    (13, 13, &[1, 3, 7, 13, 3, 53, 3, 13, 17, 157, 453, 3191, 2543]),
+    (13, 19, &[1, 3, 5, 13, 5, 63, 105, 85, 235, 719, 1755, 3639, 5963]),
+    (13, 26, &[1, 1, 5, 9, 23, 19, 79, 75, 467, 17, 289, 2451, 7393]),
+    (13, 41, &[1, 1, 7, 1, 29, 9, 109, 45, 339, 669, 1081, 2689, 5653]),
+    (13, 50, &[1, 3, 1, 15, 3, 53, 25, 41, 275, 261, 1383, 637, 1497]),
+    (13, 55, &[1, 1, 7, 9, 31, 55, 85, 185, 271, 465, 155, 3979, 6197]),
+    (13, 69, &[1, 3, 7, 3, 29, 59, 69, 91, 253, 265, 1683, 3103, 4359]),
+    (13, 70, &[1, 1, 7, 11, 11, 63, 67, 115, 299, 397, 1191, 2527, 2253]),
+    (13, 79, &[1, 3, 7, 15, 7, 43, 29, 89, 221, 325, 7, 183, 1079]),
+    (13, 82, &[1, 3, 1, 9, 21, 21, 69, 41, 31, 211, 1947, 3269, 189]),
+    (13, 87, &[1, 3, 3, 3, 7, 9, 1, 171, 221, 393, 1883, 501, 1271]),
+    (13, 93, &[1, 1, 7, 7, 3, 33, 83, 89, 387, 469, 119, 2215, 1175]),
+    (13, 94, &[1, 1, 1, 13, 9, 17, 37, 7, 97, 753, 1573, 2105, 2953]),
+    (13, 97, &[1, 3, 1, 3, 9, 55, 111, 45, 259, 1009, 415, 397, 3535]),
+    (13, 100, &[1, 1, 7, 9, 19, 45, 75, 215, 509, 919, 481, 2499, 3825]),
+    (13, 112, &[1, 3, 5, 13, 31, 23, 97, 229, 299, 705, 1661, 353, 6531]),
+    (13, 121, &[1, 1, 3, 7, 21, 3, 121, 241, 247, 411, 1161, 2769, 6117]),
+    (13, 134, &[1, 3, 7, 7, 7, 63, 119, 157, 433, 507, 1873, 2303, 1039]),
+    (13, 138, &[1, 3, 5, 3, 13, 19, 95, 155, 411, 69, 141, 3149, 3957]),
+    (13, 148, &[1, 1, 3, 5, 21, 21, 83, 119, 371, 921, 927, 2617, 1677]),
+    (13, 151, &[1, 1, 1, 13, 11, 9, 91, 55, 481, 383, 805, 2179, 755]),
+    (13, 157, &[1, 3, 1, 7, 3, 13, 73, 245, 347, 141, 1797, 1547, 7399]),
+    (13, 161, &[1, 1, 1, 7, 23, 41, 125, 117, 127, 135, 2015, 2081, 3057]),
+    (13, 179, &[1, 3, 7, 1, 1, 63, 27, 69, 511, 809, 1239, 1823, 4145]),
+    (13, 181, &[1, 3, 5, 1, 27, 55, 89, 133, 349, 247, 103, 1879, 5209]),
+    (13, 188, &[1, 3, 1, 9, 5, 5, 89, 189, 345, 567, 1171, 3019, 3197]),
+    (13, 196, &[1, 3, 5, 7, 23, 31, 121, 87, 401, 363, 1433, 867, 1573]),
+    (13, 203, &[1, 1, 7, 5, 17, 41, 73, 99, 443, 489, 1203, 155, 6811]),
+    (13, 206, &[1, 1, 3, 13, 21, 57, 41, 141, 111, 203, 1795, 3117, 7509]),
+    (13, 223, &[1, 3, 5, 9, 5, 25, 75, 33, 139, 3, 763, 799, 7641]),
+    (13, 224, &[1, 1, 1, 7, 7, 3, 79, 19, 419, 53, 2013, 1875, 3901]),
+    (13, 227, &[1, 1, 7, 5, 3, 29, 105, 115, 255, 543, 203, 2987, 4093]),
+    (13, 230, &[1, 1, 5, 9, 7, 23, 121, 71, 219, 11, 883, 3973, 6587]),
+    (13, 239, &[1, 1, 3, 13, 9, 51, 77, 201, 95, 763, 1839, 2785, 5461]),
+    (13, 241, &[1, 1, 3, 1, 1, 49, 47, 51, 219, 959, 249, 1049, 3089]),
+    (13, 248, &[1, 3, 1, 1, 9, 35, 105, 15, 131, 927, 665, 3245, 917]),
+    (13, 253, &[1, 1, 1, 9, 19, 13, 29, 75, 461, 939, 1733, 313, 2651]),
+    (13, 268, &[1, 3, 3, 3, 17, 15, 123, 17, 183, 951, 73, 441, 1115]),
+    (13, 274, &[1, 3, 3, 1, 3, 17, 19, 79, 427, 9, 1665, 397, 6063]),
+    (13, 283, &[1, 1, 7, 9, 5, 21, 89, 23, 109, 301, 903, 609, 581]),
+    (13, 286, &[1, 1, 5, 5, 25, 13, 39, 201, 317, 559, 275, 3217, 297]),
+    (13, 289, &[1, 3, 5, 11, 3, 37, 123, 109, 269, 15, 159, 831, 6873]),
+    (13, 301, &[1, 1, 5, 5, 3, 21, 43, 145, 199, 603, 167, 1335, 7741]),
+    (13, 302, &[1, 1, 3, 5, 7, 3, 87, 65, 91, 591, 1771, 1479, 1069]),
+    (13, 316, &[1, 3, 7, 11, 1, 61, 61, 127, 463, 625, 173, 3677, 6241]),
+    (13, 319, &[1, 1, 7, 13, 17, 35, 111, 9, 157, 749, 1553, 1453, 3879]),
+    (13, 324, &[1, 1, 1, 15, 29, 19, 113, 225, 47, 147, 491, 2569, 5917]),
+    (13, 331, &[1, 1, 3, 11, 13, 61, 119, 175, 403, 449, 1019, 397, 3823]),
+    (13, 333, &[1, 1, 1, 15, 11, 57, 65, 199, 133, 673, 901, 147, 1203]),
+    (13, 345, &[1, 3, 5, 1, 25, 51, 91, 255, 457, 171, 1795, 2173, 2477]),
+    (13, 351, &[1, 3, 7, 3, 3, 19, 1, 43, 143, 663, 1219, 411, 4473]),
+    (13, 358, &[1, 1, 7, 3, 21, 21, 55, 243, 13, 441, 627, 919, 2161]),
+    (13, 375, &[1, 1, 3, 7, 21, 61, 101, 205, 493, 491, 1689, 709, 6433]),
+    (13, 379, &[1, 1, 1, 11, 17, 35, 79, 37, 193, 1005, 1763, 1919, 7921]),
+    (13, 381, &[1, 1, 7, 9, 19, 3, 127, 239, 395, 889, 951, 2465, 1331]),
+    (13, 386, &[1, 3, 7, 3, 5, 47, 49, 239, 393, 623, 667, 2527, 2445]),
+    (13, 403, &[1, 3, 1, 11, 11, 37, 65, 145, 463, 201, 249, 2649, 365]),
+    (13, 405, &[1, 3, 1, 15, 13, 1, 81, 187, 51, 267, 1721, 1701, 4809]),
+    (13, 419, &[1, 3, 5, 5, 11, 61, 103, 159, 303, 949, 515, 599, 7955]),
+    (13, 426, &[1, 1, 7, 1, 19, 29, 29, 159, 375, 325, 1439, 3205, 4413]),
+    (13, 428, &[1, 3, 7, 3, 25, 25, 13, 163, 3, 583, 1507, 3257, 5473]),
+    (13, 439, &[1, 1, 7, 13, 21, 63, 95, 85, 257, 679, 355, 3665, 6189]),
+    (13, 440, &[1, 1, 5, 11, 7, 33, 121, 35, 403, 537, 869, 755, 1655]),
+    (13, 446, &[1, 3, 3, 13, 7, 41, 51, 189, 319, 693, 657, 501, 2711]),
+    (13, 451, &[1, 3, 1, 9, 27, 45, 13, 39, 235, 899, 1653, 2041, 1303]),
+    (13, 454, &[1, 3, 5, 9, 7, 47, 97, 51, 7, 287, 229, 2981, 1555]),
+    (13, 458, &[1, 3, 5, 9, 25, 51, 37, 95, 23, 3, 1147, 2707, 7207]),
+    (13, 465, &[1, 1, 1, 7, 27, 13, 3, 223, 237, 1021, 1139, 3339, 4497]),
+    (13, 468, &[1, 1, 1, 3, 13, 55, 119, 67, 509, 459, 1817, 679, 3843]),
+    (13, 472, &[1, 1, 3, 5, 31, 11, 31, 191, 155, 163, 707, 551, 1219]),
+    (13, 475, &[1, 3, 7, 5, 17, 3, 81, 9, 449, 763, 167, 1223, 7503]),
+    (13, 477, &[1, 3, 5, 11, 27, 1, 127, 155, 121, 289, 1339, 2337, 5651]),
+    (13, 496, &[1, 3, 7, 3, 27, 33, 31, 23, 85, 925, 451, 1233, 3897]),
+    (13, 502, &[1, 3, 5, 3, 1, 31, 93, 145, 105, 253, 525, 2073, 5033]),
+    (13, 508, &[1, 1, 1, 3, 3, 25, 107, 155, 91, 755, 791, 1441, 2315]),
+    (13, 517, &[1, 1, 5, 9, 5, 41, 43, 209, 157, 565, 1473, 843, 6893]),
+    (13, 521, &[1, 1, 1, 7, 9, 27, 53, 121, 105, 657, 365, 3871, 2273]),
+    (13, 527, &[1, 3, 5, 15, 3, 43, 81, 205, 95, 171, 339, 1761, 5069]),
+    (13, 530, &[1, 3, 3, 15, 23, 1, 11, 155, 121, 373, 87, 2423, 1957]),
+    (13, 532, &[1, 3, 1, 15, 1, 19, 41, 29, 51, 727, 419, 1889, 3407]),
+    (13, 542, &[1, 1, 3, 5, 19, 13, 123, 255, 319, 881, 1479, 3197, 2341]),
+    (13, 552, &[1, 1, 7, 3, 3, 15, 87, 243, 159, 11, 1835, 2245, 5845]),
+    (13, 555, &[1, 1, 7, 11, 11, 21, 115, 29, 179, 1011, 197, 1077, 7805]),
+    (13, 560, &[1, 3, 3, 7, 29, 61, 69, 243, 291, 249, 1577, 885, 2771]),
+    (13, 566, &[1, 1, 5, 13, 31, 31, 47, 141, 99, 111, 1895, 3961, 1863]),
+    (13, 575, &[1, 1, 1, 1, 31, 41, 39, 145, 7, 783, 281, 299, 1961]),
+    (13, 577, &[1, 1, 5, 1, 7, 63, 85, 195, 33, 853, 467, 3793, 5005]),
+    (13, 589, &[1, 1, 5, 13, 29, 27, 119, 209, 481, 703, 1635, 3631, 3475]),
+    (13, 590, &[1, 1, 5, 9, 1, 61, 117, 39, 511, 969, 167, 2455, 1701]),
+    (13, 602, &[1, 3, 3, 5, 21, 1, 107, 75, 351, 769, 175, 511, 157]),
+    (13, 607, &[1, 1, 5, 9, 11, 55, 121, 239, 15, 443, 1209, 3793, 2701]),
+    (13, 608, &[1, 1, 1, 3, 15, 23, 1, 219, 173, 993, 607, 1739, 3143]),
+    (13, 611, &[1, 3, 5, 1, 25, 17, 101, 153, 395, 729, 357, 2551, 1509]),
+    (13, 613, &[1, 1, 7, 1, 17, 63, 43, 255, 205, 53, 333, 3519, 7205]),
+    (13, 625, &[1, 3, 5, 9, 27, 21, 113, 197, 387, 825, 1591, 3979, 2197]),
+    (13, 644, &[1, 3, 1, 11, 9, 35, 51, 11, 135, 591, 1195, 115, 4851]),
+    (13, 651, &[1, 1, 7, 5, 13, 7, 83, 131, 469, 527, 83, 2707, 2919]),
+    (13, 654, &[1, 1, 1, 5, 1, 53, 75, 241, 321, 47, 551, 1235, 2651]),
+    (13, 656, &[1, 1, 7, 1, 19, 21, 111, 139, 273, 939, 429, 2191, 7647]),
+    (13, 662, &[1, 1, 7, 7, 19, 63, 81, 53, 201, 225, 569, 2781, 2185]),
+    (13, 668, &[1, 3, 7, 1, 19, 13, 39, 25, 189, 433, 1355, 2321, 3395]),
+    (13, 681, &[1, 1, 7, 7, 29, 9, 123, 27, 227, 979, 1341, 679, 8099]),
+    (13, 682, &[1, 1, 3, 9, 21, 1, 113, 45, 331, 333, 1271, 3199, 7079]),
+    (13, 689, &[1, 1, 1, 5, 27, 53, 19, 155, 195, 763, 231, 3975, 313]),
+    (13, 696, &[1, 1, 1, 5, 11, 23, 1, 189, 59, 743, 1563, 3607, 5323]),
+    (13, 699, &[1, 3, 3, 9, 11, 57, 71, 19, 437, 707, 647, 707, 7207]),
+    (13, 707, &[1, 3, 1, 7, 11, 11, 33, 129, 485, 807, 673, 101, 645]),
+    (13, 709, &[1, 1, 7, 5, 27, 35, 57, 43, 199, 7, 1431, 2253, 7389]),
+    (13, 714, &[1, 1, 7, 15, 23, 49, 15, 19, 269, 375, 1461, 463, 6445]),
+    (13, 716, &[1, 1, 5, 13, 25, 37, 37, 49, 287, 689, 1109, 2493, 7215]),
+    (13, 719, &[1, 1, 3, 11, 27, 15, 77, 73, 499, 789, 851, 2853, 7731]),
+    (13, 727, &[1, 1, 1, 13, 3, 33, 85, 97, 173, 853, 1827, 3197, 7301]),
+    (13, 734, &[1, 3, 3, 5, 11, 31, 43, 219, 137, 673, 1797, 3131, 227]),
+    (13, 738, &[1, 3, 1, 5, 9, 35, 81, 55, 159, 135, 1143, 191, 5375]),
+    (13, 743, &[1, 3, 7, 7, 13, 17, 99, 201, 41, 593, 1885, 3059, 5989]),
+    (13, 747, &[1, 3, 5, 5, 11, 3, 39, 91, 267, 449, 43, 3499, 7761]),
+    (13, 757, &[1, 1, 7, 7, 19, 15, 89, 39, 383, 961, 999, 865, 711]),
+    (13, 769, &[1, 1, 3, 7, 11, 15, 21, 97, 405, 575, 795, 925, 283]),
+    (13, 770, &[1, 1, 5, 5, 21, 3, 95, 153, 435, 353, 307, 2977, 2423]),
+    (13, 776, &[1, 1, 3, 9, 25, 27, 69, 253, 57, 917, 827, 1709, 6657]),
+    (13, 790, &[1, 3, 1, 11, 11, 61, 79, 161, 123, 995, 541, 2297, 1257]),
+    (13, 799, &[1, 3, 3, 11, 19, 31, 93, 107, 67, 627, 111, 1071, 519]),
+    (13, 805, &[1, 3, 7, 1, 11, 11, 77, 181, 179, 91, 1011, 309, 2807]),
+    (13, 809, &[1, 3, 3, 3, 29, 43, 73, 11, 357, 631, 251, 3355, 2081]),
+    (13, 812, &[1, 3, 3, 7, 7, 57, 45, 115, 417, 199, 151, 3335, 1661]),
+    (13, 820, &[1, 3, 3, 7, 29, 39, 95, 155, 501, 655, 59, 1835, 3493]),
+    (13, 827, &[1, 3, 7, 5, 29, 17, 55, 179, 455, 353, 115, 531, 3447]),
+    (13, 829, &[1, 3, 1, 7, 25, 5, 7, 247, 315, 737, 1901, 179, 6035]),
+    (13, 835, &[1, 3, 1, 5, 1, 51, 121, 159, 113, 537, 965, 2547, 4417]),
+    (13, 841, &[1, 1, 5, 5, 13, 47, 123, 201, 201, 845, 1243, 1523, 1625]),
+    (13, 844, &[1, 1, 5, 9, 11, 9, 103, 223, 345, 355, 921, 2495, 77]),
+    (13, 856, &[1, 1, 3, 11, 27, 37, 49, 245, 225, 789, 547, 4023, 4489]),
+    (13, 859, &[1, 1, 1, 5, 15, 55, 89, 89, 417, 323, 177, 337, 3667]),
+    (13, 862, &[1, 3, 3, 15, 11, 21, 63, 183, 495, 481, 1547, 1479, 7629]),
+    (13, 865, &[1, 3, 3, 3, 21, 63, 53, 111, 103, 563, 1135, 567, 1843]),
+    (13, 885, &[1, 1, 7, 7, 5, 23, 71, 207, 469, 175, 1663, 3203, 2747]),
+    (13, 890, &[1, 3, 7, 9, 19, 51, 123, 181, 411, 677, 633, 3805, 5889]),
+    (13, 905, &[1, 1, 1, 9, 29, 35, 13, 177, 215, 707, 111, 2495, 1677]),
+    (13, 916, &[1, 1, 3, 13, 27, 53, 33, 235, 397, 923, 893, 907, 557]),
+    (13, 925, &[1, 3, 5, 5, 23, 9, 47, 219, 249, 617, 1509, 2015, 6737]),
+    (13, 935, &[1, 3, 5, 9, 1, 23, 87, 1, 441, 827, 941, 587, 2127]),
+    (13, 939, &[1, 1, 3, 3, 9, 53, 69, 147, 393, 539, 551, 787, 7349]),
+    (13, 942, &[1, 3, 5, 15, 13, 49, 45, 1, 421, 959, 1313, 923, 1373]),
+    (13, 949, &[1, 3, 3, 7, 7, 43, 3, 207, 137, 661, 849, 455, 6149]),
+    (13, 953, &[1, 3, 3, 5, 15, 1, 29, 41, 241, 889, 1935, 2385, 4963]),
+    (13, 956, &[1, 3, 5, 13, 31, 23, 91, 205, 421, 819, 429, 3375, 2037]),
+    (13, 961, &[1, 1, 5, 11, 7, 55, 109, 195, 469, 923, 725, 1985, 175]),
+    (13, 968, &[1, 1, 5, 15, 21, 7, 1, 167, 49, 933, 1955, 381, 8159]),
+    (13, 976, &[1, 1, 7, 11, 23, 39, 117, 1, 263, 697, 773, 3635, 7343]),
+    (13, 988, &[1, 1, 1, 13, 13, 13, 31, 167, 377, 123, 1531, 3489, 5403]),
+    (13, 995, &[1, 3, 1, 9, 13, 41, 69, 71, 171, 439, 373, 1527, 3229]),
+    (13, 997, &[1, 1, 7, 9, 15, 59, 67, 85, 159, 707, 1079, 2223, 1183]),
+    (13, 1007, &[1, 1, 1, 5, 23, 63, 31, 107, 163, 231, 1607, 3723, 2615]),
+    (13, 1015, &[1, 3, 1, 15, 5, 5, 53, 37, 461, 207, 1111, 4079, 4817]),
+    (13, 1016, &[1, 3, 7, 11, 25, 49, 61, 183, 175, 535, 707, 151, 4791]),
+    (13, 1027, &[1, 1, 1, 13, 23, 37, 45, 237, 131, 111, 655, 3147, 5555]),
+    (13, 1036, &[1, 1, 5, 7, 5, 57, 17, 153, 41, 221, 1069, 3621, 2681]),
+    (13, 1039, &[1, 3, 3, 15, 3, 57, 11, 247, 343, 97, 1681, 1053, 3221]),
+    (13, 1041, &[1, 1, 1, 7, 17, 17, 59, 247, 449, 359, 349, 1151, 1239]),
+    (13, 1048, &[1, 3, 5, 11, 21, 55, 127, 143, 179, 275, 633, 3085, 5877]),
+    (13, 1053, &[1, 1, 1, 11, 11, 53, 105, 17, 451, 737, 1309, 1415, 4045]),
+    (13, 1054, &[1, 3, 3, 5, 3, 61, 55, 57, 469, 239, 71, 1575, 1355]),
+    (13, 1058, &[1, 3, 7, 5, 3, 55, 47, 107, 69, 845, 1507, 249, 3801]),
+    (13, 1075, &[1, 1, 1, 11, 31, 51, 115, 27, 115, 819, 1941, 3419, 3169]),
+    (13, 1082, &[1, 1, 7, 1, 3, 13, 31, 237, 437, 359, 749, 2505, 6779]),
+    (13, 1090, &[1, 3, 7, 13, 13, 29, 1, 43, 271, 469, 1359, 2981, 3571]),
+    (13, 1109, &[1, 1, 5, 3, 21, 33, 87, 65, 41, 17, 1081, 2865, 1991]),
+    (13, 1110, &[1, 3, 7, 11, 31, 43, 99, 99, 273, 527, 1963, 1749, 7865]),
+    (13, 1119, &[1, 1, 7, 1, 3, 35, 53, 253, 423, 845, 499, 1761, 5101]),
+    (13, 1126, &[1, 1, 1, 11, 15, 15, 45, 127, 411, 233, 1473, 3467, 5457]),
+    (13, 1130, &[1, 1, 1, 7, 15, 17, 15, 89, 207, 255, 1303, 797, 7035]),
+    (13, 1135, &[1, 1, 3, 9, 23, 33, 15, 201, 9, 117, 773, 4083, 5101]),
+    (13, 1137, &[1, 1, 3, 1, 13, 13, 109, 113, 413, 851, 165, 1797, 2315]),
+    (13, 1140, &[1, 1, 1, 1, 29, 57, 73, 87, 463, 933, 1971, 2445, 5183]),
+    (13, 1149, &[1, 1, 5, 9, 25, 41, 19, 87, 355, 225, 785, 55, 1847]),
+    (13, 1156, &[1, 3, 1, 9, 31, 17, 63, 51, 109, 413, 175, 2159, 6591]),
+    (13, 1159, &[1, 3, 1, 15, 27, 63, 97, 151, 337, 459, 1657, 1845, 6203]),
+    (13, 1160, &[1, 3, 3, 5, 17, 7, 43, 225, 357, 963, 381, 3039, 7657]),
+    (13, 1165, &[1, 3, 5, 9, 25, 17, 109, 209, 221, 705, 2045, 579, 1095]),
+    (13, 1173, &[1, 3, 1, 1, 21, 11, 45, 7, 483, 189, 1253, 3949, 7955]),
+    (13, 1178, &[1, 3, 5, 11, 31, 57, 29, 133, 317, 811, 1621, 1705, 4805]),
+    (13, 1183, &[1, 1, 1, 7, 7, 21, 13, 55, 353, 967, 233, 3337, 2131]),
+    (13, 1184, &[1, 1, 5, 5, 5, 31, 111, 75, 51, 899, 2037, 1883, 2389]),
+    (13, 1189, &[1, 3, 5, 5, 21, 43, 121, 41, 457, 631, 891, 2285, 3997]),
+    (13, 1194, &[1, 3, 3, 15, 27, 17, 67, 231, 331, 549, 185, 1225, 1057]),
+    (13, 1211, &[1, 3, 5, 15, 27, 55, 57, 141, 3, 555, 1655, 523, 4975]),
+    (13, 1214, &[1, 3, 3, 5, 15, 13, 15, 195, 343, 181, 1981, 2563, 4471]),
+    (13, 1216, &[1, 3, 5, 15, 11, 45, 81, 49, 153, 645, 273, 3123, 7115]),
+    (13, 1225, &[1, 3, 5, 7, 31, 59, 15, 103, 177, 989, 67, 1235, 1979]),
+    (13, 1231, &[1, 3, 7, 5, 3, 37, 89, 7, 241, 153, 385, 2403, 2865]),
+    (13, 1239, &[1, 1, 3, 9, 29, 9, 9, 131, 329, 875, 1949, 1139, 5883]),
+    (13, 1243, &[1, 3, 5, 5, 13, 3, 55, 201, 423, 681, 101, 277, 4375]),
+    (13, 1246, &[1, 3, 5, 3, 9, 21, 7, 147, 133, 737, 189, 3309, 4565]),
+    (13, 1249, &[1, 3, 3, 9, 5, 11, 115, 249, 423, 557, 1857, 2175, 6415]),
+    (13, 1259, &[1, 1, 1, 3, 27, 63, 71, 209, 191, 7, 295, 1653, 3449]),
+    (13, 1273, &[1, 1, 5, 5, 21, 53, 71, 29, 153, 345, 1883, 2661, 5649]),
+    (13, 1274, &[1, 3, 1, 5, 25, 15, 57, 193, 79, 791, 1233, 1945, 6071]),
+    (13, 1281, &[1, 1, 5, 9, 25, 41, 97, 165, 477, 671, 219, 667, 6495]),
+    (13, 1287, &[1, 3, 1, 11, 17, 17, 15, 217, 29, 1017, 519, 1523, 3417]),
+    (13, 1294, &[1, 3, 3, 11, 21, 37, 7, 171, 417, 47, 1263, 2375, 7189]),
+    (13, 1296, &[1, 3, 7, 13, 7, 7, 15, 113, 219, 631, 2019, 3901, 291]),
+    (13, 1305, &[1, 3, 1, 15, 31, 29, 123, 127, 247, 227, 1691, 2503, 5269]),
+    (13, 1306, &[1, 3, 7, 13, 21, 29, 79, 175, 193, 857, 1271, 2671, 891]),
+    (13, 1318, &[1, 3, 7, 1, 29, 21, 37, 217, 403, 793, 799, 489, 1131]),
+    (13, 1332, &[1, 1, 3, 9, 21, 43, 83, 251, 413, 847, 1249, 1769, 6021]),
+    (13, 1335, &[1, 1, 3, 5, 21, 23, 59, 53, 277, 639, 547, 1767, 2795]),
+    (13, 1336, &[1, 3, 5, 3, 23, 33, 89, 57, 11, 315, 1537, 3549, 5575]),
+    (13, 1341, &[1, 3, 5, 9, 29, 1, 19, 45, 293, 799, 169, 2665, 5917]),
+    (13, 1342, &[1, 3, 5, 7, 5, 3, 123, 71, 33, 445, 95, 879, 2761]),
+    (13, 1362, &[1, 1, 5, 13, 19, 13, 71, 35, 411, 437, 847, 2505, 3451]),
+    (13, 1364, &[1, 1, 5, 7, 23, 25, 95, 199, 363, 249, 1561, 2645, 6625]),
+    (13, 1368, &[1, 1, 1, 7, 3, 55, 95, 7, 251, 823, 1241, 3009, 4247]),
+    (13, 1378, &[1, 3, 1, 3, 19, 5, 27, 13, 225, 581, 1241, 3249, 1129]),
+    (13, 1387, &[1, 3, 1, 7, 27, 13, 69, 65, 15, 487, 1645, 2705, 2565]),
+    (13, 1389, &[1, 3, 7, 5, 19, 5, 33, 171, 175, 599, 1483, 1585, 5597]),
+    (13, 1397, &[1, 3, 3, 5, 9, 39, 107, 21, 449, 871, 1321, 2163, 6979]),
+    (13, 1401, &[1, 1, 7, 1, 9, 33, 39, 109, 113, 305, 179, 1763, 7951]),
+    (13, 1408, &[1, 3, 1, 1, 3, 39, 77, 171, 19, 727, 403, 2001, 4351]),
+    (13, 1418, &[1, 3, 1, 7, 3, 7, 45, 99, 127, 223, 175, 943, 2825]),
+    (13, 1425, &[1, 3, 7, 15, 27, 7, 33, 221, 47, 727, 155, 3851, 7733]),
+    (13, 1426, &[1, 3, 5, 13, 13, 41, 71, 103, 383, 865, 223, 1631, 243]),
+    (13, 1431, &[1, 3, 1, 5, 27, 57, 25, 25, 503, 937, 415, 829, 827]),
+    (13, 1435, &[1, 3, 7, 9, 15, 49, 93, 197, 181, 349, 1559, 779, 5133]),
+    (13, 1441, &[1, 3, 7, 15, 29, 13, 91, 175, 325, 811, 1261, 37, 5125]),
+    (13, 1444, &[1, 3, 1, 11, 17, 47, 111, 225, 149, 17, 1889, 3583, 1989]),
+    (13, 1462, &[1, 1, 5, 1, 23, 29, 75, 165, 267, 137, 1481, 291, 4449]),
+    (13, 1471, &[1, 3, 7, 3, 29, 51, 69, 9, 87, 239, 679, 3091, 5131]),
+    (13, 1474, &[1, 3, 7, 3, 1, 15, 41, 223, 177, 977, 1303, 349, 5503]),
+    (13, 1483, &[1, 1, 1, 9, 25, 27, 47, 45, 99, 539, 2031, 2417, 2595]),
+    (13, 1485, &[1, 1, 3, 13, 25, 11, 89, 249, 267, 321, 1747, 1055, 8023]),
+    (13, 1494, &[1, 3, 3, 15, 1, 57, 113, 61, 277, 771, 1901, 3219, 7243]),
+    (13, 1497, &[1, 1, 7, 15, 5, 27, 19, 123, 215, 627, 1359, 1221, 5517]),
+    (13, 1516, &[1, 3, 5, 1, 17, 1, 27, 217, 403, 813, 331, 1449, 6147]),
+    (13, 1522, &[1, 3, 5, 11, 13, 59, 89, 239, 23, 3, 827, 3613, 3617]),
+    (13, 1534, &[1, 1, 5, 5, 13, 61, 23, 183, 341, 151, 575, 811, 589]),
+    (13, 1543, &[1, 3, 3, 3, 19, 25, 119, 201, 229, 797, 667, 159, 7327]),
+    (13, 1552, &[1, 1, 7, 13, 9, 31, 7, 185, 263, 669, 1137, 793, 2751]),
+    (13, 1557, &[1, 1, 5, 1, 1, 29, 7, 105, 239, 771, 1797, 3899, 5669]),
+    (13, 1558, &[1, 3, 7, 11, 3, 27, 13, 115, 355, 37, 1941, 3787, 1015]),
+    (13, 1567, &[1, 1, 3, 15, 29, 3, 39, 251, 303, 845, 147, 2275, 403]),
+    (13, 1568, &[1, 3, 3, 7, 9, 21, 13, 3, 249, 969, 759, 2867, 4293]),
+    (13, 1574, &[1, 1, 7, 9, 25, 59, 111, 233, 211, 43, 1595, 4071, 2297]),
+    (13, 1592, &[1, 3, 7, 15, 23, 51, 101, 175, 435, 725, 57, 793, 8031]),
+    (13, 1605, &[1, 1, 7, 7, 13, 53, 29, 63, 501, 695, 1859, 3821, 7473]),
+    (13, 1606, &[1, 3, 3, 11, 31, 37, 29, 151, 251, 27, 883, 565, 5233]),
+    (13, 1610, &[1, 1, 3, 13, 5, 43, 69, 59, 145, 625, 1613, 1505, 8043]),
+    (13, 1617, &[1, 3, 1, 3, 15, 57, 101, 213, 467, 1013, 1971, 1661, 4913]),
+    (13, 1623, &[1, 1, 7, 9, 23, 47, 127, 77, 61, 713, 789, 3667, 207]),
+    (13, 1630, &[1, 1, 5, 7, 27, 15, 41, 221, 373, 907, 869, 3, 7171]),
+    (13, 1634, &[1, 1, 1, 3, 3, 7, 7, 141, 455, 629, 1225, 817, 311]),
+    (13, 1640, &[1, 3, 3, 11, 29, 31, 21, 39, 21, 905, 1191, 3087, 6007]),
+    (13, 1643, &[1, 1, 1, 1, 23, 61, 33, 103, 143, 671, 1339, 1, 2529]),
+    (13, 1648, &[1, 1, 1, 15, 15, 19, 77, 153, 439, 237, 1501, 3575, 5037]),
+    (13, 1651, &[1, 1, 7, 11, 23, 7, 13, 185, 99, 7, 783, 2105, 4233]),
+    (13, 1653, &[1, 1, 7, 9, 11, 17, 119, 111, 505, 719, 275, 1761, 5773]),
+    (13, 1670, &[1, 1, 5, 15, 27, 27, 3, 159, 389, 671, 1267, 3803, 7463]),
+    (13, 1676, &[1, 1, 1, 5, 11, 63, 55, 229, 509, 63, 991, 2217, 1689]),
+    (13, 1684, &[1, 3, 5, 7, 21, 55, 111, 59, 63, 319, 1789, 1777, 2845]),
+    (13, 1687, &[1, 1, 1, 7, 9, 17, 1, 53, 193, 385, 195, 1059, 1931]),
+    (13, 1691, &[1, 1, 3, 3, 29, 55, 47, 253, 203, 895, 145, 3217, 4233]),
+    (13, 1693, &[1, 3, 5, 11, 29, 59, 105, 167, 113, 593, 557, 3257, 641]),
+    (13, 1698, &[1, 1, 3, 11, 31, 43, 107, 37, 79, 691, 991, 2633, 5181]),
+    (13, 1709, &[1, 1, 7, 11, 19, 27, 107, 185, 379, 45, 1549, 273, 4755]),
+    (13, 1715, &[1, 1, 5, 15, 23, 41, 113, 229, 345, 475, 769, 775, 2177]),
+    (13, 1722, &[1, 3, 7, 1, 13, 21, 43, 1, 313, 679, 739, 4071, 4473]),
+    (13, 1732, &[1, 3, 3, 13, 9, 47, 47, 3, 129, 421, 1519, 3797, 1481]),
+    (13, 1735, &[1, 1, 1, 3, 21, 49, 21, 251, 351, 945, 807, 1361, 795]),
+    (13, 1747, &[1, 3, 1, 3, 29, 49, 101, 109, 79, 699, 1855, 1761, 2541]),
+    (13, 1749, &[1, 3, 1, 11, 13, 11, 1, 145, 469, 551, 1547, 1655, 7451]),
+    (13, 1754, &[1, 1, 3, 11, 15, 57, 99, 117, 49, 869, 1931, 2357, 4487]),
+    (13, 1777, &[1, 3, 1, 3, 27, 9, 115, 185, 249, 553, 1973, 1691, 7895]),
+    (13, 1784, &[1, 3, 1, 3, 23, 29, 53, 179, 63, 867, 213, 933, 3555]),
+    (13, 1790, &[1, 1, 7, 5, 1, 37, 7, 45, 129, 877, 1251, 2411, 5817]),
+    (13, 1795, &[1, 1, 3, 15, 13, 27, 117, 253, 491, 441, 1691, 1207, 7359]),
+    (13, 1801, &[1, 1, 7, 9, 1, 3, 49, 177, 361, 251, 595, 3287, 5839]),
+    (13, 1802, &[1, 3, 1, 5, 3, 21, 7, 147, 297, 983, 441, 3323, 1699]),
+    (13, 1812, &[1, 3, 7, 1, 7, 49, 113, 173, 383, 489, 1651, 1007, 2825]),
+    (13, 1828, &[1, 3, 7, 7, 25, 21, 125, 137, 381, 495, 1125, 653, 7753]),
+    (13, 1831, &[1, 1, 3, 11, 19, 25, 67, 199, 289, 991, 1513, 1555, 63]),
+    (13, 1837, &[1, 3, 5, 5, 25, 51, 59, 123, 67, 659, 407, 517, 4289]),
+    (13, 1838, &[1, 1, 7, 1, 23, 21, 99, 67, 423, 677, 825, 2031, 4577]),
+    (13, 1840, &[1, 1, 3, 11, 19, 15, 87, 221, 355, 489, 123, 2059, 795]),
+    (13, 1845, &[1, 1, 3, 11, 3, 39, 93, 173, 287, 69, 845, 1243, 1715]),
+    (13, 1863, &[1, 3, 3, 9, 3, 57, 97, 245, 35, 811, 573, 3225, 2017]),
+    (13, 1864, &[1, 3, 5, 11, 3, 19, 9, 211, 205, 677, 929, 615, 2883]),
+    (13, 1867, &[1, 3, 7, 5, 31, 17, 71, 19, 423, 949, 347, 233, 905]),
+    (13, 1870, &[1, 3, 5, 15, 3, 25, 119, 115, 21, 569, 317, 1471, 5877]),
+    (13, 1877, &[1, 1, 3, 15, 25, 41, 7, 101, 331, 965, 851, 725, 5777]),
+    (13, 1881, &[1, 1, 5, 9, 17, 19, 79, 133, 481, 151, 127, 3263, 7783]),
+    (13, 1884, &[1, 1, 1, 7, 5, 49, 77, 3, 89, 445, 1679, 563, 8151]),
+    (13, 1903, &[1, 3, 1, 1, 27, 49, 73, 147, 181, 361, 475, 3385, 5795]),
+    (13, 1917, &[1, 1, 7, 5, 3, 47, 9, 147, 281, 943, 633, 2669, 2937]),
+    (13, 1918, &[1, 3, 3, 15, 15, 31, 79, 251, 145, 497, 1299, 3369, 6259]),
+    (13, 1922, &[1, 3, 7, 7, 3, 27, 105, 199, 431, 725, 1889, 3423, 2981]),
+    (13, 1924, &[1, 1, 3, 7, 31, 9, 27, 223, 19, 323, 521, 381, 7295]),
+    (13, 1928, &[1, 3, 7, 5, 7, 3, 81, 209, 19, 867, 1157, 3325, 2245]),
+    (13, 1931, &[1, 1, 3, 3, 25, 39, 41, 35, 193, 437, 807, 3537, 363]),
+    (13, 1951, &[1, 3, 5, 11, 13, 55, 117, 13, 381, 599, 1507, 633, 8157]),
+    (13, 1952, &[1, 1, 3, 13, 29, 15, 37, 131, 351, 589, 65, 3985, 3759]),
+    (13, 1957, &[1, 1, 7, 7, 7, 9, 119, 87, 331, 193, 1857, 127, 8031]),
+    (13, 1958, &[1, 1, 7, 1, 19, 61, 25, 51, 359, 485, 1623, 3819, 1463]),
+    (13, 1964, &[1, 1, 1, 5, 1, 61, 55, 251, 23, 807, 193, 3153, 1673]),
+    (13, 1967, &[1, 1, 1, 5, 29, 47, 15, 55, 145, 797, 891, 2317, 3935]),
+    (13, 1970, &[1, 1, 5, 5, 27, 5, 91, 137, 9, 365, 1735, 2443, 6009]),
+    (13, 1972, &[1, 1, 5, 3, 19, 47, 127, 23, 443, 1009, 639, 1915, 6997]),
+    (13, 1994, &[1, 3, 5, 7, 3, 63, 45, 41, 509, 1015, 801, 519, 5059]),
+    (13, 2002, &[1, 1, 5, 5, 5, 3, 93, 173, 165, 517, 1849, 3559, 4125]),
+    (13, 2007, &[1, 1, 3, 7, 7, 57, 99, 91, 281, 791, 889, 2255, 7711]),
+    (13, 2008, &[1, 1, 7, 13, 3, 17, 89, 221, 275, 425, 1507, 1817, 3191]),
+    (13, 2023, &[1, 3, 7, 1, 13, 47, 59, 21, 329, 771, 1365, 2047, 4401]),
+    (13, 2030, &[1, 3, 1, 11, 3, 49, 69, 1, 229, 793, 223, 847, 2761]),
+    (13, 2035, &[1, 3, 3, 7, 15, 29, 37, 35, 337, 7, 1399, 1965, 5379]),
+    (13, 2038, &[1, 3, 3, 9, 13, 43, 35, 141, 417, 859, 1621, 1849, 4439]),
+    (13, 2042, &[1, 1, 1, 7, 21, 37, 35, 131, 95, 435, 425, 1595, 6743]),
+    (13, 2047, &[1, 3, 7, 9, 23, 45, 57, 183, 503, 537, 613, 3761, 4525]),
+    (13, 2051, &[1, 1, 7, 11, 11, 47, 69, 53, 127, 151, 479, 125, 1469]),
+    (13, 2058, &[1, 1, 3, 15, 15, 3, 73, 215, 37, 329, 195, 2403, 6417]),
+    (13, 2060, &[1, 1, 5, 7, 27, 21, 7, 25, 103, 955, 173, 1421, 5255]),
+    (13, 2071, &[1, 1, 7, 15, 19, 1, 91, 149, 99, 119, 895, 1929, 5575]),
+    (13, 2084, &[1, 1, 1, 1, 7, 61, 91, 51, 235, 911, 729, 1213, 1397]),
+    (13, 2087, &[1, 1, 3, 13, 9, 37, 11, 63, 483, 829, 395, 1513, 4419]),
+    (13, 2099, &[1, 3, 3, 11, 17, 5, 35, 105, 291, 941, 555, 2015, 5521]),
+    (13, 2108, &[1, 1, 7, 3, 15, 3, 35, 65, 493, 7, 1407, 501, 3511]),
+    (13, 2111, &[1, 3, 7, 13, 19, 59, 119, 217, 317, 867, 1053, 517, 993]),
+    (13, 2120, &[1, 3, 7, 7, 27, 1, 29, 187, 107, 623, 1011, 1477, 5935]),
+    (13, 2128, &[1, 1, 5, 5, 17, 17, 99, 53, 467, 209, 1601, 3421, 903]),
+    (13, 2138, &[1, 1, 3, 13, 13, 35, 19, 33, 37, 673, 1061, 3261, 8133]),
+    (13, 2143, &[1, 3, 5, 1, 1, 11, 111, 135, 117, 557, 2009, 1783, 5083]),
+    (13, 2144, &[1, 3, 7, 5, 7, 39, 57, 147, 241, 297, 1971, 3965, 7267]),
+    (13, 2153, &[1, 1, 5, 15, 31, 39, 119, 205, 433, 975, 569, 173, 1677]),
+    (13, 2156, &[1, 3, 3, 5, 19, 11, 61, 209, 45, 829, 1851, 1541, 233]),
+    (13, 2162, &[1, 1, 7, 1, 31, 17, 7, 43, 463, 285, 223, 831, 8025]),
+    (13, 2167, &[1, 3, 3, 3, 15, 61, 89, 143, 321, 91, 1395, 1801, 7719]),
+    (13, 2178, &[1, 1, 5, 3, 5, 45, 53, 21, 205, 241, 689, 1413, 6575]),
+    (13, 2183, &[1, 1, 1, 9, 9, 31, 39, 103, 265, 859, 533, 1521, 6491]),
+    (13, 2202, &[1, 3, 7, 7, 17, 27, 87, 135, 355, 625, 1053, 3957, 3467]),
+    (13, 2211, &[1, 1, 1, 11, 11, 9, 81, 141, 271, 821, 371, 2321, 3619]),
+    (13, 2214, &[1, 3, 1, 5, 15, 1, 49, 199, 147, 763, 727, 2179, 51]),
+    (13, 2223, &[1, 1, 1, 7, 25, 23, 51, 185, 57, 661, 1457, 2043, 341]),
+    (13, 2225, &[1, 3, 5, 13, 1, 25, 39, 143, 55, 707, 239, 495, 6829]),
+    (13, 2232, &[1, 3, 7, 9, 1, 27, 125, 87, 509, 715, 1727, 371, 6321]),
+    (13, 2237, &[1, 1, 7, 3, 23, 23, 87, 7, 379, 497, 1099, 1715, 511]),
+    (13, 2257, &[1, 3, 1, 11, 7, 59, 109, 225, 263, 41, 553, 2973, 1899]),
+    (13, 2260, &[1, 3, 7, 9, 31, 9, 9, 17, 71, 631, 1515, 2513, 7299]),
+    (13, 2267, &[1, 1, 3, 7, 29, 17, 99, 149, 473, 587, 255, 3807, 6275]),
+    (13, 2274, &[1, 1, 3, 15, 9, 23, 33, 179, 249, 453, 1071, 1015, 1105]),
+    (13, 2276, &[1, 3, 1, 1, 1, 45, 95, 127, 227, 571, 223, 2495, 5325]),
+    (13, 2285, &[1, 1, 3, 3, 7, 21, 47, 87, 43, 675, 1807, 1137, 4633]),
+    (13, 2288, &[1, 1, 7, 9, 5, 39, 113, 181, 289, 307, 1145, 769, 6663]),
+    (13, 2293, &[1, 3, 5, 9, 29, 19, 103, 191, 179, 639, 819, 1381, 3087]),
+    (13, 2294, &[1, 3, 7, 5, 19, 49, 105, 167, 369, 819, 1141, 1853, 1267]),
+    (13, 2297, &[1, 1, 7, 7, 9, 27, 95, 105, 117, 837, 227, 2275, 7763]),
+    (13, 2303, &[1, 3, 7, 3, 13, 17, 77, 215, 497, 859, 565, 833, 199]),
+    (13, 2308, &[1, 3, 3, 15, 29, 45, 113, 121, 457, 995, 205, 1421, 8071]),
+    (13, 2311, &[1, 1, 1, 1, 29, 15, 77, 201, 315, 817, 1047, 2303, 3023]),
+    (13, 2318, &[1, 3, 1, 1, 9, 15, 85, 163, 219, 1003, 261, 167, 2439]),
+    (13, 2323, &[1, 1, 1, 5, 13, 45, 61, 69, 371, 857, 1631, 1347, 5161]),
+    (13, 2332, &[1, 3, 1, 11, 9, 47, 3, 119, 25, 901, 1211, 1763, 7311]),
+    (13, 2341, &[1, 3, 3, 5, 13, 19, 5, 75, 511, 565, 569, 4045, 6099]),
+    (13, 2345, &[1, 1, 5, 13, 23, 11, 111, 229, 287, 665, 1453, 2247, 4503]),
+    (13, 2348, &[1, 1, 5, 3, 1, 33, 121, 63, 503, 1001, 599, 1347, 4553]),
+    (13, 2354, &[1, 1, 7, 7, 31, 53, 55, 187, 23, 623, 363, 3065, 7247]),
+    (13, 2368, &[1, 1, 1, 5, 23, 61, 113, 45, 161, 321, 251, 203, 7851]),
+    (13, 2377, &[1, 3, 3, 15, 13, 47, 95, 143, 295, 609, 1965, 755, 5271]),
+    (13, 2380, &[1, 3, 1, 13, 7, 1, 101, 229, 321, 447, 1325, 4007, 7385]),
+    (13, 2383, &[1, 3, 1, 11, 25, 29, 21, 49, 259, 145, 625, 1685, 4153]),
+    (13, 2388, &[1, 1, 5, 3, 29, 13, 11, 191, 359, 833, 2047, 3681, 7933]),
+    (13, 2395, &[1, 1, 1, 7, 15, 23, 5, 243, 87, 11, 835, 741, 4353]),
+    (13, 2397, &[1, 3, 5, 13, 19, 43, 97, 139, 261, 179, 771, 1413, 4463]),
+    (13, 2401, &[1, 3, 7, 13, 17, 57, 49, 229, 89, 369, 1387, 3185, 393]),
+    (13, 2411, &[1, 1, 5, 3, 23, 41, 17, 33, 375, 381, 1775, 2483, 4129]),
+    (13, 2413, &[1, 1, 3, 11, 19, 33, 83, 23, 71, 675, 391, 373, 6359]),
+    (13, 2419, &[1, 3, 7, 11, 15, 53, 51, 79, 171, 535, 1915, 4085, 3435]),
+    (13, 2435, &[1, 1, 7, 13, 31, 35, 65, 71, 431, 737, 557, 3497, 4819]),
+    (13, 2442, &[1, 1, 3, 5, 5, 61, 33, 97, 209, 749, 609, 3173, 7325]),
+    (13, 2455, &[1, 1, 5, 13, 7, 59, 89, 203, 201, 929, 503, 933, 7747]),
+    (13, 2472, &[1, 3, 5, 13, 7, 39, 119, 169, 215, 303, 1221, 381, 4383]),
+    (13, 2478, &[1, 1, 1, 15, 13, 21, 5, 249, 271, 561, 877, 3307, 2067]),
+    (13, 2490, &[1, 3, 5, 15, 13, 21, 89, 173, 27, 671, 1075, 2747, 3695]),
+    (13, 2507, &[1, 3, 3, 7, 29, 5, 13, 81, 485, 419, 865, 3815, 667]),
+    (13, 2509, &[1, 3, 7, 13, 19, 1, 73, 167, 393, 699, 119, 1143, 4607]),
+    (13, 2517, &[1, 3, 7, 5, 29, 59, 65, 97, 335, 173, 989, 3819, 5175]),
+    (13, 2524, &[1, 3, 1, 13, 15, 57, 49, 217, 309, 263, 341, 3159, 5583]),
+    (13, 2528, &[1, 3, 7, 9, 25, 25, 103, 225, 239, 779, 1733, 2553, 779]),
+    (13, 2531, &[1, 1, 5, 15, 27, 23, 63, 191, 301, 371, 1465, 3635, 911]),
+    (13, 2538, &[1, 1, 7, 1, 5, 51, 123, 235, 135, 655, 1963, 1717, 2745]),
+    (13, 2545, &[1, 1, 3, 3, 15, 33, 121, 57, 59, 3, 811, 2429, 5967]),
+    (13, 2546, &[1, 3, 1, 9, 23, 61, 3, 85, 437, 367, 1205, 3485, 4327]),
+    (13, 2555, &[1, 3, 3, 1, 1, 1, 113, 249, 151, 267, 1993, 3799, 6859]),
+    (13, 2557, &[1, 3, 1, 11, 25, 15, 115, 183, 355, 621, 321, 2275, 6835]),
+    (13, 2564, &[1, 3, 3, 1, 21, 17, 55, 179, 463, 957, 2011, 1637, 2153]),
+    (13, 2573, &[1, 1, 5, 15, 3, 53, 117, 221, 157, 345, 1419, 3889, 1775]),
+    (13, 2579, &[1, 3, 3, 13, 7, 5, 39, 49, 77, 597, 1841, 3183, 7255]),
+    (13, 2592, &[1, 1, 7, 9, 25, 3, 69, 61, 407, 277, 1303, 2845, 4907]),
+    (13, 2598, &[1, 3, 7, 5, 31, 17, 113, 85, 433, 143, 2047, 2769, 3845]),
+    (13, 2607, &[1, 1, 7, 11, 3, 23, 101, 197, 353, 763, 95, 1807, 7105]),
+    (13, 2612, &[1, 3, 3, 3, 23, 57, 47, 25, 311, 981, 171, 2555, 7785]),
+    (13, 2619, &[1, 3, 1, 5, 25, 19, 75, 77, 281, 483, 1705, 3333, 4479]),
+    (13, 2621, &[1, 1, 7, 5, 23, 43, 35, 13, 39, 715, 471, 1829, 6603]),
+    (13, 2627, &[1, 3, 1, 9, 7, 1, 9, 203, 295, 189, 1013, 2979, 7157]),
+    (13, 2633, &[1, 3, 7, 11, 5, 29, 65, 85, 167, 47, 1183, 1111, 383]),
+    (13, 2636, &[1, 1, 1, 15, 11, 25, 81, 197, 253, 5, 1685, 2745, 8141]),
+    (13, 2642, &[1, 3, 5, 13, 7, 51, 127, 239, 197, 449, 523, 4047, 7505]),
+    (13, 2654, &[1, 1, 5, 1, 25, 9, 5, 223, 3, 103, 1975, 921, 8191]),
+    (13, 2660, &[1, 1, 1, 3, 27, 63, 65, 183, 223, 763, 1759, 227, 6243]),
+    (13, 2669, &[1, 3, 7, 1, 15, 21, 41, 143, 131, 763, 1847, 2137, 6985]),
+    (13, 2675, &[1, 1, 7, 5, 31, 15, 105, 35, 331, 737, 1829, 2859, 829]),
+    (13, 2684, &[1, 3, 1, 1, 7, 13, 41, 203, 253, 285, 935, 3601, 7245]),
+    (13, 2694, &[1, 1, 1, 3, 15, 7, 43, 71, 479, 701, 1001, 1087, 1751]),
+    (13, 2703, &[1, 3, 3, 5, 25, 55, 83, 57, 389, 327, 1437, 983, 977]),
+    (13, 2706, &[1, 3, 5, 11, 1, 51, 85, 241, 57, 773, 1091, 2219, 6043]),
+    (13, 2712, &[1, 3, 7, 7, 25, 1, 45, 141, 121, 1005, 347, 1529, 3443]),
+    (13, 2715, &[1, 3, 1, 11, 19, 57, 7, 153, 321, 459, 1369, 3315, 2283]),
+    (13, 2722, &[1, 3, 5, 5, 17, 13, 37, 71, 371, 925, 1235, 1083, 4147]),
+    (13, 2727, &[1, 1, 1, 7, 3, 51, 43, 197, 191, 183, 1663, 147, 7987]),
+    (13, 2734, &[1, 3, 5, 9, 15, 11, 85, 1, 7, 459, 721, 133, 3045]),
+    (13, 2742, &[1, 3, 1, 9, 25, 33, 105, 91, 481, 419, 1609, 2069, 2693]),
+    (13, 2745, &[1, 3, 7, 5, 11, 37, 1, 165, 415, 313, 1027, 507, 409]),
+    (13, 2751, &[1, 3, 3, 3, 15, 45, 125, 11, 265, 587, 1791, 1331, 2233]),
+    (13, 2766, &[1, 3, 1, 7, 15, 39, 121, 53, 371, 13, 1109, 1885, 7603]),
+    (13, 2768, &[1, 1, 7, 13, 7, 33, 55, 141, 49, 257, 1195, 2723, 6903]),
+    (13, 2780, &[1, 3, 1, 15, 7, 31, 37, 89, 255, 63, 495, 1485, 6279]),
+    (13, 2790, &[1, 3, 5, 7, 23, 41, 19, 119, 185, 245, 365, 2687, 7371]),
+    (13, 2794, &[1, 3, 5, 7, 1, 63, 51, 147, 415, 989, 1379, 1913, 2263]),
+    (13, 2796, &[1, 1, 1, 7, 11, 5, 119, 5, 429, 283, 889, 367, 7053]),
+    (13, 2801, &[1, 1, 5, 9, 13, 13, 119, 93, 491, 561, 1285, 3359, 4869]),
+    (13, 2804, &[1, 1, 3, 7, 31, 33, 41, 69, 257, 383, 869, 3843, 2493]),
+    (13, 2807, &[1, 3, 3, 3, 19, 63, 31, 185, 163, 771, 1597, 3361, 5179]),
+    (13, 2816, &[1, 3, 5, 15, 29, 21, 55, 241, 183, 515, 991, 169, 8105]),
+    (13, 2821, &[1, 1, 3, 11, 31, 63, 109, 47, 481, 851, 597, 801, 7143]),
+    (13, 2831, &[1, 3, 7, 7, 23, 23, 1, 243, 133, 9, 1177, 3905, 6815]),
+    (13, 2834, &[1, 1, 3, 3, 13, 43, 91, 87, 243, 659, 1727, 835, 6405]),
+    (13, 2839, &[1, 1, 3, 15, 21, 23, 81, 41, 321, 543, 235, 2987, 3485]),
+    (13, 2845, &[1, 1, 1, 3, 1, 25, 69, 7, 75, 663, 1907, 2893, 5467]),
+    (13, 2852, &[1, 3, 3, 1, 17, 27, 121, 49, 247, 63, 1047, 703, 4635]),
+    (13, 2856, &[1, 1, 7, 13, 31, 37, 93, 161, 431, 169, 1921, 2879, 3945]),
+    (13, 2861, &[1, 1, 3, 7, 17, 19, 29, 133, 369, 123, 1979, 965, 3171]),
+    (13, 2873, &[1, 1, 7, 3, 23, 55, 107, 113, 115, 971, 149, 789, 627]),
+    (13, 2874, &[1, 1, 3, 3, 29, 45, 89, 159, 307, 129, 353, 2179, 1043]),
+    (13, 2888, &[1, 1, 1, 15, 29, 29, 115, 171, 77, 117, 421, 3303, 4863]),
+    (13, 2893, &[1, 3, 3, 5, 9, 63, 85, 247, 145, 257, 451, 353, 8131]),
+    (13, 2894, &[1, 1, 1, 5, 1, 51, 125, 223, 329, 135, 1111, 2719, 1419]),
+    (13, 2902, &[1, 1, 1, 9, 29, 15, 59, 217, 137, 365, 95, 239, 6031]),
+    (13, 2917, &[1, 1, 7, 13, 27, 31, 9, 157, 439, 233, 881, 3895, 809]),
+    (13, 2921, &[1, 3, 3, 9, 27, 3, 71, 199, 453, 575, 1051, 1539, 5647]),
+    (13, 2922, &[1, 1, 1, 1, 21, 3, 5, 207, 491, 389, 1695, 1795, 2125]),
+    (13, 2929, &[1, 1, 7, 3, 19, 35, 15, 221, 243, 151, 1607, 1163, 7203]),
+    (13, 2935, &[1, 3, 3, 1, 15, 41, 103, 199, 453, 203, 1099, 947, 7925]),
+    (13, 2946, &[1, 1, 1, 7, 29, 33, 47, 247, 165, 173, 381, 3071, 6921]),
+    (13, 2951, &[1, 1, 7, 3, 23, 19, 49, 13, 233, 665, 301, 671, 3355]),
+    (13, 2957, &[1, 1, 7, 15, 21, 29, 81, 113, 131, 939, 1069, 629, 113]),
+    (13, 2960, &[1, 1, 7, 9, 11, 9, 103, 219, 401, 999, 1497, 547, 551]),
+    (13, 2966, &[1, 1, 1, 7, 17, 21, 95, 243, 69, 457, 915, 2501, 23]),
+    (13, 2972, &[1, 1, 3, 1, 3, 3, 7, 217, 187, 299, 129, 1649, 1821]),
+    (13, 2976, &[1, 3, 7, 11, 21, 39, 61, 189, 249, 613, 1225, 2897, 1575]),
+    (13, 2979, &[1, 3, 1, 3, 17, 25, 109, 173, 371, 781, 1339, 3169, 7033]),
+    (13, 2985, &[1, 1, 7, 7, 3, 15, 99, 93, 293, 3, 1363, 713, 6829]),
+    (13, 3000, &[1, 3, 5, 11, 27, 1, 47, 59, 299, 305, 35, 2419, 7937]),
+    (13, 3003, &[1, 1, 3, 9, 31, 25, 113, 171, 499, 733, 2009, 2743, 7939]),
+    (13, 3013, &[1, 1, 5, 11, 15, 43, 25, 211, 213, 65, 737, 2245, 7797]),
+    (13, 3018, &[1, 1, 3, 13, 19, 61, 57, 91, 135, 983, 1409, 763, 8095]),
+    (13, 3020, &[1, 1, 7, 13, 19, 5, 89, 187, 271, 861, 1507, 2729, 1373]),
+    (13, 3025, &[1, 1, 7, 3, 5, 9, 55, 47, 433, 199, 479, 3437, 4119]),
+    (13, 3042, &[1, 3, 5, 9, 27, 23, 57, 213, 399, 941, 1587, 1759, 6355]),
+    (13, 3047, &[1, 1, 3, 9, 21, 35, 53, 65, 255, 199, 1247, 935, 1281]),
+    (13, 3048, &[1, 3, 7, 11, 25, 45, 25, 225, 75, 851, 533, 977, 3447]),
+    (13, 3051, &[1, 3, 1, 5, 19, 47, 105, 137, 335, 377, 843, 3325, 229]),
+    (13, 3054, &[1, 1, 1, 3, 17, 11, 29, 217, 153, 597, 681, 2557, 367]),
+    (13, 3056, &[1, 1, 1, 9, 17, 59, 113, 65, 43, 571, 79, 3055, 2073]),
+    (13, 3065, &[1, 3, 7, 13, 15, 33, 41, 1, 325, 587, 1927, 1977, 3799]),
+    (13, 3073, &[1, 3, 7, 7, 31, 27, 63, 141, 337, 295, 1221, 3117, 1109]),
+    (13, 3074, &[1, 3, 1, 9, 25, 31, 95, 187, 225, 289, 603, 2207, 1359]),
+    (13, 3083, &[1, 3, 7, 13, 5, 59, 29, 1, 153, 401, 563, 1943, 4185]),
+    (13, 3086, &[1, 1, 3, 3, 1, 37, 117, 57, 311, 757, 1337, 2675, 7303]),
+    (13, 3091, &[1, 1, 3, 7, 1, 29, 95, 35, 507, 677, 249, 3097, 2017]),
+    (13, 3097, &[1, 1, 7, 15, 29, 53, 43, 165, 447, 197, 1981, 963, 6853]),
+    (13, 3109, &[1, 1, 7, 5, 3, 59, 49, 187, 189, 405, 1863, 2297, 4329]),
+    (13, 3116, &[1, 1, 7, 1, 31, 21, 105, 133, 345, 559, 1107, 2667, 319]),
+    (13, 3124, &[1, 1, 1, 9, 15, 39, 31, 49, 45, 613, 1489, 2635, 7391]),
+    (13, 3128, &[1, 1, 5, 7, 3, 53, 75, 153, 483, 517, 1473, 875, 5997]),
+    (13, 3153, &[1, 1, 7, 9, 13, 21, 77, 99, 97, 837, 1635, 1023, 7321]),
+    (13, 3160, &[1, 3, 1, 9, 21, 23, 121, 127, 115, 259, 1345, 2315, 4089]),
+    (13, 3165, &[1, 3, 5, 11, 27, 9, 69, 235, 367, 9, 377, 2715, 1679]),
+    (13, 3172, &[1, 1, 5, 9, 15, 21, 127, 91, 191, 539, 1015, 3729, 6925]),
+    (13, 3175, &[1, 1, 5, 7, 5, 61, 5, 1, 95, 383, 1477, 319, 7069]),
+    (13, 3184, &[1, 3, 1, 5, 15, 35, 111, 111, 499, 309, 7, 983, 945]),
+    (13, 3193, &[1, 1, 7, 5, 29, 1, 111, 215, 331, 557, 1985, 207, 6415]),
+    (13, 3196, &[1, 1, 7, 11, 27, 41, 95, 229, 391, 497, 1451, 2625, 373]),
+    (13, 3200, &[1, 1, 3, 11, 9, 45, 35, 187, 173, 313, 1083, 2115, 1555]),
+    (13, 3203, &[1, 3, 3, 3, 23, 63, 63, 87, 31, 747, 631, 1955, 1325]),
+    (13, 3205, &[1, 3, 7, 11, 9, 33, 97, 185, 9, 553, 845, 3803, 2983]),
+    (13, 3209, &[1, 1, 1, 1, 29, 53, 25, 253, 199, 291, 137, 825, 6111]),
+    (13, 3224, &[1, 1, 5, 11, 11, 7, 39, 213, 309, 63, 1431, 749, 7721]),
+    (13, 3239, &[1, 3, 5, 11, 17, 41, 99, 241, 1, 909, 2035, 861, 3131]),
+    (13, 3251, &[1, 1, 5, 13, 23, 9, 89, 157, 211, 199, 1047, 1743, 1507]),
+    (13, 3254, &[1, 3, 1, 11, 31, 21, 43, 7, 243, 823, 977, 2407, 3317]),
+    (13, 3265, &[1, 1, 1, 1, 11, 55, 61, 51, 83, 875, 1805, 2579, 5127]),
+    (13, 3266, &[1, 1, 1, 5, 21, 63, 47, 113, 65, 789, 2043, 2165, 3621]),
+    (13, 3275, &[1, 3, 5, 1, 19, 1, 97, 63, 25, 701, 145, 2449, 6275]),
+    (13, 3280, &[1, 1, 1, 7, 1, 13, 3, 87, 179, 411, 169, 265, 2283]),
+    (13, 3283, &[1, 3, 7, 9, 15, 15, 103, 93, 139, 923, 141, 3101, 6757]),
+    (13, 3286, &[1, 3, 1, 9, 25, 23, 57, 245, 341, 587, 151, 3815, 6683]),
+    (13, 3301, &[1, 1, 1, 11, 31, 61, 113, 75, 61, 393, 363, 1737, 4869]),
+    (13, 3302, &[1, 1, 1, 3, 17, 55, 51, 79, 15, 3, 483, 129, 1889]),
+    (13, 3305, &[1, 3, 5, 3, 29, 11, 105, 217, 371, 785, 1735, 3403, 4497]),
+    (13, 3319, &[1, 3, 5, 7, 23, 35, 85, 1, 121, 61, 109, 3677, 129]),
+    (13, 3323, &[1, 1, 5, 9, 11, 37, 115, 223, 105, 593, 917, 403, 209]),
+    (13, 3326, &[1, 3, 7, 5, 3, 47, 75, 35, 461, 135, 655, 459, 7849]),
+    (13, 3331, &[1, 1, 5, 13, 5, 57, 69, 227, 159, 507, 1585, 3789, 753]),
+    (13, 3348, &[1, 1, 5, 7, 19, 17, 61, 89, 197, 469, 1479, 2381, 7743]),
+    (13, 3351, &[1, 3, 1, 11, 21, 59, 57, 251, 257, 163, 1971, 1757, 5649]),
+    (13, 3358, &[1, 1, 5, 1, 25, 43, 105, 51, 413, 315, 1305, 2787, 6071]),
+    (13, 3368, &[1, 3, 3, 11, 5, 57, 21, 113, 295, 833, 1991, 2553, 3851]),
+    (13, 3374, &[1, 3, 1, 5, 15, 31, 25, 249, 111, 191, 1095, 751, 8075]),
+    (13, 3376, &[1, 3, 7, 13, 29, 19, 83, 255, 199, 235, 1291, 2881, 3703]),
+    (13, 3379, &[1, 3, 7, 9, 19, 27, 77, 231, 461, 779, 1021, 2875, 333]),
+    (13, 3385, &[1, 3, 7, 1, 1, 41, 79, 109, 479, 113, 1593, 3141, 3997]),
+    (13, 3386, &[1, 3, 7, 3, 3, 31, 127, 75, 423, 729, 1389, 3959, 4233]),
+    (13, 3396, &[1, 3, 1, 11, 27, 29, 117, 43, 273, 623, 1627, 3527, 1533]),
+    (13, 3420, &[1, 1, 3, 7, 27, 41, 43, 117, 219, 11, 467, 465, 2643]),
+    (13, 3423, &[1, 3, 5, 11, 29, 57, 59, 131, 457, 991, 209, 3761, 3853]),
+    (13, 3430, &[1, 1, 7, 13, 31, 41, 29, 175, 113, 549, 1993, 55, 1399]),
+    (13, 3433, &[1, 3, 5, 7, 23, 31, 37, 149, 449, 571, 2023, 2601, 7901]),
+    (13, 3434, &[1, 3, 5, 5, 13, 27, 83, 25, 261, 1023, 505, 331, 5801]),
+    (13, 3439, &[1, 3, 1, 5, 31, 53, 79, 183, 335, 73, 1681, 2525, 1743]),
+    (13, 3442, &[1, 3, 7, 1, 11, 61, 77, 227, 235, 879, 41, 649, 7981]),
+    (13, 3444, &[1, 1, 7, 13, 29, 57, 95, 107, 177, 693, 233, 1401, 3635]),
+    (13, 3453, &[1, 3, 7, 11, 11, 45, 121, 31, 239, 543, 523, 1295, 7717]),
+    (13, 3464, &[1, 3, 7, 1, 21, 53, 41, 89, 23, 815, 697, 3853, 1651]),
+    (13, 3477, &[1, 1, 3, 7, 3, 17, 91, 109, 423, 237, 733, 3049, 6687]),
+    (13, 3478, &[1, 3, 3, 1, 7, 25, 35, 25, 287, 289, 1315, 1257, 2017]),
+    (13, 3482, &[1, 1, 7, 9, 5, 3, 107, 13, 7, 939, 861, 2779, 5583]),
+    (13, 3487, &[1, 3, 3, 5, 13, 29, 43, 79, 135, 295, 821, 707, 2247]),
+    (13, 3497, &[1, 3, 7, 3, 15, 33, 125, 223, 447, 553, 1573, 2185, 793]),
+    (13, 3500, &[1, 3, 7, 15, 9, 33, 115, 21, 145, 165, 1413, 79, 3727]),
+    (13, 3505, &[1, 3, 1, 3, 19, 49, 97, 239, 459, 5, 805, 3811, 3519]),
+    (13, 3506, &[1, 1, 3, 13, 7, 23, 7, 163, 357, 859, 517, 881, 2327]),
+    (13, 3511, &[1, 1, 3, 13, 19, 9, 121, 205, 63, 1015, 809, 143, 7503]),
+    (13, 3512, &[1, 1, 1, 9, 21, 37, 125, 201, 283, 729, 1413, 431, 1165]),
+    (13, 3515, &[1, 3, 3, 9, 31, 47, 119, 195, 41, 37, 1845, 715, 6863]),
+    (13, 3525, &[1, 1, 7, 11, 15, 51, 83, 169, 357, 31, 455, 3189, 6317]),
+    (13, 3532, &[1, 1, 7, 15, 27, 47, 77, 161, 3, 435, 1905, 895, 7737]),
+    (13, 3538, &[1, 1, 5, 1, 29, 31, 91, 237, 279, 433, 2011, 933, 3171]),
+];
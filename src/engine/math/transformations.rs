@@ -1,13 +1,26 @@
 use std::ops::{Index, IndexMut, Mul};
 use nalgebra::Matrix4;
+use crate::engine::Interactions::surface_interaction::{Shading, SurfaceInteraction};
+use crate::engine::math::bounding_box::Bound3f;
+use crate::engine::math::gamma;
+use crate::engine::math::Normal::Normal3f;
 use crate::engine::math::Point::Point3f;
+use crate::engine::math::rays::BaseRay;
+use crate::engine::math::rays::Ray::Ray;
+use crate::engine::math::rays::ray_differential::RayDifferential;
 use crate::engine::math::Vector::Vector3f;
 
-struct Transform{
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Transform{
     mat : Matrix4x4,
     mat_inv : Matrix4x4,
 }
 
+/// Apply a Transform to a geometric entity of type `T`
+pub(crate) trait Apply<T>{
+    fn apply(&self, t : &T) -> T;
+}
+
 impl Transform{
     pub fn new(mat : Matrix4x4, mat_inv : Matrix4x4) -> Transform{
         Self{
@@ -16,7 +29,98 @@ impl Transform{
         }
     }
 
-    pub fn translate(&self, v : Vector3f) -> Transform{
+    pub fn identity() -> Transform{
+        Self{
+            mat: Matrix4x4::identity(),
+            mat_inv: Matrix4x4::identity(),
+        }
+    }
+
+    pub fn inverse(&self) -> Transform{
+        Self{
+            mat: self.mat_inv,
+            mat_inv: self.mat,
+        }
+    }
+
+    pub fn transpose(&self) -> Transform{
+        Self{
+            mat: self.mat.transpose(),
+            mat_inv: self.mat_inv.transpose(),
+        }
+    }
+
+    pub fn is_identity(&self) -> bool{
+        self.mat == Matrix4x4::identity()
+    }
+
+    pub fn get_matrix(&self) -> &Matrix4x4{
+        &self.mat
+    }
+
+    /// Check if the transformation changes a left-handed coordinate system into a right-handed one,
+    /// which happens when the determinant of its upper 3x3 part is negative
+    pub fn swaps_handedness(&self) -> bool{
+        let m = &self.mat;
+        let det = m[(0, 0)] * (m[(1, 1)] * m[(2, 2)] - m[(1, 2)] * m[(2, 1)])
+            - m[(0, 1)] * (m[(1, 0)] * m[(2, 2)] - m[(1, 2)] * m[(2, 0)])
+            + m[(0, 2)] * (m[(1, 0)] * m[(2, 1)] - m[(1, 1)] * m[(2, 0)]);
+        det < 0.0
+    }
+
+    /// Transform a point and bound the rounding error introduced by the transformation
+    pub fn apply_point_with_error(&self, p : &Point3f) -> (Point3f, Vector3f){
+        let m = &self.mat;
+        let x_abs_sum = (m[(0, 0)] * p.x).abs() + (m[(0, 1)] * p.y).abs() + (m[(0, 2)] * p.z).abs() + m[(0, 3)].abs();
+        let y_abs_sum = (m[(1, 0)] * p.x).abs() + (m[(1, 1)] * p.y).abs() + (m[(1, 2)] * p.z).abs() + m[(1, 3)].abs();
+        let z_abs_sum = (m[(2, 0)] * p.x).abs() + (m[(2, 1)] * p.y).abs() + (m[(2, 2)] * p.z).abs() + m[(2, 3)].abs();
+
+        (self.apply(p), Vector3f::new(x_abs_sum, y_abs_sum, z_abs_sum) * gamma(3))
+    }
+
+    /// Transform a point that already carries an error bound, accounting for both
+    /// the propagated error and the rounding error of the transformation
+    pub fn apply_point_with_abs_error(&self, p : &Point3f, p_error : &Vector3f) -> (Point3f, Vector3f){
+        let m = &self.mat;
+        let abs_error = |row : usize, p : &Point3f, e : &Vector3f| {
+            (gamma(3) + 1.0) * (m[(row, 0)].abs() * e.x + m[(row, 1)].abs() * e.y + m[(row, 2)].abs() * e.z)
+                + gamma(3) * ((m[(row, 0)] * p.x).abs() + (m[(row, 1)] * p.y).abs() + (m[(row, 2)] * p.z).abs() + m[(row, 3)].abs())
+        };
+
+        (
+            self.apply(p),
+            Vector3f::new(abs_error(0, p, p_error), abs_error(1, p, p_error), abs_error(2, p, p_error))
+        )
+    }
+
+    /// Transform a vector and bound the rounding error introduced by the transformation
+    pub fn apply_vector_with_error(&self, v : &Vector3f) -> (Vector3f, Vector3f){
+        let m = &self.mat;
+        let x_abs_sum = (m[(0, 0)] * v.x).abs() + (m[(0, 1)] * v.y).abs() + (m[(0, 2)] * v.z).abs();
+        let y_abs_sum = (m[(1, 0)] * v.x).abs() + (m[(1, 1)] * v.y).abs() + (m[(1, 2)] * v.z).abs();
+        let z_abs_sum = (m[(2, 0)] * v.x).abs() + (m[(2, 1)] * v.y).abs() + (m[(2, 2)] * v.z).abs();
+
+        (self.apply(v), Vector3f::new(x_abs_sum, y_abs_sum, z_abs_sum) * gamma(3))
+    }
+
+    /// Transform a ray, returning the error bounds of its new origin and direction.
+    /// The origin is moved to the edge of its error bounds so the ray doesn't start behind the real one
+    pub fn apply_ray_with_error(&self, r : &Ray) -> (Ray, Vector3f, Vector3f){
+        let (mut o, o_error) = self.apply_point_with_error(&r.get_origin());
+        let (d, d_error) = self.apply_vector_with_error(&r.get_direction());
+        let mut t_max = r.get_t_max();
+
+        let length_sq = d.length_sq();
+        if length_sq > 0.0 {
+            let dt = d.abs().dot(&o_error) / length_sq;
+            o = o + d * dt;
+            t_max -= dt;
+        }
+
+        (Ray::new(o, d, t_max, r.get_time(), r.get_medium()), o_error, d_error)
+    }
+
+    pub fn translate(v : Vector3f) -> Transform{
         Self{
             mat:Matrix4x4::new(
                 1.0, 0.0, 0.0, v.x,
@@ -33,7 +137,7 @@ impl Transform{
         }
     }
 
-    pub fn scale(&self, x : f32, y : f32, z : f32) -> Transform{
+    pub fn scale(x : f32, y : f32, z : f32) -> Transform{
         Self{
             mat:Matrix4x4::new(
                 x, 0.0, 0.0, 0.0,
@@ -50,7 +154,7 @@ impl Transform{
         }
     }
 
-    pub fn rotate_x(&self, x : f32) -> Transform{
+    pub fn rotate_x(x : f32) -> Transform{
         let sin_theta = x.to_radians().sin();
         let cos_theta = x.to_radians().cos();
         let mat = Matrix4x4::new(
@@ -65,7 +169,7 @@ impl Transform{
         }
     }
 
-    pub fn rotate_y(&self, x : f32) -> Transform{
+    pub fn rotate_y(x : f32) -> Transform{
         let sin_theta = x.to_radians().sin();
         let cos_theta = x.to_radians().cos();
         let mat = Matrix4x4::new(
@@ -80,7 +184,7 @@ impl Transform{
         }
     }
 
-    pub fn rotate_z(&self, x : f32) -> Transform{
+    pub fn rotate_z(x : f32) -> Transform{
         let sin_theta = x.to_radians().sin();
         let cos_theta = x.to_radians().cos();
         let mat = Matrix4x4::new(
@@ -95,33 +199,36 @@ impl Transform{
         }
     }
 
-    pub fn rotate(&self, theta : f32, axis : &Vector3f) -> Transform{
+    pub fn rotate(theta : f32, axis : &Vector3f) -> Transform{
         let a = axis.normalize();
         let sin_theta = theta.to_radians().sin();
         let cos_theta = theta.to_radians().cos();
         let mut mat = Matrix4x4::zeros();
 
-        mat[0][0] = a.x * a.x + (1.9 - a.x * a.x) * cos_theta;
-        mat[0][1] = a.x * a.y + (1.9 - cos_theta) - a.z * sin_theta;
-        mat[0][2] = a.x * a.z + (1.9 - cos_theta) + a.y * sin_theta;
-        mat[0][3] = 0.0;
+        mat[(0, 0)] = a.x * a.x + (1.0 - a.x * a.x) * cos_theta;
+        mat[(0, 1)] = a.x * a.y * (1.0 - cos_theta) - a.z * sin_theta;
+        mat[(0, 2)] = a.x * a.z * (1.0 - cos_theta) + a.y * sin_theta;
+        mat[(0, 3)] = 0.0;
+
+        mat[(1, 0)] = a.x * a.y * (1.0 - cos_theta) + a.z * sin_theta;
+        mat[(1, 1)] = a.y * a.y + (1.0 - a.y * a.y) * cos_theta;
+        mat[(1, 2)] = a.y * a.z * (1.0 - cos_theta) - a.x * sin_theta;
+        mat[(1, 3)] = 0.0;
 
-        mat[1][0] = a.x * a.y + (1.0 - cos_theta) + a.z * sin_theta;
-        mat[1][1] = a.y * a.y + (1.0 - a.y * a.y) * cos_theta;
-        mat[1][2] = a.y * a.z + (1.0 - cos_theta) - a.x * sin_theta;
-        mat[1][3] = 0.0;
+        mat[(2, 0)] = a.x * a.z * (1.0 - cos_theta) - a.y * sin_theta;
+        mat[(2, 1)] = a.y * a.z * (1.0 - cos_theta) + a.x * sin_theta;
+        mat[(2, 2)] = a.z * a.z + (1.0 - a.z * a.z) * cos_theta;
+        mat[(2, 3)] = 0.0;
 
-        mat[2][0] = a.x * a.z + (1.0 - cos_theta) - a.y * sin_theta;
-        mat[2][1] = a.y * a.z + (1.0 - cos_theta) + a.x * sin_theta;
-        mat[2][2] = a.z * a.z + (1.0 - a.z * a.z) * cos_theta;
-        mat[2][3] = 0.0;
+        mat[(3, 3)] = 1.0;
 
         Transform{
-            mat_inv : mat.inverse(),
+            mat_inv : mat.transpose(),
             mat
         }
     }
 
+    /// Build the camera to world transformation of a camera placed at `pos` looking at `look`
     pub fn look_at(pos : Point3f, look : Point3f, up : &Vector3f) -> Transform{
         let mut cam_to_world = Matrix4x4::zeros();
         let dir = (look - pos).normalize();
@@ -129,23 +236,23 @@ impl Transform{
             .cross(&dir);
         let new_up = dir.cross(&right);
 
-        cam_to_world[0][3] = pos.x;
-        cam_to_world[1][3] = pos.y;
-        cam_to_world[2][3] = pos.z;
-        cam_to_world[3][3] = 1.0;
-
-        cam_to_world[0][0] = right.x;
-        cam_to_world[1][0] = right.y;
-        cam_to_world[2][0] = right.z;
-        cam_to_world[3][0] = 0.;
-        cam_to_world[0][1] = new_up.x;
-        cam_to_world[1][1] = new_up.y;
-        cam_to_world[2][1] = new_up.z;
-        cam_to_world[3][1] = 0.;
-        cam_to_world[0][2] = dir.x;
-        cam_to_world[1][2] = dir.y;
-        cam_to_world[2][2] = dir.z;
-        cam_to_world[3][2] = 0.;
+        cam_to_world[(0, 3)] = pos.x;
+        cam_to_world[(1, 3)] = pos.y;
+        cam_to_world[(2, 3)] = pos.z;
+        cam_to_world[(3, 3)] = 1.0;
+
+        cam_to_world[(0, 0)] = right.x;
+        cam_to_world[(1, 0)] = right.y;
+        cam_to_world[(2, 0)] = right.z;
+        cam_to_world[(3, 0)] = 0.;
+        cam_to_world[(0, 1)] = new_up.x;
+        cam_to_world[(1, 1)] = new_up.y;
+        cam_to_world[(2, 1)] = new_up.z;
+        cam_to_world[(3, 1)] = 0.;
+        cam_to_world[(0, 2)] = dir.x;
+        cam_to_world[(1, 2)] = dir.y;
+        cam_to_world[(2, 2)] = dir.z;
+        cam_to_world[(3, 2)] = 0.;
 
         Transform{
             mat_inv : cam_to_world.inverse(),
@@ -153,10 +260,142 @@ impl Transform{
         }
 
     }
+
+    /// Project the camera space onto the screen, mapping depths between `near` and `far` to [0, 1].
+    /// `fov` is the angle in degrees covered by the narrowest side of the screen window
+    pub fn perspective(fov : f32, near : f32, far : f32) -> Transform{
+        let persp = Matrix4x4::new(
+            1.0, 0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, far / (far - near), -far * near / (far - near),
+            0.0, 0.0, 1.0, 0.0,
+        );
+        let inv_tan_angle = 1.0 / (fov.to_radians() / 2.0).tan();
+        Transform::scale(inv_tan_angle, inv_tan_angle, 1.0) * Transform::new(persp, persp.inverse())
+    }
+
+    /// Keep x and y unchanged while mapping depths between `near` and `far` to [0, 1]
+    pub fn orthographic(near : f32, far : f32) -> Transform{
+        Transform::scale(1.0, 1.0, 1.0 / (far - near)) * Transform::translate(Vector3f::new(0.0, 0.0, -near))
+    }
 }
 
+impl Apply<Point3f> for Transform{
+    fn apply(&self, p : &Point3f) -> Point3f {
+        let m = &self.mat;
+        let xp = m[(0, 0)] * p.x + m[(0, 1)] * p.y + m[(0, 2)] * p.z + m[(0, 3)];
+        let yp = m[(1, 0)] * p.x + m[(1, 1)] * p.y + m[(1, 2)] * p.z + m[(1, 3)];
+        let zp = m[(2, 0)] * p.x + m[(2, 1)] * p.y + m[(2, 2)] * p.z + m[(2, 3)];
+        let wp = m[(3, 0)] * p.x + m[(3, 1)] * p.y + m[(3, 2)] * p.z + m[(3, 3)];
 
-struct Matrix4x4(Matrix4<f32>);
+        if wp == 1.0 {
+            Point3f::new(xp, yp, zp)
+        } else {
+            Point3f::new(xp, yp, zp) / wp
+        }
+    }
+}
+
+impl Apply<Vector3f> for Transform{
+    fn apply(&self, v : &Vector3f) -> Vector3f {
+        let m = &self.mat;
+        Vector3f::new(
+            m[(0, 0)] * v.x + m[(0, 1)] * v.y + m[(0, 2)] * v.z,
+            m[(1, 0)] * v.x + m[(1, 1)] * v.y + m[(1, 2)] * v.z,
+            m[(2, 0)] * v.x + m[(2, 1)] * v.y + m[(2, 2)] * v.z,
+        )
+    }
+}
+
+/// Normals are transformed by the inverse transpose so that they stay
+/// perpendicular to the transformed surface
+impl Apply<Normal3f> for Transform{
+    fn apply(&self, n : &Normal3f) -> Normal3f {
+        let m_inv = &self.mat_inv;
+        Normal3f::new(
+            m_inv[(0, 0)] * n.x + m_inv[(1, 0)] * n.y + m_inv[(2, 0)] * n.z,
+            m_inv[(0, 1)] * n.x + m_inv[(1, 1)] * n.y + m_inv[(2, 1)] * n.z,
+            m_inv[(0, 2)] * n.x + m_inv[(1, 2)] * n.y + m_inv[(2, 2)] * n.z,
+        )
+    }
+}
+
+impl Apply<Ray> for Transform{
+    fn apply(&self, r : &Ray) -> Ray {
+        let (ray, _, _) = self.apply_ray_with_error(r);
+        ray
+    }
+}
+
+impl Apply<RayDifferential> for Transform{
+    fn apply(&self, r : &RayDifferential) -> RayDifferential {
+        let mut ret = RayDifferential::new(
+            self.apply(&r.get_origin()),
+            self.apply(&r.get_direction()),
+            r.get_t_max(),
+            r.get_time(),
+            r.get_medium()
+        );
+        ret.is_differential = r.is_differential;
+        ret.rx_orig = r.rx_orig.map(|p| self.apply(&p));
+        ret.ry_orig = r.ry_orig.map(|p| self.apply(&p));
+        ret.rx_direction = r.rx_direction.map(|d| self.apply(&d));
+        ret.ry_direction = r.ry_direction.map(|d| self.apply(&d));
+
+        ret
+    }
+}
+
+/// Transform all the eight corners of the box and bound them again
+impl Apply<Bound3f> for Transform{
+    fn apply(&self, b : &Bound3f) -> Bound3f {
+        let mut ret = Bound3f::from_point(&self.apply(&b.corner(0)));
+        for corner in 1..8 {
+            ret = ret.union(&self.apply(&b.corner(corner)));
+        }
+
+        ret
+    }
+}
+
+impl Apply<SurfaceInteraction> for Transform{
+    fn apply(&self, si : &SurfaceInteraction) -> SurfaceInteraction {
+        let mut ret = si.clone();
+        (ret.point, ret.point_error) = self.apply_point_with_abs_error(&si.point, &si.point_error);
+        ret.normal = self.apply(&si.normal).normalize();
+        ret.wo = self.apply(&si.wo).normalize();
+        ret.dp_du = self.apply(&si.dp_du);
+        ret.dp_dv = self.apply(&si.dp_dv);
+        ret.dn_du = self.apply(&si.dn_du);
+        ret.dn_dv = self.apply(&si.dn_dv);
+        ret.shading = Shading{
+            normal: self.apply(&si.shading.normal).normalize(),
+            dp_du: self.apply(&si.shading.dp_du),
+            dp_dv: self.apply(&si.shading.dp_dv),
+            dn_du: self.apply(&si.shading.dn_du),
+            dn_dv: self.apply(&si.shading.dn_dv),
+        };
+        ret.shading.normal = ret.shading.normal.face_forward(&ret.normal);
+
+        ret
+    }
+}
+
+/// Compose two transformations, the right hand side is applied first
+impl Mul for Transform{
+    type Output = Transform;
+
+    fn mul(self, rhs: Transform) -> Self::Output {
+        Transform{
+            mat: self.mat.mul(&rhs.mat),
+            mat_inv: rhs.mat_inv.mul(&self.mat_inv),
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Matrix4x4(Matrix4<f32>);
 
 impl Matrix4x4{
     pub fn new(t00: f32, t01: f32, t02: f32, t03: f32,
@@ -172,6 +411,10 @@ impl Matrix4x4{
         ))
     }
 
+    pub fn identity()->Matrix4x4{
+        Matrix4x4(Matrix4::identity())
+    }
+
     pub fn zeros()->Matrix4x4{
         Matrix4x4(Matrix4::new(
             0.0,0.0,0.0,0.0,
@@ -200,16 +443,84 @@ impl Matrix4x4{
     }
 }
 
-impl Index<usize> for Matrix4x4{
-    type Output = [f32;4];
+impl Index<(usize, usize)> for Matrix4x4{
+    type Output = f32;
 
-    fn index(&self, index: usize) -> &Self::Output {
-        &self[index]
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.0[index]
     }
 }
 
-impl IndexMut<usize> for Matrix4x4{
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self[index]
+impl IndexMut<(usize, usize)> for Matrix4x4{
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_point_eq(a : Point3f, b : Point3f) {
+        assert!(a.distance(b) < 1e-5, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn test_translate_point_and_vector() {
+        let t = Transform::translate(Vector3f::new(1.0, 2.0, 3.0));
+        assert_point_eq(t.apply(&Point3f::new(1.0, 1.0, 1.0)), Point3f::new(2.0, 3.0, 4.0));
+        // Vectors are not affected by translations
+        assert_eq!(t.apply(&Vector3f::new(1.0, 1.0, 1.0)), Vector3f::new(1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn test_compose_and_inverse() {
+        let t = Transform::translate(Vector3f::new(1.0, 0.0, 0.0)) * Transform::rotate_z(90.0);
+        // The rotation is applied first
+        assert_point_eq(t.apply(&Point3f::new(1.0, 0.0, 0.0)), Point3f::new(1.0, 1.0, 0.0));
+        assert_point_eq(t.inverse().apply(&Point3f::new(1.0, 1.0, 0.0)), Point3f::new(1.0, 0.0, 0.0));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_rotate_around_axis_matches_rotate_z() {
+        let p = Point3f::new(0.3, -0.2, 0.7);
+        let a = Transform::rotate(30.0, &Vector3f::new(0.0, 0.0, 2.0)).apply(&p);
+        let b = Transform::rotate_z(30.0).apply(&p);
+        assert_point_eq(a, b);
+    }
+
+    #[test]
+    fn test_normal_stays_perpendicular() {
+        let t = Transform::scale(1.0, 4.0, 1.0);
+        let tangent = Vector3f::new(1.0, 1.0, 0.0);
+        let n = Normal3f::new(1.0, -1.0, 0.0);
+        let transformed_tangent = t.apply(&tangent);
+        assert!(t.apply(&n).dot(&transformed_tangent).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_bound_transform() {
+        let b = Bound3f::from_points(&Point3f::new(-1.0, -1.0, -1.0), &Point3f::new(1.0, 1.0, 1.0));
+        let t = Transform::translate(Vector3f::new(0.0, 0.0, 5.0)) * Transform::scale(2.0, 1.0, 1.0);
+        let tb = t.apply(&b);
+        assert_point_eq(tb.p_min, Point3f::new(-2.0, -1.0, 4.0));
+        assert_point_eq(tb.p_max, Point3f::new(2.0, 1.0, 6.0));
+    }
+
+    #[test]
+    fn test_point_error_bounds_exact_result() {
+        let t = Transform::translate(Vector3f::new(0.1, 0.2, 0.3)) * Transform::scale(3.0, 3.0, 3.0);
+        let p = Point3f::new(1.0 / 3.0, 0.7, -2.5);
+        let (tp, error) = t.apply_point_with_error(&p);
+        assert!(error.x > 0.0 && error.y > 0.0 && error.z > 0.0);
+
+        let exact_x = 1.0_f64 / 3.0 * 3.0 + 0.1;
+        assert!((tp.x as f64 - exact_x).abs() <= error.x as f64 + 1e-7);
+    }
+
+    #[test]
+    fn test_swaps_handedness() {
+        assert!(!Transform::rotate_y(45.0).swaps_handedness());
+        assert!(Transform::scale(1.0, -1.0, 1.0).swaps_handedness());
+    }
+}
@@ -1,22 +1,25 @@
+use std::sync::Arc;
+use crate::engine::accelerators::bvh::{BVHAggregate, SplitMethod};
+use crate::engine::accelerators::kdtree::KdTreeAggregate;
 use crate::engine::primitives::Primitive;
 use crate::engine::lights::Light;
 use crate::engine::math::rays::Ray::Ray;
+pub(crate) use crate::engine::math::bounding_box::Bound3f;
+pub(crate) use crate::engine::Interactions::surface_interaction::SurfaceInteraction;
 
 mod math;
 mod samplers;
 mod lights;
 mod primitives;
 mod Interactions;
+mod accelerators;
+mod materials;
+mod cameras;
+mod film;
+mod filters;
+mod spectrum;
 // Primitive Describe a Shape Geometry and it's Material
 
-struct SurfaceInteraction{
-
-}
-
-struct Bound3f{
-
-}
-
 pub struct Bound2i{
 
 }
@@ -60,4 +63,19 @@ impl<Primitives : Primitive, Lights : Light> Scene<Primitives, Lights> {
     pub fn intersect_p(&self, ray : &Ray) -> bool {
         self.aggregate.intersect_p(ray)
     }
-}
\ No newline at end of file
+}
+
+impl<Lights : Light> Scene<BVHAggregate, Lights> {
+    /// Build the scene over a BVH of the primitives.
+    /// `SplitMethod::Hlbvh` builds much faster, `SplitMethod::Sah` produces hierarchies that trace faster
+    pub fn with_bvh(primitives : Vec<Arc<dyn Primitive>>, lights : Vec<Lights>, split_method : SplitMethod) -> Scene<BVHAggregate, Lights> {
+        Self::new(BVHAggregate::new(primitives, 4, split_method), lights)
+    }
+}
+
+impl<Lights : Light> Scene<KdTreeAggregate, Lights> {
+    /// Build the scene over a kd-tree of the primitives with the default costs
+    pub fn with_kd_tree(primitives : Vec<Arc<dyn Primitive>>, lights : Vec<Lights>) -> Scene<KdTreeAggregate, Lights> {
+        Self::new(KdTreeAggregate::with_defaults(primitives), lights)
+    }
+}
@@ -0,0 +1,173 @@
+use std::f32::consts::PI;
+use std::sync::Arc;
+use crate::engine::{Bound3f, SurfaceInteraction};
+use crate::engine::Interactions::surface_interaction::DifferentialGeometry;
+use crate::engine::math::Normal::Normal3f;
+use crate::engine::math::Point::{Point2f, Point3f};
+use crate::engine::math::efloat::EFloat;
+use crate::engine::math::rays::BaseRay;
+use crate::engine::math::rays::Ray::Ray;
+use crate::engine::math::transformations::{Apply, Transform};
+use crate::engine::math::Vector::Vector3f;
+use crate::engine::primitives::{closest_quadric_hit, ray_components_with_error, ray_equation_error, weingarten, Shape};
+
+/// A cone around the z axis with its base of the given radius at z = 0 and its apex at z = height
+pub(crate) struct Cone{
+    object_to_world : Arc<Transform>,
+    world_to_object : Arc<Transform>,
+    reverse_orientation : bool,
+
+    radius : f32,
+    height : f32,
+    phi_max : f32,
+}
+
+impl Cone{
+    pub fn new(
+        object_to_world : Arc<Transform>, world_to_object : Arc<Transform>, reverse_orientation : bool,
+        height : f32, radius : f32, phi_max : f32
+    ) -> Cone{
+        Self{
+            object_to_world,
+            world_to_object,
+            reverse_orientation,
+            radius,
+            height,
+            phi_max: phi_max.clamp(0.0, 360.0).to_radians(),
+        }
+    }
+
+    fn hit_point(&self, ray : &Ray, t : f32) -> Option<(Point3f, f32)> {
+        let p_hit = ray.point_at(t);
+
+        let mut phi = p_hit.y.atan2(p_hit.x);
+        if phi < 0.0 {
+            phi += 2.0 * PI;
+        }
+
+        if p_hit.z < 0.0 || p_hit.z > self.height || phi > self.phi_max {
+            return None;
+        }
+        Some((p_hit, phi))
+    }
+
+    fn find_hit(&self, ray : &Ray, o_error : &Vector3f, d_error : &Vector3f) -> Option<(f32, (Point3f, f32, Vector3f))> {
+        let (o, d) = ray_components_with_error(ray, o_error, d_error);
+        let [ox, oy, oz] = o;
+        let [dx, dy, dz] = d;
+
+        let k = EFloat::from(self.radius) / EFloat::from(self.height);
+        let k = k * k;
+        let height = EFloat::from(self.height);
+        let a = dx * dx + dy * dy - k * dz * dz;
+        let b = (dx * ox + dy * oy - k * dz * (oz - height)) * 2.0;
+        let c = ox * ox + oy * oy - k * (oz - height) * (oz - height);
+
+        let (t0, t1) = EFloat::quadratic(a, b, c)?;
+        closest_quadric_hit(t0, t1, ray.get_t_max(), |t| {
+            let (p_hit, phi) = self.hit_point(ray, t.value())?;
+            Some((p_hit, phi, ray_equation_error(&o, &d, t)))
+        })
+    }
+}
+
+impl Shape for Cone{
+    fn object_bound(&self) -> Bound3f {
+        Bound3f::from_points(
+            &Point3f::new(-self.radius, -self.radius, 0.0),
+            &Point3f::new(self.radius, self.radius, self.height),
+        )
+    }
+
+    fn intersect(&self, r : &Ray) -> Option<(f32, SurfaceInteraction)> {
+        let (ray, o_error, d_error) = self.world_to_object.apply_ray_with_error(r);
+        let (t_shape_hit, (p_hit, phi, p_error)) = self.find_hit(&ray, &o_error, &d_error)?;
+
+        let u = phi / self.phi_max;
+        let v = p_hit.z / self.height;
+
+        let dp_du = Vector3f::new(-self.phi_max * p_hit.y, self.phi_max * p_hit.x, 0.0);
+        let dp_dv = Vector3f::new(-p_hit.x / (1.0 - v), -p_hit.y / (1.0 - v), self.height);
+
+        let d2p_duu = Vector3f::new(p_hit.x, p_hit.y, 0.0) * (-self.phi_max * self.phi_max);
+        let d2p_duv = Vector3f::new(p_hit.y, -p_hit.x, 0.0) * (self.phi_max / (1.0 - v));
+        let d2p_dvv = Vector3f::default();
+        let (dn_du, dn_dv) = weingarten(&dp_du, &dp_dv, &d2p_duu, &d2p_duv, &d2p_dvv);
+
+        let normal = Normal3f::from_vector(dp_du.cross(&dp_dv).normalize());
+        let interaction = SurfaceInteraction::new_surface(
+            p_hit,
+            normal,
+            p_error,
+            ray.get_direction().neg(),
+            ray.get_time(),
+            DifferentialGeometry{
+                uv: Point2f::new(u, v),
+                dp_du,
+                dp_dv,
+                dn_du,
+                dn_dv,
+            },
+            Some(self),
+        );
+
+        Some((t_shape_hit, self.object_to_world.apply(&interaction)))
+    }
+
+    fn intersect_p(&self, r : &Ray) -> bool {
+        let (ray, o_error, d_error) = self.world_to_object.apply_ray_with_error(r);
+        self.find_hit(&ray, &o_error, &d_error).is_some()
+    }
+
+    fn area(&self) -> f32 {
+        self.radius * (self.height * self.height + self.radius * self.radius).sqrt() * self.phi_max / 2.0
+    }
+
+    fn object_to_world(&self) -> &Transform {
+        &self.object_to_world
+    }
+
+    fn reverse_orientation(&self) -> bool {
+        self.reverse_orientation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cone() -> Cone {
+        Cone::new(
+            Arc::new(Transform::identity()),
+            Arc::new(Transform::identity()),
+            false,
+            2.0, 1.0, 360.0
+        )
+    }
+
+    fn ray(origin : Point3f, direction : Vector3f) -> Ray {
+        Ray::new(origin, direction, f32::INFINITY, 0.0, None)
+    }
+
+    #[test]
+    fn test_cone_hit_inside_bound() {
+        let cone = cone();
+        // Halfway up the cone its radius is 0.5
+        let (t, si) = cone.intersect(&ray(Point3f::new(-5.0, 0.0, 1.0), Vector3f::new(1.0, 0.0, 0.0))).unwrap();
+        assert!((t - 4.5).abs() < 1e-5);
+        assert!(cone.object_bound().inside(si.point));
+        assert!((si.uv.y - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_cone_misses_outside_height() {
+        let cone = cone();
+        assert!(!cone.intersect_p(&ray(Point3f::new(-5.0, 0.0, 3.0), Vector3f::new(1.0, 0.0, 0.0))));
+        assert!(!cone.intersect_p(&ray(Point3f::new(-5.0, 0.0, -1.0), Vector3f::new(1.0, 0.0, 0.0))));
+    }
+
+    #[test]
+    fn test_cone_area() {
+        assert!((cone().area() - PI * 5.0_f32.sqrt()).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,186 @@
+use std::f32::consts::PI;
+use std::sync::Arc;
+use crate::engine::{Bound3f, SurfaceInteraction};
+use crate::engine::Interactions::surface_interaction::DifferentialGeometry;
+use crate::engine::math::Normal::Normal3f;
+use crate::engine::math::Point::{Point2f, Point3f};
+use crate::engine::math::efloat::EFloat;
+use crate::engine::math::gamma;
+use crate::engine::math::rays::BaseRay;
+use crate::engine::math::rays::Ray::Ray;
+use crate::engine::math::transformations::{Apply, Transform};
+use crate::engine::math::Vector::Vector3f;
+use crate::engine::primitives::{closest_quadric_hit, ray_components_with_error, weingarten, Shape};
+
+/// An open cylinder around the z axis between `z_min` and `z_max`
+pub(crate) struct Cylinder{
+    object_to_world : Arc<Transform>,
+    world_to_object : Arc<Transform>,
+    reverse_orientation : bool,
+
+    radius : f32,
+    z_min : f32,
+    z_max : f32,
+    phi_max : f32,
+}
+
+impl Cylinder{
+    pub fn new(
+        object_to_world : Arc<Transform>, world_to_object : Arc<Transform>, reverse_orientation : bool,
+        radius : f32, z_min : f32, z_max : f32, phi_max : f32
+    ) -> Cylinder{
+        Self{
+            object_to_world,
+            world_to_object,
+            reverse_orientation,
+            radius,
+            z_min: z_min.min(z_max),
+            z_max: z_min.max(z_max),
+            phi_max: phi_max.clamp(0.0, 360.0).to_radians(),
+        }
+    }
+
+    fn hit_point(&self, ray : &Ray, t : f32) -> Option<(Point3f, f32)> {
+        let mut p_hit = ray.point_at(t);
+
+        // Refine the hit point so it lies on the cylinder surface
+        let hit_radius = (p_hit.x * p_hit.x + p_hit.y * p_hit.y).sqrt();
+        p_hit.x *= self.radius / hit_radius;
+        p_hit.y *= self.radius / hit_radius;
+
+        let mut phi = p_hit.y.atan2(p_hit.x);
+        if phi < 0.0 {
+            phi += 2.0 * PI;
+        }
+
+        if p_hit.z < self.z_min || p_hit.z > self.z_max || phi > self.phi_max {
+            return None;
+        }
+        Some((p_hit, phi))
+    }
+
+    fn find_hit(&self, ray : &Ray, o_error : &Vector3f, d_error : &Vector3f) -> Option<(f32, (Point3f, f32))> {
+        let ([ox, oy, _], [dx, dy, _]) = ray_components_with_error(ray, o_error, d_error);
+        let radius = EFloat::from(self.radius);
+
+        let a = dx * dx + dy * dy;
+        let b = (dx * ox + dy * oy) * 2.0;
+        let c = ox * ox + oy * oy - radius * radius;
+
+        let (t0, t1) = EFloat::quadratic(a, b, c)?;
+        closest_quadric_hit(t0, t1, ray.get_t_max(), |t| self.hit_point(ray, t.value()))
+    }
+}
+
+impl Shape for Cylinder{
+    fn object_bound(&self) -> Bound3f {
+        Bound3f::from_points(
+            &Point3f::new(-self.radius, -self.radius, self.z_min),
+            &Point3f::new(self.radius, self.radius, self.z_max),
+        )
+    }
+
+    fn intersect(&self, r : &Ray) -> Option<(f32, SurfaceInteraction)> {
+        let (ray, o_error, d_error) = self.world_to_object.apply_ray_with_error(r);
+        let (t_shape_hit, (p_hit, phi)) = self.find_hit(&ray, &o_error, &d_error)?;
+
+        let u = phi / self.phi_max;
+        let v = (p_hit.z - self.z_min) / (self.z_max - self.z_min);
+
+        let dp_du = Vector3f::new(-self.phi_max * p_hit.y, self.phi_max * p_hit.x, 0.0);
+        let dp_dv = Vector3f::new(0.0, 0.0, self.z_max - self.z_min);
+
+        let d2p_duu = Vector3f::new(p_hit.x, p_hit.y, 0.0) * (-self.phi_max * self.phi_max);
+        let d2p_duv = Vector3f::default();
+        let d2p_dvv = Vector3f::default();
+        let (dn_du, dn_dv) = weingarten(&dp_du, &dp_dv, &d2p_duu, &d2p_duv, &d2p_dvv);
+
+        // Only x and y are refined, z comes straight from the ray equation and is exact enough
+        let p_error = Vector3f::new(p_hit.x, p_hit.y, 0.0).abs() * gamma(3);
+
+        let normal = Normal3f::from_vector(dp_du.cross(&dp_dv).normalize());
+        let interaction = SurfaceInteraction::new_surface(
+            p_hit,
+            normal,
+            p_error,
+            ray.get_direction().neg(),
+            ray.get_time(),
+            DifferentialGeometry{
+                uv: Point2f::new(u, v),
+                dp_du,
+                dp_dv,
+                dn_du,
+                dn_dv,
+            },
+            Some(self),
+        );
+
+        Some((t_shape_hit, self.object_to_world.apply(&interaction)))
+    }
+
+    fn intersect_p(&self, r : &Ray) -> bool {
+        let (ray, o_error, d_error) = self.world_to_object.apply_ray_with_error(r);
+        self.find_hit(&ray, &o_error, &d_error).is_some()
+    }
+
+    fn area(&self) -> f32 {
+        (self.z_max - self.z_min) * self.radius * self.phi_max
+    }
+
+    fn object_to_world(&self) -> &Transform {
+        &self.object_to_world
+    }
+
+    fn reverse_orientation(&self) -> bool {
+        self.reverse_orientation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cylinder(phi_max : f32) -> Cylinder {
+        Cylinder::new(
+            Arc::new(Transform::identity()),
+            Arc::new(Transform::identity()),
+            false,
+            1.0, -1.0, 1.0, phi_max
+        )
+    }
+
+    fn ray(origin : Point3f, direction : Vector3f) -> Ray {
+        Ray::new(origin, direction, f32::INFINITY, 0.0, None)
+    }
+
+    #[test]
+    fn test_cylinder_hit_inside_bound() {
+        let cylinder = cylinder(360.0);
+        let (t, si) = cylinder.intersect(&ray(Point3f::new(-5.0, 0.0, 0.5), Vector3f::new(1.0, 0.0, 0.0))).unwrap();
+        assert!((t - 4.0).abs() < 1e-5);
+        assert!(cylinder.object_bound().expands(1e-4).inside(si.point));
+        assert!((si.normal.x.abs() - 1.0).abs() < 1e-5);
+        assert!((si.uv.y - 0.75).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_cylinder_is_open_along_its_axis() {
+        let cylinder = cylinder(360.0);
+        assert!(!cylinder.intersect_p(&ray(Point3f::new(0.0, 0.0, -5.0), Vector3f::new(0.0, 0.0, 1.0))));
+        assert!(!cylinder.intersect_p(&ray(Point3f::new(-5.0, 0.0, 1.5), Vector3f::new(1.0, 0.0, 0.0))));
+    }
+
+    #[test]
+    fn test_cylinder_partial_sweep_hits_inside() {
+        // With half of the cylinder missing the ray passes through the gap and hits the far wall from inside
+        let cylinder = cylinder(180.0);
+        let (t, si) = cylinder.intersect(&ray(Point3f::new(0.0, -5.0, 0.0), Vector3f::new(0.0, 1.0, 0.0))).unwrap();
+        assert!((t - 6.0).abs() < 1e-5);
+        assert!(si.point.y > 0.0);
+    }
+
+    #[test]
+    fn test_cylinder_area() {
+        assert!((cylinder(360.0).area() - 4.0 * PI).abs() < 1e-4);
+    }
+}
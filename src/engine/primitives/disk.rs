@@ -0,0 +1,183 @@
+use std::f32::consts::PI;
+use std::sync::Arc;
+use crate::engine::{Bound3f, SurfaceInteraction};
+use crate::engine::Interactions::surface_interaction::DifferentialGeometry;
+use crate::engine::math::Normal::Normal3f;
+use crate::engine::math::Point::{Point2f, Point3f};
+use crate::engine::math::rays::BaseRay;
+use crate::engine::math::rays::Ray::Ray;
+use crate::engine::math::transformations::{Apply, Transform};
+use crate::engine::math::Vector::Vector3f;
+use crate::engine::primitives::Shape;
+
+/// A disk centered on the z axis at the given height, facing +z.
+/// A non zero inner radius turns the disk into an annulus
+pub(crate) struct Disk{
+    object_to_world : Arc<Transform>,
+    world_to_object : Arc<Transform>,
+    reverse_orientation : bool,
+
+    height : f32,
+    radius : f32,
+    inner_radius : f32,
+    phi_max : f32,
+}
+
+impl Disk{
+    pub fn new(
+        object_to_world : Arc<Transform>, world_to_object : Arc<Transform>, reverse_orientation : bool,
+        height : f32, radius : f32, inner_radius : f32, phi_max : f32
+    ) -> Disk{
+        Self{
+            object_to_world,
+            world_to_object,
+            reverse_orientation,
+            height,
+            radius,
+            inner_radius,
+            phi_max: phi_max.clamp(0.0, 360.0).to_radians(),
+        }
+    }
+
+    /// Find the valid hit along an object space ray, returning its distance, position and angle phi
+    fn find_hit(&self, ray : &Ray) -> Option<(f32, Point3f, f32)> {
+        let o = ray.get_origin();
+        let d = ray.get_direction();
+
+        // Rays parallel to the disk plane never hit it
+        if d.z == 0.0 {
+            return None;
+        }
+        let t_shape_hit = (self.height - o.z) / d.z;
+        if t_shape_hit <= 0.0 || t_shape_hit >= ray.get_t_max() {
+            return None;
+        }
+
+        let mut p_hit = ray.point_at(t_shape_hit);
+        let dist2 = p_hit.x * p_hit.x + p_hit.y * p_hit.y;
+        if dist2 > self.radius * self.radius || dist2 < self.inner_radius * self.inner_radius {
+            return None;
+        }
+
+        let mut phi = p_hit.y.atan2(p_hit.x);
+        if phi < 0.0 {
+            phi += 2.0 * PI;
+        }
+        if phi > self.phi_max {
+            return None;
+        }
+
+        // Refine the hit point so it lies exactly on the disk plane
+        p_hit.z = self.height;
+
+        Some((t_shape_hit, p_hit, phi))
+    }
+}
+
+impl Shape for Disk{
+    fn object_bound(&self) -> Bound3f {
+        Bound3f::from_points(
+            &Point3f::new(-self.radius, -self.radius, self.height),
+            &Point3f::new(self.radius, self.radius, self.height),
+        )
+    }
+
+    fn intersect(&self, r : &Ray) -> Option<(f32, SurfaceInteraction)> {
+        let ray = self.world_to_object.apply(r);
+        let (t_shape_hit, p_hit, phi) = self.find_hit(&ray)?;
+
+        let u = phi / self.phi_max;
+        let r_hit = (p_hit.x * p_hit.x + p_hit.y * p_hit.y).sqrt();
+        let v = (self.radius - r_hit) / (self.radius - self.inner_radius);
+
+        let dp_du = Vector3f::new(-self.phi_max * p_hit.y, self.phi_max * p_hit.x, 0.0);
+        let dp_dv = Vector3f::new(p_hit.x, p_hit.y, 0.0) * ((self.inner_radius - self.radius) / r_hit);
+
+        // The disk is flat so its normal never changes
+        // The hit point is snapped onto the disk plane, so it has no error
+        let normal = Normal3f::from_vector(dp_du.cross(&dp_dv).normalize());
+        let interaction = SurfaceInteraction::new_surface(
+            p_hit,
+            normal,
+            Vector3f::default(),
+            ray.get_direction().neg(),
+            ray.get_time(),
+            DifferentialGeometry{
+                uv: Point2f::new(u, v),
+                dp_du,
+                dp_dv,
+                dn_du: Normal3f::default(),
+                dn_dv: Normal3f::default(),
+            },
+            Some(self),
+        );
+
+        Some((t_shape_hit, self.object_to_world.apply(&interaction)))
+    }
+
+    fn intersect_p(&self, r : &Ray) -> bool {
+        let ray = self.world_to_object.apply(r);
+        self.find_hit(&ray).is_some()
+    }
+
+    fn area(&self) -> f32 {
+        self.phi_max * 0.5 * (self.radius * self.radius - self.inner_radius * self.inner_radius)
+    }
+
+    fn object_to_world(&self) -> &Transform {
+        &self.object_to_world
+    }
+
+    fn reverse_orientation(&self) -> bool {
+        self.reverse_orientation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn disk(inner_radius : f32, phi_max : f32) -> Disk {
+        Disk::new(
+            Arc::new(Transform::identity()),
+            Arc::new(Transform::identity()),
+            false,
+            1.0, 2.0, inner_radius, phi_max
+        )
+    }
+
+    fn ray(origin : Point3f, direction : Vector3f) -> Ray {
+        Ray::new(origin, direction, f32::INFINITY, 0.0, None)
+    }
+
+    #[test]
+    fn test_disk_hit_inside_bound() {
+        let disk = disk(0.0, 360.0);
+        let (t, si) = disk.intersect(&ray(Point3f::new(0.5, 0.5, -1.0), Vector3f::new(0.0, 0.0, 1.0))).unwrap();
+        assert!((t - 2.0).abs() < 1e-5);
+        assert!(disk.object_bound().inside(si.point));
+        assert!((si.normal.z.abs() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_disk_parallel_ray_misses() {
+        let disk = disk(0.0, 360.0);
+        assert!(!disk.intersect_p(&ray(Point3f::new(-5.0, 0.0, 1.0), Vector3f::new(1.0, 0.0, 0.0))));
+    }
+
+    #[test]
+    fn test_disk_inner_radius_and_sweep() {
+        let annulus = disk(1.0, 360.0);
+        assert!(!annulus.intersect_p(&ray(Point3f::new(0.5, 0.0, 0.0), Vector3f::new(0.0, 0.0, 1.0))));
+        assert!(annulus.intersect_p(&ray(Point3f::new(1.5, 0.0, 0.0), Vector3f::new(0.0, 0.0, 1.0))));
+
+        let half = disk(0.0, 180.0);
+        assert!(half.intersect_p(&ray(Point3f::new(0.0, 1.0, 0.0), Vector3f::new(0.0, 0.0, 1.0))));
+        assert!(!half.intersect_p(&ray(Point3f::new(0.0, -1.0, 0.0), Vector3f::new(0.0, 0.0, 1.0))));
+    }
+
+    #[test]
+    fn test_disk_area() {
+        assert!((disk(1.0, 360.0).area() - 3.0 * PI).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,255 @@
+use std::f32::consts::PI;
+use std::sync::Arc;
+use crate::engine::{Bound3f, SurfaceInteraction};
+use crate::engine::Interactions::surface_interaction::DifferentialGeometry;
+use crate::engine::math::Normal::Normal3f;
+use crate::engine::math::Point::{Point2f, Point3f};
+use crate::engine::math::efloat::EFloat;
+use crate::engine::math::rays::BaseRay;
+use crate::engine::math::rays::Ray::Ray;
+use crate::engine::math::transformations::{Apply, Transform};
+use crate::engine::math::Vector::Vector3f;
+use crate::engine::primitives::{closest_quadric_hit, ray_components_with_error, ray_equation_error, weingarten, Shape};
+
+/// The surface of revolution swept by the line segment between `p1` and `p2` around the z axis
+pub(crate) struct Hyperboloid{
+    object_to_world : Arc<Transform>,
+    world_to_object : Arc<Transform>,
+    reverse_orientation : bool,
+
+    p1 : Point3f,
+    p2 : Point3f,
+    z_min : f32,
+    z_max : f32,
+    r_max : f32,
+    phi_max : f32,
+    // Coefficients of the implicit form a*x^2 + a*y^2 - c*z^2 = 1
+    ah : f32,
+    ch : f32,
+}
+
+// Steps along the line through the points to find where the implicit coefficients are well defined
+const MAX_WALK_STEPS : usize = 32;
+
+impl Hyperboloid{
+    pub fn new(
+        object_to_world : Arc<Transform>, world_to_object : Arc<Transform>, reverse_orientation : bool,
+        point1 : Point3f, point2 : Point3f, phi_max : f32
+    ) -> Hyperboloid{
+        let radius1 = (point1.x * point1.x + point1.y * point1.y).sqrt();
+        let radius2 = (point2.x * point2.x + point2.y * point2.y).sqrt();
+
+        assert!(point1 != point2, "the points of a hyperboloid must differ");
+        assert!(point1.z != 0.0 || point2.z != 0.0, "the points of a hyperboloid can't both lie on z = 0");
+
+        // Compute the implicit coefficients, walking along the line until they are well defined
+        let (p1, p2) = if point2.z == 0.0 { (point2, point1) } else { (point1, point2) };
+        let mut pp = p1;
+        let mut ah = f32::NAN;
+        let mut ch = f32::NAN;
+        for _ in 0..MAX_WALK_STEPS {
+            pp = pp + (p2 - p1) * 2.0;
+            let xy1 = pp.x * pp.x + pp.y * pp.y;
+            let xy2 = p2.x * p2.x + p2.y * p2.y;
+            ah = (1.0 / xy1 - (pp.z * pp.z) / (xy1 * p2.z * p2.z))
+                / (1.0 - (xy2 * pp.z * pp.z) / (xy1 * p2.z * p2.z));
+            ch = (ah * xy2 - 1.0) / (p2.z * p2.z);
+            if ah.is_finite() {
+                break;
+            }
+        }
+        assert!(ah.is_finite() && ch.is_finite(), "degenerate hyperboloid through {:?} and {:?}", point1, point2);
+
+        Self{
+            object_to_world,
+            world_to_object,
+            reverse_orientation,
+            p1,
+            p2,
+            z_min: p1.z.min(p2.z),
+            z_max: p1.z.max(p2.z),
+            r_max: radius1.max(radius2),
+            phi_max: phi_max.clamp(0.0, 360.0).to_radians(),
+            ah,
+            ch,
+        }
+    }
+
+    fn hit_point(&self, ray : &Ray, t : f32) -> Option<(Point3f, f32, f32)> {
+        let p_hit = ray.point_at(t);
+
+        // Phi is measured relative to the point of the generating line at the same height
+        let v = (p_hit.z - self.p1.z) / (self.p2.z - self.p1.z);
+        let pr = self.p1 * (1.0 - v) + self.p2 * v;
+        let mut phi = (pr.x * p_hit.y - p_hit.x * pr.y).atan2(p_hit.x * pr.x + p_hit.y * pr.y);
+        if phi < 0.0 {
+            phi += 2.0 * PI;
+        }
+
+        if p_hit.z < self.z_min || p_hit.z > self.z_max || phi > self.phi_max {
+            return None;
+        }
+        Some((p_hit, phi, v))
+    }
+
+    fn find_hit(&self, ray : &Ray, o_error : &Vector3f, d_error : &Vector3f) -> Option<(f32, (Point3f, f32, f32, Vector3f))> {
+        let (o, d) = ray_components_with_error(ray, o_error, d_error);
+        let [ox, oy, oz] = o;
+        let [dx, dy, dz] = d;
+
+        let ah = EFloat::from(self.ah);
+        let ch = EFloat::from(self.ch);
+        let a = ah * dx * dx + ah * dy * dy - ch * dz * dz;
+        let b = (ah * dx * ox + ah * dy * oy - ch * dz * oz) * 2.0;
+        let c = ah * ox * ox + ah * oy * oy - ch * oz * oz - EFloat::from(1.0);
+
+        let (t0, t1) = EFloat::quadratic(a, b, c)?;
+        closest_quadric_hit(t0, t1, ray.get_t_max(), |t| {
+            let (p_hit, phi, v) = self.hit_point(ray, t.value())?;
+            Some((p_hit, phi, v, ray_equation_error(&o, &d, t)))
+        })
+    }
+}
+
+impl Shape for Hyperboloid{
+    fn object_bound(&self) -> Bound3f {
+        Bound3f::from_points(
+            &Point3f::new(-self.r_max, -self.r_max, self.z_min),
+            &Point3f::new(self.r_max, self.r_max, self.z_max),
+        )
+    }
+
+    fn intersect(&self, r : &Ray) -> Option<(f32, SurfaceInteraction)> {
+        let (ray, o_error, d_error) = self.world_to_object.apply_ray_with_error(r);
+        let (t_shape_hit, (p_hit, phi, v, p_error)) = self.find_hit(&ray, &o_error, &d_error)?;
+
+        let u = phi / self.phi_max;
+        let cos_phi = phi.cos();
+        let sin_phi = phi.sin();
+
+        let dp_du = Vector3f::new(-self.phi_max * p_hit.y, self.phi_max * p_hit.x, 0.0);
+        let dp_dv = Vector3f::new(
+            (self.p2.x - self.p1.x) * cos_phi - (self.p2.y - self.p1.y) * sin_phi,
+            (self.p2.x - self.p1.x) * sin_phi + (self.p2.y - self.p1.y) * cos_phi,
+            self.p2.z - self.p1.z,
+        );
+
+        let d2p_duu = Vector3f::new(p_hit.x, p_hit.y, 0.0) * (-self.phi_max * self.phi_max);
+        let d2p_duv = Vector3f::new(-dp_dv.y, dp_dv.x, 0.0) * self.phi_max;
+        let d2p_dvv = Vector3f::default();
+        let (dn_du, dn_dv) = weingarten(&dp_du, &dp_dv, &d2p_duu, &d2p_duv, &d2p_dvv);
+
+        let normal = Normal3f::from_vector(dp_du.cross(&dp_dv).normalize());
+        let interaction = SurfaceInteraction::new_surface(
+            p_hit,
+            normal,
+            p_error,
+            ray.get_direction().neg(),
+            ray.get_time(),
+            DifferentialGeometry{
+                uv: Point2f::new(u, v),
+                dp_du,
+                dp_dv,
+                dn_du,
+                dn_dv,
+            },
+            Some(self),
+        );
+
+        Some((t_shape_hit, self.object_to_world.apply(&interaction)))
+    }
+
+    fn intersect_p(&self, r : &Ray) -> bool {
+        let (ray, o_error, d_error) = self.world_to_object.apply_ray_with_error(r);
+        self.find_hit(&ray, &o_error, &d_error).is_some()
+    }
+
+    fn area(&self) -> f32 {
+        let (p1, p2) = (self.p1, self.p2);
+        let sqr = |a : f32| a * a;
+        let quad = |a : f32| sqr(a) * sqr(a);
+
+        self.phi_max / 6.0 * (
+            2.0 * quad(p1.x) - 2.0 * p1.x * p1.x * p1.x * p2.x + 2.0 * quad(p2.x)
+                + 2.0 * (p1.y * p1.y + p1.y * p2.y + p2.y * p2.y) * (sqr(p1.y - p2.y) + sqr(p1.z - p2.z))
+                + p2.x * p2.x * (5.0 * p1.y * p1.y + 2.0 * p1.y * p2.y - 4.0 * p2.y * p2.y + 2.0 * sqr(p1.z - p2.z))
+                + p1.x * p1.x * (-4.0 * p1.y * p1.y + 2.0 * p1.y * p2.y + 5.0 * p2.y * p2.y + 2.0 * sqr(p1.z - p2.z))
+                - 2.0 * p1.x * p2.x * (p2.x * p2.x - p1.y * p1.y + 5.0 * p1.y * p2.y - p2.y * p2.y - p1.z * p1.z
+                    + 2.0 * p1.z * p2.z - p2.z * p2.z)
+        )
+    }
+
+    fn object_to_world(&self) -> &Transform {
+        &self.object_to_world
+    }
+
+    fn reverse_orientation(&self) -> bool {
+        self.reverse_orientation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // One sheet hyperboloid x^2 + y^2 - z^2 = 1 between z = -1 and z = 1
+    fn hyperboloid() -> Hyperboloid {
+        Hyperboloid::new(
+            Arc::new(Transform::identity()),
+            Arc::new(Transform::identity()),
+            false,
+            Point3f::new(1.0, -1.0, -1.0),
+            Point3f::new(1.0, 1.0, 1.0),
+            360.0
+        )
+    }
+
+    fn ray(origin : Point3f, direction : Vector3f) -> Ray {
+        Ray::new(origin, direction, f32::INFINITY, 0.0, None)
+    }
+
+    #[test]
+    fn test_hyperboloid_waist_hit() {
+        let hyperboloid = hyperboloid();
+        let (t, si) = hyperboloid.intersect(&ray(Point3f::new(-5.0, 0.0, 0.0), Vector3f::new(1.0, 0.0, 0.0))).unwrap();
+        assert!((t - 4.0).abs() < 1e-4);
+        assert!(hyperboloid.object_bound().expands(1e-4).inside(si.point));
+        assert!((si.normal.x.abs() - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_hyperboloid_misses_outside_height() {
+        let hyperboloid = hyperboloid();
+        assert!(!hyperboloid.intersect_p(&ray(Point3f::new(-5.0, 0.0, 1.5), Vector3f::new(1.0, 0.0, 0.0))));
+    }
+
+    #[test]
+    fn test_hyperboloid_bound() {
+        let bound = hyperboloid().object_bound();
+        let r_max = 2.0_f32.sqrt();
+        assert!((bound.p_max.x - r_max).abs() < 1e-5);
+        assert_eq!(bound.p_min.z, -1.0);
+        assert_eq!(bound.p_max.z, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "must differ")]
+    fn test_hyperboloid_with_coincident_points() {
+        let p = Point3f::new(1.0, 0.0, 1.0);
+        Hyperboloid::new(Arc::new(Transform::identity()), Arc::new(Transform::identity()), false, p, p, 360.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "degenerate hyperboloid")]
+    fn test_hyperboloid_along_the_axis() {
+        // The line never leaves the z axis, so the coefficients are never defined
+        Hyperboloid::new(
+            Arc::new(Transform::identity()),
+            Arc::new(Transform::identity()),
+            false,
+            Point3f::new(0.0, 0.0, 1.0),
+            Point3f::new(0.0, 0.0, 2.0),
+            360.0
+        );
+    }
+}
@@ -1,21 +1,146 @@
+pub(crate) mod sphere;
+pub(crate) mod triangle;
+pub(crate) mod disk;
+pub(crate) mod cylinder;
+pub(crate) mod cone;
+pub(crate) mod paraboloid;
+pub(crate) mod hyperboloid;
+
+use std::sync::Arc;
 use crate::engine::{Bound3f, SurfaceInteraction};
+use crate::engine::Interactions::MediumInterface;
+use crate::engine::lights::AreaLight;
+use crate::engine::materials::Material;
+use crate::engine::math::animated_transform::AnimatedTransform;
+use crate::engine::math::efloat::EFloat;
+use crate::engine::math::Normal::Normal3f;
+use crate::engine::math::rays::BaseRay;
 use crate::engine::math::rays::Ray::Ray;
+use crate::engine::math::transformations::{Apply, Transform};
+use crate::engine::math::Vector::Vector3f;
+
+pub(crate) trait Shape : Send + Sync{
+
+    // Get the BoundingBox of the shape in its own object space
+    fn object_bound(&self) -> Bound3f;
+
+    // Get the BoundingBox of the shape in world space
+    fn world_bound(&self) -> Bound3f {
+        self.object_to_world().apply(&self.object_bound())
+    }
+
+    // Transformation placing the shape in the scene
+    fn object_to_world(&self) -> &Transform;
+
+    // Find the closest intersection along the ray, returning its parametric distance
+    // and the interaction details in world space
+    fn intersect(&self, ray : &Ray) -> Option<(f32, SurfaceInteraction)>;
+
+    // Check if there is an intersection along the ray without computing the interaction details
+    fn intersect_p(&self, ray : &Ray) -> bool {
+        self.intersect(ray).is_some()
+    }
+
+    // Surface area of the shape in object space. Triangles are the exception and give their world space area,
+    // since their meshes only keep the vertices transformed to world space
+    fn area(&self) -> f32;
+
+    // Whether the surface normals should point to the inside of the shape
+    fn reverse_orientation(&self) -> bool;
+
+    // Whether the object to world transformation changes the handedness of the coordinate system
+    fn transform_swaps_handedness(&self) -> bool {
+        self.object_to_world().swaps_handedness()
+    }
+}
+
+
+/// Compute the partial derivatives of the surface normal from the first and second
+/// derivatives of the surface using the Weingarten equations
+pub(crate) fn weingarten(
+    dp_du : &Vector3f, dp_dv : &Vector3f, d2p_duu : &Vector3f, d2p_duv : &Vector3f, d2p_dvv : &Vector3f
+) -> (Normal3f, Normal3f) {
+    // Coefficients of the first fundamental form
+    let e1 = dp_du.dot(dp_du);
+    let f1 = dp_du.dot(dp_dv);
+    let g1 = dp_dv.dot(dp_dv);
+
+    // Coefficients of the second fundamental form
+    let n = dp_du.cross(dp_dv).normalize();
+    let e2 = n.dot(d2p_duu);
+    let f2 = n.dot(d2p_duv);
+    let g2 = n.dot(d2p_dvv);
+
+    let inv_egf2 = 1.0 / (e1 * g1 - f1 * f1);
+    let dn_du = *dp_du * ((f2 * f1 - e2 * g1) * inv_egf2) + *dp_dv * ((e2 * f1 - f2 * e1) * inv_egf2);
+    let dn_dv = *dp_du * ((g2 * f1 - f2 * g1) * inv_egf2) + *dp_dv * ((f2 * f1 - g2 * e1) * inv_egf2);
+
+    (Normal3f::from_vector(dn_du), Normal3f::from_vector(dn_dv))
+}
+
+
+/// Select the closest root of a quadric that is inside the ray extent and survives the clipping of the shape.
+/// The roots are only accepted when their whole error interval is in front of the ray origin.
+/// `hit_at` returns `None` when the hit at the given distance is clipped away
+pub(crate) fn closest_quadric_hit<H>(t0 : EFloat, t1 : EFloat, t_max : f32, hit_at : impl Fn(EFloat) -> Option<H>) -> Option<(f32, H)> {
+    if t0.upper_bound() > t_max || t1.lower_bound() <= 0.0 {
+        return None;
+    }
 
-pub(crate) trait Shape{
-    
+    if t0.lower_bound() > 0.0 {
+        if let Some(hit) = hit_at(t0) {
+            return Some((t0.value(), hit));
+        }
+    }
+
+    // The first hit is either behind the ray or clipped away, try the second one
+    if t1.upper_bound() > t_max {
+        return None;
+    }
+    hit_at(t1).map(|hit| (t1.value(), hit))
+}
+
+/// Split an object space ray into its origin and direction components, carrying the
+/// error introduced by transforming it from world space
+pub(crate) fn ray_components_with_error(ray : &Ray, o_error : &Vector3f, d_error : &Vector3f) -> ([EFloat; 3], [EFloat; 3]) {
+    let o = ray.get_origin();
+    let d = ray.get_direction();
+    (
+        [EFloat::new(o.x, o_error.x), EFloat::new(o.y, o_error.y), EFloat::new(o.z, o_error.z)],
+        [EFloat::new(d.x, d_error.x), EFloat::new(d.y, d_error.y), EFloat::new(d.z, d_error.z)],
+    )
+}
+
+/// Error bounds of a hit point evaluated directly with the ray equation `o + t*d`
+pub(crate) fn ray_equation_error(o : &[EFloat; 3], d : &[EFloat; 3], t : EFloat) -> Vector3f {
+    let px = o[0] + t * d[0];
+    let py = o[1] + t * d[1];
+    let pz = o[2] + t * d[2];
+    Vector3f::new(px.absolute_error(), py.absolute_error(), pz.absolute_error())
 }
 
 
-pub(crate) trait Primitive{
+pub(crate) trait Primitive : Send + Sync{
 
     // Get the BoundingBox of the scene Geometry
     fn world_bound(&self) -> Bound3f;
 
-    // Check if a ray hit an object and return the interaction details
+    // Check if a ray hit an object and return the interaction details.
+    // On a hit the ray t_max is shortened to the hit distance so aggregates can skip anything farther
     fn intersect(&self, ray : &Ray) -> Option<SurfaceInteraction>;
 
     // Check if there is an intersection along the ray but only return a state
     fn intersect_p(&self, ray : &Ray) -> bool;
+
+    // Light emitted by the primitive, None for aggregates and non emissive primitives
+    fn get_area_light(&self) -> Option<Arc<dyn AreaLight>> {
+        None
+    }
+
+    // Material of the primitive, None for aggregates and for surfaces only delimiting participating media
+    fn get_material(&self) -> Option<Arc<dyn Material>> {
+        None
+    }
 }
 
 pub struct GeneralPrimitive{
@@ -24,9 +149,7 @@ pub struct GeneralPrimitive{
 
 impl Primitive for GeneralPrimitive{
     fn world_bound(&self) -> Bound3f {
-        Bound3f{
-
-        }
+        Bound3f::new()
     }
 
     fn intersect(&self, ray: &Ray) -> Option<SurfaceInteraction> {
@@ -36,4 +159,189 @@ impl Primitive for GeneralPrimitive{
     fn intersect_p(&self, ray: &Ray) -> bool {
         false
     }
-}
\ No newline at end of file
+}
+
+/// A single shape along with its appearance
+pub(crate) struct GeometricPrimitive{
+    shape : Arc<dyn Shape>,
+    material : Option<Arc<dyn Material>>,
+    area_light : Option<Arc<dyn AreaLight>>,
+    medium_interface : Option<Arc<dyn MediumInterface>>,
+    primitive_id : u32,
+    material_id : u32,
+}
+
+impl GeometricPrimitive{
+    pub fn new(
+        shape : Arc<dyn Shape>, material : Option<Arc<dyn Material>>, area_light : Option<Arc<dyn AreaLight>>,
+        medium_interface : Option<Arc<dyn MediumInterface>>
+    ) -> GeometricPrimitive{
+        Self{
+            shape,
+            material,
+            area_light,
+            medium_interface,
+            primitive_id: 0,
+            material_id: 0,
+        }
+    }
+
+    /// Identify the primitive and its material in the interactions it reports, for the AOVs of the film
+    pub fn with_ids(mut self, primitive_id : u32, material_id : u32) -> GeometricPrimitive{
+        self.primitive_id = primitive_id;
+        self.material_id = material_id;
+        self
+    }
+}
+
+impl Primitive for GeometricPrimitive{
+    fn world_bound(&self) -> Bound3f {
+        self.shape.world_bound()
+    }
+
+    fn intersect(&self, ray : &Ray) -> Option<SurfaceInteraction> {
+        let (t_hit, mut si) = self.shape.intersect(ray)?;
+        ray.set_t_max(t_hit);
+
+        si.material = self.material.clone();
+        si.area_light = self.area_light.clone();
+        si.primitive_id = self.primitive_id;
+        si.material_id = self.material_id;
+        // Without an interface of its own the primitive is inside the medium the ray travels through
+        if self.medium_interface.is_some() {
+            si.medium_interface = self.medium_interface.clone();
+        }
+        Some(si)
+    }
+
+    fn intersect_p(&self, ray : &Ray) -> bool {
+        self.shape.intersect_p(ray)
+    }
+
+    fn get_area_light(&self) -> Option<Arc<dyn AreaLight>> {
+        self.area_light.clone()
+    }
+
+    fn get_material(&self) -> Option<Arc<dyn Material>> {
+        self.material.clone()
+    }
+}
+
+/// Place a shared primitive in the scene with its own, possibly animated, transform so that
+/// the same geometry can be instanced many times without being duplicated
+pub(crate) struct TransformedPrimitive{
+    primitive : Arc<dyn Primitive>,
+    primitive_to_world : AnimatedTransform,
+}
+
+impl TransformedPrimitive{
+    pub fn new(primitive : Arc<dyn Primitive>, primitive_to_world : AnimatedTransform) -> TransformedPrimitive{
+        Self{
+            primitive,
+            primitive_to_world,
+        }
+    }
+
+    /// Transformation of the primitive at the time of the ray, along with the ray in the space of the primitive
+    fn to_primitive_space(&self, r : &Ray) -> (Transform, Ray) {
+        let primitive_to_world = self.primitive_to_world.interpolate(r.get_time());
+        let ray = primitive_to_world.inverse().apply(r);
+        (primitive_to_world, ray)
+    }
+}
+
+impl Primitive for TransformedPrimitive{
+    fn world_bound(&self) -> Bound3f {
+        self.primitive_to_world.motion_bounds(&self.primitive.world_bound())
+    }
+
+    fn intersect(&self, r : &Ray) -> Option<SurfaceInteraction> {
+        let (primitive_to_world, ray) = self.to_primitive_space(r);
+        let si = self.primitive.intersect(&ray)?;
+        // The direction keeps its scale in the space of the primitive, so the distance of the hit carries over to the
+        // world space ray. Only the origin was moved forward past its rounding error, which leaves the distance that much short
+        r.set_t_max(ray.get_t_max());
+        Some(primitive_to_world.apply(&si))
+    }
+
+    fn intersect_p(&self, r : &Ray) -> bool {
+        let (_, ray) = self.to_primitive_space(r);
+        self.primitive.intersect_p(&ray)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::math::Point::Point3f;
+    use crate::engine::primitives::sphere::Sphere;
+
+    fn unit_sphere() -> Arc<dyn Primitive> {
+        let sphere = Sphere::new(
+            Arc::new(Transform::identity()),
+            Arc::new(Transform::identity()),
+            false,
+            1.0, -1.0, 1.0, 360.0
+        );
+        Arc::new(GeometricPrimitive::new(Arc::new(sphere), None, None, None))
+    }
+
+    fn ray(origin : Point3f, direction : Vector3f) -> Ray {
+        Ray::new(origin, direction, f32::INFINITY, 0.0, None)
+    }
+
+    #[test]
+    fn test_geometric_primitive_shortens_ray() {
+        let r = ray(Point3f::new(0.0, 0.0, -5.0), Vector3f::new(0.0, 0.0, 1.0));
+        assert!(unit_sphere().intersect(&r).is_some());
+        assert!((r.get_t_max() - 4.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_transformed_primitives_share_geometry() {
+        let sphere = unit_sphere();
+        let left = TransformedPrimitive::new(
+            sphere.clone(),
+            AnimatedTransform::from_transform(Transform::translate(Vector3f::new(-5.0, 0.0, 0.0)))
+        );
+        let right = TransformedPrimitive::new(
+            sphere.clone(),
+            AnimatedTransform::from_transform(Transform::translate(Vector3f::new(5.0, 0.0, 0.0)) * Transform::scale(2.0, 2.0, 2.0))
+        );
+
+        let r = ray(Point3f::new(-20.0, 0.0, 0.0), Vector3f::new(1.0, 0.0, 0.0));
+        let si = left.intersect(&r).unwrap();
+        assert!((si.point.x + 6.0).abs() < 1e-4);
+        assert!((r.get_t_max() - 14.0).abs() < 1e-4);
+        // The right instance is behind the hit found so far
+        assert!(right.intersect(&r).is_none());
+
+        let r = ray(Point3f::new(20.0, 0.0, 0.0), Vector3f::new(-1.0, 0.0, 0.0));
+        let si = right.intersect(&r).unwrap();
+        assert!((si.point.x - 7.0).abs() < 1e-4);
+        assert!((si.normal.x.abs() - 1.0).abs() < 1e-4);
+        assert!((r.get_t_max() - 13.0).abs() < 1e-4);
+
+        let bound = right.world_bound();
+        assert!((bound.p_min.x - 3.0).abs() < 1e-4);
+        assert!((bound.p_max.x - 7.0).abs() < 1e-4);
+        assert_eq!(Arc::strong_count(&sphere), 3);
+    }
+
+    #[test]
+    fn test_moving_primitive_uses_ray_time() {
+        let moving = TransformedPrimitive::new(unit_sphere(), AnimatedTransform::new(
+            Transform::identity(), 0.0,
+            Transform::translate(Vector3f::new(0.0, 10.0, 0.0)), 1.0
+        ));
+
+        let at_time = |time : f32| Ray::new(Point3f::new(0.0, 5.0, -5.0), Vector3f::new(0.0, 0.0, 1.0), f32::INFINITY, time, None);
+        assert!(!moving.intersect_p(&at_time(0.0)));
+        assert!(moving.intersect_p(&at_time(0.5)));
+        assert!(moving.intersect(&at_time(0.45)).is_some());
+
+        // The bounds cover the whole motion
+        let bound = moving.world_bound();
+        assert!(bound.p_min.y <= -1.0 && bound.p_max.y >= 11.0);
+    }
+}
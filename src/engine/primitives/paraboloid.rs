@@ -0,0 +1,183 @@
+use std::f32::consts::PI;
+use std::sync::Arc;
+use crate::engine::{Bound3f, SurfaceInteraction};
+use crate::engine::Interactions::surface_interaction::DifferentialGeometry;
+use crate::engine::math::Normal::Normal3f;
+use crate::engine::math::Point::{Point2f, Point3f};
+use crate::engine::math::efloat::EFloat;
+use crate::engine::math::rays::BaseRay;
+use crate::engine::math::rays::Ray::Ray;
+use crate::engine::math::transformations::{Apply, Transform};
+use crate::engine::math::Vector::Vector3f;
+use crate::engine::primitives::{closest_quadric_hit, ray_components_with_error, ray_equation_error, weingarten, Shape};
+
+/// A paraboloid around the z axis, whose cross section at `z_max` has the given radius
+pub(crate) struct Paraboloid{
+    object_to_world : Arc<Transform>,
+    world_to_object : Arc<Transform>,
+    reverse_orientation : bool,
+
+    radius : f32,
+    z_min : f32,
+    z_max : f32,
+    phi_max : f32,
+}
+
+impl Paraboloid{
+    pub fn new(
+        object_to_world : Arc<Transform>, world_to_object : Arc<Transform>, reverse_orientation : bool,
+        radius : f32, z0 : f32, z1 : f32, phi_max : f32
+    ) -> Paraboloid{
+        Self{
+            object_to_world,
+            world_to_object,
+            reverse_orientation,
+            radius,
+            z_min: z0.min(z1),
+            z_max: z0.max(z1),
+            phi_max: phi_max.clamp(0.0, 360.0).to_radians(),
+        }
+    }
+
+    fn hit_point(&self, ray : &Ray, t : f32) -> Option<(Point3f, f32)> {
+        let p_hit = ray.point_at(t);
+
+        let mut phi = p_hit.y.atan2(p_hit.x);
+        if phi < 0.0 {
+            phi += 2.0 * PI;
+        }
+
+        if p_hit.z < self.z_min || p_hit.z > self.z_max || phi > self.phi_max {
+            return None;
+        }
+        Some((p_hit, phi))
+    }
+
+    fn find_hit(&self, ray : &Ray, o_error : &Vector3f, d_error : &Vector3f) -> Option<(f32, (Point3f, f32, Vector3f))> {
+        let (o, d) = ray_components_with_error(ray, o_error, d_error);
+        let [ox, oy, oz] = o;
+        let [dx, dy, dz] = d;
+
+        let radius = EFloat::from(self.radius);
+        let k = EFloat::from(self.z_max) / (radius * radius);
+        let a = k * (dx * dx + dy * dy);
+        let b = k * (dx * ox + dy * oy) * 2.0 - dz;
+        let c = k * (ox * ox + oy * oy) - oz;
+
+        let (t0, t1) = EFloat::quadratic(a, b, c)?;
+        closest_quadric_hit(t0, t1, ray.get_t_max(), |t| {
+            let (p_hit, phi) = self.hit_point(ray, t.value())?;
+            Some((p_hit, phi, ray_equation_error(&o, &d, t)))
+        })
+    }
+}
+
+impl Shape for Paraboloid{
+    fn object_bound(&self) -> Bound3f {
+        Bound3f::from_points(
+            &Point3f::new(-self.radius, -self.radius, self.z_min),
+            &Point3f::new(self.radius, self.radius, self.z_max),
+        )
+    }
+
+    fn intersect(&self, r : &Ray) -> Option<(f32, SurfaceInteraction)> {
+        let (ray, o_error, d_error) = self.world_to_object.apply_ray_with_error(r);
+        let (t_shape_hit, (p_hit, phi, p_error)) = self.find_hit(&ray, &o_error, &d_error)?;
+
+        let u = phi / self.phi_max;
+        let z_range = self.z_max - self.z_min;
+        let v = (p_hit.z - self.z_min) / z_range;
+
+        let dp_du = Vector3f::new(-self.phi_max * p_hit.y, self.phi_max * p_hit.x, 0.0);
+        let dp_dv = Vector3f::new(p_hit.x / (2.0 * p_hit.z), p_hit.y / (2.0 * p_hit.z), 1.0) * z_range;
+
+        let d2p_duu = Vector3f::new(p_hit.x, p_hit.y, 0.0) * (-self.phi_max * self.phi_max);
+        let d2p_duv = Vector3f::new(-p_hit.y / (2.0 * p_hit.z), p_hit.x / (2.0 * p_hit.z), 0.0) * (z_range * self.phi_max);
+        let d2p_dvv = Vector3f::new(
+            p_hit.x / (4.0 * p_hit.z * p_hit.z),
+            p_hit.y / (4.0 * p_hit.z * p_hit.z),
+            0.0
+        ) * (-z_range * z_range);
+        let (dn_du, dn_dv) = weingarten(&dp_du, &dp_dv, &d2p_duu, &d2p_duv, &d2p_dvv);
+
+        let normal = Normal3f::from_vector(dp_du.cross(&dp_dv).normalize());
+        let interaction = SurfaceInteraction::new_surface(
+            p_hit,
+            normal,
+            p_error,
+            ray.get_direction().neg(),
+            ray.get_time(),
+            DifferentialGeometry{
+                uv: Point2f::new(u, v),
+                dp_du,
+                dp_dv,
+                dn_du,
+                dn_dv,
+            },
+            Some(self),
+        );
+
+        Some((t_shape_hit, self.object_to_world.apply(&interaction)))
+    }
+
+    fn intersect_p(&self, r : &Ray) -> bool {
+        let (ray, o_error, d_error) = self.world_to_object.apply_ray_with_error(r);
+        self.find_hit(&ray, &o_error, &d_error).is_some()
+    }
+
+    fn area(&self) -> f32 {
+        let radius2 = self.radius * self.radius;
+        let k = 4.0 * self.z_max / radius2;
+        (radius2 * radius2 * self.phi_max / (12.0 * self.z_max * self.z_max))
+            * ((k * self.z_max + 1.0).powf(1.5) - (k * self.z_min + 1.0).powf(1.5))
+    }
+
+    fn object_to_world(&self) -> &Transform {
+        &self.object_to_world
+    }
+
+    fn reverse_orientation(&self) -> bool {
+        self.reverse_orientation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paraboloid() -> Paraboloid {
+        Paraboloid::new(
+            Arc::new(Transform::identity()),
+            Arc::new(Transform::identity()),
+            false,
+            1.0, 0.0, 1.0, 360.0
+        )
+    }
+
+    fn ray(origin : Point3f, direction : Vector3f) -> Ray {
+        Ray::new(origin, direction, f32::INFINITY, 0.0, None)
+    }
+
+    #[test]
+    fn test_paraboloid_hit_inside_bound() {
+        let paraboloid = paraboloid();
+        // z = x^2 + y^2, so the ray hits the vertical line x = 0.5 at z = 0.25
+        let (t, si) = paraboloid.intersect(&ray(Point3f::new(0.5, 0.0, 5.0), Vector3f::new(0.0, 0.0, -1.0))).unwrap();
+        assert!((t - 4.75).abs() < 1e-5);
+        assert!(paraboloid.object_bound().inside(si.point));
+        assert!((si.uv.y - 0.25).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_paraboloid_misses_above_z_max() {
+        let paraboloid = paraboloid();
+        assert!(!paraboloid.intersect_p(&ray(Point3f::new(-5.0, 0.0, 1.5), Vector3f::new(1.0, 0.0, 0.0))));
+    }
+
+    #[test]
+    fn test_paraboloid_area() {
+        // Closed form for z = x^2 + y^2 cut at z = 1
+        let expected = PI / 6.0 * (5.0_f32.powf(1.5) - 1.0);
+        assert!((paraboloid().area() - expected).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,295 @@
+use std::f32::consts::PI;
+use std::sync::Arc;
+use crate::engine::{Bound3f, SurfaceInteraction};
+use crate::engine::Interactions::surface_interaction::DifferentialGeometry;
+use crate::engine::math::Normal::Normal3f;
+use crate::engine::math::Point::{Point2f, Point3f};
+use crate::engine::math::efloat::EFloat;
+use crate::engine::math::gamma;
+use crate::engine::math::rays::BaseRay;
+use crate::engine::math::rays::Ray::Ray;
+use crate::engine::math::transformations::{Apply, Transform};
+use crate::engine::math::Vector::Vector3f;
+use crate::engine::primitives::{closest_quadric_hit, ray_components_with_error, weingarten, Shape};
+
+/// A sphere centered at the origin of its object space.
+/// Partial spheres are described by clipping the z range and limiting the sweep angle phi
+pub(crate) struct Sphere{
+    object_to_world : Arc<Transform>,
+    world_to_object : Arc<Transform>,
+    reverse_orientation : bool,
+
+    radius : f32,
+    z_min : f32,
+    z_max : f32,
+    theta_z_min : f32,
+    theta_z_max : f32,
+    phi_max : f32,
+}
+
+impl Sphere{
+    pub fn new(
+        object_to_world : Arc<Transform>, world_to_object : Arc<Transform>, reverse_orientation : bool,
+        radius : f32, z_min : f32, z_max : f32, phi_max : f32
+    ) -> Sphere{
+        let z_min_clamped = z_min.min(z_max).clamp(-radius, radius);
+        let z_max_clamped = z_min.max(z_max).clamp(-radius, radius);
+        Self{
+            object_to_world,
+            world_to_object,
+            reverse_orientation,
+            radius,
+            z_min: z_min_clamped,
+            z_max: z_max_clamped,
+            theta_z_min: (z_min_clamped / radius).clamp(-1.0, 1.0).acos(),
+            theta_z_max: (z_max_clamped / radius).clamp(-1.0, 1.0).acos(),
+            phi_max: phi_max.clamp(0.0, 360.0).to_radians(),
+        }
+    }
+
+    /// Compute the object space hit point for the given parametric distance
+    /// and its angle phi around the z axis
+    fn hit_point(&self, ray : &Ray, t : f32) -> (Point3f, f32) {
+        let mut p_hit = ray.point_at(t);
+
+        // Refine the hit point so it lies on the sphere surface
+        p_hit = p_hit * (self.radius / p_hit.distance(Point3f::default()));
+        if p_hit.x == 0.0 && p_hit.y == 0.0 {
+            p_hit.x = 1e-5 * self.radius;
+        }
+
+        let mut phi = p_hit.y.atan2(p_hit.x);
+        if phi < 0.0 {
+            phi += 2.0 * PI;
+        }
+
+        (p_hit, phi)
+    }
+
+    /// A hit point is outside a partial sphere if it is clipped by the z range or the sweep angle
+    fn is_clipped(&self, p_hit : &Point3f, phi : f32) -> bool {
+        (self.z_min > -self.radius && p_hit.z < self.z_min)
+            || (self.z_max < self.radius && p_hit.z > self.z_max)
+            || phi > self.phi_max
+    }
+
+    /// Find the closest valid hit along an object space ray
+    fn find_hit(&self, ray : &Ray, o_error : &Vector3f, d_error : &Vector3f) -> Option<(f32, Point3f, f32)> {
+        let ([ox, oy, oz], [dx, dy, dz]) = ray_components_with_error(ray, o_error, d_error);
+        let radius = EFloat::from(self.radius);
+
+        let a = dx * dx + dy * dy + dz * dz;
+        let b = (dx * ox + dy * oy + dz * oz) * 2.0;
+        let c = ox * ox + oy * oy + oz * oz - radius * radius;
+
+        let (t0, t1) = EFloat::quadratic(a, b, c)?;
+        let (t_shape_hit, (p_hit, phi)) = closest_quadric_hit(t0, t1, ray.get_t_max(), |t| {
+            let (p_hit, phi) = self.hit_point(ray, t.value());
+            if self.is_clipped(&p_hit, phi) { None } else { Some((p_hit, phi)) }
+        })?;
+
+        Some((t_shape_hit, p_hit, phi))
+    }
+}
+
+impl Shape for Sphere{
+    fn object_bound(&self) -> Bound3f {
+        Bound3f::from_points(
+            &Point3f::new(-self.radius, -self.radius, self.z_min),
+            &Point3f::new(self.radius, self.radius, self.z_max),
+        )
+    }
+
+    fn intersect(&self, r : &Ray) -> Option<(f32, SurfaceInteraction)> {
+        let (ray, o_error, d_error) = self.world_to_object.apply_ray_with_error(r);
+        let (t_shape_hit, p_hit, phi) = self.find_hit(&ray, &o_error, &d_error)?;
+
+        // Parametric representation of the hit point
+        let u = phi / self.phi_max;
+        let cos_theta = p_hit.z / self.radius;
+        let theta = cos_theta.clamp(-1.0, 1.0).acos();
+        let theta_range = self.theta_z_max - self.theta_z_min;
+        let v = (theta - self.theta_z_min) / theta_range;
+
+        let z_radius = (p_hit.x * p_hit.x + p_hit.y * p_hit.y).sqrt();
+        let cos_phi = p_hit.x / z_radius;
+        let sin_phi = p_hit.y / z_radius;
+        let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+
+        let dp_du = Vector3f::new(-self.phi_max * p_hit.y, self.phi_max * p_hit.x, 0.0);
+        let dp_dv = Vector3f::new(p_hit.z * cos_phi, p_hit.z * sin_phi, -self.radius * sin_theta) * theta_range;
+
+        // Second derivatives of the surface for the Weingarten equations
+        let d2p_duu = Vector3f::new(p_hit.x, p_hit.y, 0.0) * (-self.phi_max * self.phi_max);
+        let d2p_duv = Vector3f::new(-sin_phi, cos_phi, 0.0) * (theta_range * p_hit.z * self.phi_max);
+        let d2p_dvv = Vector3f::new(p_hit.x, p_hit.y, p_hit.z) * (-theta_range * theta_range);
+
+        let (dn_du, dn_dv) = weingarten(&dp_du, &dp_dv, &d2p_duu, &d2p_duv, &d2p_dvv);
+
+        // The refined hit point is within a few ulps of the true sphere surface
+        let p_error = Vector3f::new(p_hit.x, p_hit.y, p_hit.z).abs() * gamma(5);
+
+        let normal = Normal3f::from_vector(dp_du.cross(&dp_dv).normalize());
+        let interaction = SurfaceInteraction::new_surface(
+            p_hit,
+            normal,
+            p_error,
+            ray.get_direction().neg(),
+            ray.get_time(),
+            DifferentialGeometry{
+                uv: Point2f::new(u, v),
+                dp_du,
+                dp_dv,
+                dn_du,
+                dn_dv,
+            },
+            Some(self),
+        );
+
+        Some((t_shape_hit, self.object_to_world.apply(&interaction)))
+    }
+
+    fn intersect_p(&self, r : &Ray) -> bool {
+        let (ray, o_error, d_error) = self.world_to_object.apply_ray_with_error(r);
+        self.find_hit(&ray, &o_error, &d_error).is_some()
+    }
+
+    fn area(&self) -> f32 {
+        self.phi_max * self.radius * (self.z_max - self.z_min)
+    }
+
+    fn object_to_world(&self) -> &Transform {
+        &self.object_to_world
+    }
+
+    fn reverse_orientation(&self) -> bool {
+        self.reverse_orientation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::Interactions::Interactions;
+
+    fn unit_sphere(z_min : f32, z_max : f32, phi_max : f32) -> Sphere {
+        Sphere::new(
+            Arc::new(Transform::identity()),
+            Arc::new(Transform::identity()),
+            false,
+            1.0, z_min, z_max, phi_max
+        )
+    }
+
+    fn ray(origin : Point3f, direction : Vector3f) -> Ray {
+        Ray::new(origin, direction, f32::INFINITY, 0.0, None)
+    }
+
+    #[test]
+    fn test_sphere_hit_from_outside() {
+        let sphere = unit_sphere(-1.0, 1.0, 360.0);
+        let (t, si) = sphere.intersect(&ray(Point3f::new(0.0, 0.0, -5.0), Vector3f::new(0.0, 0.0, 1.0))).unwrap();
+        assert!((t - 4.0).abs() < 1e-5);
+        assert!((si.point.z + 1.0).abs() < 1e-5);
+        assert!((si.normal.length() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_sphere_hit_from_inside() {
+        let sphere = unit_sphere(-1.0, 1.0, 360.0);
+        let (t, _) = sphere.intersect(&ray(Point3f::default(), Vector3f::new(1.0, 0.0, 0.0))).unwrap();
+        assert!((t - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_spawned_ray_does_not_self_intersect() {
+        // Far away from the origin the rounding errors of the hit point are large
+        let object_to_world = Transform::translate(Vector3f::new(1000.3, -200.7, 51.1));
+        let sphere = Sphere::new(
+            Arc::new(object_to_world),
+            Arc::new(object_to_world.inverse()),
+            false,
+            3.7, -3.7, 3.7, 360.0
+        );
+
+        for i in 0..64 {
+            let angle = i as f32 * 0.37;
+            let direction = Vector3f::new(angle.cos(), angle.sin(), 0.3 * (angle * 1.7).sin()).normalize();
+            let origin = Point3f::new(1000.3, -200.7, 51.1) + direction * -20.0;
+            let (_, si) = sphere.intersect(&ray(origin, direction)).unwrap();
+            assert!(si.point_error.length() > 0.0);
+
+            let normal = Vector3f::from_normal(si.normal);
+            assert!(!sphere.intersect_p(&si.spawn_ray(normal)));
+            assert!(sphere.intersect_p(&si.spawn_ray(normal.neg())));
+        }
+    }
+
+    #[test]
+    fn test_sphere_miss() {
+        let sphere = unit_sphere(-1.0, 1.0, 360.0);
+        let r = ray(Point3f::new(0.0, 2.0, -5.0), Vector3f::new(0.0, 0.0, 1.0));
+        assert!(sphere.intersect(&r).is_none());
+        assert!(!sphere.intersect_p(&r));
+    }
+
+    #[test]
+    fn test_sphere_z_clipping_uses_far_hit() {
+        // The near cap is clipped away so the ray should hit the inside of the far side
+        let sphere = unit_sphere(-0.5, 1.0, 360.0);
+        let (t, si) = sphere.intersect(&ray(Point3f::new(0.0, 0.0, -5.0), Vector3f::new(0.0, 0.0, 1.0))).unwrap();
+        assert!((t - 6.0).abs() < 1e-5);
+        assert!((si.point.z - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_sphere_phi_clipping() {
+        // Only the quarter of the sphere with positive x and y is kept
+        let sphere = unit_sphere(-1.0, 1.0, 90.0);
+        let inside_sweep = ray(Point3f::new(5.0, 0.5, 0.0), Vector3f::new(-1.0, 0.0, 0.0));
+        let outside_sweep = ray(Point3f::new(5.0, -0.5, 0.0), Vector3f::new(-1.0, 0.0, 0.0));
+        assert!(sphere.intersect(&inside_sweep).is_some());
+        assert!(sphere.intersect(&outside_sweep).is_none());
+    }
+
+    #[test]
+    fn test_sphere_uv_and_derivatives() {
+        let sphere = unit_sphere(-1.0, 1.0, 360.0);
+        let (_, si) = sphere.intersect(&ray(Point3f::new(5.0, 0.0, 0.0), Vector3f::new(-1.0, 0.0, 0.0))).unwrap();
+        assert!(si.uv.x.abs() < 1e-5);
+        assert!((si.uv.y - 0.5).abs() < 1e-5);
+        // dp/du is tangent to the equator and dp/dv points from z_min towards z_max
+        assert!(si.dp_du.x.abs() < 1e-5 && si.dp_du.y > 0.0);
+        assert!(si.dp_dv.z > 0.0);
+        // The normal of a unit sphere changes exactly as fast as the point does
+        assert!((si.dn_du.y - si.dp_du.y).abs() < 1e-4);
+        assert!((si.dn_dv.z - si.dp_dv.z).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_sphere_reverse_orientation_and_handedness() {
+        let r = ray(Point3f::new(0.0, 0.0, -5.0), Vector3f::new(0.0, 0.0, 1.0));
+
+        let reversed = Sphere::new(
+            Arc::new(Transform::identity()), Arc::new(Transform::identity()), true, 1.0, -1.0, 1.0, 360.0
+        );
+        let (_, si) = reversed.intersect(&r).unwrap();
+        assert!(si.normal.z > 0.0);
+
+        // Mirroring the sphere swaps its handedness, the normal must still agree with dp/du x dp/dv in world space
+        let mirror = Transform::scale(1.0, 1.0, -1.0);
+        let mirrored = Sphere::new(Arc::new(mirror), Arc::new(mirror.inverse()), false, 1.0, -1.0, 1.0, 360.0);
+        let (_, si) = mirrored.intersect(&r).unwrap();
+        assert!(si.normal.dot(&si.dp_du.cross(&si.dp_dv)) > 0.0);
+        assert!(mirrored.world_bound().inside(si.point));
+    }
+
+    #[test]
+    fn test_sphere_bound_and_area() {
+        let sphere = unit_sphere(-1.0, 1.0, 360.0);
+        let bound = sphere.object_bound();
+        assert_eq!(bound.p_min, Point3f::new(-1.0, -1.0, -1.0));
+        assert_eq!(bound.p_max, Point3f::new(1.0, 1.0, 1.0));
+        assert!((sphere.area() - 4.0 * PI).abs() < 1e-4);
+    }
+}
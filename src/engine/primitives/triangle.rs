@@ -0,0 +1,416 @@
+use std::sync::Arc;
+use crate::engine::{Bound3f, SurfaceInteraction};
+use crate::engine::Interactions::surface_interaction::DifferentialGeometry;
+use crate::engine::math::gamma;
+use crate::engine::math::Normal::Normal3f;
+use crate::engine::math::Point::{Point2f, Point3f};
+use crate::engine::math::rays::BaseRay;
+use crate::engine::math::rays::Ray::Ray;
+use crate::engine::math::transformations::{Apply, Transform};
+use crate::engine::math::Vector::Vector3f;
+use crate::engine::primitives::Shape;
+
+/// Vertex data shared by all the triangles of a mesh.
+/// Positions, normals and tangents are stored in world space
+pub(crate) struct TriangleMesh{
+    object_to_world : Arc<Transform>,
+    world_to_object : Arc<Transform>,
+    reverse_orientation : bool,
+    transform_swaps_handedness : bool,
+
+    pub n_triangles : usize,
+    pub vertex_indices : Vec<usize>,
+    pub p : Vec<Point3f>,
+    pub n : Option<Vec<Normal3f>>,
+    pub s : Option<Vec<Vector3f>>,
+    pub uv : Option<Vec<Point2f>>,
+}
+
+/// The vertex buffers of a mesh in object space, three indices per triangle.
+/// Normals, tangents and uv coordinates are optional and given per vertex
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MeshBuffers{
+    pub vertex_indices : Vec<usize>,
+    pub p : Vec<Point3f>,
+    pub n : Option<Vec<Normal3f>>,
+    pub s : Option<Vec<Vector3f>>,
+    pub uv : Option<Vec<Point2f>>,
+}
+
+impl TriangleMesh{
+    pub fn new(
+        object_to_world : Arc<Transform>, world_to_object : Arc<Transform>, reverse_orientation : bool, buffers : MeshBuffers
+    ) -> TriangleMesh{
+        let MeshBuffers{ vertex_indices, p, n, s, uv } = buffers;
+        assert_eq!(vertex_indices.len() % 3, 0, "Triangle mesh indices must come in groups of three");
+
+        Self{
+            n_triangles: vertex_indices.len() / 3,
+            vertex_indices,
+            p: p.iter().map(|p| object_to_world.apply(p)).collect(),
+            n: n.map(|n| n.iter().map(|n| object_to_world.apply(n)).collect()),
+            s: s.map(|s| s.iter().map(|s| object_to_world.apply(s)).collect()),
+            uv,
+            transform_swaps_handedness: object_to_world.swaps_handedness(),
+            object_to_world,
+            world_to_object,
+            reverse_orientation,
+        }
+    }
+}
+
+/// Create one `Triangle` shape per face of the mesh, all sharing the same vertex buffers
+pub(crate) fn create_triangle_mesh(mesh : TriangleMesh) -> Vec<Arc<dyn Shape>> {
+    let mesh = Arc::new(mesh);
+    (0..mesh.n_triangles)
+        .map(|i| Arc::new(Triangle::new(mesh.clone(), i)) as Arc<dyn Shape>)
+        .collect()
+}
+
+pub(crate) struct Triangle{
+    mesh : Arc<TriangleMesh>,
+    v : usize, // Offset of the first vertex index of the triangle
+}
+
+impl Triangle{
+    pub fn new(mesh : Arc<TriangleMesh>, triangle_index : usize) -> Triangle{
+        Self{
+            mesh,
+            v: 3 * triangle_index,
+        }
+    }
+
+    fn vertices(&self) -> (usize, usize, usize) {
+        let indices = &self.mesh.vertex_indices;
+        (indices[self.v], indices[self.v + 1], indices[self.v + 2])
+    }
+
+    fn uvs(&self) -> [Point2f; 3] {
+        match &self.mesh.uv {
+            Some(uv) => {
+                let (v0, v1, v2) = self.vertices();
+                [uv[v0], uv[v1], uv[v2]]
+            }
+            None => [Point2f::new(0.0, 0.0), Point2f::new(1.0, 0.0), Point2f::new(1.0, 1.0)]
+        }
+    }
+
+    /// Watertight ray-triangle test, returning the parametric distance and barycentric coordinates of the hit.
+    /// The ray is moved to the origin and sheared so it points along +z, which reduces
+    /// the test to a 2D edge function evaluation that never misses along shared edges
+    fn find_hit(&self, ray : &Ray) -> Option<(f32, f32, f32, f32)> {
+        let (v0, v1, v2) = self.vertices();
+        let o = ray.get_origin();
+
+        // Translate the vertices based on the ray origin
+        let mut p0t = self.mesh.p[v0] - o;
+        let mut p1t = self.mesh.p[v1] - o;
+        let mut p2t = self.mesh.p[v2] - o;
+
+        // Permute the components so that the largest direction component is z
+        let kz = ray.get_direction().abs().max_dimension();
+        let kx = if kz + 1 == 3 { 0 } else { kz + 1 };
+        let ky = if kx + 1 == 3 { 0 } else { kx + 1 };
+        let d = ray.get_direction().permute(kx, ky, kz);
+        p0t = p0t.permute(kx, ky, kz);
+        p1t = p1t.permute(kx, ky, kz);
+        p2t = p2t.permute(kx, ky, kz);
+
+        // Shear the vertices so the ray direction becomes (0, 0, 1)
+        let sx = -d.x / d.z;
+        let sy = -d.y / d.z;
+        let sz = 1.0 / d.z;
+        p0t.x += sx * p0t.z;
+        p0t.y += sy * p0t.z;
+        p1t.x += sx * p1t.z;
+        p1t.y += sy * p1t.z;
+        p2t.x += sx * p2t.z;
+        p2t.y += sy * p2t.z;
+
+        // Edge functions
+        let mut e0 = p1t.x * p2t.y - p1t.y * p2t.x;
+        let mut e1 = p2t.x * p0t.y - p2t.y * p0t.x;
+        let mut e2 = p0t.x * p1t.y - p0t.y * p1t.x;
+
+        // Fall back to double precision when an edge lies exactly on the ray
+        if e0 == 0.0 || e1 == 0.0 || e2 == 0.0 {
+            e0 = ((p1t.x as f64) * (p2t.y as f64) - (p1t.y as f64) * (p2t.x as f64)) as f32;
+            e1 = ((p2t.x as f64) * (p0t.y as f64) - (p2t.y as f64) * (p0t.x as f64)) as f32;
+            e2 = ((p0t.x as f64) * (p1t.y as f64) - (p0t.y as f64) * (p1t.x as f64)) as f32;
+        }
+
+        if (e0 < 0.0 || e1 < 0.0 || e2 < 0.0) && (e0 > 0.0 || e1 > 0.0 || e2 > 0.0) {
+            return None;
+        }
+        let det = e0 + e1 + e2;
+        if det == 0.0 {
+            return None;
+        }
+
+        // Compute the scaled hit distance and test it against the ray range
+        p0t.z *= sz;
+        p1t.z *= sz;
+        p2t.z *= sz;
+        let t_scaled = e0 * p0t.z + e1 * p1t.z + e2 * p2t.z;
+        let t_max = ray.get_t_max();
+        if det < 0.0 && (t_scaled >= 0.0 || t_scaled < t_max * det) {
+            return None;
+        }
+        if det > 0.0 && (t_scaled <= 0.0 || t_scaled > t_max * det) {
+            return None;
+        }
+
+        let inv_det = 1.0 / det;
+        let t = t_scaled * inv_det;
+
+        // Make sure the hit distance is conservatively greater than zero, given the rounding
+        // errors of the transformed vertices and of the edge functions
+        let max_zt = Vector3f::new(p0t.z, p1t.z, p2t.z).abs().max_component();
+        let max_xt = Vector3f::new(p0t.x, p1t.x, p2t.x).abs().max_component();
+        let max_yt = Vector3f::new(p0t.y, p1t.y, p2t.y).abs().max_component();
+        let delta_z = gamma(3) * max_zt;
+        let delta_x = gamma(5) * (max_xt + max_zt);
+        let delta_y = gamma(5) * (max_yt + max_zt);
+        let delta_e = 2.0 * (gamma(2) * max_xt * max_yt + delta_y * max_xt + delta_x * max_yt);
+        let max_e = Vector3f::new(e0, e1, e2).abs().max_component();
+        let delta_t = 3.0 * (gamma(3) * max_e * max_zt + delta_e * max_zt + delta_z * max_e) * inv_det.abs();
+        if t <= delta_t {
+            return None;
+        }
+
+        Some((t, e0 * inv_det, e1 * inv_det, e2 * inv_det))
+    }
+}
+
+impl Shape for Triangle{
+    fn object_bound(&self) -> Bound3f {
+        let (v0, v1, v2) = self.vertices();
+        let world_to_object = &self.mesh.world_to_object;
+        Bound3f::from_points(
+            &world_to_object.apply(&self.mesh.p[v0]),
+            &world_to_object.apply(&self.mesh.p[v1]),
+        ).union(&world_to_object.apply(&self.mesh.p[v2]))
+    }
+
+    // The vertices are already in world space so they can be bound directly
+    fn world_bound(&self) -> Bound3f {
+        let (v0, v1, v2) = self.vertices();
+        Bound3f::from_points(&self.mesh.p[v0], &self.mesh.p[v1])
+            .union(&self.mesh.p[v2])
+    }
+
+    fn object_to_world(&self) -> &Transform {
+        &self.mesh.object_to_world
+    }
+
+    fn intersect(&self, ray : &Ray) -> Option<(f32, SurfaceInteraction)> {
+        let (t, b0, b1, b2) = self.find_hit(ray)?;
+        let (v0, v1, v2) = self.vertices();
+        let p0 = self.mesh.p[v0];
+        let p1 = self.mesh.p[v1];
+        let p2 = self.mesh.p[v2];
+        let uv = self.uvs();
+
+        // Partial derivatives of the triangle from its parameterization
+        let duv02 = uv[0] - uv[2];
+        let duv12 = uv[1] - uv[2];
+        let dp02 = p0 - p2;
+        let dp12 = p1 - p2;
+        let determinant = duv02.x * duv12.y - duv02.y * duv12.x;
+        let degenerate_uv = determinant.abs() < 1e-8;
+
+        let mut dp_du = Vector3f::default();
+        let mut dp_dv = Vector3f::default();
+        if !degenerate_uv {
+            let inv_det = 1.0 / determinant;
+            dp_du = (dp02 * duv12.y - dp12 * duv02.y) * inv_det;
+            dp_dv = (dp12 * duv02.x - dp02 * duv12.x) * inv_det;
+        }
+        if degenerate_uv || dp_du.cross(&dp_dv).length_sq() == 0.0 {
+            // Pick an arbitrary coordinate system around the geometric normal
+            let ng = (p2 - p0).cross(&(p1 - p0));
+            if ng.length_sq() == 0.0 {
+                return None;
+            }
+            (dp_du, dp_dv) = ng.normalize().co_ordinate_system();
+        }
+
+        // Interpolate the hit point and its uv coordinates
+        let p_hit = p0 * b0 + p1 * b1 + p2 * b2;
+        let uv_hit = uv[0] * b0 + uv[1] * b1 + uv[2] * b2;
+
+        // Error bounds of the barycentric interpolation of the hit point
+        let x_abs_sum = (b0 * p0.x).abs() + (b1 * p1.x).abs() + (b2 * p2.x).abs();
+        let y_abs_sum = (b0 * p0.y).abs() + (b1 * p1.y).abs() + (b2 * p2.y).abs();
+        let z_abs_sum = (b0 * p0.z).abs() + (b1 * p1.z).abs() + (b2 * p2.z).abs();
+        let p_error = Vector3f::new(x_abs_sum, y_abs_sum, z_abs_sum) * gamma(7);
+
+        let normal = Normal3f::from_vector(dp02.cross(&dp12).normalize());
+        let mut interaction = SurfaceInteraction::new_surface(
+            p_hit,
+            normal,
+            p_error,
+            ray.get_direction().neg(),
+            ray.get_time(),
+            DifferentialGeometry{
+                uv: uv_hit,
+                dp_du,
+                dp_dv,
+                dn_du: Normal3f::default(),
+                dn_dv: Normal3f::default(),
+            },
+            Some(self),
+        );
+
+        if self.mesh.n.is_some() || self.mesh.s.is_some() {
+            // Shading normal
+            let ns = match &self.mesh.n {
+                Some(n) => {
+                    let ns = n[v0] * b0 + n[v1] * b1 + n[v2] * b2;
+                    if ns.length() > 0.0 { ns.normalize() } else { interaction.normal }
+                }
+                None => interaction.normal
+            };
+
+            // Shading tangent
+            let mut ss = match &self.mesh.s {
+                Some(s) => s[v0] * b0 + s[v1] * b1 + s[v2] * b2,
+                None => interaction.dp_du
+            };
+            if ss.length_sq() > 0.0 {
+                ss = ss.normalize();
+            } else {
+                ss = interaction.dp_du.normalize();
+            }
+
+            // Shading bitangent, and adjust the tangent so all three are orthogonal
+            let ns_vector = Vector3f::from_normal(ns);
+            let mut ts = ns_vector.cross(&ss);
+            if ts.length_sq() > 0.0 {
+                ts = ts.normalize();
+                ss = ts.cross(&ns_vector);
+            } else {
+                (ss, ts) = ns_vector.co_ordinate_system();
+            }
+
+            // Partial derivatives of the shading normal
+            let (dn_du, dn_dv) = match &self.mesh.n {
+                Some(n) if !degenerate_uv => {
+                    let dn1 = n[v0] - n[v2];
+                    let dn2 = n[v1] - n[v2];
+                    let inv_det = 1.0 / determinant;
+                    (
+                        (dn1 * duv12.y - dn2 * duv02.y) * inv_det,
+                        (dn2 * duv02.x - dn1 * duv12.x) * inv_det,
+                    )
+                }
+                _ => (Normal3f::default(), Normal3f::default())
+            };
+
+            interaction.set_shading_geometry(ss, ts, dn_du, dn_dv, true);
+        }
+
+        Some((t, interaction))
+    }
+
+    fn intersect_p(&self, ray : &Ray) -> bool {
+        self.find_hit(ray).is_some()
+    }
+
+    /// Area in world space, unlike the other shapes, as the mesh only stores the transformed vertices
+    fn area(&self) -> f32 {
+        let (v0, v1, v2) = self.vertices();
+        let p0 = self.mesh.p[v0];
+        (self.mesh.p[v1] - p0).cross(&(self.mesh.p[v2] - p0)).length() * 0.5
+    }
+
+    fn reverse_orientation(&self) -> bool {
+        self.mesh.reverse_orientation
+    }
+
+    fn transform_swaps_handedness(&self) -> bool {
+        self.mesh.transform_swaps_handedness
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quad(n : Option<Vec<Normal3f>>) -> Vec<Arc<dyn Shape>> {
+        let mesh = TriangleMesh::new(
+            Arc::new(Transform::identity()),
+            Arc::new(Transform::identity()),
+            false,
+            MeshBuffers{
+                vertex_indices: vec![0, 1, 2, 0, 2, 3],
+                p: vec![
+                    Point3f::new(-1.0, -1.0, 0.0),
+                    Point3f::new(1.0, -1.0, 0.0),
+                    Point3f::new(1.0, 1.0, 0.0),
+                    Point3f::new(-1.0, 1.0, 0.0),
+                ],
+                n,
+                ..Default::default()
+            },
+        );
+        create_triangle_mesh(mesh)
+    }
+
+    fn ray(origin : Point3f, direction : Vector3f) -> Ray {
+        Ray::new(origin, direction, f32::INFINITY, 0.0, None)
+    }
+
+    #[test]
+    fn test_triangle_hit_and_miss() {
+        let triangles = quad(None);
+        let r = ray(Point3f::new(0.5, -0.5, -2.0), Vector3f::new(0.0, 0.0, 1.0));
+        let (t, si) = triangles[0].intersect(&r).unwrap();
+        assert!((t - 2.0).abs() < 1e-5);
+        assert!((si.point.x - 0.5).abs() < 1e-5 && (si.point.y + 0.5).abs() < 1e-5);
+        assert!(triangles[1].intersect(&r).is_none());
+        assert!(!triangles[1].intersect_p(&r));
+    }
+
+    #[test]
+    fn test_triangle_shared_edge_is_watertight() {
+        // A ray through the diagonal shared by both triangles must hit at least one of them
+        let triangles = quad(None);
+        let r = ray(Point3f::new(0.25, 0.25, 1.0), Vector3f::new(0.0, 0.0, -1.0));
+        assert!(triangles.iter().any(|t| t.intersect_p(&r)));
+    }
+
+    #[test]
+    fn test_triangle_respects_t_max() {
+        let triangles = quad(None);
+        let r = Ray::new(Point3f::new(0.5, -0.5, -2.0), Vector3f::new(0.0, 0.0, 1.0), 1.0, 0.0, None);
+        assert!(triangles[0].intersect(&r).is_none());
+    }
+
+    #[test]
+    fn test_triangle_interpolates_shading_normal() {
+        let tilted = Normal3f::new(1.0, 0.0, 1.0).normalize();
+        let up = Normal3f::new(0.0, 0.0, 1.0);
+        let triangles = quad(Some(vec![up, tilted, tilted, up]));
+        let r = ray(Point3f::new(1.0 - 1e-3, 0.0, 1.0), Vector3f::new(0.0, 0.0, -1.0));
+        let (_, si) = triangles[0].intersect(&r).unwrap();
+        assert!(si.shading.normal.x > 0.5);
+        assert!(si.normal.dot(&Vector3f::from_normal(si.shading.normal)) > 0.0);
+    }
+
+    #[test]
+    fn test_triangle_area() {
+        let triangles = quad(None);
+        assert!((triangles[0].area() - 2.0).abs() < 1e-5);
+
+        // The area is measured in world space
+        let scale = Transform::scale(2.0, 3.0, 1.0);
+        let buffers = MeshBuffers{
+            vertex_indices: vec![0, 1, 2],
+            p: vec![Point3f::new(0.0, 0.0, 0.0), Point3f::new(1.0, 0.0, 0.0), Point3f::new(0.0, 1.0, 0.0)],
+            ..Default::default()
+        };
+        let triangles = create_triangle_mesh(TriangleMesh::new(Arc::new(scale), Arc::new(scale.inverse()), false, buffers));
+        assert!((triangles[0].area() - 3.0).abs() < 1e-5);
+    }
+}
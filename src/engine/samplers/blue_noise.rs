@@ -0,0 +1,89 @@
+use crate::engine::math::blue_noise::blue_noise;
+use crate::engine::math::Point::{Point2f, Point2i};
+use crate::engine::math::sampling::mix_bits;
+use crate::engine::math::sobol::{sobol_sample, SobolRandomization};
+use crate::engine::math::ONE_MINUS_EPSILON;
+use crate::engine::samplers::Sampler;
+
+/// Take the samples of every pixel from the same Owen scrambled Sobol points, dithered in screen space by a
+/// blue noise texture: the order of the samples is ranked by xoring their index with a key read from the
+/// texture, and the points are toroidally shifted by other values of the texture. Neighbouring pixels then
+/// get very different samples, so at low sample counts the error looks like fine grain rather than blotches.
+/// Each dimension reads the texture at its own offset
+#[derive(Clone)]
+pub(crate) struct BlueNoiseSampler{
+    samples_per_pixel : u64,
+    seed : u64,
+    pixel : Point2i,
+    sample_num : u64,
+    dimension : u64,
+}
+
+impl BlueNoiseSampler{
+    pub fn new(samples_per_pixel : u64, seed : u64) -> BlueNoiseSampler{
+        Self{
+            samples_per_pixel: samples_per_pixel.max(1).next_power_of_two(),
+            seed,
+            pixel: Point2i{ x: 0, y: 0 },
+            sample_num: 0,
+            dimension: 0,
+        }
+    }
+
+    /// Hash of the current dimension, the same in every pixel
+    fn dimension_hash(&self, dimension : u64) -> u64 {
+        mix_bits(dimension ^ mix_bits(self.seed))
+    }
+
+    /// Value of the blue noise texture for the current pixel, at the offset chosen by `hash`
+    fn dither(&self, hash : u64) -> f32 {
+        blue_noise(self.pixel.x + (hash & 0xffff) as i32, self.pixel.y + ((hash >> 16) & 0xffff) as i32)
+    }
+
+    /// Sobol point of the current sample for the dimension `dimension`, ranked and shifted by the texture
+    fn sample_dimension(&self, dimension : u64, sobol_dimension : usize) -> f32 {
+        let hash = self.dimension_hash(dimension);
+        let key = (self.dither(hash >> 32) * self.samples_per_pixel as f32) as u64;
+        let v = sobol_sample(self.sample_num ^ key, sobol_dimension, SobolRandomization::Owen, hash as u32);
+        let v = v + self.dither(mix_bits(hash.wrapping_add(1 + sobol_dimension as u64)));
+        (if v >= 1.0 { v - 1.0 } else { v }).min(ONE_MINUS_EPSILON)
+    }
+}
+
+impl Sampler for BlueNoiseSampler{
+    fn samples_per_pixel(&self) -> u64 {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel(&mut self, p : &Point2i) {
+        self.pixel = *p;
+        self.set_sample_number(0);
+    }
+
+    fn start_next_sample(&mut self) -> bool {
+        self.set_sample_number(self.sample_num + 1)
+    }
+
+    fn set_sample_number(&mut self, sample_num : u64) -> bool {
+        self.sample_num = sample_num;
+        self.dimension = 0;
+        sample_num < self.samples_per_pixel
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        let v = self.sample_dimension(self.dimension, 0);
+        self.dimension += 1;
+        v
+    }
+
+    fn get_2d(&mut self) -> Point2f {
+        // Both coordinates share the ranking of the pair, so that they come from the same Sobol point
+        let p = Point2f::new(self.sample_dimension(self.dimension, 0), self.sample_dimension(self.dimension, 1));
+        self.dimension += 2;
+        p
+    }
+
+    fn clone_with_seed(&self, _seed : u64) -> Box<dyn Sampler> {
+        Box::new(self.clone())
+    }
+}
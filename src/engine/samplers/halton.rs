@@ -0,0 +1,177 @@
+use std::sync::Arc;
+use crate::engine::math::bounding_box::Bound2i;
+use crate::engine::math::Point::{Point2f, Point2i};
+use crate::engine::math::rng::Rng;
+use crate::engine::math::sampling::{
+    compute_radical_inverse_permutations, inverse_radical_inverse, radical_inverse, scrambled_radical_inverse, PRIMES, PRIME_TABLE_SIZE
+};
+use crate::engine::samplers::Sampler;
+
+// The Halton sequence is spread over tiles of at most this many pixels in each direction
+const MAX_RESOLUTION : i32 = 128;
+
+/// Take the samples of the whole image from a single Halton sequence, whose two first dimensions are scaled
+/// over the pixels so that the samples falling in a pixel can be found directly from the index of the pixel.
+/// The dimensions past them use scrambled radical inverses with random digit permutations, which breaks the
+/// correlation between the dimensions of large prime bases. Dimensions beyond the table of primes wrap around
+pub(crate) struct HaltonSampler{
+    samples_per_pixel : u64,
+    sample_bounds : Bound2i,
+    // Shared by the clones so that the tiles all sample the same sequence
+    permutations : Arc<Vec<Vec<u16>>>,
+    base_scales : [u64; 2],
+    base_exponents : [u32; 2],
+    sample_stride : u64,
+    mult_inverse : [u64; 2],
+    pixel : Point2i,
+    offset_for_current_pixel : u64,
+    sample_num : u64,
+    dimension : usize,
+    index : u64,
+}
+
+impl HaltonSampler{
+    /// `sample_bounds` are the pixels of the film that will be sampled, `seed` chooses the digit permutations
+    pub fn new(samples_per_pixel : u64, sample_bounds : Bound2i, seed : u64) -> HaltonSampler{
+        let permutations = Arc::new(compute_radical_inverse_permutations(&mut Rng::new(seed)));
+        Self::with_permutations(samples_per_pixel, sample_bounds, permutations)
+    }
+
+    fn with_permutations(samples_per_pixel : u64, sample_bounds : Bound2i, permutations : Arc<Vec<Vec<u16>>>) -> HaltonSampler{
+        // Scale the two first dimensions so that each pixel holds one sample every `sample_stride` samples
+        let resolution = [sample_bounds.p_max.x - sample_bounds.p_min.x, sample_bounds.p_max.y - sample_bounds.p_min.y];
+        let mut base_scales = [1; 2];
+        let mut base_exponents = [0; 2];
+        for i in 0..2 {
+            while base_scales[i] < resolution[i].clamp(1, MAX_RESOLUTION) as u64 {
+                base_scales[i] *= PRIMES[i];
+                base_exponents[i] += 1;
+            }
+        }
+
+        Self{
+            samples_per_pixel,
+            sample_bounds,
+            permutations,
+            base_scales,
+            base_exponents,
+            sample_stride: base_scales[0] * base_scales[1],
+            mult_inverse: [
+                multiplicative_inverse(base_scales[1], base_scales[0]),
+                multiplicative_inverse(base_scales[0], base_scales[1])
+            ],
+            pixel: sample_bounds.p_min,
+            offset_for_current_pixel: 0,
+            sample_num: 0,
+            dimension: 0,
+            index: 0,
+        }
+    }
+
+    /// Index in the sequence of the sample `sample_num` of the current pixel
+    fn index_for_sample(&self, sample_num : u64) -> u64 {
+        self.offset_for_current_pixel + sample_num * self.sample_stride
+    }
+
+    fn sample_dimension(&self, index : u64, dimension : usize) -> f32 {
+        match dimension {
+            0 => radical_inverse(PRIMES[0], index >> self.base_exponents[0]),
+            1 => radical_inverse(PRIMES[1], index / self.base_scales[1]),
+            _ => {
+                let d = dimension % PRIME_TABLE_SIZE;
+                scrambled_radical_inverse(PRIMES[d], index, &self.permutations[d])
+            }
+        }
+    }
+}
+
+impl Sampler for HaltonSampler{
+    fn samples_per_pixel(&self) -> u64 {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel(&mut self, p : &Point2i) {
+        self.pixel = *p;
+        // The first index whose two first radical inverses fall in the pixel: its lowest digits in
+        // base 2 and base 3 are the reversed digits of the pixel coordinates, combined with the chinese remainder theorem
+        self.offset_for_current_pixel = 0;
+        if self.sample_stride > 1 {
+            let pm = [
+                (p.x - self.sample_bounds.p_min.x).rem_euclid(MAX_RESOLUTION) as u64,
+                (p.y - self.sample_bounds.p_min.y).rem_euclid(MAX_RESOLUTION) as u64,
+            ];
+            for i in 0..2 {
+                let dim_offset = inverse_radical_inverse(PRIMES[i], pm[i], self.base_exponents[i]);
+                self.offset_for_current_pixel += dim_offset * (self.sample_stride / self.base_scales[i]) * self.mult_inverse[i];
+            }
+            self.offset_for_current_pixel %= self.sample_stride;
+        }
+        self.set_sample_number(0);
+    }
+
+    fn start_next_sample(&mut self) -> bool {
+        self.set_sample_number(self.sample_num + 1)
+    }
+
+    fn set_sample_number(&mut self, sample_num : u64) -> bool {
+        self.sample_num = sample_num;
+        self.dimension = 0;
+        self.index = self.index_for_sample(sample_num);
+        sample_num < self.samples_per_pixel
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        let v = self.sample_dimension(self.index, self.dimension);
+        self.dimension += 1;
+        v
+    }
+
+    fn get_2d(&mut self) -> Point2f {
+        let p = Point2f::new(self.sample_dimension(self.index, self.dimension), self.sample_dimension(self.index, self.dimension + 1));
+        self.dimension += 2;
+        p
+    }
+
+    fn clone_with_seed(&self, _seed : u64) -> Box<dyn Sampler> {
+        Box::new(HaltonSampler::with_permutations(self.samples_per_pixel, self.sample_bounds, self.permutations.clone()))
+    }
+}
+
+/// The inverse of `a` modulo `n`, found with the extended Euclidean algorithm
+fn multiplicative_inverse(a : u64, n : u64) -> u64 {
+    let (mut old_r, mut r) = (a as i64, n as i64);
+    let (mut old_s, mut s) = (1i64, 0i64);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+    old_s.rem_euclid(n as i64) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_samples_of_a_pixel_are_in_the_pixel() {
+        let sample_bounds = Bound2i::from_corners(Point2i{ x: -1, y: -1 }, Point2i{ x: 11, y: 6 });
+        let mut sampler = HaltonSampler::new(8, sample_bounds, 3);
+        let mut indices = Vec::new();
+        for p in sample_bounds.pixels() {
+            sampler.start_pixel(&p);
+            for sample_num in 0..8 {
+                // The radical inverses scaled over the pixels, before keeping only the position inside of the pixel
+                let index = sampler.index_for_sample(sample_num);
+                let x = radical_inverse(PRIMES[0], index) * sampler.base_scales[0] as f32;
+                let y = radical_inverse(PRIMES[1], index) * sampler.base_scales[1] as f32;
+                assert_eq!((x as i32, y as i32), (p.x - sample_bounds.p_min.x, p.y - sample_bounds.p_min.y), "{:?} {}", p, sample_num);
+                indices.push(index);
+            }
+        }
+        let count = indices.len();
+        indices.sort();
+        indices.dedup();
+        assert_eq!(indices.len(), count);
+    }
+}
@@ -0,0 +1,53 @@
+use crate::engine::math::Point::{Point2f, Point2i};
+use crate::engine::math::rng::Rng;
+use crate::engine::samplers::Sampler;
+
+/// Uniform random values for every dimension of every sample, without any stratification
+pub(crate) struct IndependentSampler{
+    samples_per_pixel : u64,
+    current_sample : u64,
+    rng : Rng,
+}
+
+impl IndependentSampler{
+    pub fn new(samples_per_pixel : u64, seed : u64) -> IndependentSampler{
+        Self{
+            samples_per_pixel,
+            current_sample: 0,
+            rng: Rng::new(seed),
+        }
+    }
+}
+
+impl Sampler for IndependentSampler{
+    fn samples_per_pixel(&self) -> u64 {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel(&mut self, _p : &Point2i) {
+        self.current_sample = 0;
+    }
+
+    fn start_next_sample(&mut self) -> bool {
+        self.current_sample += 1;
+        self.current_sample < self.samples_per_pixel
+    }
+
+    fn set_sample_number(&mut self, sample_num : u64) -> bool {
+        self.current_sample = sample_num;
+        self.current_sample < self.samples_per_pixel
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        self.rng.uniform_f32()
+    }
+
+    fn get_2d(&mut self) -> Point2f {
+        let x = self.rng.uniform_f32();
+        Point2f::new(x, self.rng.uniform_f32())
+    }
+
+    fn clone_with_seed(&self, seed : u64) -> Box<dyn Sampler> {
+        Box::new(IndependentSampler::new(self.samples_per_pixel, seed))
+    }
+}
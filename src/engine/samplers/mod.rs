@@ -1,8 +1,21 @@
+pub(crate) mod independent;
+pub(crate) mod stratified;
+pub(crate) mod halton;
+pub(crate) mod sobol;
+pub(crate) mod zero_two_sequence;
+pub(crate) mod padded_sobol;
+pub(crate) mod pmj02;
+pub(crate) mod blue_noise;
+
+use crate::engine::cameras::CameraSample;
 use crate::engine::lights::GeneralLight;
 use crate::engine::math::{Camera, Integrator};
+use crate::engine::math::Point::{Point2f, Point2i};
 use crate::engine::math::rays::Ray::Ray;
+use crate::engine::math::rng::Rng;
 use crate::engine::primitives::GeneralPrimitive;
 use crate::engine::Scene;
+use crate::engine::spectrum::Spectrum;
 
 pub trait SamplerIntegrator : Integrator {
     fn render(&self, scene: &Scene<GeneralPrimitive, GeneralLight>){
@@ -21,7 +34,7 @@ pub trait SamplerIntegrator : Integrator {
         // };
         //
         // let seed = tile.y * n_tiles.x + tile.x;
-        // let tile_sampler = Sampler::Clone(seed);
+        // let tile_sampler = self.get_sampler().clone_with_seed(seed);
         //
         // let x0 = sample_bounds.pMin.x + tile.x * TILE_SIZE;
         // let x1 = (x0 + TILE_SIZE, sample_bounds.pMax.x).min();
@@ -36,7 +49,8 @@ pub trait SamplerIntegrator : Integrator {
         //     .get_film_tile(tile_bounds);
         //
         // for (pixel in tile_bounds){
-        //     let camera_sample = tile_sampler.get_camera_sampler(pixel);
+        //     tile_sampler.start_pixel(pixel);
+        //     let camera_sample = tile_sampler.get_camera_sample(pixel);
         //     let (ray_weight, ray) = self.get_camera()
         //         .generate_ray_differentail();
         //     ray.scale_differentials(1/(tile_sampler.samples_per_pixel).sqrt())
@@ -59,15 +73,308 @@ pub trait SamplerIntegrator : Integrator {
     fn Li(&self, ray : Ray, scene: Scene<GeneralPrimitive, GeneralLight>, area : MemoryArea, depth : i32) -> Spectrum;
 }
 
-struct Spectrum{
+// A Class generating the sample vectors of each pixel, one dimension at a time.
+// The samples of a pixel are taken in turn, each one drawing as many dimensions as the integrator needs
+pub(crate) trait Sampler : Send + Sync{
+
+    // Number of samples taken in each pixel. The samplers built on base 2 sequences round the requested
+    // number up to a power of two, so that every sample of the pixel comes from the same stratified set
+    fn samples_per_pixel(&self) -> u64;
+
+    // Start generating the samples of the pixel, from its first sample
+    fn start_pixel(&mut self, p : &Point2i);
+
+    // Move to the next sample of the pixel, returns false once all of its samples were taken
+    fn start_next_sample(&mut self) -> bool;
+
+    // Move to the given sample of the pixel, returns false if the pixel doesn't have that many samples
+    fn set_sample_number(&mut self, sample_num : u64) -> bool;
+
+    // Value of the next dimension of the current sample, in [0, 1)
+    fn get_1d(&mut self) -> f32;
 
+    // Values of the next two dimensions of the current sample, in [0, 1)^2
+    fn get_2d(&mut self) -> Point2f;
+
+    // A new sampler with the same settings and its own deterministic sequence, so that each
+    // tile rendered in parallel gets the same samples whatever the thread rendering it. The samplers whose samples
+    // only depend on the pixel, taken from a sequence shared by the whole image or scrambled per pixel, ignore the seed
+    fn clone_with_seed(&self, seed : u64) -> Box<dyn Sampler>;
+
+    // Film position, time and lens position of the current sample of the pixel `p_raster`
+    fn get_camera_sample(&mut self, p_raster : &Point2i) -> CameraSample {
+        let offset = self.get_2d();
+        let p_film = Point2f::new(p_raster.x as f32 + offset.x, p_raster.y as f32 + offset.y);
+        let time = self.get_1d();
+        let p_lens = self.get_2d();
+        CameraSample{
+            p_film,
+            p_lens,
+            time,
+        }
+    }
 }
 
-trait Sampler{
 
+/// The values of the first dimensions of every sample of the current pixel, for the samplers generating
+/// all the samples of a pixel at once when it is started. The dimensions past them are uniform random values
+pub(crate) struct PixelSamples{
+    pub samples_1d : Vec<Vec<f32>>,
+    pub samples_2d : Vec<Vec<Point2f>>,
+    pub rng : Rng,
+    samples_per_pixel : usize,
+    current_sample : usize,
+    current_1d_dimension : usize,
+    current_2d_dimension : usize,
 }
 
+impl PixelSamples{
+    pub fn new(samples_per_pixel : usize, sampled_dimensions : usize, seed : u64) -> PixelSamples{
+        Self{
+            samples_1d: vec![vec![0.0; samples_per_pixel]; sampled_dimensions],
+            samples_2d: vec![vec![Point2f::new(0.0, 0.0); samples_per_pixel]; sampled_dimensions],
+            rng: Rng::new(seed),
+            samples_per_pixel,
+            current_sample: 0,
+            current_1d_dimension: 0,
+            current_2d_dimension: 0,
+        }
+    }
+
+    pub fn sampled_dimensions(&self) -> usize {
+        self.samples_1d.len()
+    }
+
+    pub fn current_sample(&self) -> usize {
+        self.current_sample
+    }
+
+    pub fn set_sample_number(&mut self, sample_num : u64) -> bool {
+        self.current_sample = sample_num as usize;
+        self.current_1d_dimension = 0;
+        self.current_2d_dimension = 0;
+        self.current_sample < self.samples_per_pixel
+    }
+
+    pub fn get_1d(&mut self) -> f32 {
+        match self.samples_1d.get(self.current_1d_dimension) {
+            Some(samples) => {
+                self.current_1d_dimension += 1;
+                samples[self.current_sample]
+            }
+            None => self.rng.uniform_f32(),
+        }
+    }
+
+    pub fn get_2d(&mut self) -> Point2f {
+        match self.samples_2d.get(self.current_2d_dimension) {
+            Some(samples) => {
+                self.current_2d_dimension += 1;
+                samples[self.current_sample]
+            }
+            None => {
+                let x = self.rng.uniform_f32();
+                Point2f::new(x, self.rng.uniform_f32())
+            }
+        }
+    }
+}
 
 struct MemoryArea{
 
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::math::bounding_box::Bound2i;
+    use crate::engine::math::pmj02::{PMJ02_SETS, PMJ02_SET_SIZE};
+    use crate::engine::math::sobol::SobolRandomization;
+    use crate::engine::samplers::halton::HaltonSampler;
+    use crate::engine::samplers::independent::IndependentSampler;
+    use crate::engine::samplers::blue_noise::BlueNoiseSampler;
+    use crate::engine::samplers::padded_sobol::PaddedSobolSampler;
+    use crate::engine::samplers::pmj02::Pmj02Sampler;
+    use crate::engine::samplers::sobol::SobolSampler;
+    use crate::engine::samplers::stratified::StratifiedSampler;
+    use crate::engine::samplers::zero_two_sequence::ZeroTwoSequenceSampler;
+
+    fn all_samplers(samples_per_pixel : u64, sample_bounds : Bound2i) -> Vec<Box<dyn Sampler>> {
+        let side = (samples_per_pixel as f64).sqrt() as usize;
+        vec![
+            Box::new(IndependentSampler::new(samples_per_pixel, 0)),
+            Box::new(StratifiedSampler::new(side, side, true, 4, 0)),
+            Box::new(HaltonSampler::new(samples_per_pixel, sample_bounds, 0)),
+            Box::new(SobolSampler::new(samples_per_pixel, sample_bounds, SobolRandomization::Owen, 0)),
+            Box::new(ZeroTwoSequenceSampler::new(samples_per_pixel as usize, 4, 0)),
+            Box::new(PaddedSobolSampler::new(samples_per_pixel, SobolRandomization::Owen, 0)),
+            Box::new(Pmj02Sampler::new(samples_per_pixel, 0)),
+            Box::new(BlueNoiseSampler::new(samples_per_pixel, 0)),
+        ]
+    }
+
+    /// Whether the points form a (0, m, 2)-net in base 2: each elementary interval of area 1/n holds one point
+    fn is_02_net(points : &[Point2f]) -> bool {
+        let m = points.len().trailing_zeros();
+        (0..=m).all(|x_bits| {
+            let mut cells : Vec<(u32, u32)> = points.iter()
+                .map(|p| ((p.x * (1 << x_bits) as f32) as u32, (p.y * (1 << (m - x_bits)) as f32) as u32))
+                .collect();
+            cells.sort();
+            cells.dedup();
+            cells.len() == points.len()
+        })
+    }
+
+    /// The first dimensions of every sample of a pixel
+    fn pixel_samples(sampler : &mut dyn Sampler, p : Point2i) -> Vec<(f32, Point2f)> {
+        sampler.start_pixel(&p);
+        let mut samples = Vec::new();
+        loop {
+            samples.push((sampler.get_1d(), sampler.get_2d()));
+            if !sampler.start_next_sample() {
+                return samples;
+            }
+        }
+    }
+
+    #[test]
+    fn test_samples_stay_in_unit_square() {
+        let sample_bounds = Bound2i::from_corners(Point2i{ x: 0, y: 0 }, Point2i{ x: 8, y: 8 });
+        for mut sampler in all_samplers(16, sample_bounds) {
+            let samples = pixel_samples(sampler.as_mut(), Point2i{ x: 3, y: 5 });
+            assert_eq!(samples.len() as u64, sampler.samples_per_pixel());
+            for (v, p) in samples {
+                assert!((0.0..1.0).contains(&v) && (0.0..1.0).contains(&p.x) && (0.0..1.0).contains(&p.y));
+            }
+
+            sampler.start_pixel(&Point2i{ x: 3, y: 5 });
+            let camera_sample = sampler.get_camera_sample(&Point2i{ x: 3, y: 5 });
+            assert!(camera_sample.p_film.x >= 3.0 && camera_sample.p_film.x < 4.0);
+            assert!(camera_sample.p_film.y >= 5.0 && camera_sample.p_film.y < 6.0);
+            assert!(!sampler.set_sample_number(16));
+        }
+    }
+
+    #[test]
+    fn test_stratified_samples_fill_every_stratum() {
+        let mut sampler = StratifiedSampler::new(4, 2, true, 2, 1);
+        let samples = pixel_samples(&mut sampler, Point2i{ x: 0, y: 0 });
+
+        let mut strata_1d : Vec<usize> = samples.iter().map(|(v, _)| (v * 8.0) as usize).collect();
+        strata_1d.sort();
+        assert_eq!(strata_1d, (0..8).collect::<Vec<_>>());
+        let mut strata_2d : Vec<(usize, usize)> = samples.iter().map(|(_, p)| ((p.x * 4.0) as usize, (p.y * 2.0) as usize)).collect();
+        strata_2d.sort();
+        assert_eq!(strata_2d, (0..4).flat_map(|x| (0..2).map(move |y| (x, y))).collect::<Vec<_>>());
+
+        // Past the stratified dimensions the values are random
+        sampler.start_pixel(&Point2i{ x: 1, y: 0 });
+        sampler.get_1d();
+        sampler.get_1d();
+        assert!((0.0..1.0).contains(&sampler.get_1d()));
+    }
+
+    #[test]
+    fn test_clones_are_deterministic_per_seed() {
+        let sample_bounds = Bound2i::from_corners(Point2i{ x: 0, y: 0 }, Point2i{ x: 8, y: 8 });
+        let p = Point2i{ x: 7, y: 2 };
+        let clone_samples = |sampler : &dyn Sampler, seed : u64| pixel_samples(sampler.clone_with_seed(seed).as_mut(), p);
+        for sampler in all_samplers(4, sample_bounds) {
+            assert_eq!(clone_samples(sampler.as_ref(), 12), clone_samples(sampler.as_ref(), 12));
+        }
+
+        // The samplers drawing their samples from a random generator get other samples with another seed
+        let seeded : Vec<Box<dyn Sampler>> = vec![
+            Box::new(IndependentSampler::new(4, 0)),
+            Box::new(StratifiedSampler::new(2, 2, true, 4, 0)),
+            Box::new(ZeroTwoSequenceSampler::new(4, 4, 0)),
+        ];
+        for sampler in seeded {
+            assert_ne!(clone_samples(sampler.as_ref(), 12), clone_samples(sampler.as_ref(), 13));
+        }
+
+        // The samplers following a sequence over the whole image, or scrambled per pixel, don't depend on the tile
+        let per_pixel : Vec<Box<dyn Sampler>> = vec![
+            Box::new(HaltonSampler::new(4, sample_bounds, 0)),
+            Box::new(SobolSampler::new(4, sample_bounds, SobolRandomization::Owen, 0)),
+            Box::new(PaddedSobolSampler::new(4, SobolRandomization::Owen, 0)),
+            Box::new(Pmj02Sampler::new(4, 0)),
+            Box::new(BlueNoiseSampler::new(4, 0)),
+        ];
+        for sampler in per_pixel {
+            assert_eq!(clone_samples(sampler.as_ref(), 12), clone_samples(sampler.as_ref(), 13));
+        }
+    }
+
+    #[test]
+    fn test_sobol_based_samplers_are_02_nets_in_each_pixel() {
+        let samplers : Vec<Box<dyn Sampler>> = vec![
+            Box::new(ZeroTwoSequenceSampler::new(16, 3, 9)),
+            Box::new(PaddedSobolSampler::new(16, SobolRandomization::Owen, 9)),
+            Box::new(PaddedSobolSampler::new(16, SobolRandomization::Xor, 9)),
+            Box::new(Pmj02Sampler::new(16, 9)),
+        ];
+        for mut sampler in samplers {
+            sampler.start_pixel(&Point2i{ x: 4, y: 1 });
+            let mut dimensions = vec![Vec::new(); 3];
+            loop {
+                for points in dimensions.iter_mut() {
+                    points.push(sampler.get_2d());
+                }
+                if !sampler.start_next_sample() {
+                    break;
+                }
+            }
+            assert!(dimensions.iter().all(|points| is_02_net(points)));
+            assert_ne!(dimensions[0], dimensions[1]);
+        }
+        assert_eq!(ZeroTwoSequenceSampler::new(5, 1, 0).samples_per_pixel(), 8);
+    }
+
+    #[test]
+    fn test_pmj02_prefixes_are_02_nets() {
+        let mut sampler = Pmj02Sampler::new(64, 2);
+        for dimension in [0, 7] {
+            sampler.start_pixel(&Point2i{ x: -3, y: 8 });
+            let mut points = Vec::new();
+            loop {
+                for _ in 0..dimension {
+                    sampler.get_2d();
+                }
+                points.push(sampler.get_2d());
+                if !sampler.start_next_sample() {
+                    break;
+                }
+            }
+            // Past the tables only the whole set of samples is a net
+            let prefixes : &[usize] = if dimension < PMJ02_SETS { &[1, 2, 4, 8, 16, 32, 64] } else { &[64] };
+            for &n in prefixes {
+                assert!(is_02_net(&points[..n]), "dimension {} prefix {}", dimension, n);
+            }
+        }
+        assert_eq!(Pmj02Sampler::new(5000, 0).samples_per_pixel(), PMJ02_SET_SIZE as u64);
+        assert_eq!(Pmj02Sampler::new(6, 0).samples_per_pixel(), 8);
+    }
+
+    #[test]
+    fn test_blue_noise_error_has_no_low_frequencies() {
+        // Estimate the integral of a linear function with one sample per pixel, and average the error over blocks
+        let block_error_variance = |sampler : &mut dyn Sampler| {
+            let mut variance = 0.0;
+            for block in 0..64 {
+                let mut error = 0.0;
+                for i in 0..16 {
+                    sampler.start_pixel(&Point2i{ x: 4 * (block % 8) + i % 4, y: 4 * (block / 8) + i / 4 });
+                    sampler.get_2d();
+                    error += sampler.get_1d() - 0.5;
+                }
+                variance += (error / 16.0) * (error / 16.0) / 64.0;
+            }
+            variance
+        };
+        let blue_noise = block_error_variance(&mut BlueNoiseSampler::new(1, 5));
+        let independent = block_error_variance(&mut IndependentSampler::new(1, 5));
+        assert!(blue_noise < 0.5 * independent, "{} {}", blue_noise, independent);
+    }
+}
@@ -0,0 +1,79 @@
+use crate::engine::math::Point::{Point2f, Point2i};
+use crate::engine::math::sampling::{mix_bits, permutation_element};
+use crate::engine::math::sobol::{sobol_sample, SobolRandomization};
+use crate::engine::samplers::Sampler;
+
+/// Take every dimension, or pair of dimensions, of the samples of a pixel from the first dimensions of the Sobol
+/// sequence, padded together by shuffling the samples differently in each pixel and dimension. This keeps the
+/// good distribution of the first dimensions in all of them, without the correlations of the high dimensions
+/// of the sequence
+#[derive(Clone)]
+pub(crate) struct PaddedSobolSampler{
+    samples_per_pixel : u64,
+    randomization : SobolRandomization,
+    seed : u64,
+    pixel : Point2i,
+    sample_num : u64,
+    dimension : u64,
+}
+
+impl PaddedSobolSampler{
+    pub fn new(samples_per_pixel : u64, randomization : SobolRandomization, seed : u64) -> PaddedSobolSampler{
+        Self{
+            samples_per_pixel: samples_per_pixel.max(1).next_power_of_two(),
+            randomization,
+            seed,
+            pixel: Point2i{ x: 0, y: 0 },
+            sample_num: 0,
+            dimension: 0,
+        }
+    }
+
+    /// Hash choosing the shuffle and the randomization of the current dimension
+    fn dimension_hash(&self) -> u64 {
+        let pixel = (self.pixel.x as u32 as u64) | ((self.pixel.y as u32 as u64) << 32);
+        mix_bits(pixel ^ mix_bits(self.dimension ^ mix_bits(self.seed)))
+    }
+}
+
+impl Sampler for PaddedSobolSampler{
+    fn samples_per_pixel(&self) -> u64 {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel(&mut self, p : &Point2i) {
+        self.pixel = *p;
+        self.set_sample_number(0);
+    }
+
+    fn start_next_sample(&mut self) -> bool {
+        self.set_sample_number(self.sample_num + 1)
+    }
+
+    fn set_sample_number(&mut self, sample_num : u64) -> bool {
+        self.sample_num = sample_num;
+        self.dimension = 0;
+        sample_num < self.samples_per_pixel
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        let hash = self.dimension_hash();
+        let index = permutation_element(self.sample_num as u32, self.samples_per_pixel as u32, hash as u32);
+        self.dimension += 1;
+        sobol_sample(index as u64, 0, self.randomization, (hash >> 32) as u32)
+    }
+
+    fn get_2d(&mut self) -> Point2f {
+        let hash = self.dimension_hash();
+        let index = permutation_element(self.sample_num as u32, self.samples_per_pixel as u32, hash as u32);
+        self.dimension += 2;
+        Point2f::new(
+            sobol_sample(index as u64, 0, self.randomization, hash as u32),
+            sobol_sample(index as u64, 1, self.randomization, (hash >> 32) as u32)
+        )
+    }
+
+    fn clone_with_seed(&self, _seed : u64) -> Box<dyn Sampler> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,94 @@
+use crate::engine::math::Point::{Point2f, Point2i};
+use crate::engine::math::pmj02::{pmj02_sample, PMJ02_SETS, PMJ02_SET_SIZE};
+use crate::engine::math::sampling::mix_bits;
+use crate::engine::math::ONE_MINUS_EPSILON;
+use crate::engine::samplers::Sampler;
+
+/// Take the samples of each pixel in order from the precomputed progressive multi-jittered (0, 2) tables, so
+/// that every prefix of the samples is well distributed and the image can be previewed at any sample count.
+/// The tables are scrambled by xoring the bits of the points differently in each pixel and dimension, which
+/// keeps them stratified over the elementary intervals. The dimensions past the tables reuse them with the
+/// samples reordered
+#[derive(Clone)]
+pub(crate) struct Pmj02Sampler{
+    samples_per_pixel : u64,
+    seed : u64,
+    pixel : Point2i,
+    sample_num : u64,
+    dimension : u64,
+}
+
+impl Pmj02Sampler{
+    /// The number of samples is rounded up to a power of two no larger than the tables, powers of four give the best distributions
+    pub fn new(samples_per_pixel : u64, seed : u64) -> Pmj02Sampler{
+        Self{
+            samples_per_pixel: samples_per_pixel.max(1).next_power_of_two().min(PMJ02_SET_SIZE as u64),
+            seed,
+            pixel: Point2i{ x: 0, y: 0 },
+            sample_num: 0,
+            dimension: 0,
+        }
+    }
+
+    /// Hash choosing the scramble of the current dimension
+    fn dimension_hash(&self) -> u64 {
+        let pixel = (self.pixel.x as u32 as u64) | ((self.pixel.y as u32 as u64) << 32);
+        mix_bits(pixel ^ mix_bits(self.dimension ^ mix_bits(self.seed)))
+    }
+
+    /// The point of the tables for the current sample and pair of dimensions
+    fn table_sample(&self, hash : u64) -> Point2f {
+        let set = (self.dimension / 2) as usize;
+        if set < PMJ02_SETS {
+            return pmj02_sample(set, self.sample_num as usize);
+        }
+        // Xoring the index with a key below the number of samples keeps the whole set of samples of the pixel
+        let key = (hash >> 40) % self.samples_per_pixel;
+        pmj02_sample(set, (self.sample_num ^ key) as usize)
+    }
+}
+
+impl Sampler for Pmj02Sampler{
+    fn samples_per_pixel(&self) -> u64 {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel(&mut self, p : &Point2i) {
+        self.pixel = *p;
+        self.set_sample_number(0);
+    }
+
+    fn start_next_sample(&mut self) -> bool {
+        self.set_sample_number(self.sample_num + 1)
+    }
+
+    fn set_sample_number(&mut self, sample_num : u64) -> bool {
+        self.sample_num = sample_num;
+        self.dimension = 0;
+        sample_num < self.samples_per_pixel
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        let hash = self.dimension_hash();
+        let p = self.table_sample(hash);
+        self.dimension += 1;
+        scramble(if hash & 1 == 0 { p.x } else { p.y }, hash as u32)
+    }
+
+    fn get_2d(&mut self) -> Point2f {
+        let hash = self.dimension_hash();
+        let p = self.table_sample(hash);
+        self.dimension += 2;
+        Point2f::new(scramble(p.x, hash as u32), scramble(p.y, (hash >> 32) as u32))
+    }
+
+    fn clone_with_seed(&self, _seed : u64) -> Box<dyn Sampler> {
+        Box::new(self.clone())
+    }
+}
+
+/// Flip the bits of the binary expansion of `v` set in `key`, swapping the elementary intervals at each level
+fn scramble(v : f32, key : u32) -> f32 {
+    let bits = (v as f64 * 32.0f64.exp2()) as u32;
+    ((bits ^ key) as f32 * (-32.0f32).exp2()).min(ONE_MINUS_EPSILON)
+}
@@ -0,0 +1,175 @@
+use crate::engine::math::bounding_box::Bound2i;
+use crate::engine::math::Point::{Point2f, Point2i};
+use crate::engine::math::sampling::mix_bits;
+use crate::engine::math::sobol::{sobol_matrices, sobol_sample, sobol_sample_bits, SobolRandomization, NUM_SOBOL_DIMENSIONS};
+use crate::engine::math::ONE_MINUS_EPSILON;
+use crate::engine::samplers::Sampler;
+
+/// Take the samples of the whole image from a single Sobol sequence, whose two first dimensions are scaled
+/// over a square of a power of two pixels covering the image. The samples of a pixel are the points of the
+/// sequence landing in it, found by inverting the generator matrices for the bits locating the pixel.
+/// The dimensions past the two first ones can be randomized, and wrap around past the tabulated dimensions
+#[derive(Clone)]
+pub(crate) struct SobolSampler{
+    samples_per_pixel : u64,
+    sample_bounds : Bound2i,
+    log2_resolution : u32,
+    randomization : SobolRandomization,
+    seed : u64,
+    // Combination of the lowest bits of the index giving each bit of the pixel coordinates
+    pixel_bits_to_index : Vec<u64>,
+    pixel : Point2i,
+    sample_num : u64,
+    dimension : usize,
+    index : u64,
+}
+
+impl SobolSampler{
+    /// `seed` chooses the randomization of the dimensions
+    pub fn new(samples_per_pixel : u64, sample_bounds : Bound2i, randomization : SobolRandomization, seed : u64) -> SobolSampler{
+        let extent = (sample_bounds.p_max.x - sample_bounds.p_min.x).max(sample_bounds.p_max.y - sample_bounds.p_min.y).max(1);
+        let log2_resolution = (extent as u64).next_power_of_two().trailing_zeros();
+
+        Self{
+            samples_per_pixel: samples_per_pixel.max(1).next_power_of_two(),
+            sample_bounds,
+            log2_resolution,
+            randomization,
+            seed,
+            pixel_bits_to_index: invert_pixel_bits(log2_resolution),
+            pixel: sample_bounds.p_min,
+            sample_num: 0,
+            dimension: 0,
+            index: 0,
+        }
+    }
+
+    /// Index in the sequence of the sample `sample_num` of the current pixel. The bits of the index above
+    /// the ones locating the pixel count the samples, and the lowest bits are solved to land in the pixel
+    fn index_for_sample(&self, sample_num : u64) -> u64 {
+        let m = self.log2_resolution;
+        if m == 0 {
+            return sample_num;
+        }
+
+        let mut delta = 0;
+        let mut high = sample_num;
+        let mut c = 2 * m as usize;
+        while high != 0 {
+            if high & 1 != 0 {
+                delta ^= pixel_bits(m, c);
+            }
+            high >>= 1;
+            c += 1;
+        }
+
+        let p = Point2i{ x: self.pixel.x - self.sample_bounds.p_min.x, y: self.pixel.y - self.sample_bounds.p_min.y };
+        let target = (((p.x as u64) << m) | p.y as u64) ^ delta;
+        let low = self.pixel_bits_to_index.iter().enumerate()
+            .filter(|(j, _)| target & (1 << j) != 0)
+            .fold(0, |low, (_, bits)| low ^ bits);
+        (sample_num << (2 * m)) | low
+    }
+
+    fn sample_dimension(&self, index : u64, dimension : usize) -> f32 {
+        if dimension < 2 {
+            // The position inside of the pixel is given by the bits following the ones locating the pixel
+            let bits = ((sobol_sample_bits(index, dimension) as u64) << self.log2_resolution) as u32;
+            return (bits as f32 * (-32.0f32).exp2()).min(ONE_MINUS_EPSILON);
+        }
+        let seed = mix_bits(dimension as u64 ^ mix_bits(self.seed)) as u32;
+        sobol_sample(index, dimension % NUM_SOBOL_DIMENSIONS, self.randomization, seed)
+    }
+}
+
+impl Sampler for SobolSampler{
+    fn samples_per_pixel(&self) -> u64 {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel(&mut self, p : &Point2i) {
+        self.pixel = *p;
+        self.set_sample_number(0);
+    }
+
+    fn start_next_sample(&mut self) -> bool {
+        self.set_sample_number(self.sample_num + 1)
+    }
+
+    fn set_sample_number(&mut self, sample_num : u64) -> bool {
+        self.sample_num = sample_num;
+        self.dimension = 0;
+        self.index = self.index_for_sample(sample_num);
+        sample_num < self.samples_per_pixel
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        let v = self.sample_dimension(self.index, self.dimension);
+        self.dimension += 1;
+        v
+    }
+
+    fn get_2d(&mut self) -> Point2f {
+        let p = Point2f::new(self.sample_dimension(self.index, self.dimension), self.sample_dimension(self.index, self.dimension + 1));
+        self.dimension += 2;
+        p
+    }
+
+    fn clone_with_seed(&self, _seed : u64) -> Box<dyn Sampler> {
+        Box::new(self.clone())
+    }
+}
+
+/// The bits locating the pixel, the `m` first bits of the two first dimensions, set by the bit `c` of the index
+fn pixel_bits(m : u32, c : usize) -> u64 {
+    let matrices = sobol_matrices();
+    ((matrices[0][c] >> (64 - m)) << m) | (matrices[1][c] >> (64 - m))
+}
+
+/// For each of the `2m` bits locating a pixel, the combination of the `2m` lowest bits of the index setting only it.
+/// The two first dimensions are a (0, 2)-sequence, so these bits always locate a different pixel
+fn invert_pixel_bits(m : u32) -> Vec<u64> {
+    let n = 2 * m as usize;
+    // Gauss-Jordan elimination on the pairs of pixel bits and index bits setting them
+    let mut rows : Vec<(u64, u64)> = (0..n).map(|c| (pixel_bits(m, c), 1 << c)).collect();
+    for j in 0..n {
+        let pivot = (j..n).find(|&i| rows[i].0 & (1 << j) != 0).expect("the pixel bits can't be inverted");
+        rows.swap(j, pivot);
+        let (pixel_bits, index_bits) = rows[j];
+        for (i, row) in rows.iter_mut().enumerate() {
+            if i != j && row.0 & (1 << j) != 0 {
+                row.0 ^= pixel_bits;
+                row.1 ^= index_bits;
+            }
+        }
+    }
+    rows.into_iter().map(|(_, index_bits)| index_bits).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_samples_of_a_pixel_are_in_the_pixel() {
+        let sample_bounds = Bound2i::from_corners(Point2i{ x: -1, y: -1 }, Point2i{ x: 11, y: 6 });
+        let mut sampler = SobolSampler::new(8, sample_bounds, SobolRandomization::Owen, 3);
+        let m = sampler.log2_resolution;
+        let mut indices = Vec::new();
+        for p in sample_bounds.pixels() {
+            sampler.start_pixel(&p);
+            for sample_num in 0..8 {
+                // The highest bits of the two first dimensions, before keeping only the position inside of the pixel
+                let index = sampler.index_for_sample(sample_num);
+                let x = sobol_sample_bits(index, 0) >> (32 - m);
+                let y = sobol_sample_bits(index, 1) >> (32 - m);
+                assert_eq!((x as i32, y as i32), (p.x - sample_bounds.p_min.x, p.y - sample_bounds.p_min.y), "{:?} {}", p, sample_num);
+                indices.push(index);
+            }
+        }
+        let count = indices.len();
+        indices.sort();
+        indices.dedup();
+        assert_eq!(indices.len(), count);
+    }
+}
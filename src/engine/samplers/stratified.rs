@@ -0,0 +1,69 @@
+use crate::engine::math::Point::{Point2f, Point2i};
+use crate::engine::math::sampling::{shuffle, stratified_sample_1d, stratified_sample_2d};
+use crate::engine::samplers::{PixelSamples, Sampler};
+
+/// Split the domain of the first dimensions into one stratum per sample of the pixel, so that the samples
+/// don't clump together. The values of every sample are generated when starting a pixel, each dimension
+/// being shuffled so that the strata of different dimensions aren't correlated. The dimensions beyond
+/// `sampled_dimensions` are uniform random values
+pub(crate) struct StratifiedSampler{
+    x_pixel_samples : usize,
+    y_pixel_samples : usize,
+    jitter : bool,
+    pixel_samples : PixelSamples,
+}
+
+impl StratifiedSampler{
+    /// Stratify the pixel into a `x_pixel_samples` by `y_pixel_samples` grid, `jitter` places each sample
+    /// at random in its stratum instead of at its center
+    pub fn new(x_pixel_samples : usize, y_pixel_samples : usize, jitter : bool, sampled_dimensions : usize, seed : u64) -> StratifiedSampler{
+        Self{
+            x_pixel_samples,
+            y_pixel_samples,
+            jitter,
+            pixel_samples: PixelSamples::new(x_pixel_samples * y_pixel_samples, sampled_dimensions, seed),
+        }
+    }
+}
+
+impl Sampler for StratifiedSampler{
+    fn samples_per_pixel(&self) -> u64 {
+        (self.x_pixel_samples * self.y_pixel_samples) as u64
+    }
+
+    fn start_pixel(&mut self, _p : &Point2i) {
+        let n = self.x_pixel_samples * self.y_pixel_samples;
+        let PixelSamples{ samples_1d, samples_2d, rng, .. } = &mut self.pixel_samples;
+        for samples in samples_1d.iter_mut() {
+            *samples = stratified_sample_1d(n, rng, self.jitter);
+            shuffle(samples, rng);
+        }
+        for samples in samples_2d.iter_mut() {
+            *samples = stratified_sample_2d(self.x_pixel_samples, self.y_pixel_samples, rng, self.jitter);
+            shuffle(samples, rng);
+        }
+        self.pixel_samples.set_sample_number(0);
+    }
+
+    fn start_next_sample(&mut self) -> bool {
+        self.pixel_samples.set_sample_number(self.pixel_samples.current_sample() as u64 + 1)
+    }
+
+    fn set_sample_number(&mut self, sample_num : u64) -> bool {
+        self.pixel_samples.set_sample_number(sample_num)
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        self.pixel_samples.get_1d()
+    }
+
+    fn get_2d(&mut self) -> Point2f {
+        self.pixel_samples.get_2d()
+    }
+
+    fn clone_with_seed(&self, seed : u64) -> Box<dyn Sampler> {
+        Box::new(StratifiedSampler::new(
+            self.x_pixel_samples, self.y_pixel_samples, self.jitter, self.pixel_samples.sampled_dimensions(), seed
+        ))
+    }
+}
@@ -0,0 +1,68 @@
+use crate::engine::math::Point::{Point2f, Point2i};
+use crate::engine::math::sampling::shuffle;
+use crate::engine::math::sobol::{sobol_sample, SobolRandomization};
+use crate::engine::samplers::{PixelSamples, Sampler};
+
+/// Take the samples of each pixel from the (0, 2)-sequence made of the two first dimensions of the Sobol
+/// sequence, randomly scrambled in each pixel. Any power of two count of samples is stratified over all
+/// the elementary intervals, so the samples are well distributed whatever the aspect of the strata.
+/// Like the stratified sampler the dimensions are shuffled, and the ones beyond `sampled_dimensions` are random
+pub(crate) struct ZeroTwoSequenceSampler{
+    samples_per_pixel : usize,
+    pixel_samples : PixelSamples,
+}
+
+impl ZeroTwoSequenceSampler{
+    pub fn new(samples_per_pixel : usize, sampled_dimensions : usize, seed : u64) -> ZeroTwoSequenceSampler{
+        let samples_per_pixel = samples_per_pixel.max(1).next_power_of_two();
+        Self{
+            samples_per_pixel,
+            pixel_samples: PixelSamples::new(samples_per_pixel, sampled_dimensions, seed),
+        }
+    }
+}
+
+impl Sampler for ZeroTwoSequenceSampler{
+    fn samples_per_pixel(&self) -> u64 {
+        self.samples_per_pixel as u64
+    }
+
+    fn start_pixel(&mut self, _p : &Point2i) {
+        let n = self.samples_per_pixel;
+        let PixelSamples{ samples_1d, samples_2d, rng, .. } = &mut self.pixel_samples;
+        for samples in samples_1d.iter_mut() {
+            let scramble = rng.uniform_u32();
+            *samples = (0..n as u64).map(|i| sobol_sample(i, 0, SobolRandomization::Xor, scramble)).collect();
+            shuffle(samples, rng);
+        }
+        for samples in samples_2d.iter_mut() {
+            let (scramble_x, scramble_y) = (rng.uniform_u32(), rng.uniform_u32());
+            *samples = (0..n as u64).map(|i| Point2f::new(
+                sobol_sample(i, 0, SobolRandomization::Xor, scramble_x),
+                sobol_sample(i, 1, SobolRandomization::Xor, scramble_y)
+            )).collect();
+            shuffle(samples, rng);
+        }
+        self.pixel_samples.set_sample_number(0);
+    }
+
+    fn start_next_sample(&mut self) -> bool {
+        self.pixel_samples.set_sample_number(self.pixel_samples.current_sample() as u64 + 1)
+    }
+
+    fn set_sample_number(&mut self, sample_num : u64) -> bool {
+        self.pixel_samples.set_sample_number(sample_num)
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        self.pixel_samples.get_1d()
+    }
+
+    fn get_2d(&mut self) -> Point2f {
+        self.pixel_samples.get_2d()
+    }
+
+    fn clone_with_seed(&self, seed : u64) -> Box<dyn Sampler> {
+        Box::new(ZeroTwoSequenceSampler::new(self.samples_per_pixel, self.pixel_samples.sampled_dimensions(), seed))
+    }
+}
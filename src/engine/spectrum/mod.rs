@@ -0,0 +1,285 @@
+pub(crate) mod rgb;
+pub(crate) mod sampled;
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::util::tone_mapping::{mul_vector, SRGB_TO_XYZ, XYZ_TO_SRGB};
+
+/// The spectrum used by the renderer, chosen at compile time: RGB by default, or sampled over wavelength bins
+/// with the `sampled_spectrum` feature
+#[cfg(not(feature = "sampled_spectrum"))]
+pub(crate) type Spectrum = rgb::RGBSpectrum;
+#[cfg(feature = "sampled_spectrum")]
+pub(crate) type Spectrum = sampled::SampledSpectrum;
+
+/// Luminance weights of the linear sRGB primaries, the Y row of the RGB to XYZ matrix
+pub const Y_WEIGHT : [f32; 3] = SRGB_TO_XYZ[1];
+
+/// Linear sRGB (D65) color of CIE XYZ tristimulus values
+pub fn xyz_to_rgb(xyz : [f32; 3]) -> [f32; 3] {
+    mul_vector(&XYZ_TO_SRGB, xyz)
+}
+
+/// CIE XYZ tristimulus values of a linear sRGB (D65) color
+pub fn rgb_to_xyz(rgb : [f32; 3]) -> [f32; 3] {
+    mul_vector(&SRGB_TO_XYZ, rgb)
+}
+
+/// A spectral distribution represented by `N` coefficients, with the operations shared by its representations.
+/// The arithmetic is done coefficient by coefficient
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct CoefficientSpectrum<const N : usize>{
+    pub c : [f32; N],
+}
+
+impl<const N : usize> CoefficientSpectrum<N>{
+    /// Spectrum with the same value for every coefficient
+    pub fn new(v : f32) -> Self{
+        Self{ c: [v; N] }
+    }
+
+    pub fn from_coefficients(c : [f32; N]) -> Self{
+        Self{ c }
+    }
+
+    pub fn is_black(&self) -> bool {
+        self.c.iter().all(|&v| v == 0.0)
+    }
+
+    pub fn has_nans(&self) -> bool {
+        self.c.iter().any(|v| v.is_nan())
+    }
+
+    pub fn max_component(&self) -> f32 {
+        self.c.iter().fold(f32::NEG_INFINITY, |m, &v| m.max(v))
+    }
+
+    pub fn clamp(&self, low : f32, high : f32) -> Self{
+        self.map(|v| v.clamp(low, high))
+    }
+
+    pub fn sqrt(&self) -> Self{
+        self.map(f32::sqrt)
+    }
+
+    pub fn exp(&self) -> Self{
+        self.map(f32::exp)
+    }
+
+    pub fn powf(&self, e : f32) -> Self{
+        self.map(|v| v.powf(e))
+    }
+
+    /// Linear interpolation between the spectra, `s1` for `t = 0` and `s2` for `t = 1`
+    pub fn lerp(t : f32, s1 : &Self, s2 : &Self) -> Self{
+        *s1 * (1.0 - t) + *s2 * t
+    }
+
+    pub fn map(&self, f : impl Fn(f32) -> f32) -> Self{
+        Self{ c: self.c.map(f) }
+    }
+
+    fn zip(&self, rhs : &Self, f : impl Fn(f32, f32) -> f32) -> Self{
+        let mut c = self.c;
+        for (v, r) in c.iter_mut().zip(rhs.c.iter()) {
+            *v = f(*v, *r);
+        }
+        Self{ c }
+    }
+}
+
+impl<const N : usize> Default for CoefficientSpectrum<N>{
+    fn default() -> Self {
+        Self::new(0.0)
+    }
+}
+
+impl<const N : usize> Add for CoefficientSpectrum<N>{
+    type Output = Self;
+
+    fn add(self, rhs : Self) -> Self::Output {
+        self.zip(&rhs, |a, b| a + b)
+    }
+}
+
+impl<const N : usize> AddAssign for CoefficientSpectrum<N>{
+    fn add_assign(&mut self, rhs : Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N : usize> Sub for CoefficientSpectrum<N>{
+    type Output = Self;
+
+    fn sub(self, rhs : Self) -> Self::Output {
+        self.zip(&rhs, |a, b| a - b)
+    }
+}
+
+impl<const N : usize> SubAssign for CoefficientSpectrum<N>{
+    fn sub_assign(&mut self, rhs : Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N : usize> Mul for CoefficientSpectrum<N>{
+    type Output = Self;
+
+    fn mul(self, rhs : Self) -> Self::Output {
+        self.zip(&rhs, |a, b| a * b)
+    }
+}
+
+impl<const N : usize> MulAssign for CoefficientSpectrum<N>{
+    fn mul_assign(&mut self, rhs : Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const N : usize> Div for CoefficientSpectrum<N>{
+    type Output = Self;
+
+    fn div(self, rhs : Self) -> Self::Output {
+        self.zip(&rhs, |a, b| a / b)
+    }
+}
+
+impl<const N : usize> DivAssign for CoefficientSpectrum<N>{
+    fn div_assign(&mut self, rhs : Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const N : usize> Mul<f32> for CoefficientSpectrum<N>{
+    type Output = Self;
+
+    fn mul(self, rhs : f32) -> Self::Output {
+        self.map(|v| v * rhs)
+    }
+}
+
+impl<const N : usize> Mul<CoefficientSpectrum<N>> for f32{
+    type Output = CoefficientSpectrum<N>;
+
+    fn mul(self, rhs : CoefficientSpectrum<N>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<const N : usize> MulAssign<f32> for CoefficientSpectrum<N>{
+    fn mul_assign(&mut self, rhs : f32) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const N : usize> Div<f32> for CoefficientSpectrum<N>{
+    type Output = Self;
+
+    fn div(self, rhs : f32) -> Self::Output {
+        debug_assert!(rhs != 0.0);
+        let inv = 1.0 / rhs;
+        self.map(|v| v * inv)
+    }
+}
+
+impl<const N : usize> DivAssign<f32> for CoefficientSpectrum<N>{
+    fn div_assign(&mut self, rhs : f32) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const N : usize> Neg for CoefficientSpectrum<N>{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(|v| -v)
+    }
+}
+
+impl<const N : usize> Index<usize> for CoefficientSpectrum<N>{
+    type Output = f32;
+
+    fn index(&self, index : usize) -> &Self::Output {
+        &self.c[index]
+    }
+}
+
+impl<const N : usize> IndexMut<usize> for CoefficientSpectrum<N>{
+    fn index_mut(&mut self, index : usize) -> &mut Self::Output {
+        &mut self.c[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::spectrum::rgb::RGBSpectrum;
+    use crate::engine::spectrum::sampled::SampledSpectrum;
+
+    fn assert_close(a : [f32; 3], b : [f32; 3], tolerance : f32) {
+        for i in 0..3 {
+            assert!((a[i] - b[i]).abs() < tolerance, "{:?} {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = RGBSpectrum::from_rgb([1.0, 2.0, 4.0]);
+        let b = RGBSpectrum::new(2.0);
+        assert_eq!((a + b).to_rgb(), [3.0, 4.0, 6.0]);
+        assert_eq!((a - b).to_rgb(), [-1.0, 0.0, 2.0]);
+        assert_eq!((a * b).to_rgb(), (2.0 * a).to_rgb());
+        assert_eq!((a / b).to_rgb(), (a / 2.0).to_rgb());
+        assert_eq!((-a)[2], -4.0);
+        assert_eq!(a.max_component(), 4.0);
+        assert_eq!(a.clamp(0.0, 3.0).to_rgb(), [1.0, 2.0, 3.0]);
+        assert_eq!((a * a).sqrt(), a);
+        assert_eq!(RGBSpectrum::new(0.0).exp(), RGBSpectrum::new(1.0));
+        assert_eq!(RGBSpectrum::lerp(0.25, &a, &b).to_rgb(), [1.25, 2.0, 3.5]);
+        assert!(RGBSpectrum::default().is_black() && !a.is_black());
+        assert!(RGBSpectrum::new(f32::NAN).has_nans());
+
+        let mut c = a;
+        c += b;
+        c *= 0.5;
+        c -= b;
+        c /= RGBSpectrum::new(0.5);
+        assert_eq!(c.to_rgb(), [-1.0, 0.0, 2.0]);
+    }
+
+    #[test]
+    fn test_xyz_rgb_round_trip() {
+        let rgb = [0.2, 0.5, 0.9];
+        assert_close(xyz_to_rgb(rgb_to_xyz(rgb)), rgb, 1e-4);
+        assert_close(rgb_to_xyz([1.0; 3]), [0.950456, 1.0, 1.088754], 1e-4);
+        assert!((RGBSpectrum::from_xyz(rgb_to_xyz(rgb)).y() - rgb_to_xyz(rgb)[1]).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_sampled_spectrum_conversions() {
+        // In gamut colors come back from the spectra they are turned into
+        for rgb in [[0.2, 0.5, 0.9], [0.8, 0.3, 0.1], [0.5, 0.5, 0.5], [0.9, 0.8, 0.05]] {
+            let s = SampledSpectrum::from_rgb(rgb);
+            assert!(s.c.iter().all(|&v| v >= 0.0));
+            assert_close(s.to_rgb(), rgb, 1e-3);
+            assert!((s.y() - rgb_to_xyz(rgb)[1]).abs() < 1e-3);
+        }
+        assert_close(SampledSpectrum::from_xyz([0.3, 0.4, 0.5]).to_xyz(), [0.3, 0.4, 0.5], 1e-3);
+
+        // Out of gamut colors are clamped to physical spectra
+        assert!(SampledSpectrum::from_rgb([0.0, 1.0, 0.0]).c.iter().all(|&v| v >= 0.0));
+
+        // A constant spectrum is close to white, and its luminance to its value
+        let white = SampledSpectrum::new(1.0);
+        assert!((white.y() - 1.0).abs() < 0.05, "{}", white.y());
+        let rgb = white.to_rgb();
+        assert!(rgb.iter().all(|&v| (v - 1.0).abs() < 0.25), "{:?}", rgb);
+        assert!(SampledSpectrum::new(0.0).is_black());
+    }
+
+    #[test]
+    fn test_spectrum_follows_the_feature() {
+        let s = Spectrum::from_rgb([0.25, 0.5, 0.75]);
+        assert_close(s.to_rgb(), [0.25, 0.5, 0.75], 1e-3);
+        assert!((s.y() - rgb_to_xyz([0.25, 0.5, 0.75])[1]).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,27 @@
+use crate::engine::spectrum::{rgb_to_xyz, xyz_to_rgb, CoefficientSpectrum, Y_WEIGHT};
+
+/// Spectrum stored as its linear sRGB color, the three coefficients being the red, green and blue values
+pub(crate) type RGBSpectrum = CoefficientSpectrum<3>;
+
+impl CoefficientSpectrum<3>{
+    pub fn from_rgb(rgb : [f32; 3]) -> RGBSpectrum{
+        Self::from_coefficients(rgb)
+    }
+
+    pub fn from_xyz(xyz : [f32; 3]) -> RGBSpectrum{
+        Self::from_rgb(xyz_to_rgb(xyz))
+    }
+
+    pub fn to_rgb(self) -> [f32; 3] {
+        self.c
+    }
+
+    pub fn to_xyz(self) -> [f32; 3] {
+        rgb_to_xyz(self.c)
+    }
+
+    /// Luminance, the Y value of the color
+    pub fn y(&self) -> f32 {
+        Y_WEIGHT[0] * self.c[0] + Y_WEIGHT[1] * self.c[1] + Y_WEIGHT[2] * self.c[2]
+    }
+}
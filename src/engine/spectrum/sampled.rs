@@ -0,0 +1,160 @@
+use std::sync::OnceLock;
+use crate::engine::spectrum::{xyz_to_rgb, CoefficientSpectrum};
+
+/// Shortest wavelength of the sampled spectra, in nanometers
+pub const SAMPLED_LAMBDA_START : f32 = 400.0;
+
+/// Longest wavelength of the sampled spectra, in nanometers
+pub const SAMPLED_LAMBDA_END : f32 = 700.0;
+
+/// Number of wavelength bins of the sampled spectra
+pub const N_SPECTRAL_SAMPLES : usize = 60;
+
+// Range of wavelengths over which the CIE matching functions are integrated
+const CIE_LAMBDA_START : f32 = 360.0;
+const CIE_LAMBDA_END : f32 = 830.0;
+
+// Wavelengths splitting the visible range in the blue, green and red bands used to turn colors into spectra
+const BAND_LIMITS : [f32; 2] = [490.0, 580.0];
+
+/// Spectrum stored as its average value over `N_SPECTRAL_SAMPLES` bins of equal width between
+/// `SAMPLED_LAMBDA_START` and `SAMPLED_LAMBDA_END`
+pub(crate) type SampledSpectrum = CoefficientSpectrum<N_SPECTRAL_SAMPLES>;
+
+impl CoefficientSpectrum<N_SPECTRAL_SAMPLES>{
+    /// Wavelength range covered by the bin `i`
+    pub fn bin_wavelengths(i : usize) -> (f32, f32) {
+        let width = (SAMPLED_LAMBDA_END - SAMPLED_LAMBDA_START) / N_SPECTRAL_SAMPLES as f32;
+        (SAMPLED_LAMBDA_START + i as f32 * width, SAMPLED_LAMBDA_START + (i + 1) as f32 * width)
+    }
+
+    /// A spectrum of the color, piecewise constant over the blue, green and red bands of the visible range.
+    /// The values of the bands are chosen so that the color comes back from the spectrum, and clamped to zero
+    /// for the saturated colors out of the gamut of the bands
+    pub fn from_rgb(rgb : [f32; 3]) -> SampledSpectrum{
+        let tables = spectral_tables();
+        let mut s = Self::new(0.0);
+        for (band, row) in tables.rgb_to_bands.iter().enumerate() {
+            let value = (row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2]).max(0.0);
+            s += tables.bands[band] * value;
+        }
+        s
+    }
+
+    pub fn from_xyz(xyz : [f32; 3]) -> SampledSpectrum{
+        Self::from_rgb(xyz_to_rgb(xyz))
+    }
+
+    /// CIE XYZ values, normalized by the integral of the Y matching function so that a constant spectrum of 1
+    /// has a luminance close to 1, the matching functions being small outside of the sampled range
+    pub fn to_xyz(self) -> [f32; 3] {
+        let tables = spectral_tables();
+        [tables.x.dot(&self), tables.y.dot(&self), tables.z.dot(&self)]
+    }
+
+    pub fn to_rgb(self) -> [f32; 3] {
+        xyz_to_rgb(self.to_xyz())
+    }
+
+    /// Luminance, the Y value of the spectrum
+    pub fn y(&self) -> f32 {
+        spectral_tables().y.dot(self)
+    }
+
+    fn dot(&self, rhs : &Self) -> f32 {
+        self.c.iter().zip(rhs.c.iter()).map(|(a, b)| a * b).sum()
+    }
+}
+
+/// Matching functions averaged over the bins and scaled by the bin width over the integral of Y,
+/// and the spectra of the bands with the matrix giving their values from a color
+struct SpectralTables{
+    x : SampledSpectrum,
+    y : SampledSpectrum,
+    z : SampledSpectrum,
+    bands : [SampledSpectrum; 3],
+    rgb_to_bands : [[f32; 3]; 3],
+}
+
+fn spectral_tables() -> &'static SpectralTables {
+    static TABLES : OnceLock<SpectralTables> = OnceLock::new();
+    TABLES.get_or_init(|| {
+        const STEPS_PER_NM : usize = 4;
+        let y_integral = integrate(CIE_LAMBDA_START, CIE_LAMBDA_END, STEPS_PER_NM, cie_y);
+        let bin = |f : fn(f32) -> f32| {
+            let mut s = SampledSpectrum::new(0.0);
+            for i in 0..N_SPECTRAL_SAMPLES {
+                let (lambda0, lambda1) = SampledSpectrum::bin_wavelengths(i);
+                s[i] = integrate(lambda0, lambda1, STEPS_PER_NM, f) / y_integral;
+            }
+            s
+        };
+        let (x, y, z) = (bin(cie_x), bin(cie_y), bin(cie_z));
+
+        // Red, green and blue bands, ordered as the components of a color
+        let band_ranges = [(BAND_LIMITS[1], SAMPLED_LAMBDA_END), (BAND_LIMITS[0], BAND_LIMITS[1]), (SAMPLED_LAMBDA_START, BAND_LIMITS[0])];
+        let bands = band_ranges.map(|(start, end)| {
+            let mut s = SampledSpectrum::new(0.0);
+            for i in 0..N_SPECTRAL_SAMPLES {
+                let (lambda0, _) = SampledSpectrum::bin_wavelengths(i);
+                if lambda0 >= start && lambda0 < end {
+                    s[i] = 1.0;
+                }
+            }
+            s
+        });
+
+        // Column j holds the color of the band j
+        let mut bands_to_rgb = [[0.0; 3]; 3];
+        for (j, band) in bands.iter().enumerate() {
+            let rgb = xyz_to_rgb([x.dot(band), y.dot(band), z.dot(band)]);
+            for i in 0..3 {
+                bands_to_rgb[i][j] = rgb[i];
+            }
+        }
+
+        SpectralTables{ x, y, z, bands, rgb_to_bands: invert(&bands_to_rgb) }
+    })
+}
+
+/// Midpoint rule integration of `f` between `a` and `b`
+fn integrate(a : f32, b : f32, steps_per_nm : usize, f : fn(f32) -> f32) -> f32 {
+    let n = ((b - a) as usize * steps_per_nm).max(1);
+    let h = (b - a) / n as f32;
+    (0..n).map(|i| f(a + (i as f32 + 0.5) * h)).sum::<f32>() * h
+}
+
+/// Piecewise gaussian of the multi-lobe fit of the CIE 1931 matching functions by Wyman, Sloan and Shirley
+fn lobe(lambda : f32, mu : f32, sigma_low : f32, sigma_high : f32) -> f32 {
+    let t = (lambda - mu) / if lambda < mu { sigma_low } else { sigma_high };
+    (-0.5 * t * t).exp()
+}
+
+fn cie_x(lambda : f32) -> f32 {
+    1.056 * lobe(lambda, 599.8, 37.9, 31.0) + 0.362 * lobe(lambda, 442.0, 16.0, 26.7) - 0.065 * lobe(lambda, 501.1, 20.4, 26.2)
+}
+
+fn cie_y(lambda : f32) -> f32 {
+    0.821 * lobe(lambda, 568.8, 46.9, 40.5) + 0.286 * lobe(lambda, 530.9, 16.3, 31.1)
+}
+
+fn cie_z(lambda : f32) -> f32 {
+    1.217 * lobe(lambda, 437.0, 11.8, 36.0) + 0.681 * lobe(lambda, 459.0, 26.0, 13.8)
+}
+
+/// Inverse of a 3 by 3 matrix by its cofactors
+fn invert(m : &[[f32; 3]; 3]) -> [[f32; 3]; 3] {
+    let cofactor = |i : usize, j : usize| {
+        let (r0, r1) = ((i + 1) % 3, (i + 2) % 3);
+        let (c0, c1) = ((j + 1) % 3, (j + 2) % 3);
+        m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+    };
+    let det = m[0][0] * cofactor(0, 0) + m[0][1] * cofactor(0, 1) + m[0][2] * cofactor(0, 2);
+    let mut inverse = [[0.0; 3]; 3];
+    for (i, row) in inverse.iter_mut().enumerate() {
+        for (j, v) in row.iter_mut().enumerate() {
+            *v = cofactor(j, i) / det;
+        }
+    }
+    inverse
+}
@@ -0,0 +1,456 @@
+use std::fs::File;
+use std::io;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// Precision of the values stored in an OpenEXR file
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExrPixelType{
+    Half,
+    Float,
+}
+
+/// How linear radiance is turned into 8 bits values for the low dynamic range formats
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LdrEncoding{
+    // Scale of the radiance in stops, the values are multiplied by 2^exposure
+    pub exposure : f32,
+    // Power applied to the scaled values, the sRGB transfer curve is used when None
+    pub gamma : Option<f32>,
+}
+
+impl Default for LdrEncoding{
+    fn default() -> Self {
+        Self{
+            exposure: 0.0,
+            gamma: None,
+        }
+    }
+}
+
+impl LdrEncoding{
+    /// Encode a linear value into an 8 bits value
+    pub fn encode(&self, v : f32) -> u8 {
+        let v = (v * self.exposure.exp2()).max(0.0);
+        let encoded = match self.gamma {
+            Some(gamma) => v.powf(1.0 / gamma),
+            None => gamma_correct(v),
+        };
+        (encoded * 255.0 + 0.5).clamp(0.0, 255.0) as u8
+    }
+}
+
+/// The sRGB transfer curve
+pub fn gamma_correct(v : f32) -> f32 {
+    if v <= 0.0031308 {
+        12.92 * v
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Write an RGB image of linear values, row by row from the top, in the format given by the extension
+/// of the path: `ppm` and `png` are 8 bits sRGB encoded with `ldr`, `pfm` and `exr` keep the linear floats
+pub fn write_image(path : &Path, rgb : &[[f32; 3]], width : usize, height : usize, ldr : &LdrEncoding) -> io::Result<()> {
+    if rgb.len() != width * height {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "the image doesn't match its resolution"));
+    }
+
+    let extension = image_extension(path);
+    if !["ppm", "png", "pfm", "exr"].contains(&extension.as_str()) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("unsupported image format: {}", path.display())));
+    }
+
+    let mut writer = BufWriter::new(File::create(path)?);
+    match extension.as_str() {
+        "ppm" => write_ppm(&mut writer, &encode_ldr(rgb, ldr), width, height),
+        "png" => write_png(&mut writer, &encode_ldr(rgb, ldr), width, height),
+        "pfm" => write_pfm(&mut writer, rgb, width, height),
+        _ => {
+            let channels = rgb_channels(rgb);
+            write_exr(&mut writer, width, height, &[("R", &channels[0]), ("G", &channels[1]), ("B", &channels[2])], ExrPixelType::Half)
+        }
+    }?;
+    writer.flush()
+}
+
+/// Write an image already encoded in 8 bits, 3 bytes per pixel row by row from the top,
+/// as a `ppm` or a `png` depending on the extension of the path
+pub fn write_ldr_image(path : &Path, rgb : &[u8], width : usize, height : usize) -> io::Result<()> {
+    if rgb.len() != width * height * 3 {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "the image doesn't match its resolution"));
+    }
+
+    let extension = image_extension(path);
+    if !["ppm", "png"].contains(&extension.as_str()) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("unsupported 8 bits image format: {}", path.display())));
+    }
+
+    let mut writer = BufWriter::new(File::create(path)?);
+    if extension == "ppm" {
+        write_ppm(&mut writer, rgb, width, height)?;
+    } else {
+        write_png(&mut writer, rgb, width, height)?;
+    }
+    writer.flush()
+}
+
+fn image_extension(path : &Path) -> String {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase())
+        .unwrap_or_default()
+}
+
+/// Split the pixels in one plane of values per channel
+pub fn rgb_channels(rgb : &[[f32; 3]]) -> [Vec<f32>; 3] {
+    [0, 1, 2].map(|c| rgb.iter().map(|p| p[c]).collect())
+}
+
+fn encode_ldr(rgb : &[[f32; 3]], ldr : &LdrEncoding) -> Vec<u8> {
+    rgb.iter().flat_map(|p| p.map(|v| ldr.encode(v))).collect()
+}
+
+/// Binary PPM, 3 bytes per pixel
+pub fn write_ppm(writer : &mut impl Write, rgb : &[u8], width : usize, height : usize) -> io::Result<()> {
+    if rgb.len() != width * height * 3 {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "the image doesn't match its resolution"));
+    }
+    write!(writer, "P6\n{} {}\n255\n", width, height)?;
+    writer.write_all(rgb)
+}
+
+/// Portable float map, the rows are stored from the bottom of the image in little endian
+pub fn write_pfm(writer : &mut impl Write, rgb : &[[f32; 3]], width : usize, height : usize) -> io::Result<()> {
+    if rgb.len() != width * height {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "the image doesn't match its resolution"));
+    }
+    write!(writer, "PF\n{} {}\n-1\n", width, height)?;
+    for y in (0..height).rev() {
+        for p in &rgb[y * width..(y + 1) * width] {
+            for v in p {
+                writer.write_all(&v.to_le_bytes())?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 8 bits RGB PNG, the image data is stored without compression
+pub fn write_png(writer : &mut impl Write, rgb : &[u8], width : usize, height : usize) -> io::Result<()> {
+    if rgb.len() != width * height * 3 {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "the image doesn't match its resolution"));
+    }
+    writer.write_all(&[0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'])?;
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    // 8 bits per channel, truecolor, deflate, adaptive filtering, no interlace
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+    write_png_chunk(writer, b"IHDR", &header)?;
+
+    // Every row starts with its filter type, none here
+    let mut raw = Vec::with_capacity(height * (width * 3 + 1));
+    for row in rgb.chunks(width * 3) {
+        raw.push(0);
+        raw.extend_from_slice(row);
+    }
+    write_png_chunk(writer, b"IDAT", &zlib_stored(&raw))?;
+    write_png_chunk(writer, b"IEND", &[])
+}
+
+fn write_png_chunk(writer : &mut impl Write, chunk_type : &[u8; 4], data : &[u8]) -> io::Result<()> {
+    writer.write_all(&(data.len() as u32).to_be_bytes())?;
+    writer.write_all(chunk_type)?;
+    writer.write_all(data)?;
+    let crc = crc32_update(crc32_update(0xffff_ffff, chunk_type), data) ^ 0xffff_ffff;
+    writer.write_all(&crc.to_be_bytes())
+}
+
+fn crc32_update(mut crc : u32, data : &[u8]) -> u32 {
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { 0xedb8_8320 ^ (crc >> 1) } else { crc >> 1 };
+        }
+    }
+    crc
+}
+
+/// Wrap the data in a zlib stream made of uncompressed deflate blocks
+fn zlib_stored(data : &[u8]) -> Vec<u8> {
+    const MAX_BLOCK : usize = 65535;
+    let mut out = Vec::with_capacity(data.len() + data.len() / MAX_BLOCK * 5 + 11);
+    out.extend_from_slice(&[0x78, 0x01]);
+
+    let n_blocks = data.len().div_ceil(MAX_BLOCK).max(1);
+    for i in 0..n_blocks {
+        let block = &data[i * MAX_BLOCK..((i + 1) * MAX_BLOCK).min(data.len())];
+        out.push(if i == n_blocks - 1 { 1 } else { 0 });
+        out.extend_from_slice(&(block.len() as u16).to_le_bytes());
+        out.extend_from_slice(&(!(block.len() as u16)).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    out.extend_from_slice(&((b << 16) | a).to_be_bytes());
+    out
+}
+
+/// Single part scanline OpenEXR without compression. Each channel is a plane of `width * height`
+/// values ordered row by row from the top, the channels are stored sorted by name as the format requires
+pub fn write_exr(
+    writer : &mut impl Write, width : usize, height : usize, channels : &[(&str, &[f32])], pixel_type : ExrPixelType
+) -> io::Result<()> {
+    if channels.iter().any(|(_, values)| values.len() != width * height) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "a channel doesn't match the resolution of the image"));
+    }
+    let mut channels = channels.to_vec();
+    channels.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut header = Vec::new();
+    header.extend_from_slice(&[0x76, 0x2f, 0x31, 0x01]);
+    header.extend_from_slice(&2u32.to_le_bytes());
+
+    let mut channel_list = Vec::new();
+    for (name, _) in &channels {
+        channel_list.extend_from_slice(name.as_bytes());
+        channel_list.push(0);
+        let type_id : i32 = match pixel_type { ExrPixelType::Half => 1, ExrPixelType::Float => 2 };
+        channel_list.extend_from_slice(&type_id.to_le_bytes());
+        // Not perceptually linear, then reserved bytes and the sampling rates
+        channel_list.extend_from_slice(&[0, 0, 0, 0]);
+        channel_list.extend_from_slice(&1i32.to_le_bytes());
+        channel_list.extend_from_slice(&1i32.to_le_bytes());
+    }
+    channel_list.push(0);
+    write_exr_attribute(&mut header, "channels", "chlist", &channel_list);
+    write_exr_attribute(&mut header, "compression", "compression", &[0]);
+
+    let mut window = Vec::new();
+    for v in [0, 0, width as i32 - 1, height as i32 - 1] {
+        window.extend_from_slice(&v.to_le_bytes());
+    }
+    write_exr_attribute(&mut header, "dataWindow", "box2i", &window);
+    write_exr_attribute(&mut header, "displayWindow", "box2i", &window);
+    write_exr_attribute(&mut header, "lineOrder", "lineOrder", &[0]);
+    write_exr_attribute(&mut header, "pixelAspectRatio", "float", &1.0f32.to_le_bytes());
+    write_exr_attribute(&mut header, "screenWindowCenter", "v2f", &[0u8; 8]);
+    write_exr_attribute(&mut header, "screenWindowWidth", "float", &1.0f32.to_le_bytes());
+    header.push(0);
+
+    let bytes_per_value = match pixel_type { ExrPixelType::Half => 2, ExrPixelType::Float => 4 };
+    let line_size = channels.len() * width * bytes_per_value;
+    // The offset table follows the header, one entry per scanline
+    let first_line = header.len() + height * 8;
+    for y in 0..height {
+        header.extend_from_slice(&((first_line + y * (line_size + 8)) as u64).to_le_bytes());
+    }
+    writer.write_all(&header)?;
+
+    let mut line = Vec::with_capacity(line_size + 8);
+    for y in 0..height {
+        line.clear();
+        line.extend_from_slice(&(y as i32).to_le_bytes());
+        line.extend_from_slice(&(line_size as i32).to_le_bytes());
+        for (_, values) in &channels {
+            for &v in &values[y * width..(y + 1) * width] {
+                match pixel_type {
+                    ExrPixelType::Half => line.extend_from_slice(&f32_to_half(v).to_le_bytes()),
+                    ExrPixelType::Float => line.extend_from_slice(&v.to_le_bytes()),
+                }
+            }
+        }
+        writer.write_all(&line)?;
+    }
+    Ok(())
+}
+
+fn write_exr_attribute(header : &mut Vec<u8>, name : &str, attribute_type : &str, value : &[u8]) {
+    header.extend_from_slice(name.as_bytes());
+    header.push(0);
+    header.extend_from_slice(attribute_type.as_bytes());
+    header.push(0);
+    header.extend_from_slice(&(value.len() as i32).to_le_bytes());
+    header.extend_from_slice(value);
+}
+
+/// Round a float to the nearest half precision float, overflowing to infinity
+pub fn f32_to_half(v : f32) -> u16 {
+    let bits = v.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x007f_ffff;
+
+    if exponent == 0xff {
+        // Infinity stays infinity, NaN keeps a non zero mantissa
+        return sign | 0x7c00 | if mantissa != 0 { 0x200 } else { 0 };
+    }
+
+    let half_exponent = exponent - 127 + 15;
+    if half_exponent >= 0x1f {
+        return sign | 0x7c00;
+    }
+    if half_exponent <= 0 {
+        // Subnormal half, or zero when the value is too small
+        if half_exponent < -10 {
+            return sign;
+        }
+        let mantissa = mantissa | 0x0080_0000;
+        let shift = (14 - half_exponent) as u32;
+        let half_mantissa = mantissa >> shift;
+        // Round to nearest, ties to even
+        let remainder = mantissa & ((1 << shift) - 1);
+        let halfway = 1 << (shift - 1);
+        let round_up = remainder > halfway || (remainder == halfway && half_mantissa & 1 != 0);
+        return sign | (half_mantissa + round_up as u32) as u16;
+    }
+
+    let half = ((half_exponent as u32) << 10) | (mantissa >> 13);
+    let remainder = mantissa & 0x1fff;
+    let round_up = remainder > 0x1000 || (remainder == 0x1000 && half & 1 != 0);
+    // A carry out of the mantissa correctly moves to the next exponent, up to infinity
+    sign | (half + round_up as u32) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Read back the image data of a PNG made of stored deflate blocks
+    fn png_image_data(png : &[u8]) -> Vec<u8> {
+        let mut offset = 8;
+        let mut zlib = Vec::new();
+        while offset < png.len() {
+            let length = u32::from_be_bytes(png[offset..offset + 4].try_into().unwrap()) as usize;
+            let chunk = &png[offset + 4..offset + 8 + length];
+            let crc = u32::from_be_bytes(png[offset + 8 + length..offset + 12 + length].try_into().unwrap());
+            assert_eq!(crc32_update(0xffff_ffff, chunk) ^ 0xffff_ffff, crc);
+            if &chunk[..4] == b"IDAT" {
+                zlib.extend_from_slice(&chunk[4..]);
+            }
+            offset += 12 + length;
+        }
+
+        let mut data = Vec::new();
+        let mut i = 2;
+        loop {
+            let last = zlib[i] == 1;
+            let len = u16::from_le_bytes([zlib[i + 1], zlib[i + 2]]) as usize;
+            assert_eq!(!len as u16, u16::from_le_bytes([zlib[i + 3], zlib[i + 4]]));
+            data.extend_from_slice(&zlib[i + 5..i + 5 + len]);
+            i += 5 + len;
+            if last {
+                break;
+            }
+        }
+        data
+    }
+
+    #[test]
+    fn test_png_round_trip() {
+        let (width, height) = (300, 120);
+        let rgb : Vec<u8> = (0..width * height * 3).map(|i| (i * 7 % 256) as u8).collect();
+        let mut png = Vec::new();
+        write_png(&mut png, &rgb, width, height).unwrap();
+        assert_eq!(&png[..8], &[0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n']);
+        // The chunk closing the file always has the same checksum
+        assert_eq!(&png[png.len() - 4..], &[0xae, 0x42, 0x60, 0x82]);
+
+        // More than one deflate block is needed for the image
+        let data = png_image_data(&png);
+        assert_eq!(data.len(), height * (width * 3 + 1));
+        for y in 0..height {
+            let row = &data[y * (width * 3 + 1)..(y + 1) * (width * 3 + 1)];
+            assert_eq!(row[0], 0);
+            assert_eq!(&row[1..], &rgb[y * width * 3..(y + 1) * width * 3]);
+        }
+    }
+
+    #[test]
+    fn test_writers_reject_pixels_not_matching_the_resolution() {
+        let rgb = [0u8; 3 * 5];
+        assert!(write_ppm(&mut Vec::new(), &rgb, 2, 3).is_err());
+        assert!(write_png(&mut Vec::new(), &rgb, 2, 3).is_err());
+        assert!(write_pfm(&mut Vec::new(), &[[0.0; 3]; 7], 2, 3).is_err());
+        assert!(write_ppm(&mut Vec::new(), &rgb, 5, 1).is_ok());
+    }
+
+    #[test]
+    fn test_half_conversion() {
+        assert_eq!(f32_to_half(0.0), 0x0000);
+        assert_eq!(f32_to_half(-0.0), 0x8000);
+        assert_eq!(f32_to_half(1.0), 0x3c00);
+        assert_eq!(f32_to_half(-2.0), 0xc000);
+        assert_eq!(f32_to_half(0.5), 0x3800);
+        assert_eq!(f32_to_half(65504.0), 0x7bff);
+        assert_eq!(f32_to_half(1e6), 0x7c00);
+        assert_eq!(f32_to_half(f32::INFINITY), 0x7c00);
+        assert_eq!(f32_to_half(f32::NAN) & 0x7c00, 0x7c00);
+        assert_ne!(f32_to_half(f32::NAN) & 0x3ff, 0);
+        // Smallest subnormal and rounding to the nearest representable value
+        assert_eq!(f32_to_half(5.960_464_5e-8), 0x0001);
+        assert_eq!(f32_to_half(1.0 + 1.0 / 2048.0), 0x3c00);
+        assert_eq!(f32_to_half(1.0 + 3.0 / 2048.0), 0x3c02);
+    }
+
+    #[test]
+    fn test_exr_layout() {
+        let (width, height) = (3, 2);
+        let r = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let a = [0.5; 6];
+        let mut exr = Vec::new();
+        write_exr(&mut exr, width, height, &[("R", &r), ("A", &a)], ExrPixelType::Float).unwrap();
+        assert_eq!(&exr[..8], &[0x76, 0x2f, 0x31, 0x01, 2, 0, 0, 0]);
+
+        // The channels are sorted by name
+        let channels = exr.windows(3).position(|w| w == b"A\0\x02").unwrap();
+        assert!(exr.windows(3).position(|w| w == b"R\0\x02").unwrap() > channels);
+
+        // Each scanline holds its y, its size and the planes of A then R
+        let header_end = exr.len() - height * (8 + 2 * width * 4) - height * 8;
+        let offset = u64::from_le_bytes(exr[header_end + 8..header_end + 16].try_into().unwrap()) as usize;
+        assert_eq!(i32::from_le_bytes(exr[offset..offset + 4].try_into().unwrap()), 1);
+        assert_eq!(i32::from_le_bytes(exr[offset + 4..offset + 8].try_into().unwrap()), 24);
+        let value = |i : usize| f32::from_le_bytes(exr[offset + 8 + 4 * i..offset + 12 + 4 * i].try_into().unwrap());
+        assert_eq!((value(0), value(3), value(4), value(5)), (0.5, 4.0, 5.0, 6.0));
+    }
+
+    #[test]
+    fn test_ldr_encoding() {
+        let srgb = LdrEncoding::default();
+        assert_eq!(srgb.encode(0.0), 0);
+        assert_eq!(srgb.encode(1.0), 255);
+        assert_eq!(srgb.encode(8.0), 255);
+        assert_eq!(srgb.encode(0.22), 129);
+
+        let exposed = LdrEncoding{ exposure: 1.0, gamma: Some(1.0) };
+        assert_eq!(exposed.encode(0.25), 128);
+    }
+
+    #[test]
+    fn test_write_image_by_extension() {
+        let dir = std::env::temp_dir();
+        let rgb = vec![[0.25, 0.5, 1.0], [2.0, 0.0, 0.125]];
+        let pfm = dir.join("image_io_test.pfm");
+        write_image(&pfm, &rgb, 1, 2, &LdrEncoding::default()).unwrap();
+        let bytes = std::fs::read(&pfm).unwrap();
+        assert!(bytes.starts_with(b"PF\n1 2\n-1\n"));
+        // The bottom row comes first, with the unscaled linear values
+        assert_eq!(f32::from_le_bytes(bytes[10..14].try_into().unwrap()), 2.0);
+
+        let ppm = dir.join("image_io_test.PPM");
+        write_image(&ppm, &rgb, 2, 1, &LdrEncoding::default()).unwrap();
+        assert_eq!(std::fs::read(&ppm).unwrap(), b"P6\n2 1\n255\n\x89\xbc\xff\xff\x00\x63".to_vec());
+
+        assert!(write_image(&dir.join("image_io_test.tga"), &rgb, 1, 2, &LdrEncoding::default()).is_err());
+        assert!(write_image(&pfm, &rgb, 2, 2, &LdrEncoding::default()).is_err());
+        std::fs::remove_file(pfm).unwrap();
+        std::fs::remove_file(ppm).unwrap();
+    }
+}
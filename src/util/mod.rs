@@ -1,3 +1,6 @@
+pub mod image_io;
+pub mod tone_mapping;
+
 use crate::engine::Point2i;
 
 // Break an image into a sequence of tiles to be processes on multi cores
@@ -5,3 +8,15 @@ pub fn parallel_for_2d(func : fn(tile : Point2i) -> i32, n_tiles : i32) -> i32 {
     n_tiles
 }
 
+// Run func on every item of the slice, splitting the items between all the available cores
+pub fn parallel_for<T : Send>(items : &mut [T], func : impl Fn(&mut T) + Sync) {
+    let n_threads = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+    let chunk_size = items.len().div_ceil(n_threads).max(1);
+
+    std::thread::scope(|scope| {
+        for chunk in items.chunks_mut(chunk_size) {
+            let func = &func;
+            scope.spawn(move || chunk.iter_mut().for_each(func));
+        }
+    });
+}
@@ -0,0 +1,341 @@
+use crate::util::image_io::gamma_correct;
+
+pub(crate) type Matrix3 = [[f32; 3]; 3];
+
+// Conversions between linear sRGB (D65) and CIE XYZ, shared with the spectra
+pub(crate) const SRGB_TO_XYZ : Matrix3 = [
+    [0.4124564, 0.3575761, 0.1804375],
+    [0.2126729, 0.7151522, 0.0721750],
+    [0.0193339, 0.119192, 0.9503041],
+];
+pub(crate) const XYZ_TO_SRGB : Matrix3 = [
+    [3.2404542, -1.5371385, -0.4985314],
+    [-0.969266, 1.8760108, 0.0415560],
+    [0.0556434, -0.2040259, 1.0572252],
+];
+// Cone responses used for the chromatic adaptation of the white balance
+const XYZ_TO_BRADFORD : Matrix3 = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+const BRADFORD_TO_XYZ : Matrix3 = [
+    [0.9869929, -0.1470543, 0.1599627],
+    [0.4323053, 0.5183603, 0.0492912],
+    [-0.0085287, 0.0400428, 0.9684867],
+];
+const SRGB_TO_DISPLAY_P3 : Matrix3 = [
+    [0.8224621, 0.177538, 0.0],
+    [0.0331941, 0.9668058, 0.0],
+    [0.0170827, 0.0723974, 0.9105199],
+];
+const SRGB_TO_REC2020 : Matrix3 = [
+    [0.627404, 0.329282, 0.0433136],
+    [0.0690970, 0.91954, 0.0113612],
+    [0.0163916, 0.0880132, 0.895595],
+];
+// Chromaticity of D65, the white point of every output color space
+const D65_XY : (f32, f32) = (0.31271, 0.32902);
+
+/// The curve compressing the scene radiance into the displayable range
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ToneCurve{
+    // Values above 1 are clipped
+    Linear,
+    Reinhard,
+    // Reinhard reaching 1 at the `white` value instead of at infinity
+    ReinhardExtended{ white : f32 },
+    // John Hable's filmic curve from Uncharted 2
+    Hable,
+    // Stephen Hill's fit of the ACES reference rendering and sRGB output transforms
+    AcesFilmic,
+    // Troy Sobotka's AgX, desaturating bright values smoothly instead of skewing their hue
+    AgX,
+}
+
+/// Primaries and transfer function of the display the image is made for, all with a D65 white point
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputColorSpace{
+    Srgb,
+    // The primaries of DCI-P3 with the sRGB transfer function
+    DisplayP3,
+    // The ITU-R BT.2020 primaries and transfer function
+    Rec2020,
+}
+
+impl OutputColorSpace{
+    fn srgb_to_output(&self) -> Option<&'static Matrix3> {
+        match self {
+            OutputColorSpace::Srgb => None,
+            OutputColorSpace::DisplayP3 => Some(&SRGB_TO_DISPLAY_P3),
+            OutputColorSpace::Rec2020 => Some(&SRGB_TO_REC2020),
+        }
+    }
+
+    /// Encode a linear value of the display in [0, 1]
+    pub fn transfer(&self, v : f32) -> f32 {
+        match self {
+            OutputColorSpace::Srgb | OutputColorSpace::DisplayP3 => gamma_correct(v),
+            OutputColorSpace::Rec2020 => {
+                if v < 0.018053968 { 4.5 * v } else { 1.0992968 * v.powf(0.45) - 0.0992968 }
+            }
+        }
+    }
+}
+
+/// The display transform turning the linear sRGB radiance of the film into encoded display values:
+/// white balance, exposure, tone curve, then conversion to the primaries and transfer function of the output
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ToneMapping{
+    // Scale of the radiance in stops, the values are multiplied by 2^exposure before the tone curve
+    pub exposure : f32,
+    pub curve : ToneCurve,
+    pub color_space : OutputColorSpace,
+    // Adaptation of the scene white to the white of the display, None keeps the colors as they are
+    white_balance : Option<Matrix3>,
+}
+
+impl Default for ToneMapping{
+    fn default() -> Self {
+        Self::new(ToneCurve::Linear, OutputColorSpace::Srgb)
+    }
+}
+
+impl ToneMapping{
+    pub fn new(curve : ToneCurve, color_space : OutputColorSpace) -> ToneMapping{
+        Self{
+            exposure: 0.0,
+            curve,
+            color_space,
+            white_balance: None,
+        }
+    }
+
+    pub fn with_exposure(mut self, exposure : f32) -> ToneMapping{
+        self.exposure = exposure;
+        self
+    }
+
+    /// Neutralize the tint of a scene lit by a light of the color temperature `temperature` in kelvins,
+    /// so that its white appears white on the display
+    pub fn with_white_balance(mut self, temperature : f32) -> ToneMapping{
+        let source = xy_to_bradford(color_temperature_to_xy(temperature));
+        let target = xy_to_bradford(D65_XY);
+        let mut scale = [[0.0; 3]; 3];
+        for c in 0..3 {
+            scale[c][c] = target[c] / source[c];
+        }
+        let adaptation = mul_matrix(&BRADFORD_TO_XYZ, &mul_matrix(&scale, &XYZ_TO_BRADFORD));
+        self.white_balance = Some(mul_matrix(&XYZ_TO_SRGB, &mul_matrix(&adaptation, &SRGB_TO_XYZ)));
+        self
+    }
+
+    /// Display values in [0, 1] of a linear sRGB radiance
+    pub fn apply(&self, rgb : [f32; 3]) -> [f32; 3] {
+        let mut rgb = rgb.map(|v| v.max(0.0));
+        if let Some(white_balance) = &self.white_balance {
+            rgb = mul_vector(white_balance, rgb).map(|v| v.max(0.0));
+        }
+        let scale = self.exposure.exp2();
+        let mapped = apply_curve(self.curve, rgb.map(|v| v * scale));
+
+        let display = match self.color_space.srgb_to_output() {
+            Some(m) => mul_vector(m, mapped),
+            None => mapped,
+        };
+        display.map(|v| self.color_space.transfer(v.clamp(0.0, 1.0)))
+    }
+
+    /// 8 bits display values of a linear sRGB radiance
+    pub fn encode(&self, rgb : [f32; 3]) -> [u8; 3] {
+        self.apply(rgb).map(|v| (v * 255.0 + 0.5).clamp(0.0, 255.0) as u8)
+    }
+}
+
+fn apply_curve(curve : ToneCurve, rgb : [f32; 3]) -> [f32; 3] {
+    match curve {
+        ToneCurve::Linear => rgb,
+        ToneCurve::Reinhard => rgb.map(|v| v / (1.0 + v)),
+        ToneCurve::ReinhardExtended{ white } => rgb.map(|v| v * (1.0 + v / (white * white)) / (1.0 + v)),
+        ToneCurve::Hable => {
+            const EXPOSURE_BIAS : f32 = 2.0;
+            const WHITE : f32 = 11.2;
+            let white_scale = 1.0 / hable_partial(WHITE);
+            rgb.map(|v| hable_partial(v * EXPOSURE_BIAS) * white_scale)
+        }
+        ToneCurve::AcesFilmic => {
+            const INPUT : Matrix3 = [
+                [0.59719, 0.35458, 0.04823],
+                [0.07600, 0.90834, 0.01566],
+                [0.02840, 0.13383, 0.83777],
+            ];
+            const OUTPUT : Matrix3 = [
+                [1.60475, -0.53108, -0.07367],
+                [-0.10208, 1.10813, -0.00605],
+                [-0.00327, -0.07276, 1.07602],
+            ];
+            let fitted = mul_vector(&INPUT, rgb).map(|v| {
+                let a = v * (v + 0.0245786) - 0.000090537;
+                let b = v * (0.983729 * v + 0.432951) + 0.238081;
+                a / b
+            });
+            mul_vector(&OUTPUT, fitted)
+        }
+        ToneCurve::AgX => {
+            const INSET : Matrix3 = [
+                [0.84247905, 0.0784336, 0.079223745],
+                [0.042328242, 0.87846863, 0.07916613],
+                [0.042375654, 0.0784336, 0.879143],
+            ];
+            const OUTSET : Matrix3 = [
+                [1.196879, -0.09802088, -0.09902974],
+                [-0.052896854, 1.1519032, -0.098961174],
+                [-0.052971635, -0.09804345, 1.1510737],
+            ];
+            const MIN_EV : f32 = -12.47393;
+            const MAX_EV : f32 = 4.026069;
+            let contrast = mul_vector(&INSET, rgb).map(|v| {
+                let x = ((v.max(1e-10).log2() - MIN_EV) / (MAX_EV - MIN_EV)).clamp(0.0, 1.0);
+                // Polynomial fit of the AgX sigmoid
+                let x2 = x * x;
+                let x4 = x2 * x2;
+                15.5 * x4 * x2 - 40.14 * x4 * x + 31.96 * x4 - 6.868 * x2 * x + 0.4298 * x2 + 0.1191 * x - 0.00232
+            });
+            // The sigmoid outputs values encoded for a 2.2 gamma display
+            mul_vector(&OUTSET, contrast).map(|v| v.max(0.0).powf(2.2))
+        }
+    }
+}
+
+fn hable_partial(x : f32) -> f32 {
+    const A : f32 = 0.15;
+    const B : f32 = 0.50;
+    const C : f32 = 0.10;
+    const D : f32 = 0.20;
+    const E : f32 = 0.02;
+    const F : f32 = 0.30;
+    (x * (A * x + C * B) + D * E) / (x * (A * x + B) + D * F) - E / F
+}
+
+/// Chromaticity of the white of a color temperature, following the CIE daylight locus from 4000K
+/// and the Planckian locus below it
+pub fn color_temperature_to_xy(temperature : f32) -> (f32, f32) {
+    let t = temperature.clamp(1667.0, 25000.0) as f64;
+    let (t2, t3) = (t * t, t * t * t);
+    if t >= 4000.0 {
+        let x = if t <= 7000.0 {
+            0.244063 + 0.09911e3 / t + 2.9678e6 / t2 - 4.6070e9 / t3
+        } else {
+            0.237040 + 0.24748e3 / t + 1.9018e6 / t2 - 2.0064e9 / t3
+        };
+        let y = -3.0 * x * x + 2.87 * x - 0.275;
+        return (x as f32, y as f32);
+    }
+
+    let x = -0.2661239e9 / t3 - 0.2343589e6 / t2 + 0.8776956e3 / t + 0.179910;
+    let y = if t <= 2222.0 {
+        -1.1063814 * x * x * x - 1.34811020 * x * x + 2.18555832 * x - 0.20219683
+    } else {
+        -0.9549476 * x * x * x - 1.37418593 * x * x + 2.09137015 * x - 0.16748867
+    };
+    (x as f32, y as f32)
+}
+
+fn xy_to_bradford((x, y) : (f32, f32)) -> [f32; 3] {
+    mul_vector(&XYZ_TO_BRADFORD, [x / y, 1.0, (1.0 - x - y) / y])
+}
+
+pub(crate) fn mul_vector(m : &Matrix3, v : [f32; 3]) -> [f32; 3] {
+    m.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+fn mul_matrix(a : &Matrix3, b : &Matrix3) -> Matrix3 {
+    let mut m = [[0.0; 3]; 3];
+    for (i, row) in m.iter_mut().enumerate() {
+        for (j, v) in row.iter_mut().enumerate() {
+            *v = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    m
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CURVES : [ToneCurve; 6] = [
+        ToneCurve::Linear, ToneCurve::Reinhard, ToneCurve::ReinhardExtended{ white: 4.0 },
+        ToneCurve::Hable, ToneCurve::AcesFilmic, ToneCurve::AgX
+    ];
+
+    #[test]
+    fn test_curves_are_monotonic_and_bounded() {
+        for curve in CURVES {
+            let tone_mapping = ToneMapping::new(curve, OutputColorSpace::Srgb);
+            let black = tone_mapping.apply([0.0; 3]);
+            assert!(black.iter().all(|&v| v < 0.01), "{:?} {:?}", curve, black);
+
+            let mut previous = black[1];
+            for i in 1..=100 {
+                let v = tone_mapping.apply([0.05 * i as f32; 3])[1];
+                assert!(v >= previous - 1e-6 && v <= 1.0, "{:?} {} {}", curve, previous, v);
+                previous = v;
+            }
+            if curve != ToneCurve::Linear {
+                // Grey stays grey and the highlights are compressed instead of clipped
+                let grey = tone_mapping.apply([0.5; 3]);
+                assert!((grey[0] - grey[2]).abs() < 1e-3, "{:?} {:?}", curve, grey);
+                assert!(tone_mapping.apply([2.0; 3])[1] < tone_mapping.apply([4.0; 3])[1], "{:?}", curve);
+            }
+        }
+
+        let extended = ToneMapping::new(ToneCurve::ReinhardExtended{ white: 4.0 }, OutputColorSpace::Srgb);
+        assert!((extended.apply([4.0; 3])[0] - 1.0).abs() < 1e-5);
+        assert_eq!(ToneMapping::default().encode([0.5, 1.0, 8.0]), [188, 255, 255]);
+    }
+
+    #[test]
+    fn test_exposure_scales_in_stops() {
+        let base = ToneMapping::default();
+        let brighter = ToneMapping::default().with_exposure(1.0);
+        assert_eq!(brighter.apply([0.1; 3]), base.apply([0.2; 3]));
+    }
+
+    #[test]
+    fn test_white_balance_neutralizes_the_light() {
+        let (x, y) = color_temperature_to_xy(6504.0);
+        assert!((x - D65_XY.0).abs() < 1e-3 && (y - D65_XY.1).abs() < 1e-3);
+
+        // The white balance for D65 leaves the colors unchanged
+        let neutral = ToneMapping::default().with_white_balance(6504.0);
+        let color = [0.2, 0.4, 0.1];
+        for (a, b) in neutral.apply(color).iter().zip(ToneMapping::default().apply(color)) {
+            assert!((a - b).abs() < 5e-3);
+        }
+
+        // A warm light is cooled down: its own white becomes neutral
+        let tungsten = ToneMapping::default().with_white_balance(3000.0);
+        let (x, y) = color_temperature_to_xy(3000.0);
+        let xyz = [x / y, 1.0, (1.0 - x - y) / y].map(|v| v * 0.2);
+        let light = mul_vector(&XYZ_TO_SRGB, xyz);
+        assert!(light[0] > light[2]);
+        let balanced = tungsten.apply(light);
+        assert!((balanced[0] - balanced[2]).abs() < 0.01 && (balanced[0] - balanced[1]).abs() < 0.01, "{:?}", balanced);
+    }
+
+    #[test]
+    fn test_output_color_spaces() {
+        for color_space in [OutputColorSpace::Srgb, OutputColorSpace::DisplayP3, OutputColorSpace::Rec2020] {
+            let tone_mapping = ToneMapping::new(ToneCurve::Linear, color_space);
+            let white = tone_mapping.apply([1.0; 3]);
+            assert!(white.iter().all(|&v| (v - 1.0).abs() < 1e-3), "{:?} {:?}", color_space, white);
+            assert_eq!(tone_mapping.apply([0.0; 3]), [0.0; 3]);
+        }
+
+        // sRGB red is inside of the wider gamuts, so it is less saturated there
+        let red = |color_space| ToneMapping::new(ToneCurve::Linear, color_space).apply([1.0, 0.0, 0.0]);
+        assert!(red(OutputColorSpace::DisplayP3)[1] > 0.0);
+        assert!(red(OutputColorSpace::Rec2020)[1] > red(OutputColorSpace::DisplayP3)[1]);
+        assert!((OutputColorSpace::Rec2020.transfer(0.01) - 0.045).abs() < 1e-6);
+    }
+}